@@ -2,6 +2,7 @@
 //!
 //! Opens the Cockpit web console in the default browser.
 
+use crate::output::normalize_bind_addr;
 use anyhow::{Result, bail};
 use clap::Args;
 use console::style;
@@ -50,11 +51,7 @@ pub async fn cmd_cockpit(_args: &CockpitArgs, maybe_host: Option<&str>, quiet: b
     let running = container_is_running(&client, CONTAINER_NAME).await?;
     if !running {
         // For 0.0.0.0 or :: bind addresses, use localhost for display
-        let display_addr = if config.bind_address == "0.0.0.0" || config.bind_address == "::" {
-            "127.0.0.1"
-        } else {
-            &config.bind_address
-        };
+        let display_addr = normalize_bind_addr(&config.bind_address);
         bail!(
             "{}\n\n\
              The container is not running. Cockpit runs inside the container.\n\n\
@@ -68,11 +65,7 @@ pub async fn cmd_cockpit(_args: &CockpitArgs, maybe_host: Option<&str>, quiet: b
 
     // Build URL
     // For 0.0.0.0 or :: bind addresses, use localhost for browser
-    let browser_addr = if config.bind_address == "0.0.0.0" || config.bind_address == "::" {
-        "127.0.0.1"
-    } else {
-        &config.bind_address
-    };
+    let browser_addr = normalize_bind_addr(&config.bind_address);
     let url = format!("http://{}:{}", browser_addr, config.cockpit_port);
 
     if !quiet {
@@ -0,0 +1,104 @@
+//! Config backup and restore subcommands
+//!
+//! Snapshots the config file to a timestamped copy in the config directory
+//! and restores from those snapshots.
+
+use anyhow::{Result, anyhow};
+use console::style;
+use opencode_cloud_core::config::{
+    ConfigSnapshot, create_config_snapshot, find_config_snapshot, get_config_path,
+    list_config_snapshots, record_config_changes, restore_config_snapshot, snapshot_timestamp_now,
+};
+use opencode_cloud_core::load_config;
+
+/// Snapshot the current config file
+pub fn cmd_config_backup(quiet: bool) -> Result<()> {
+    let config_path =
+        get_config_path().ok_or_else(|| anyhow!("Could not determine config file path"))?;
+    let snapshot_path = create_config_snapshot(&config_path, &snapshot_timestamp_now())?;
+
+    if !quiet {
+        println!(
+            "{} Config backed up to {}",
+            style("Success:").green().bold(),
+            snapshot_path.display()
+        );
+    }
+    Ok(())
+}
+
+/// List snapshots, or restore the one matching `timestamp`
+///
+/// The current config is snapshotted before it is replaced, so a restore
+/// can itself be undone.
+pub fn cmd_config_restore(timestamp: Option<&str>, list: bool, quiet: bool) -> Result<()> {
+    let config_path =
+        get_config_path().ok_or_else(|| anyhow!("Could not determine config file path"))?;
+    let snapshots = list_config_snapshots(&config_path)?;
+
+    let Some(timestamp) = timestamp.filter(|_| !list) else {
+        print_snapshots(&snapshots);
+        return Ok(());
+    };
+
+    let snapshot = find_config_snapshot(&snapshots, timestamp).map_err(|e| anyhow!(e))?;
+
+    let previous = load_config().ok();
+    let backup = if config_path.exists() {
+        Some(create_config_snapshot(
+            &config_path,
+            &snapshot_timestamp_now(),
+        )?)
+    } else {
+        None
+    };
+    let restored = restore_config_snapshot(&config_path, snapshot)?;
+    if let Some(previous) = previous {
+        record_config_changes(&previous, &restored);
+    }
+
+    if !quiet {
+        println!(
+            "{} Restored config from snapshot {}",
+            style("Success:").green().bold(),
+            snapshot.timestamp
+        );
+        if let Some(backup) = backup {
+            println!(
+                "  {} Previous config saved to {}",
+                style("Note:").dim(),
+                backup.display()
+            );
+        }
+        println!(
+            "  {} Run {} to apply the restored config.",
+            style("Tip:").dim(),
+            style("occ restart").yellow()
+        );
+    }
+    Ok(())
+}
+
+fn print_snapshots(snapshots: &[ConfigSnapshot]) {
+    if snapshots.is_empty() {
+        println!(
+            "No config snapshots. Create one with {}.",
+            style("occ config backup").yellow()
+        );
+        return;
+    }
+
+    println!("{}", style("Config snapshots (oldest first):").bold());
+    for snapshot in snapshots {
+        println!(
+            "  {}  {}",
+            style(&snapshot.timestamp).cyan(),
+            style(snapshot.path.display()).dim()
+        );
+    }
+    println!();
+    println!(
+        "Restore with {}",
+        style("occ config restore <timestamp>").yellow()
+    );
+}
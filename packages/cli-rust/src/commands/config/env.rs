@@ -1,10 +1,11 @@
 //! Config env subcommand
 //!
-//! Manages container environment variables.
+//! Manages container environment variables stored in `container_env`.
 
-use anyhow::{Result, bail};
+use anyhow::{Result, anyhow, bail};
 use clap::Subcommand;
 use console::style;
+use opencode_cloud_core::config::parse_env_entry;
 use opencode_cloud_core::{load_config, save_config};
 
 /// Environment variable management subcommands
@@ -18,6 +19,7 @@ pub enum EnvCommands {
     /// List all configured environment variables
     List,
     /// Remove an environment variable
+    #[command(visible_alias = "unset")]
     Remove {
         /// Environment variable key to remove
         key: String,
@@ -37,28 +39,8 @@ pub fn cmd_config_env(cmd: EnvCommands, quiet: bool) -> Result<()> {
 
 /// Set or update an environment variable
 fn cmd_env_set(env_var: &str, quiet: bool) -> Result<()> {
-    // Validate format: must contain '='
-    let Some(eq_pos) = env_var.find('=') else {
-        bail!("Format must be KEY=value\n\nExample: occ config env set FOO=bar");
-    };
-
-    // Extract key (before first '=')
-    let key = &env_var[..eq_pos];
-    if key.is_empty() {
-        bail!("Environment variable key cannot be empty");
-    }
-
-    // Load config
     let mut config = load_config()?;
-
-    // Remove any existing entry with the same key
-    let key_prefix = format!("{key}=");
-    config.container_env.retain(|e| !e.starts_with(&key_prefix));
-
-    // Add new entry
-    config.container_env.push(env_var.to_string());
-
-    // Save config
+    let key = set_env_entry(&mut config.container_env, env_var)?;
     save_config(&config)?;
 
     if !quiet {
@@ -73,23 +55,27 @@ fn cmd_env_set(env_var: &str, quiet: bool) -> Result<()> {
 }
 
 /// List all configured environment variables
+///
+/// Shows the value the container will actually get for each key (the last
+/// entry wins when a file edit left duplicates).
 fn cmd_env_list(quiet: bool) -> Result<()> {
     let config = load_config()?;
+    let entries = effective_env(&config.container_env);
 
-    if config.container_env.is_empty() {
+    if entries.is_empty() {
         if !quiet {
             println!("(no environment variables configured)");
         }
         return Ok(());
     }
 
-    for env_var in &config.container_env {
+    for env_var in &entries {
         println!("  {env_var}");
     }
 
     if !quiet {
         println!();
-        println!("{} environment variable(s)", config.container_env.len());
+        println!("{} environment variable(s)", entries.len());
     }
 
     Ok(())
@@ -99,21 +85,10 @@ fn cmd_env_list(quiet: bool) -> Result<()> {
 fn cmd_env_remove(key: &str, quiet: bool) -> Result<()> {
     let mut config = load_config()?;
 
-    // Check if any entry matches
-    let key_prefix = format!("{key}=");
-    let found = config
-        .container_env
-        .iter()
-        .any(|e| e.starts_with(&key_prefix));
-
-    if !found {
+    if !remove_env_key(&mut config.container_env, key) {
         bail!("Environment variable not found: {key}");
     }
 
-    // Remove matching entry
-    config.container_env.retain(|e| !e.starts_with(&key_prefix));
-
-    // Save config
     save_config(&config)?;
 
     if !quiet {
@@ -127,33 +102,104 @@ fn cmd_env_remove(key: &str, quiet: bool) -> Result<()> {
     Ok(())
 }
 
+/// Validate `env_var` and store it, replacing every entry with the same key
+///
+/// Returns the key.
+fn set_env_entry<'a>(env: &mut Vec<String>, env_var: &'a str) -> Result<&'a str> {
+    if !env_var.contains('=') {
+        bail!("Format must be KEY=value\n\nExample: occ config env set FOO=bar");
+    }
+    let key = parse_env_entry(env_var).map_err(|msg| anyhow!("{msg}"))?;
+
+    remove_env_key(env, key);
+    env.push(env_var.to_string());
+    Ok(key)
+}
+
+/// Remove every entry for `key`, returning whether any was found
+///
+/// A malformed entry without '=' matches exactly, so it can be cleaned up
+/// with the same command.
+fn remove_env_key(env: &mut Vec<String>, key: &str) -> bool {
+    let key_prefix = format!("{key}=");
+    let before = env.len();
+    env.retain(|e| !e.starts_with(&key_prefix) && e != key);
+    env.len() != before
+}
+
+/// Entries as the container sees them: the last entry per key, in order
+fn effective_env(env: &[String]) -> Vec<&str> {
+    env.iter()
+        .enumerate()
+        .filter(|(i, entry)| {
+            let Ok(key) = parse_env_entry(entry) else {
+                return true;
+            };
+            !env[i + 1..]
+                .iter()
+                .any(|later| parse_env_entry(later).ok() == Some(key))
+        })
+        .map(|(_, entry)| entry.as_str())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    fn env(entries: &[&str]) -> Vec<String> {
+        entries.iter().map(|e| e.to_string()).collect()
+    }
+
+    #[test]
+    fn set_adds_new_entry() {
+        let mut vars = env(&["A=1"]);
+        assert_eq!(set_env_entry(&mut vars, "B=2").unwrap(), "B");
+        assert_eq!(vars, env(&["A=1", "B=2"]));
+    }
+
+    #[test]
+    fn set_replaces_and_dedups_existing_key() {
+        let mut vars = env(&["FOO=1", "BAR=x", "FOO=2"]);
+        set_env_entry(&mut vars, "FOO=bar=baz").unwrap();
+        assert_eq!(vars, env(&["BAR=x", "FOO=bar=baz"]));
+    }
+
+    #[test]
+    fn set_does_not_touch_prefix_keys() {
+        let mut vars = env(&["FOO_BAR=1"]);
+        set_env_entry(&mut vars, "FOO=2").unwrap();
+        assert_eq!(vars, env(&["FOO_BAR=1", "FOO=2"]));
+    }
+
+    #[test]
+    fn set_rejects_invalid_entries() {
+        let mut vars = Vec::new();
+        for bad in ["FOObar", "=value", "1FOO=x", "FOO-BAR=x"] {
+            assert!(set_env_entry(&mut vars, bad).is_err(), "{bad}");
+        }
+        assert!(vars.is_empty());
+    }
+
     #[test]
-    fn test_key_extraction_from_env_var() {
-        let env_var = "FOO=bar";
-        let eq_pos = env_var.find('=').unwrap();
-        let key = &env_var[..eq_pos];
-        assert_eq!(key, "FOO");
+    fn unset_removes_all_entries_for_key() {
+        let mut vars = env(&["FOO=1", "BAR=x", "FOO=2"]);
+        assert!(remove_env_key(&mut vars, "FOO"));
+        assert_eq!(vars, env(&["BAR=x"]));
+        assert!(!remove_env_key(&mut vars, "FOO"));
     }
 
     #[test]
-    fn test_key_extraction_with_equals_in_value() {
-        let env_var = "FOO=bar=baz";
-        let eq_pos = env_var.find('=').unwrap();
-        let key = &env_var[..eq_pos];
-        assert_eq!(key, "FOO");
+    fn unset_removes_malformed_entry() {
+        let mut vars = env(&["FOObar", "A=1"]);
+        assert!(remove_env_key(&mut vars, "FOObar"));
+        assert_eq!(vars, env(&["A=1"]));
     }
 
     #[test]
-    fn test_key_prefix_matching() {
-        let entries = ["FOO=bar".to_string(), "BAR=baz".to_string()];
-        let key_prefix = "FOO=";
-        let remaining: Vec<_> = entries
-            .iter()
-            .filter(|e| !e.starts_with(key_prefix))
-            .collect();
-        assert_eq!(remaining.len(), 1);
-        assert_eq!(remaining[0], "BAR=baz");
+    fn list_shows_last_value_per_key() {
+        let vars = env(&["FOO=1", "BAR=x", "FOO=2", "broken"]);
+        assert_eq!(effective_env(&vars), vec!["BAR=x", "FOO=2", "broken"]);
+        assert!(effective_env(&[]).is_empty());
     }
 }
@@ -1,6 +1,7 @@
 //! Config get subcommand
 //!
 //! Retrieves a single configuration value by key.
+//! List fields also accept an index, e.g. `users.0` or `container_env.1`.
 
 use anyhow::{Result, bail};
 use opencode_cloud_core::Config;
@@ -8,8 +9,15 @@ use opencode_cloud_core::Config;
 /// Get a single configuration value
 ///
 /// Outputs just the value (no formatting) for scripting.
-/// Passwords are always masked for security.
+/// Passwords are never printed: `password` reports only `set`/`not set`,
+/// and `auth` summarizes the effective authentication state.
 pub fn cmd_config_get(config: &Config, key: &str, _quiet: bool) -> Result<()> {
+    // Indexed access into list fields (e.g. "users.0")
+    if let Some((field, index)) = key.split_once('.') {
+        println!("{}", get_list_element(config, field, index)?);
+        return Ok(());
+    }
+
     // Normalize key (support both short and full forms)
     let value = match key.to_lowercase().as_str() {
         "version" => config.version.to_string(),
@@ -18,16 +26,12 @@ pub fn cmd_config_get(config: &Config, key: &str, _quiet: bool) -> Result<()> {
         "bind_address" | "host" => config.bind_address.clone(),
         "auto_restart" => config.auto_restart.to_string(),
         "boot_mode" => config.boot_mode.clone(),
+        "update_check" => config.update_check.clone(),
         "restart_retries" => config.restart_retries.to_string(),
         "restart_delay" => config.restart_delay.to_string(),
         "username" | "auth_username" => format_optional(&config.auth_username),
-        "password" | "auth_password" => {
-            // Never reveal actual password
-            match &config.auth_password {
-                Some(s) if !s.is_empty() => "********".to_string(),
-                _ => String::new(),
-            }
-        }
+        "password" | "auth_password" => password_status(config).to_string(),
+        "auth" => auth_summary(config),
         "env" | "container_env" => {
             // Output as JSON array for scripting
             serde_json::to_string(&config.container_env)?
@@ -40,6 +44,7 @@ pub fn cmd_config_get(config: &Config, key: &str, _quiet: bool) -> Result<()> {
         "rate_limit_window_seconds" | "rate_window" | "rate_limit_window" => {
             config.rate_limit_window_seconds.to_string()
         }
+        "mounts" => serde_json::to_string(&config.mounts)?,
         "users" => {
             if config.users.is_empty() {
                 "(none)".to_string()
@@ -49,6 +54,29 @@ pub fn cmd_config_get(config: &Config, key: &str, _quiet: bool) -> Result<()> {
         }
         "cockpit_enabled" | "cockpit" => config.cockpit_enabled.to_string(),
         "cockpit_port" => config.cockpit_port.to_string(),
+        "health_check_enabled" | "health_check" => config.health_check_enabled.to_string(),
+        "health_check_interval" => config.health_check_interval.to_string(),
+        "health_check_timeout" => config.health_check_timeout.to_string(),
+        "health_check_retries" => config.health_check_retries.to_string(),
+        "audit_log_enabled" | "audit_log" => config.audit_log_enabled.to_string(),
+        "timezone" | "tz" => format_optional(&config.timezone),
+        "locale" => format_optional(&config.locale),
+        "memory_limit_mb" | "memory" => {
+            format_optional(&config.memory_limit_mb.map(|mb| mb.to_string()))
+        }
+        "cpu_limit" | "cpus" => format_optional(&config.cpu_limit.map(|cpus| cpus.to_string())),
+        // Only the registry; credentials are never printed
+        "image_registry_auth" | "registry_auth" => format_optional(
+            &config
+                .image_registry_auth
+                .as_ref()
+                .map(|auth| auth.split('=').next().unwrap_or_default().to_string()),
+        ),
+        "motd" => format_optional(&config.motd),
+        "base_path" => format_optional(&config.base_path),
+        "startup_timeout_seconds" | "startup_timeout" => {
+            format_optional(&config.startup_timeout_seconds.map(|secs| secs.to_string()))
+        }
         _ => {
             bail!(
                 "Unknown configuration key: {key}\n\n\
@@ -59,18 +87,35 @@ pub fn cmd_config_get(config: &Config, key: &str, _quiet: bool) -> Result<()> {
                   bind_address / host\n  \
                   auto_restart\n  \
                   boot_mode\n  \
+                  update_check\n  \
                   restart_retries\n  \
                   restart_delay\n  \
                   username / auth_username\n  \
                   password / auth_password\n  \
+                  auth\n  \
                   env / container_env\n  \
+                  mounts\n  \
                   trust_proxy / proxy\n  \
                   allow_unauthenticated_network / allow_unauth\n  \
                   rate_limit_attempts / rate_attempts\n  \
                   rate_limit_window_seconds / rate_window\n  \
                   users\n  \
                   cockpit_enabled / cockpit\n  \
-                  cockpit_port"
+                  cockpit_port\n  \
+                  health_check_enabled / health_check\n  \
+                  health_check_interval\n  \
+                  health_check_timeout\n  \
+                  health_check_retries\n  \
+                  audit_log_enabled / audit_log\n  \
+                  timezone / tz\n  \
+                  locale\n  \
+                  memory_limit_mb / memory\n  \
+                  cpu_limit / cpus\n  \
+                  image_registry_auth / registry_auth\n  \
+                  motd\n  \
+                  startup_timeout_seconds / startup_timeout\n  \
+                  base_path\n\n\
+                List fields (users, container_env, mounts) accept an index, e.g. users.0"
             );
         }
     };
@@ -79,6 +124,60 @@ pub fn cmd_config_get(config: &Config, key: &str, _quiet: bool) -> Result<()> {
     Ok(())
 }
 
+/// Get one element of a list field by index (e.g. `users.0`)
+fn get_list_element(config: &Config, field: &str, index: &str) -> Result<String> {
+    let (name, items) = match field.to_lowercase().as_str() {
+        "users" => ("users", &config.users),
+        "env" | "container_env" => ("container_env", &config.container_env),
+        "mounts" => ("mounts", &config.mounts),
+        _ => bail!(
+            "Key '{field}' is not a list and cannot be indexed.\n\
+             Indexable keys: users, container_env, mounts"
+        ),
+    };
+
+    let Ok(position) = index.parse::<usize>() else {
+        bail!("Invalid index '{index}' for {name}: expected a non-negative integer");
+    };
+
+    match items.get(position) {
+        Some(item) => Ok(item.clone()),
+        None => bail!(
+            "Index {position} is out of range for {name} ({} item{})",
+            items.len(),
+            if items.len() == 1 { "" } else { "s" }
+        ),
+    }
+}
+
+/// Whether a legacy password is configured (never the value itself)
+fn password_status(config: &Config) -> &'static str {
+    match &config.auth_password {
+        Some(s) if !s.is_empty() => "set",
+        _ => "not set",
+    }
+}
+
+/// One-line summary of the effective authentication state
+///
+/// PAM users (`occ user add`) take precedence over the legacy
+/// username/password pair, mirroring `Config::has_required_auth`.
+fn auth_summary(config: &Config) -> String {
+    if !config.has_required_auth() {
+        return if config.allow_unauthenticated_network {
+            "not configured (unauthenticated network access allowed)".to_string()
+        } else {
+            "not configured".to_string()
+        };
+    }
+
+    match config.users.len() {
+        0 => "configured (legacy username/password)".to_string(),
+        1 => "configured (1 user)".to_string(),
+        n => format!("configured ({n} users)"),
+    }
+}
+
 /// Format an optional string, returning empty string if None
 fn format_optional(value: &Option<String>) -> String {
     value.clone().unwrap_or_default()
@@ -102,4 +201,109 @@ mod tests {
     fn test_format_optional_with_empty() {
         assert_eq!(format_optional(&Some(String::new())), "");
     }
+
+    #[test]
+    fn test_list_index_access() {
+        let config = Config {
+            users: vec!["alice".to_string(), "bob".to_string()],
+            container_env: vec!["A=1".to_string()],
+            ..Config::default()
+        };
+        assert_eq!(get_list_element(&config, "users", "1").unwrap(), "bob");
+        assert_eq!(get_list_element(&config, "env", "0").unwrap(), "A=1");
+        assert_eq!(
+            get_list_element(&config, "container_env", "0").unwrap(),
+            "A=1"
+        );
+    }
+
+    #[test]
+    fn test_list_index_out_of_range() {
+        let config = Config {
+            users: vec!["alice".to_string()],
+            ..Config::default()
+        };
+        let err = get_list_element(&config, "users", "1").unwrap_err();
+        assert!(err.to_string().contains("out of range for users (1 item)"));
+
+        let err = get_list_element(&config, "mounts", "0").unwrap_err();
+        assert!(err.to_string().contains("(0 items)"));
+    }
+
+    #[test]
+    fn test_list_index_rejects_invalid_index() {
+        let config = Config::default();
+        let err = get_list_element(&config, "users", "-1").unwrap_err();
+        assert!(err.to_string().contains("Invalid index"));
+    }
+
+    #[test]
+    fn test_non_indexable_key() {
+        let config = Config::default();
+        let err = get_list_element(&config, "port", "0").unwrap_err();
+        assert!(err.to_string().contains("not a list"));
+    }
+
+    #[test]
+    fn test_password_status_never_reveals_value() {
+        let config = Config {
+            auth_password: Some("hunter2".to_string()),
+            ..Config::default()
+        };
+        assert_eq!(password_status(&config), "set");
+        assert_eq!(password_status(&Config::default()), "not set");
+
+        let empty = Config {
+            auth_password: Some(String::new()),
+            ..Config::default()
+        };
+        assert_eq!(password_status(&empty), "not set");
+    }
+
+    #[test]
+    fn test_auth_summary_legacy() {
+        let legacy = Config {
+            auth_username: Some("admin".to_string()),
+            auth_password: Some("hunter2".to_string()),
+            ..Config::default()
+        };
+        let summary = auth_summary(&legacy);
+        assert_eq!(summary, "configured (legacy username/password)");
+        assert!(!summary.contains("hunter2"));
+
+        let password_only = Config {
+            auth_password: Some("hunter2".to_string()),
+            ..Config::default()
+        };
+        assert_eq!(auth_summary(&password_only), "not configured");
+    }
+
+    #[test]
+    fn test_auth_summary_pam_users() {
+        let one = Config {
+            users: vec!["alice".to_string()],
+            ..Config::default()
+        };
+        assert_eq!(auth_summary(&one), "configured (1 user)");
+
+        let two = Config {
+            users: vec!["alice".to_string(), "bob".to_string()],
+            auth_username: Some("admin".to_string()),
+            auth_password: Some("hunter2".to_string()),
+            ..Config::default()
+        };
+        assert_eq!(auth_summary(&two), "configured (2 users)");
+    }
+
+    #[test]
+    fn test_auth_summary_unauthenticated_network() {
+        let config = Config {
+            allow_unauthenticated_network: true,
+            ..Config::default()
+        };
+        assert_eq!(
+            auth_summary(&config),
+            "not configured (unauthenticated network access allowed)"
+        );
+    }
 }
@@ -0,0 +1,100 @@
+//! Config lint subcommand
+//!
+//! Reports advisory security findings for the current configuration.
+
+use anyhow::Result;
+use console::style;
+use opencode_cloud_core::Config;
+use opencode_cloud_core::config::{LintFinding, LintSeverity, has_high_severity, lint_config};
+
+/// Lint the configuration for security best practices
+///
+/// Exits with status 1 when any finding is high severity, so the command
+/// can gate deployments in scripts.
+pub fn cmd_config_lint(config: &Config, json: bool, quiet: bool) -> Result<()> {
+    let findings = lint_config(config);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&findings)?);
+    } else if !quiet {
+        print_findings(&findings);
+    }
+
+    if has_high_severity(&findings) {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn print_findings(findings: &[LintFinding]) {
+    if findings.is_empty() {
+        println!("{} No security findings", style("Success:").green().bold());
+        return;
+    }
+
+    for finding in findings {
+        println!(
+            "{} {} {}",
+            severity_label(finding.severity),
+            style(finding.code).dim(),
+            finding.message
+        );
+        println!("       {} {}", style("Fix:").dim(), finding.fix_hint);
+    }
+    println!();
+    println!("{}", summary_line(findings));
+}
+
+fn severity_label(severity: LintSeverity) -> String {
+    let label = format!("{:<6}", severity.label().to_uppercase());
+    match severity {
+        LintSeverity::High => style(label).red().bold().to_string(),
+        LintSeverity::Medium => style(label).yellow().bold().to_string(),
+        LintSeverity::Low => style(label).cyan().to_string(),
+    }
+}
+
+/// e.g. "3 findings (1 high, 1 medium, 1 low)"
+fn summary_line(findings: &[LintFinding]) -> String {
+    let count = |severity| findings.iter().filter(|f| f.severity == severity).count();
+    let noun = if findings.len() == 1 {
+        "finding"
+    } else {
+        "findings"
+    };
+    format!(
+        "{} {noun} ({} high, {} medium, {} low)",
+        findings.len(),
+        count(LintSeverity::High),
+        count(LintSeverity::Medium),
+        count(LintSeverity::Low)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary_counts_by_severity() {
+        let config = Config {
+            bind_address: "0.0.0.0".to_string(),
+            rate_limit_attempts: 100,
+            ..Config::default()
+        };
+        assert_eq!(
+            summary_line(&lint_config(&config)),
+            "3 findings (1 high, 1 medium, 1 low)"
+        );
+
+        let config = Config {
+            rate_limit_attempts: 100,
+            users: vec!["admin".to_string()],
+            ..Config::default()
+        };
+        assert_eq!(
+            summary_line(&lint_config(&config)),
+            "1 finding (0 high, 0 medium, 1 low)"
+        );
+    }
+}
@@ -0,0 +1,64 @@
+//! Config migrate subcommand
+//!
+//! Runs the config migration pipeline explicitly and reports what changed.
+
+use anyhow::{Result, bail};
+use console::style;
+use opencode_cloud_core::config::{get_config_path, migrate_config_file};
+
+/// Migrate the config file to the current schema version
+///
+/// With `dry_run`, reports the steps that would be applied without writing.
+pub fn cmd_config_migrate(dry_run: bool, quiet: bool) -> Result<()> {
+    let config_path =
+        get_config_path().ok_or_else(|| anyhow::anyhow!("Could not determine config file path"))?;
+    if !config_path.exists() {
+        bail!(
+            "No config file found at {}\nRun 'occ setup' to create one.",
+            config_path.display()
+        );
+    }
+
+    let report = migrate_config_file(&config_path, dry_run)?;
+    if quiet {
+        return Ok(());
+    }
+
+    let from = report
+        .from_version
+        .map(|v| v.to_string())
+        .unwrap_or_else(|| "none".to_string());
+
+    if report.is_noop() {
+        println!(
+            "{} Config is already at version {} ({})",
+            style("Success:").green().bold(),
+            from,
+            style(config_path.display()).dim()
+        );
+        return Ok(());
+    }
+
+    let heading = if dry_run { "Would apply:" } else { "Applied:" };
+    println!("{}", style(heading).bold());
+    for step in &report.steps {
+        println!("  - {step}");
+    }
+    println!();
+    println!("Version:     {} -> {}", from, report.to_version);
+
+    if dry_run {
+        println!(
+            "{} Config not saved. Run without --dry-run to apply.",
+            style("Dry run:").cyan().bold()
+        );
+    } else {
+        println!(
+            "{} Migrated {} (previous version kept as .bak)",
+            style("Success:").green().bold(),
+            style(config_path.display()).dim()
+        );
+    }
+
+    Ok(())
+}
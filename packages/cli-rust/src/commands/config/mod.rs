@@ -2,8 +2,11 @@
 //!
 //! Provides `occ config` subcommands for viewing and managing configuration.
 
+mod backup;
 mod env;
 mod get;
+mod lint;
+mod migrate;
 mod reset;
 mod set;
 mod show;
@@ -11,12 +14,16 @@ mod show;
 use anyhow::Result;
 use clap::{Args, Subcommand};
 use opencode_cloud_core::Config;
+use opencode_cloud_core::config::{ConfigOverrides, validate_cpu_limit, validate_memory_limit_mb};
 
+pub use backup::{cmd_config_backup, cmd_config_restore};
 pub use env::{EnvCommands, cmd_config_env};
 pub use get::cmd_config_get;
+pub use lint::cmd_config_lint;
+pub use migrate::cmd_config_migrate;
 pub use reset::cmd_config_reset;
-pub use set::cmd_config_set;
-pub use show::cmd_config_show;
+pub use set::{cmd_config_set, cmd_config_set_from_stdin};
+pub use show::{cmd_config_show, cmd_config_show_effective};
 
 /// Configuration command arguments
 #[derive(Args)]
@@ -25,6 +32,10 @@ pub struct ConfigArgs {
     #[arg(long)]
     json: bool,
 
+    /// Reveal secret-looking container_env values
+    #[arg(long)]
+    show_secrets: bool,
+
     #[command(subcommand)]
     command: Option<ConfigSubcommands>,
 }
@@ -37,6 +48,30 @@ pub enum ConfigSubcommands {
         /// Output as JSON instead of table format
         #[arg(long)]
         json: bool,
+
+        /// Reveal secret-looking container_env values (e.g. API_KEY=...)
+        #[arg(long)]
+        show_secrets: bool,
+
+        /// Show the merged config `occ start` would use, with each value's source
+        #[arg(long)]
+        effective: bool,
+
+        /// Preview `occ start --port` (requires --effective)
+        #[arg(long, requires = "effective")]
+        port: Option<u16>,
+
+        /// Preview `occ start --bind-address` (requires --effective)
+        #[arg(long, requires = "effective")]
+        bind_address: Option<String>,
+
+        /// Preview `occ start --memory` (requires --effective)
+        #[arg(long, requires = "effective", value_parser = validate_memory_limit_mb)]
+        memory: Option<u64>,
+
+        /// Preview `occ start --cpus` (requires --effective)
+        #[arg(long, requires = "effective", value_parser = validate_cpu_limit)]
+        cpus: Option<f64>,
     },
     /// Get a single configuration value
     Get {
@@ -46,9 +81,24 @@ pub enum ConfigSubcommands {
     /// Set a configuration value
     Set {
         /// Configuration key to set (e.g., "port", "username", "password")
-        key: String,
+        #[arg(required_unless_present = "from_stdin")]
+        key: Option<String>,
         /// Value to set (omit for password to prompt securely)
         value: Option<String>,
+        /// Clear an optional key (e.g. username, env, mounts, timezone)
+        #[arg(long, conflicts_with = "value")]
+        unset: bool,
+        /// Read key=value lines from stdin and apply them all in one save
+        ///
+        /// If any line is invalid nothing is changed. Passwords are rejected.
+        #[arg(long, conflicts_with_all = ["key", "value", "unset"])]
+        from_stdin: bool,
+        /// Show the resulting changes without saving
+        #[arg(long)]
+        dry_run: bool,
+        /// Skip the DNS check when setting bind/hostname
+        #[arg(long)]
+        no_resolve: bool,
     },
     /// Reset configuration to defaults
     Reset {
@@ -56,6 +106,33 @@ pub enum ConfigSubcommands {
         #[arg(long, short)]
         force: bool,
     },
+    /// Save a timestamped snapshot of the config file
+    Backup,
+    /// List config snapshots or restore one
+    ///
+    /// The current config is snapshotted before being replaced.
+    Restore {
+        /// Snapshot timestamp (YYYYMMDD-HHMMSS), a unique prefix of it, or "latest"
+        timestamp: Option<String>,
+
+        /// List available snapshots
+        #[arg(long, conflicts_with = "timestamp")]
+        list: bool,
+    },
+    /// Check the configuration for security best practices
+    ///
+    /// Exits non-zero when any finding is high severity.
+    Lint {
+        /// Output findings as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Migrate the config file to the current schema version
+    Migrate {
+        /// Show the migration steps without saving
+        #[arg(long)]
+        dry_run: bool,
+    },
     /// Manage container environment variables
     #[command(subcommand)]
     Env(EnvCommands),
@@ -65,18 +142,53 @@ pub enum ConfigSubcommands {
 ///
 /// Routes to the appropriate handler based on the subcommand.
 /// If no subcommand is given, defaults to Show.
-pub fn cmd_config(args: ConfigArgs, config: &Config, quiet: bool) -> Result<()> {
+pub async fn cmd_config(args: ConfigArgs, config: &Config, quiet: bool) -> Result<()> {
     match args.command {
-        Some(ConfigSubcommands::Show { json }) => cmd_config_show(config, json, quiet),
-        Some(ConfigSubcommands::Get { key }) => cmd_config_get(config, &key, quiet),
-        Some(ConfigSubcommands::Set { key, value }) => {
-            cmd_config_set(&key, value.as_deref(), quiet)
+        Some(ConfigSubcommands::Show {
+            json,
+            show_secrets,
+            effective: true,
+            port,
+            bind_address,
+            memory,
+            cpus,
+        }) => {
+            let overrides = ConfigOverrides {
+                opencode_web_port: port,
+                bind_address,
+                memory_limit_mb: memory,
+                cpu_limit: cpus,
+            };
+            cmd_config_show_effective(config, &overrides, json, show_secrets)
         }
+        Some(ConfigSubcommands::Show {
+            json, show_secrets, ..
+        }) => cmd_config_show(config, json, show_secrets, quiet),
+        Some(ConfigSubcommands::Get { key }) => cmd_config_get(config, &key, quiet),
+        Some(ConfigSubcommands::Set {
+            key,
+            value,
+            unset,
+            from_stdin,
+            dry_run,
+            no_resolve,
+        }) => match key {
+            Some(key) if !from_stdin => {
+                cmd_config_set(&key, value.as_deref(), unset, dry_run, !no_resolve, quiet).await
+            }
+            _ => cmd_config_set_from_stdin(dry_run, quiet).await,
+        },
         Some(ConfigSubcommands::Reset { force }) => cmd_config_reset(force, quiet),
+        Some(ConfigSubcommands::Backup) => cmd_config_backup(quiet),
+        Some(ConfigSubcommands::Restore { timestamp, list }) => {
+            cmd_config_restore(timestamp.as_deref(), list, quiet)
+        }
+        Some(ConfigSubcommands::Lint { json }) => cmd_config_lint(config, json, quiet),
+        Some(ConfigSubcommands::Migrate { dry_run }) => cmd_config_migrate(dry_run, quiet),
         Some(ConfigSubcommands::Env(env_cmd)) => cmd_config_env(env_cmd, quiet),
         None => {
             // Default to show when no subcommand given
-            cmd_config_show(config, args.json, quiet)
+            cmd_config_show(config, args.json, args.show_secrets, quiet)
         }
     }
 }
@@ -5,7 +5,8 @@
 use anyhow::Result;
 use console::style;
 use dialoguer::Confirm;
-use opencode_cloud_core::{Config, save_config};
+use opencode_cloud_core::config::record_config_changes;
+use opencode_cloud_core::{Config, load_config, save_config};
 
 /// Reset configuration to defaults
 ///
@@ -27,8 +28,12 @@ pub fn cmd_config_reset(force: bool, quiet: bool) -> Result<()> {
     }
 
     // Create default config and save
+    let previous = load_config().ok();
     let config = Config::default();
     save_config(&config)?;
+    if let Some(previous) = previous {
+        record_config_changes(&previous, &config);
+    }
 
     if !quiet {
         println!(
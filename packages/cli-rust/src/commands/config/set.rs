@@ -2,19 +2,446 @@
 //!
 //! Sets a single configuration value.
 
+use crate::commands::mount::{add_mount_entry, remove_mount_entries};
+use crate::output::print_firewall_hint;
 use anyhow::{Result, bail};
 use console::style;
 use dialoguer::{Confirm, Password};
-use opencode_cloud_core::config::validate_bind_address;
-use opencode_cloud_core::docker::{CONTAINER_NAME, DockerClient, container_is_running};
-use opencode_cloud_core::{load_config, save_config};
+use opencode_cloud_core::config::{
+    ChangeImpact, RESTART_DELAY_RANGE, RESTART_RETRIES_RANGE, change_impact, clamp_to_range,
+    cockpit_users_warning, config_changes, display_validation_warning, record_config_changes,
+    validate_base_path, validate_bind_address, validate_config, validate_cpu_limit,
+    validate_image_registry_auth, validate_memory_limit_mb, validate_port,
+};
+use opencode_cloud_core::docker::{
+    CONTAINER_NAME, DockerClient, DockerError, MAX_MOTD_LEN, ParsedMount, ResourceLimits,
+    check_container_path_warning, container_is_running, update_resource_limits,
+    update_restart_policy,
+};
+use opencode_cloud_core::{
+    Config, UPDATE_CHECK_VALUES, UpdateCheckPolicy, load_config, save_config,
+};
+use std::future::Future;
+use std::io::Read;
+use std::net::{IpAddr, TcpListener, ToSocketAddrs};
+use std::ops::RangeInclusive;
 
 /// Set a configuration value
 ///
 /// Special handling for password: prompts interactively if value is None.
 /// Returns error if password value is provided on command line (security risk).
-pub fn cmd_config_set(key: &str, value: Option<&str>, quiet: bool) -> Result<()> {
+/// With `unset`, clears an optional key back to its default instead.
+/// With `dry_run`, prints the resulting changes without writing the config.
+/// With `resolve_hostname`, a `bind`/`hostname` value is looked up in DNS
+/// and a warning is shown if it would not work as a bind target.
+pub async fn cmd_config_set(
+    key: &str,
+    value: Option<&str>,
+    unset: bool,
+    dry_run: bool,
+    resolve_hostname: bool,
+    quiet: bool,
+) -> Result<()> {
     let mut config = load_config()?;
+    let original = config.clone();
+
+    let display_value = if unset {
+        unset_setting(&mut config, key)?;
+        "(unset)".to_string()
+    } else {
+        match apply_setting(&mut config, key, value, dry_run, quiet)? {
+            Some(display_value) => display_value,
+            None => return Ok(()),
+        }
+    };
+
+    if resolve_hostname && !quiet && matches!(key.to_lowercase().as_str(), "bind" | "hostname") {
+        if let Some(warning) = bind_resolution_warning(&config.bind, resolve_host) {
+            eprintln!("{} {warning}", style("Warning:").yellow().bold());
+        }
+    }
+
+    if dry_run {
+        print_dry_run(&original, &config);
+        return Ok(());
+    }
+
+    // Save the config
+    save_config(&config)?;
+    record_config_changes(&original, &config);
+    apply_saved_changes(&original, &config, quiet).await;
+
+    if !quiet {
+        println!(
+            "{} Set {} = {}",
+            style("Success:").green().bold(),
+            key,
+            display_value
+        );
+    }
+
+    Ok(())
+}
+
+/// Set several values from `key=value` lines on stdin as one change
+///
+/// Every line is applied to an in-memory copy first; if any line fails,
+/// nothing is saved. Otherwise the config is written once. Blank lines and
+/// `#` comments are skipped. Keys that would prompt (the password, and
+/// enabling unauthenticated network access) are rejected, and the merged
+/// config must pass `validate_config` before it is saved.
+pub async fn cmd_config_set_from_stdin(dry_run: bool, quiet: bool) -> Result<()> {
+    let mut input = String::new();
+    std::io::stdin().read_to_string(&mut input)?;
+
+    let original = load_config()?;
+    let (config, applied) = apply_batch(&original, &input, dry_run, quiet)?;
+
+    if dry_run {
+        print_dry_run(&original, &config);
+        return Ok(());
+    }
+    if config == original {
+        if !quiet {
+            println!("No changes.");
+        }
+        return Ok(());
+    }
+
+    save_config(&config)?;
+    record_config_changes(&original, &config);
+    apply_saved_changes(&original, &config, quiet).await;
+
+    if !quiet {
+        for (key, display_value) in &applied {
+            println!(
+                "{} Set {} = {}",
+                style("Success:").green().bold(),
+                key,
+                display_value
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Apply `key=value` lines to a copy of `original`, all or nothing
+///
+/// Returns the updated config and the applied keys with their display values.
+/// Fails without applying anything if a line needs an interactive prompt or
+/// the resulting config does not validate.
+fn apply_batch(
+    original: &Config,
+    input: &str,
+    dry_run: bool,
+    quiet: bool,
+) -> Result<(Config, Vec<(String, String)>)> {
+    let mut config = original.clone();
+    let mut applied = Vec::new();
+
+    for (index, line) in input.lines().enumerate() {
+        let line_no = index + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .map(|(key, value)| (key.trim(), value.trim()))
+            .ok_or_else(|| anyhow::anyhow!("line {line_no}: expected key=value, got '{line}'"))?;
+        if let Some(reason) = batch_rejection(key, value) {
+            bail!("line {line_no}: {reason}");
+        }
+
+        let display_value = apply_setting(&mut config, key, Some(value), dry_run, quiet)
+            .map_err(|e| anyhow::anyhow!("line {line_no}: {e}"))?
+            .ok_or_else(|| anyhow::anyhow!("line {line_no}: {key} was not changed"))?;
+        applied.push((key.to_string(), display_value));
+    }
+
+    if let Err(error) = validate_config(&config) {
+        bail!(
+            "Resulting config is invalid: {}: {}\n\n\
+             Fix the input and try again.",
+            error.field,
+            error.message
+        );
+    }
+
+    Ok((config, applied))
+}
+
+/// Why `key=value` cannot be applied from stdin, if it cannot
+///
+/// Settings that `apply_setting` confirms or reads interactively have no
+/// terminal to prompt on in batch mode.
+fn batch_rejection(key: &str, value: &str) -> Option<String> {
+    match key.to_lowercase().as_str() {
+        "password" | "auth_password" => Some(
+            "password cannot be set from stdin for security.\n\
+             Use: occ config set password  (will prompt securely)"
+                .to_string(),
+        ),
+        "allow_unauthenticated_network" | "allow_unauth" | "unauth_network"
+            if parse_bool(value) == Some(true) =>
+        {
+            Some(format!(
+                "{key}=true needs interactive confirmation and cannot be set from stdin.\n\
+                 Use: occ config set allow_unauthenticated_network true"
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Hint for a running container after `keys` changed to `config`, for the
+/// most disruptive change; `None` when everything applied live
+fn change_hint(keys: &[&str], config: &Config) -> Option<String> {
+    match keys.iter().map(|key| change_impact(key, config)).max()? {
+        ChangeImpact::HostOnly | ChangeImpact::UpdateContainer => None,
+        ChangeImpact::Restart => Some("Restart required for changes to take effect".to_string()),
+        ChangeImpact::Recreate => {
+            let recreate_keys: Vec<&str> = keys
+                .iter()
+                .copied()
+                .filter(|key| change_impact(key, config) == ChangeImpact::Recreate)
+                .collect();
+            Some(format!(
+                "Container must be recreated for {} to take effect; \
+                 run `occ start --recreate` to recreate it with the new settings",
+                recreate_keys.join(", ")
+            ))
+        }
+    }
+}
+
+/// Follow-up for a saved change: apply what can be applied live, else warn
+async fn apply_saved_changes(original: &Config, config: &Config, quiet: bool) {
+    let changes = config_changes(original, config);
+    let keys: Vec<&str> = changes.iter().map(|change| change.key.as_str()).collect();
+    apply_container_updates(original, config, &keys, quiet).await;
+
+    let Some(hint) = change_hint(&keys, config) else {
+        return;
+    };
+    if !quiet
+        && running_or_false(|| async {
+            let client = DockerClient::new()?;
+            Ok(container_is_running(&client, CONTAINER_NAME).await?)
+        })
+        .await
+    {
+        eprintln!("{} {hint}", style("Warning:").yellow().bold());
+    }
+}
+
+/// Apply the restart policy and memory/CPU limits to the running container
+///
+/// Covers the `keys` that `change_impact` says `docker update` can apply.
+/// Does nothing when Docker is unreachable or the service is not running;
+/// the settings are applied at the next container creation instead.
+async fn apply_container_updates(original: &Config, config: &Config, keys: &[&str], quiet: bool) {
+    let updates: Vec<&str> = keys
+        .iter()
+        .copied()
+        .filter(|key| change_impact(key, config) == ChangeImpact::UpdateContainer)
+        .collect();
+    if updates.is_empty() {
+        return;
+    }
+    let Ok(client) = DockerClient::new() else {
+        return;
+    };
+    if !container_is_running(&client, CONTAINER_NAME)
+        .await
+        .unwrap_or(false)
+    {
+        return;
+    }
+
+    let result = async {
+        if updates.contains(&"auto_restart") {
+            update_restart_policy(&client, CONTAINER_NAME, config.auto_restart).await?;
+        }
+        if updates.contains(&"memory_limit_mb") || updates.contains(&"cpu_limit") {
+            // A limit being removed needs a recreate; keep it until then
+            let limits = ResourceLimits {
+                memory_mb: config.memory_limit_mb.or(original.memory_limit_mb),
+                cpus: config.cpu_limit.or(original.cpu_limit),
+            };
+            update_resource_limits(&client, CONTAINER_NAME, &limits).await?;
+        }
+        Ok::<(), DockerError>(())
+    }
+    .await;
+
+    match result {
+        Ok(()) => {
+            if !quiet {
+                println!(
+                    "{} Updated the running container: {}",
+                    style("Applied:").green().bold(),
+                    updates.join(", ")
+                );
+            }
+        }
+        Err(e) => {
+            if !quiet {
+                eprintln!(
+                    "{} Could not update the running container: {e}",
+                    style("Warning:").yellow().bold()
+                );
+                eprintln!(
+                    "  Run {} to apply {}.",
+                    style("occ start --recreate").cyan(),
+                    updates.join(", ")
+                );
+            }
+        }
+    }
+}
+
+/// Print what a dry run would change
+fn print_dry_run(original: &Config, updated: &Config) {
+    let lines = dry_run_lines(original, updated);
+    if lines.is_empty() {
+        println!("{} No changes (dry run)", style("Dry run:").cyan().bold());
+        return;
+    }
+
+    println!(
+        "{} Would apply the following changes (config not saved):",
+        style("Dry run:").cyan().bold()
+    );
+    for line in lines {
+        println!("  {line}");
+    }
+}
+
+/// Render the changes between two configs, one line per field, secrets masked
+fn dry_run_lines(original: &Config, updated: &Config) -> Vec<String> {
+    config_changes(original, updated)
+        .into_iter()
+        .map(|change| format!("{}: {} -> {}", change.key, change.old, change.new))
+        .collect()
+}
+
+/// An optional config key that `--unset` clears
+struct UnsettableKey {
+    /// Name shown in help and errors
+    name: &'static str,
+    /// Other accepted spellings (canonical field name, short forms)
+    aliases: &'static [&'static str],
+    /// Reset the key to its default
+    clear: fn(&mut Config),
+}
+
+impl UnsettableKey {
+    fn matches(&self, key: &str) -> bool {
+        self.name == key || self.aliases.contains(&key)
+    }
+}
+
+/// Every key `--unset` accepts; the error's key list is generated from this
+const UNSETTABLE_KEYS: &[UnsettableKey] = &[
+    UnsettableKey {
+        name: "username",
+        aliases: &["auth_username"],
+        clear: |c| c.auth_username = None,
+    },
+    UnsettableKey {
+        name: "password",
+        aliases: &["auth_password"],
+        clear: |c| c.auth_password = None,
+    },
+    UnsettableKey {
+        name: "env",
+        aliases: &["container_env"],
+        clear: |c| c.container_env.clear(),
+    },
+    UnsettableKey {
+        name: "mounts",
+        aliases: &[],
+        clear: |c| c.mounts.clear(),
+    },
+    UnsettableKey {
+        name: "timezone",
+        aliases: &["tz"],
+        clear: |c| c.timezone = None,
+    },
+    UnsettableKey {
+        name: "locale",
+        aliases: &[],
+        clear: |c| c.locale = None,
+    },
+    UnsettableKey {
+        name: "memory_limit_mb",
+        aliases: &["memory"],
+        clear: |c| c.memory_limit_mb = None,
+    },
+    UnsettableKey {
+        name: "cpu_limit",
+        aliases: &["cpus"],
+        clear: |c| c.cpu_limit = None,
+    },
+    UnsettableKey {
+        name: "image_registry_auth",
+        aliases: &["registry_auth"],
+        clear: |c| c.image_registry_auth = None,
+    },
+    UnsettableKey {
+        name: "motd",
+        aliases: &[],
+        clear: |c| c.motd = None,
+    },
+    UnsettableKey {
+        name: "startup_timeout_seconds",
+        aliases: &["startup_timeout"],
+        clear: |c| c.startup_timeout_seconds = None,
+    },
+    UnsettableKey {
+        name: "base_path",
+        aliases: &[],
+        clear: |c| c.base_path = None,
+    },
+];
+
+/// Clear an optional key back to its default in memory
+///
+/// Required scalar keys (port, bind_address, ...) have no unset state and
+/// are rejected.
+fn unset_setting(config: &mut Config, key: &str) -> Result<()> {
+    let normalized_key = key.to_lowercase();
+    let Some(entry) = UNSETTABLE_KEYS
+        .iter()
+        .find(|entry| entry.matches(&normalized_key))
+    else {
+        let names: Vec<&str> = UNSETTABLE_KEYS.iter().map(|entry| entry.name).collect();
+        bail!(
+            "Cannot unset '{key}': it is not an optional key.\n\n\
+             Keys that can be unset: {}\n\
+             To change it instead, use: occ config set {key} <value>",
+            names.join(", ")
+        );
+    };
+    (entry.clear)(config);
+    Ok(())
+}
+
+/// Apply a single key/value change to `config` in memory
+///
+/// Returns the value to display, or `None` when the user aborted a
+/// confirmation or there is nothing to change. Confirmation prompts are skipped for dry runs since
+/// nothing is written.
+fn apply_setting(
+    config: &mut Config,
+    key: &str,
+    value: Option<&str>,
+    dry_run: bool,
+    quiet: bool,
+) -> Result<Option<String>> {
     let normalized_key = key.to_lowercase();
 
     // Display value for output (password is masked)
@@ -42,9 +469,8 @@ pub fn cmd_config_set(key: &str, value: Option<&str>, quiet: bool) -> Result<()>
 
         "port" | "opencode_web_port" => {
             let val = require_value(value, key)?;
-            let port: u16 = val.parse().map_err(|_| {
-                anyhow::anyhow!("Invalid port number: {val}. Must be a number between 1-65535.")
-            })?;
+            let port = validate_port(val).map_err(|e| anyhow::anyhow!(e))?;
+            warn_if_privileged_port(port, quiet);
             config.opencode_web_port = port;
             display_value = port.to_string();
         }
@@ -59,7 +485,7 @@ pub fn cmd_config_set(key: &str, value: Option<&str>, quiet: bool) -> Result<()>
             let val = require_value(value, key)?;
 
             // Validate the address
-            validate_bind_address(val).map_err(|_| {
+            let ip = validate_bind_address(val).map_err(|_| {
                 anyhow::anyhow!(
                     "Invalid address: {val}\n\
                      Valid examples: 127.0.0.1, ::1, 0.0.0.0, ::, or localhost"
@@ -67,7 +493,7 @@ pub fn cmd_config_set(key: &str, value: Option<&str>, quiet: bool) -> Result<()>
             })?;
 
             // Check for network exposure and show warning
-            if val == "0.0.0.0" || val == "::" {
+            if ip.is_unspecified() {
                 eprintln!();
                 eprintln!(
                     "{} {}",
@@ -91,6 +517,7 @@ pub fn cmd_config_set(key: &str, value: Option<&str>, quiet: bool) -> Result<()>
                     style("occ config set bind_address 127.0.0.1").cyan()
                 );
                 eprintln!();
+                print_firewall_hint(config.opencode_web_port);
             }
 
             config.bind_address = val.to_string();
@@ -122,11 +549,24 @@ pub fn cmd_config_set(key: &str, value: Option<&str>, quiet: bool) -> Result<()>
             display_value = val.to_string();
         }
 
+        "update_check" => {
+            let val = require_value(value, key)?.trim();
+            if UpdateCheckPolicy::parse(val).is_none() {
+                bail!(
+                    "Invalid update_check: {val}. Must be one of: {}",
+                    UPDATE_CHECK_VALUES.join(", ")
+                );
+            }
+            config.update_check = val.to_string();
+            display_value = val.to_string();
+        }
+
         "restart_retries" => {
             let val = require_value(value, key)?;
             let retries: u32 = val.parse().map_err(|_| {
                 anyhow::anyhow!("Invalid restart_retries: {val}. Must be a positive integer.")
             })?;
+            let retries = clamp_restart_setting(key, retries, &RESTART_RETRIES_RANGE, quiet);
             config.restart_retries = retries;
             display_value = retries.to_string();
         }
@@ -138,6 +578,7 @@ pub fn cmd_config_set(key: &str, value: Option<&str>, quiet: bool) -> Result<()>
                     "Invalid restart_delay: {val}. Must be a positive integer (seconds)."
                 )
             })?;
+            let delay = clamp_restart_setting(key, delay, &RESTART_DELAY_RANGE, quiet);
             config.restart_delay = delay;
             display_value = delay.to_string();
         }
@@ -205,7 +646,10 @@ pub fn cmd_config_set(key: &str, value: Option<&str>, quiet: bool) -> Result<()>
                 anyhow::anyhow!("Invalid boolean value: {val}. Use: true/false, yes/no, or 1/0")
             })?;
 
-            if allow {
+            if allow && dry_run {
+                config.allow_unauthenticated_network = true;
+                display_value = "true".to_string();
+            } else if allow {
                 // Double opt-in per CONTEXT.md
                 println!();
                 println!(
@@ -230,7 +674,7 @@ pub fn cmd_config_set(key: &str, value: Option<&str>, quiet: bool) -> Result<()>
 
                 if !confirm1 {
                     println!("Aborted. Setting not changed.");
-                    return Ok(());
+                    return Ok(None);
                 }
 
                 // Second confirmation (double opt-in)
@@ -241,7 +685,7 @@ pub fn cmd_config_set(key: &str, value: Option<&str>, quiet: bool) -> Result<()>
 
                 if !confirm2 {
                     println!("Aborted. Setting not changed.");
-                    return Ok(());
+                    return Ok(None);
                 }
 
                 config.allow_unauthenticated_network = true;
@@ -286,17 +730,154 @@ pub fn cmd_config_set(key: &str, value: Option<&str>, quiet: bool) -> Result<()>
 
             config.cockpit_enabled = enabled;
             display_value = enabled.to_string();
+            if let Some(warning) = cockpit_users_warning(config) {
+                if !quiet {
+                    display_validation_warning(&warning);
+                }
+            }
         }
 
         "cockpit_port" => {
             let val = require_value(value, key)?;
-            let port: u16 = val.parse().map_err(|_| {
-                anyhow::anyhow!("Invalid port number: {val}. Must be a number between 1-65535")
-            })?;
+            let port = validate_port(val).map_err(|e| anyhow::anyhow!(e))?;
+            warn_if_privileged_port(port, quiet);
             config.cockpit_port = port;
             display_value = port.to_string();
         }
 
+        "health_check_enabled" | "health_check" => {
+            let val = require_value(value, key)?;
+            let enabled = parse_bool(val).ok_or_else(|| {
+                anyhow::anyhow!("Invalid boolean value: {val}. Use: true/false, yes/no, or 1/0")
+            })?;
+            config.health_check_enabled = enabled;
+            display_value = enabled.to_string();
+        }
+
+        "health_check_interval" | "health_check_timeout" => {
+            let val = require_value(value, key)?;
+            let secs: u32 = val.parse().map_err(|_| {
+                anyhow::anyhow!(
+                    "Invalid {normalized_key}: {val}. Must be a positive integer (seconds)."
+                )
+            })?;
+            if secs == 0 {
+                bail!("{normalized_key} must be at least 1 second");
+            }
+            if normalized_key == "health_check_interval" {
+                config.health_check_interval = secs;
+            } else {
+                config.health_check_timeout = secs;
+            }
+            display_value = secs.to_string();
+        }
+
+        "audit_log_enabled" | "audit_log" => {
+            let val = require_value(value, key)?;
+            let enabled = parse_bool(val).ok_or_else(|| {
+                anyhow::anyhow!("Invalid boolean value: {val}. Use: true/false, yes/no, or 1/0")
+            })?;
+            config.audit_log_enabled = enabled;
+            display_value = enabled.to_string();
+        }
+
+        "timezone" | "tz" => {
+            let val = require_value(value, key)?.trim();
+            validate_timezone(val)?;
+            config.timezone = Some(val.to_string());
+            display_value = val.to_string();
+        }
+
+        "locale" => {
+            let val = require_value(value, key)?.trim();
+            validate_locale(val)?;
+            config.locale = Some(val.to_string());
+            display_value = val.to_string();
+        }
+
+        "motd" => {
+            let val = require_value(value, key)?.trim();
+            validate_motd(val)?;
+            config.motd = Some(val.to_string());
+            display_value = val.to_string();
+        }
+
+        "base_path" => {
+            let val = require_value(value, key)?;
+            let path = validate_base_path(val).map_err(|e| anyhow::anyhow!(e))?;
+            display_value = path.clone();
+            config.base_path = Some(path);
+        }
+
+        "startup_timeout_seconds" | "startup_timeout" => {
+            let val = require_value(value, key)?;
+            let secs = match val.parse::<u32>() {
+                Ok(secs) if secs > 0 => secs,
+                _ => bail!("Invalid startup_timeout_seconds: {val}. Must be a positive integer."),
+            };
+            config.startup_timeout_seconds = Some(secs);
+            display_value = format!("{secs}s");
+        }
+
+        "health_check_retries" => {
+            let val = require_value(value, key)?;
+            let retries = match val.parse::<u32>() {
+                Ok(retries) if retries > 0 => retries,
+                _ => bail!("Invalid health_check_retries: {val}. Must be a positive integer."),
+            };
+            config.health_check_retries = retries;
+            display_value = retries.to_string();
+        }
+
+        "memory_limit_mb" | "memory" => {
+            let val = require_value(value, key)?;
+            let mb = validate_memory_limit_mb(val).map_err(|e| anyhow::anyhow!(e))?;
+            config.memory_limit_mb = Some(mb);
+            display_value = format!("{mb} MB");
+        }
+
+        "cpu_limit" | "cpus" => {
+            let val = require_value(value, key)?;
+            let cpus = validate_cpu_limit(val).map_err(|e| anyhow::anyhow!(e))?;
+            config.cpu_limit = Some(cpus);
+            display_value = cpus.to_string();
+        }
+
+        "image_registry_auth" | "registry_auth" => {
+            let val = require_value(value, key)?;
+            validate_image_registry_auth(val).map_err(|e| anyhow::anyhow!(e))?;
+            config.image_registry_auth = Some(val.trim().to_string());
+            // Never echo the password
+            display_value = format!(
+                "{} (credentials saved)",
+                val.split('=').next().unwrap_or("")
+            );
+        }
+
+        "mounts.add" => {
+            let spec = require_value(value, key)?;
+            let parsed = ParsedMount::parse(spec)?;
+            parsed.validate_source()?;
+            if let Some(warning) = check_container_path_warning(&parsed.container_path) {
+                bail!("{warning}\n\nUse occ mount add --force to add it anyway.");
+            }
+            if let Some(existing) = add_mount_entry(&mut config.mounts, &parsed) {
+                if !quiet {
+                    println!("Mount {existing} already configured; nothing to change.");
+                }
+                return Ok(None);
+            }
+            display_value = format!("+{}", parsed.to_spec());
+        }
+
+        "mounts.remove" => {
+            let target = require_value(value, key)?;
+            if remove_mount_entries(&mut config.mounts, target) == 0 {
+                bail!("No mount found for: {target}\n\nList mounts with: occ mount list");
+            }
+            display_value = format!("-{target}");
+        }
+
         _ => {
             bail!(
                 "Unknown configuration key: {key}\n\n\
@@ -308,6 +889,7 @@ pub fn cmd_config_set(key: &str, value: Option<&str>, quiet: bool) -> Result<()>
                   password / auth_password\n  \
                   auto_restart\n  \
                   boot_mode\n  \
+                  update_check\n  \
                   restart_retries\n  \
                   restart_delay\n  \
                   trust_proxy / proxy\n  \
@@ -315,35 +897,76 @@ pub fn cmd_config_set(key: &str, value: Option<&str>, quiet: bool) -> Result<()>
                   rate_limit_window_seconds / rate_window\n  \
                   allow_unauthenticated_network / allow_unauth\n  \
                   cockpit_enabled / cockpit\n  \
-                  cockpit_port\n\n\
+                  cockpit_port\n  \
+                  health_check_enabled / health_check\n  \
+                  health_check_interval\n  \
+                  health_check_timeout\n  \
+                  health_check_retries\n  \
+                  audit_log_enabled / audit_log\n  \
+                  timezone / tz\n  \
+                  locale\n  \
+                  memory_limit_mb / memory\n  \
+                  cpu_limit / cpus\n  \
+                  image_registry_auth / registry_auth\n  \
+                  motd\n  \
+                  startup_timeout_seconds / startup_timeout\n  \
+                  base_path\n  \
+                  mounts.add / mounts.remove\n\n\
                 For environment variables, use: occ config env set KEY=value"
             );
         }
     }
 
-    // Save the config
-    save_config(&config)?;
+    Ok(Some(display_value))
+}
 
-    // Check if service is running and warn
-    if !quiet {
-        if let Ok(true) = check_container_running() {
-            eprintln!(
-                "{} Restart required for changes to take effect",
-                style("Warning:").yellow().bold()
-            );
-        }
+/// Look up the addresses a hostname resolves to
+fn resolve_host(host: &str) -> std::io::Result<Vec<IpAddr>> {
+    Ok((host, 0).to_socket_addrs()?.map(|addr| addr.ip()).collect())
+}
+
+/// Whether `ip` can be bound on this machine
+fn is_local_address(ip: IpAddr) -> bool {
+    ip.is_loopback() || ip.is_unspecified() || TcpListener::bind((ip, 0)).is_ok()
+}
+
+/// Warn when a bind hostname won't resolve to an address on this machine
+///
+/// Literal IPs and `localhost` are accepted without a lookup; anything else
+/// goes through `resolve` (injectable for tests).
+fn bind_resolution_warning(
+    host: &str,
+    resolve: impl FnOnce(&str) -> std::io::Result<Vec<IpAddr>>,
+) -> Option<String> {
+    let host = host.trim();
+    let unbracketed = host.trim_start_matches('[').trim_end_matches(']');
+    if host.eq_ignore_ascii_case("localhost") || unbracketed.parse::<IpAddr>().is_ok() {
+        return None;
     }
 
-    if !quiet {
-        println!(
-            "{} Set {} = {}",
-            style("Success:").green().bold(),
-            key,
-            display_value
-        );
+    let addrs = match resolve(host) {
+        Ok(addrs) if !addrs.is_empty() => addrs,
+        _ => {
+            return Some(format!(
+                "'{host}' does not resolve; starting the service will fail to bind.\n  \
+                 Use an IP address, or skip this check with --no-resolve."
+            ));
+        }
+    };
+
+    if addrs.iter().any(|ip| is_local_address(*ip)) {
+        return None;
     }
 
-    Ok(())
+    let list = addrs
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+    Some(format!(
+        "'{host}' resolves to {list}, which is not an address on this machine; \
+         binding to it will fail."
+    ))
 }
 
 /// Require a value for non-password keys
@@ -373,6 +996,76 @@ fn validate_username(username: &str) -> Result<()> {
     Ok(())
 }
 
+/// Warning text for ports below 1024, which need elevated privileges to bind
+fn privileged_port_warning(port: u16) -> Option<String> {
+    (port < 1024)
+        .then(|| format!("Port {port} is privileged (<1024) and may require root/admin to bind."))
+}
+
+/// Print the privileged-port warning unless quiet
+fn warn_if_privileged_port(port: u16, quiet: bool) {
+    if quiet {
+        return;
+    }
+    if let Some(warning) = privileged_port_warning(port) {
+        eprintln!("{} {warning}", style("Warning:").yellow().bold());
+    }
+}
+
+/// Clamp a restart setting into its accepted range, warning when it was adjusted
+fn clamp_restart_setting(key: &str, value: u32, range: &RangeInclusive<u32>, quiet: bool) -> u32 {
+    let (clamped, changed) = clamp_to_range(value, range);
+    if changed && !quiet {
+        eprintln!(
+            "{} {key} must be between {} and {}; using {clamped} instead of {value}",
+            style("Warning:").yellow().bold(),
+            range.start(),
+            range.end()
+        );
+    }
+    clamped
+}
+
+/// Validate a timezone value: "auto" or an IANA-style name like "Europe/Berlin"
+fn validate_timezone(tz: &str) -> Result<()> {
+    let valid = !tz.is_empty()
+        && tz
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '/' | '_' | '-' | '+'));
+    if !valid {
+        bail!(
+            "Invalid timezone: '{tz}'. Use an IANA name like Europe/Berlin, or \"auto\" to detect the host timezone"
+        );
+    }
+    Ok(())
+}
+
+/// Validate a locale value like "en_US.UTF-8" or "C.UTF-8"
+fn validate_locale(locale: &str) -> Result<()> {
+    let valid = !locale.is_empty()
+        && locale
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-' | '@'));
+    if !valid {
+        bail!("Invalid locale: '{locale}'. Use a name like en_US.UTF-8");
+    }
+    Ok(())
+}
+
+/// Validate a message of the day: non-empty and at most MAX_MOTD_LEN characters
+fn validate_motd(motd: &str) -> Result<()> {
+    if motd.is_empty() {
+        bail!(
+            "Invalid motd: message cannot be empty. To clear it, use: occ config set motd --unset"
+        );
+    }
+    let len = motd.chars().count();
+    if len > MAX_MOTD_LEN {
+        bail!("Invalid motd: {len} characters, at most {MAX_MOTD_LEN} allowed");
+    }
+    Ok(())
+}
+
 /// Parse boolean from various string representations
 fn parse_bool(s: &str) -> Option<bool> {
     match s.to_lowercase().as_str() {
@@ -382,15 +1075,16 @@ fn parse_bool(s: &str) -> Option<bool> {
     }
 }
 
-/// Check if the container is running (synchronous wrapper)
-fn check_container_running() -> Result<bool> {
-    let rt = tokio::runtime::Runtime::new()?;
-    rt.block_on(async {
-        let client = DockerClient::new()?;
-        container_is_running(&client, CONTAINER_NAME)
-            .await
-            .map_err(|e| anyhow::anyhow!("{e}"))
-    })
+/// Run a container-running probe on the caller's runtime
+///
+/// Docker being unreachable is treated as "not running" since the check
+/// only decides whether to print a restart hint.
+async fn running_or_false<F, Fut>(probe: F) -> bool
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<bool>>,
+{
+    probe().await.unwrap_or(false)
 }
 
 #[cfg(test)]
@@ -454,4 +1148,509 @@ mod tests {
         assert_eq!(parse_bool("2"), None);
         assert_eq!(parse_bool(""), None);
     }
+
+    #[test]
+    fn test_port_zero_rejected() {
+        assert!(validate_port("0").is_err());
+    }
+
+    #[test]
+    fn test_privileged_port_warning() {
+        assert!(privileged_port_warning(80).unwrap().contains("privileged"));
+        assert!(privileged_port_warning(1023).is_some());
+        assert!(privileged_port_warning(1024).is_none());
+        assert!(privileged_port_warning(3000).is_none());
+    }
+
+    #[test]
+    fn test_dry_run_shows_change() {
+        let original = Config::default();
+        let mut config = original.clone();
+        let display = apply_setting(&mut config, "port", Some("4000"), true, true).unwrap();
+
+        assert_eq!(display.as_deref(), Some("4000"));
+        let lines = dry_run_lines(&original, &config);
+        assert_eq!(
+            lines,
+            vec![format!(
+                "opencode_web_port: {} -> 4000",
+                original.opencode_web_port
+            )]
+        );
+    }
+
+    #[test]
+    fn test_update_check_accepts_each_valid_value() {
+        for value in UPDATE_CHECK_VALUES {
+            let mut config = Config::default();
+            let display =
+                apply_setting(&mut config, "update_check", Some(value), true, true).unwrap();
+            assert_eq!(display.as_deref(), Some(*value));
+            assert_eq!(config.update_check, *value);
+        }
+    }
+
+    #[test]
+    fn test_update_check_rejects_unknown_values() {
+        let mut config = Config::default();
+        let err = apply_setting(&mut config, "update_check", Some("monthly"), true, true)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("always, daily, weekly, once, never"), "{err}");
+        assert_eq!(config.update_check, Config::default().update_check);
+    }
+
+    #[test]
+    fn test_change_hint_uses_most_disruptive_change() {
+        let config = Config {
+            memory_limit_mb: Some(2048),
+            ..Config::default()
+        };
+        assert_eq!(change_hint(&[], &config), None);
+        assert_eq!(
+            change_hint(&["auto_restart", "memory_limit_mb"], &config),
+            None
+        );
+        assert_eq!(change_hint(&["startup_timeout_seconds"], &config), None);
+        assert_eq!(
+            change_hint(&["auto_restart", "trust_proxy"], &config).as_deref(),
+            Some("Restart required for changes to take effect")
+        );
+        let hint = change_hint(
+            &[
+                "base_path",
+                "cockpit_port",
+                "opencode_web_port",
+                "trust_proxy",
+            ],
+            &config,
+        )
+        .unwrap();
+        assert!(
+            hint.contains("base_path, cockpit_port, opencode_web_port"),
+            "{hint}"
+        );
+        assert!(hint.contains("run `occ start --recreate`"), "{hint}");
+        let hint = change_hint(&["cpu_limit"], &config).unwrap();
+        assert!(hint.contains("cpu_limit"), "{hint}");
+    }
+
+    #[test]
+    fn test_dry_run_skips_unauth_confirmation() {
+        let mut config = Config::default();
+        let display = apply_setting(&mut config, "allow_unauth", Some("true"), true, true).unwrap();
+        assert_eq!(display.as_deref(), Some("true"));
+        assert!(config.allow_unauthenticated_network);
+    }
+
+    #[test]
+    fn test_dry_run_masks_password() {
+        let original = Config::default();
+        let config = Config {
+            auth_password: Some("hunter2".to_string()),
+            ..original.clone()
+        };
+        let lines = dry_run_lines(&original, &config);
+        assert!(lines.iter().all(|line| !line.contains("hunter2")));
+    }
+
+    #[test]
+    fn test_batch_applies_all_lines() {
+        let original = Config::default();
+        let input = "# provisioning\nport = 4100\n\nmotd=Welcome\nrestart_retries=5\n";
+        let (config, applied) = apply_batch(&original, input, true, true).unwrap();
+        assert_eq!(config.opencode_web_port, 4100);
+        assert_eq!(config.motd.as_deref(), Some("Welcome"));
+        assert_eq!(config.restart_retries, 5);
+        let keys: Vec<_> = applied.iter().map(|(key, _)| key.as_str()).collect();
+        assert_eq!(keys, vec!["port", "motd", "restart_retries"]);
+    }
+
+    #[test]
+    fn test_batch_is_all_or_nothing() {
+        let original = Config::default();
+        let err = apply_batch(&original, "port=4100\nport=not-a-port\n", true, true).unwrap_err();
+        assert!(err.to_string().starts_with("line 2:"));
+
+        let err = apply_batch(&original, "motd=hi\njust-a-key\n", true, true).unwrap_err();
+        assert!(err.to_string().contains("line 2: expected key=value"));
+    }
+
+    #[test]
+    fn test_batch_rejects_password() {
+        let original = Config::default();
+        for input in ["password=hunter2", "motd=hi\nAUTH_PASSWORD=hunter2"] {
+            let err = apply_batch(&original, input, true, true).unwrap_err();
+            assert!(
+                err.to_string()
+                    .contains("password cannot be set from stdin")
+            );
+            assert!(!err.to_string().contains("hunter2"));
+        }
+    }
+
+    #[test]
+    fn test_batch_rejects_prompting_keys() {
+        let original = Config::default();
+        let err = apply_batch(
+            &original,
+            "motd=hi\nallow_unauthenticated_network=true",
+            true,
+            true,
+        )
+        .unwrap_err();
+        assert!(err.to_string().starts_with("line 2:"));
+        assert!(err.to_string().contains("interactive confirmation"));
+
+        let (config, _) = apply_batch(&original, "allow_unauth=false", true, true).unwrap();
+        assert!(!config.allow_unauthenticated_network);
+    }
+
+    #[test]
+    fn test_batch_validates_merged_config() {
+        let original = Config {
+            rate_limit_attempts: 0,
+            ..Config::default()
+        };
+        let err = apply_batch(&original, "motd=hi", true, true).unwrap_err();
+        assert!(err.to_string().contains("Resulting config is invalid"));
+        assert!(err.to_string().contains("rate_limit_attempts"));
+
+        let (config, _) = apply_batch(&Config::default(), "port=80", true, true).unwrap();
+        assert_eq!(config.opencode_web_port, 80);
+    }
+
+    #[tokio::test]
+    async fn test_running_check_uses_injected_probe() {
+        assert!(running_or_false(|| async { Ok(true) }).await);
+        assert!(!running_or_false(|| async { Ok(false) }).await);
+        assert!(!running_or_false(|| async { Err(anyhow::anyhow!("docker down")) }).await);
+    }
+
+    #[test]
+    fn test_unset_auth_username() {
+        let mut config = Config {
+            auth_username: Some("admin".to_string()),
+            ..Config::default()
+        };
+        unset_setting(&mut config, "auth_username").unwrap();
+        assert_eq!(config.auth_username, None);
+    }
+
+    #[test]
+    fn test_set_and_unset_resource_limits() {
+        let mut config = Config::default();
+        apply_setting(&mut config, "memory", Some("2048"), true, true).unwrap();
+        apply_setting(&mut config, "cpus", Some("1.5"), true, true).unwrap();
+        assert_eq!(config.memory_limit_mb, Some(2048));
+        assert_eq!(config.cpu_limit, Some(1.5));
+        assert!(apply_setting(&mut config, "memory", Some("2"), true, true).is_err());
+
+        unset_setting(&mut config, "memory_limit_mb").unwrap();
+        unset_setting(&mut config, "cpu_limit").unwrap();
+        assert_eq!(config.memory_limit_mb, None);
+        assert_eq!(config.cpu_limit, None);
+    }
+
+    #[test]
+    fn test_set_registry_auth_validates_and_masks() {
+        let mut config = Config::default();
+        let display = apply_setting(
+            &mut config,
+            "registry_auth",
+            Some("ghcr.io=bot:token"),
+            true,
+            true,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            config.image_registry_auth.as_deref(),
+            Some("ghcr.io=bot:token")
+        );
+        assert!(!display.contains("token"));
+        assert!(
+            apply_setting(&mut config, "registry_auth", Some("bot:token"), true, true).is_err()
+        );
+
+        unset_setting(&mut config, "image_registry_auth").unwrap();
+        assert_eq!(config.image_registry_auth, None);
+    }
+
+    #[test]
+    fn test_set_and_unset_motd() {
+        let mut config = Config::default();
+        apply_setting(
+            &mut config,
+            "motd",
+            Some(" Maintenance at 18:00 "),
+            true,
+            true,
+        )
+        .unwrap();
+        assert_eq!(config.motd.as_deref(), Some("Maintenance at 18:00"));
+        assert!(apply_setting(&mut config, "motd", Some("  "), true, true).is_err());
+        let too_long = "x".repeat(MAX_MOTD_LEN + 1);
+        assert!(apply_setting(&mut config, "motd", Some(&too_long), true, true).is_err());
+
+        unset_setting(&mut config, "motd").unwrap();
+        assert_eq!(config.motd, None);
+    }
+
+    #[test]
+    fn test_base_path() {
+        let mut config = Config::default();
+        apply_setting(&mut config, "base_path", Some("/opencode"), true, true).unwrap();
+        assert_eq!(config.base_path.as_deref(), Some("/opencode"));
+        assert!(apply_setting(&mut config, "base_path", Some("opencode"), true, true).is_err());
+        assert!(apply_setting(&mut config, "base_path", Some("/my path"), true, true).is_err());
+        assert_eq!(config.base_path.as_deref(), Some("/opencode"));
+
+        unset_setting(&mut config, "base_path").unwrap();
+        assert_eq!(config.base_path, None);
+    }
+
+    #[test]
+    fn test_startup_timeout_seconds() {
+        let mut config = Config::default();
+        apply_setting(&mut config, "startup_timeout", Some("240"), true, true).unwrap();
+        assert_eq!(config.startup_timeout_seconds, Some(240));
+        assert!(
+            apply_setting(
+                &mut config,
+                "startup_timeout_seconds",
+                Some("0"),
+                true,
+                true
+            )
+            .is_err()
+        );
+        assert!(
+            apply_setting(
+                &mut config,
+                "startup_timeout_seconds",
+                Some("2m"),
+                true,
+                true
+            )
+            .is_err()
+        );
+
+        unset_setting(&mut config, "startup_timeout_seconds").unwrap();
+        assert_eq!(config.startup_timeout_seconds, None);
+    }
+
+    #[test]
+    fn test_health_check_retries_requires_at_least_one() {
+        let mut config = Config::default();
+        apply_setting(&mut config, "health_check_retries", Some("1"), true, true).unwrap();
+        assert_eq!(config.health_check_retries, 1);
+        assert!(apply_setting(&mut config, "health_check_retries", Some("0"), true, true).is_err());
+        assert_eq!(config.health_check_retries, 1);
+    }
+
+    #[test]
+    fn test_unset_list_keys() {
+        let mut config = Config {
+            container_env: vec!["A=1".to_string()],
+            mounts: vec!["/tmp:/workspace".to_string()],
+            ..Config::default()
+        };
+        unset_setting(&mut config, "env").unwrap();
+        unset_setting(&mut config, "MOUNTS").unwrap();
+        assert!(config.container_env.is_empty());
+        assert!(config.mounts.is_empty());
+    }
+
+    #[test]
+    fn test_unset_required_key_rejected() {
+        let mut config = Config::default();
+        let err = unset_setting(&mut config, "port").unwrap_err();
+        assert!(err.to_string().contains("Cannot unset 'port'"));
+        assert!(err.to_string().contains("username, password, env, mounts"));
+        assert!(err.to_string().contains("base_path"));
+        assert_eq!(config, Config::default());
+    }
+
+    #[test]
+    fn test_every_unsettable_key_resets_to_default() {
+        let set = Config {
+            auth_username: Some("admin".to_string()),
+            auth_password: Some("secret".to_string()),
+            container_env: vec!["A=1".to_string()],
+            mounts: vec!["/tmp:/workspace".to_string()],
+            timezone: Some("Europe/Berlin".to_string()),
+            locale: Some("de_DE.UTF-8".to_string()),
+            memory_limit_mb: Some(2048),
+            cpu_limit: Some(1.5),
+            image_registry_auth: Some("ghcr.io=user:token".to_string()),
+            motd: Some("hi".to_string()),
+            startup_timeout_seconds: Some(300),
+            base_path: Some("/occ".to_string()),
+            ..Config::default()
+        };
+        let mut config = set.clone();
+        for entry in UNSETTABLE_KEYS {
+            let before = config.clone();
+            unset_setting(&mut config, entry.name).unwrap();
+            assert_ne!(config, before, "{} changed nothing", entry.name);
+            for alias in entry.aliases {
+                unset_setting(&mut set.clone(), alias).unwrap();
+            }
+        }
+        assert_eq!(config, Config::default());
+    }
+
+    #[test]
+    fn test_timezone_validation() {
+        assert!(validate_timezone("Europe/Berlin").is_ok());
+        assert!(validate_timezone("auto").is_ok());
+        assert!(validate_timezone("Etc/GMT+5").is_ok());
+        assert!(validate_timezone("").is_err());
+        assert!(validate_timezone("Europe/Berlin; rm -rf").is_err());
+    }
+
+    #[test]
+    fn test_locale_validation() {
+        assert!(validate_locale("en_US.UTF-8").is_ok());
+        assert!(validate_locale("sr_RS@latin").is_ok());
+        assert!(validate_locale("en US").is_err());
+    }
+
+    #[test]
+    fn test_mounts_add_remove_via_set() {
+        let dir = tempfile::tempdir().unwrap();
+        let host = dir.path().canonicalize().unwrap();
+        let spec = format!("{}:/workspace/data", host.display());
+        let mut config = Config::default();
+
+        let display = apply_setting(&mut config, "mounts.add", Some(&spec), false, true).unwrap();
+        assert_eq!(display, Some(format!("+{spec}")));
+        assert_eq!(config.mounts, vec![spec.clone()]);
+
+        let display = apply_setting(
+            &mut config,
+            "mounts.remove",
+            Some("/workspace/data"),
+            false,
+            true,
+        )
+        .unwrap();
+        assert_eq!(display.as_deref(), Some("-/workspace/data"));
+        assert!(config.mounts.is_empty());
+
+        assert!(
+            apply_setting(
+                &mut config,
+                "mounts.remove",
+                Some("/workspace/data"),
+                false,
+                true
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_mounts_add_dedupes_by_container_target() {
+        let first = tempfile::tempdir().unwrap();
+        let second = tempfile::tempdir().unwrap();
+        let existing = format!(
+            "{}:/workspace/data",
+            first.path().canonicalize().unwrap().display()
+        );
+        let mut config = Config {
+            mounts: vec![existing.clone()],
+            ..Config::default()
+        };
+
+        let spec = format!("{}:/workspace/data:ro", second.path().display());
+        let display = apply_setting(&mut config, "mounts.add", Some(&spec), false, true).unwrap();
+
+        assert_eq!(display, None);
+        assert_eq!(config.mounts, vec![existing]);
+    }
+
+    #[test]
+    fn test_mounts_add_validates_spec() {
+        let mut config = Config::default();
+        assert!(
+            apply_setting(&mut config, "mounts.add", Some("relative:/x"), false, true).is_err()
+        );
+        assert!(
+            apply_setting(
+                &mut config,
+                "mounts.add",
+                Some("/does/not/exist:/x"),
+                false,
+                true
+            )
+            .is_err()
+        );
+        assert!(config.mounts.is_empty());
+    }
+
+    #[test]
+    fn test_bind_literal_ip_skips_resolution() {
+        let no_lookup = |_: &str| -> std::io::Result<Vec<IpAddr>> {
+            panic!("literal addresses must not be resolved")
+        };
+        for host in [
+            "127.0.0.1",
+            "0.0.0.0",
+            "::1",
+            "[::1]",
+            "localhost",
+            "LOCALHOST",
+        ] {
+            assert_eq!(bind_resolution_warning(host, no_lookup), None, "{host}");
+        }
+    }
+
+    #[test]
+    fn test_bind_unresolvable_host_warns() {
+        let warning = bind_resolution_warning("myhost.local", |_| {
+            Err(std::io::Error::other("no such host"))
+        })
+        .unwrap();
+        assert!(warning.contains("'myhost.local' does not resolve"));
+        assert!(warning.contains("--no-resolve"));
+
+        assert!(bind_resolution_warning("empty.example", |_| Ok(Vec::new())).is_some());
+    }
+
+    #[test]
+    fn test_bind_host_resolving_locally_is_accepted() {
+        let loopback = |_: &str| Ok(vec!["127.0.1.1".parse().unwrap()]);
+        assert_eq!(bind_resolution_warning("myhost", loopback), None);
+    }
+
+    #[test]
+    fn test_bind_host_resolving_elsewhere_warns() {
+        // 203.0.113.0/24 is reserved for documentation and never local
+        let remote = |_: &str| Ok(vec!["203.0.113.7".parse().unwrap()]);
+        let warning = bind_resolution_warning("remote.example", remote).unwrap();
+        assert!(warning.contains("resolves to 203.0.113.7"));
+        assert!(warning.contains("not an address on this machine"));
+    }
+
+    #[test]
+    fn test_clamp_restart_setting() {
+        assert_eq!(
+            clamp_restart_setting("restart_retries", 0, &RESTART_RETRIES_RANGE, true),
+            1
+        );
+        assert_eq!(
+            clamp_restart_setting("restart_retries", 500, &RESTART_RETRIES_RANGE, true),
+            50
+        );
+        assert_eq!(
+            clamp_restart_setting("restart_delay", 30, &RESTART_DELAY_RANGE, true),
+            30
+        );
+        assert_eq!(
+            clamp_restart_setting("restart_delay", 3600, &RESTART_DELAY_RANGE, true),
+            300
+        );
+    }
 }
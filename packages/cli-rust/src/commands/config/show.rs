@@ -2,14 +2,84 @@
 //!
 //! Displays current configuration in table or JSON format.
 //! Uses serde serialization to automatically include all Config fields.
-
-use anyhow::Result;
-use comfy_table::{Cell, Color, Table};
+//!
+//! Output order is fixed so it diffs cleanly: JSON keys are sorted
+//! alphabetically, and the table groups fields into sections (General,
+//! Security, Cockpit, Mounts) sorted alphabetically within each section.
+
+use anyhow::{Result, anyhow};
+use comfy_table::{Attribute, Cell, Color, Table};
+use opencode_cloud_core::config::{
+    ConfigOverrides, ConfigSource, EffectiveConfig, redact_env_entry, resolve_effective_config,
+};
 use opencode_cloud_core::{Config, config};
 use serde_json::Value;
+use std::collections::BTreeMap;
 
 /// Fields that should have their values masked in output
-const SENSITIVE_FIELDS: &[&str] = &["auth_password"];
+const SENSITIVE_FIELDS: &[&str] = &["auth_password", "image_registry_auth"];
+
+/// Table section for each field; fields not listed are shown under General
+const FIELD_SECTIONS: &[(&str, Section)] = &[
+    ("allow_unauthenticated_network", Section::Security),
+    ("audit_log_enabled", Section::Security),
+    ("auth_password", Section::Security),
+    ("auth_username", Section::Security),
+    ("image_registry_auth", Section::Security),
+    ("rate_limit_attempts", Section::Security),
+    ("rate_limit_window_seconds", Section::Security),
+    ("trust_proxy", Section::Security),
+    ("users", Section::Security),
+    ("cockpit_enabled", Section::Cockpit),
+    ("cockpit_port", Section::Cockpit),
+    ("mounts", Section::Mounts),
+];
+
+/// Sections of the table output, in display order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Section {
+    General,
+    Security,
+    Cockpit,
+    Mounts,
+}
+
+impl Section {
+    fn of(key: &str) -> Self {
+        FIELD_SECTIONS
+            .iter()
+            .find(|(field, _)| *field == key)
+            .map_or(Self::General, |(_, section)| *section)
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            Self::General => "General",
+            Self::Security => "Security",
+            Self::Cockpit => "Cockpit",
+            Self::Mounts => "Mounts",
+        }
+    }
+}
+
+/// Environment variables that change behavior alongside the config file,
+/// with the effect shown when one is set
+const RECOGNIZED_ENV_VARS: &[(&str, &str)] = &[
+    (
+        "DOCKER_CONFIG",
+        "directory searched for registry credentials",
+    ),
+    ("DOCKER_HOST", "Docker endpoint (unless --socket is given)"),
+    (
+        "OPENCODE_DOCKER_BUILD_ERROR_TAIL",
+        "error lines kept from a failed image build",
+    ),
+    (
+        "OPENCODE_DOCKER_BUILD_LOG_TAIL",
+        "build log lines kept for error reports",
+    ),
+    ("TZ", "container timezone when timezone = \"auto\""),
+];
 
 /// Fields that should be highlighted when they indicate security concerns
 const SECURITY_FIELDS: &[(&str, &str)] = &[
@@ -22,24 +92,97 @@ const SECURITY_FIELDS: &[(&str, &str)] = &[
 ///
 /// Displays all configuration values in a formatted table.
 /// Uses serde serialization to automatically include all fields.
-/// Passwords are masked for security.
-pub fn cmd_config_show(config: &Config, json: bool, _quiet: bool) -> Result<()> {
+/// Passwords are masked for security, and secret-looking `container_env`
+/// values are redacted unless `show_secrets` is set. Recognized environment
+/// variables that are set are listed afterwards, since they also change
+/// behavior.
+pub fn cmd_config_show(
+    config: &Config,
+    json: bool,
+    show_secrets: bool,
+    _quiet: bool,
+) -> Result<()> {
+    let mut value = serde_json::to_value(config)?;
+    if !show_secrets {
+        redact_container_env(&mut value);
+    }
+
     if json {
-        return show_json(config);
+        show_json(value)?;
+    } else {
+        show_table(value)?;
     }
+    print_env_overrides(&active_env_overrides(|name| std::env::var(name).ok()));
+    Ok(())
+}
 
-    show_table(config)
+/// Recognized environment variables that are set, with their values and effects
+fn active_env_overrides(
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Vec<(&'static str, String, &'static str)> {
+    RECOGNIZED_ENV_VARS
+        .iter()
+        .filter_map(|&(name, effect)| {
+            let value = lookup(name).filter(|value| !value.is_empty())?;
+            Some((name, value, effect))
+        })
+        .collect()
 }
 
-fn show_json(config: &Config) -> Result<()> {
-    let mut value = serde_json::to_value(config)?;
+/// Note which environment variables are in effect
+///
+/// Goes to stderr so `--json` output stays parseable.
+fn print_env_overrides(overrides: &[(&str, String, &str)]) {
+    if overrides.is_empty() {
+        return;
+    }
+    eprintln!();
+    eprintln!("Environment variables in effect:");
+    for (name, value, effect) in overrides {
+        eprintln!("  {name}={value}  ({effect})");
+    }
+}
+
+fn show_json(mut value: Value) -> Result<()> {
     mask_sensitive_fields(&mut value);
-    println!("{}", serde_json::to_string_pretty(&value)?);
+    println!("{}", to_sorted_json(&value)?);
     Ok(())
 }
 
-fn show_table(config: &Config) -> Result<()> {
-    let value = serde_json::to_value(config)?;
+/// Pretty-print with top-level keys in alphabetical order
+///
+/// Goes through a `BTreeMap` so the order does not depend on whether
+/// serde_json's `preserve_order` feature is enabled somewhere in the build.
+fn to_sorted_json(value: &Value) -> Result<String> {
+    let Value::Object(obj) = value else {
+        return Ok(serde_json::to_string_pretty(value)?);
+    };
+    let sorted: BTreeMap<&String, &Value> = obj.iter().collect();
+    Ok(serde_json::to_string_pretty(&sorted)?)
+}
+
+/// Top-level fields grouped by section, in display order
+fn sectioned_fields(obj: &serde_json::Map<String, Value>) -> Vec<(Section, Vec<(&str, &Value)>)> {
+    let mut sections: BTreeMap<Section, BTreeMap<&str, &Value>> = BTreeMap::new();
+    for (key, val) in obj {
+        sections
+            .entry(Section::of(key))
+            .or_default()
+            .insert(key.as_str(), val);
+    }
+    sections
+        .into_iter()
+        .map(|(section, fields)| (section, fields.into_iter().collect()))
+        .collect()
+}
+
+fn section_row(section: Section, columns: usize) -> Vec<Cell> {
+    let mut row = vec![Cell::new(section.title()).add_attribute(Attribute::Bold)];
+    row.extend((1..columns).map(|_| Cell::new("")));
+    row
+}
+
+fn show_table(value: Value) -> Result<()> {
     let obj = value
         .as_object()
         .expect("Config should serialize to object");
@@ -47,10 +190,13 @@ fn show_table(config: &Config) -> Result<()> {
     let mut table = Table::new();
     table.set_header(vec!["Key", "Value"]);
 
-    for (key, val) in obj {
-        let display_value = format_value(key, val);
-        let cell = apply_cell_styling(key, val, display_value);
-        table.add_row(vec![Cell::new(key), cell]);
+    for (section, fields) in sectioned_fields(obj) {
+        table.add_row(section_row(section, 2));
+        for (key, val) in fields {
+            let display_value = format_value(key, val);
+            let cell = apply_cell_styling(key, val, display_value);
+            table.add_row(vec![Cell::new(format!("  {key}")), cell]);
+        }
     }
 
     println!("{table}");
@@ -63,6 +209,76 @@ fn show_table(config: &Config) -> Result<()> {
     Ok(())
 }
 
+/// Show the effective configuration `occ start` would use
+///
+/// Applies the same overrides as `occ start --port/--bind-address` via the
+/// shared resolver and annotates each field with where its value came from.
+pub fn cmd_config_show_effective(
+    config: &Config,
+    overrides: &ConfigOverrides,
+    json: bool,
+    show_secrets: bool,
+) -> Result<()> {
+    let effective = resolve_effective_config(config, overrides).map_err(|e| anyhow!("{e}"))?;
+    let mut value = serde_json::to_value(&effective.config)?;
+    if !show_secrets {
+        redact_container_env(&mut value);
+    }
+
+    if json {
+        mask_sensitive_fields(&mut value);
+        let annotated = annotate_sources(value, &effective);
+        println!("{}", to_sorted_json(&annotated)?);
+        return Ok(());
+    }
+
+    let obj = value
+        .as_object()
+        .expect("Config should serialize to object");
+
+    let mut table = Table::new();
+    table.set_header(vec!["Key", "Value", "Source"]);
+
+    for (section, fields) in sectioned_fields(obj) {
+        table.add_row(section_row(section, 3));
+        for (key, val) in fields {
+            let display_value = format_value(key, val);
+            let cell = apply_cell_styling(key, val, display_value);
+            let source = effective.source(key);
+            let source_cell = match source {
+                ConfigSource::Default => Cell::new(source.label()).fg(Color::DarkGrey),
+                ConfigSource::File => Cell::new(source.label()),
+                ConfigSource::Flag => Cell::new(source.label()).fg(Color::Cyan),
+            };
+            table.add_row(vec![Cell::new(format!("  {key}")), cell, source_cell]);
+        }
+    }
+
+    println!("{table}");
+
+    if let Some(path) = config::paths::get_config_path() {
+        println!();
+        println!("Config file: {}", path.display());
+    }
+
+    Ok(())
+}
+
+/// Wrap each top-level value as `{ "value": ..., "source": ... }`
+fn annotate_sources(value: Value, effective: &EffectiveConfig) -> Value {
+    let Value::Object(obj) = value else {
+        return value;
+    };
+
+    obj.into_iter()
+        .map(|(key, val)| {
+            let source = effective.source(&key).label();
+            (key, serde_json::json!({ "value": val, "source": source }))
+        })
+        .collect::<serde_json::Map<_, _>>()
+        .into()
+}
+
 /// Format a JSON value for display
 fn format_value(key: &str, value: &Value) -> String {
     // Handle sensitive fields first
@@ -150,6 +366,19 @@ fn is_localhost(addr: &str) -> bool {
     matches!(addr, "127.0.0.1" | "::1" | "localhost")
 }
 
+/// Redact secret-looking entries of `container_env` in a serialized config
+fn redact_container_env(value: &mut Value) {
+    let Some(Value::Array(entries)) = value.get_mut("container_env") else {
+        return;
+    };
+
+    for entry in entries.iter_mut() {
+        if let Value::String(s) = entry {
+            *s = redact_env_entry(s);
+        }
+    }
+}
+
 /// Mask sensitive fields in a JSON Value (for JSON output)
 fn mask_sensitive_fields(value: &mut Value) {
     let Value::Object(obj) = value else {
@@ -175,6 +404,31 @@ fn mask_sensitive_fields(value: &mut Value) {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_active_env_overrides_reports_set_vars() {
+        let env = [
+            ("OPENCODE_DOCKER_BUILD_LOG_TAIL", "100"),
+            ("TZ", "Europe/Berlin"),
+            ("DOCKER_HOST", ""),
+            ("UNRELATED", "1"),
+        ];
+        let lookup = |name: &str| {
+            env.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        };
+        let active = active_env_overrides(lookup);
+        let names: Vec<&str> = active.iter().map(|(name, _, _)| *name).collect();
+        assert_eq!(names, vec!["OPENCODE_DOCKER_BUILD_LOG_TAIL", "TZ"]);
+        assert_eq!(active[1].1, "Europe/Berlin");
+        assert!(active[1].2.contains("timezone"));
+    }
+
+    #[test]
+    fn test_active_env_overrides_empty_when_unset() {
+        assert!(active_env_overrides(|_| None).is_empty());
+    }
+
     #[test]
     fn test_format_value_masks_password() {
         let val = Value::String("secret".to_string());
@@ -243,4 +497,106 @@ mod tests {
         assert!(!is_localhost("0.0.0.0"));
         assert!(!is_localhost("192.168.1.1"));
     }
+
+    #[test]
+    fn test_redact_container_env_in_json() {
+        let config = Config {
+            container_env: vec!["GITHUB_TOKEN=ghp_x".to_string(), "TZ=UTC".to_string()],
+            ..Config::default()
+        };
+        let mut value = serde_json::to_value(&config).unwrap();
+        redact_container_env(&mut value);
+        assert_eq!(
+            value["container_env"],
+            serde_json::json!(["GITHUB_TOKEN=***", "TZ=UTC"])
+        );
+    }
+
+    #[test]
+    fn test_annotate_sources_wraps_values() {
+        let file = Config {
+            opencode_web_port: 4000,
+            ..Default::default()
+        };
+        let overrides = ConfigOverrides {
+            bind_address: Some("::1".to_string()),
+            ..Default::default()
+        };
+        let effective = resolve_effective_config(&file, &overrides).unwrap();
+        let value = serde_json::to_value(&effective.config).unwrap();
+
+        let annotated = annotate_sources(value, &effective);
+
+        assert_eq!(
+            annotated["opencode_web_port"],
+            serde_json::json!({ "value": 4000, "source": "config file" })
+        );
+        assert_eq!(
+            annotated["bind_address"],
+            serde_json::json!({ "value": "::1", "source": "flag" })
+        );
+        assert_eq!(annotated["auto_restart"]["source"], "default");
+    }
+
+    /// Top-level keys of pretty-printed JSON, in output order
+    fn top_level_keys(json: &str) -> Vec<String> {
+        json.lines()
+            .filter(|line| line.starts_with("  \"") && !line.starts_with("    "))
+            .filter_map(|line| line.trim().split('"').nth(1).map(str::to_string))
+            .collect()
+    }
+
+    #[test]
+    fn test_json_keys_are_sorted_and_stable() {
+        let config = Config {
+            mounts: vec!["/a:/b".to_string()],
+            ..Config::default()
+        };
+        let value = serde_json::to_value(&config).unwrap();
+
+        let first = to_sorted_json(&value).unwrap();
+        let second = to_sorted_json(&serde_json::to_value(&config).unwrap()).unwrap();
+        assert_eq!(first, second);
+
+        let keys = top_level_keys(&first);
+        let mut sorted = keys.clone();
+        sorted.sort();
+        assert_eq!(keys, sorted);
+        assert_eq!(keys.len(), value.as_object().unwrap().len());
+    }
+
+    #[test]
+    fn test_table_sections_in_order() {
+        let value = serde_json::to_value(Config::default()).unwrap();
+        let sections = sectioned_fields(value.as_object().unwrap());
+
+        let order: Vec<Section> = sections.iter().map(|(s, _)| *s).collect();
+        assert_eq!(
+            order,
+            vec![
+                Section::General,
+                Section::Security,
+                Section::Cockpit,
+                Section::Mounts
+            ]
+        );
+
+        let keys_in = |section: Section| -> Vec<&str> {
+            sections
+                .iter()
+                .find(|(s, _)| *s == section)
+                .map(|(_, fields)| fields.iter().map(|(k, _)| *k).collect())
+                .unwrap_or_default()
+        };
+        assert_eq!(
+            keys_in(Section::Cockpit),
+            vec!["cockpit_enabled", "cockpit_port"]
+        );
+        assert_eq!(keys_in(Section::Mounts), vec!["mounts"]);
+        assert!(keys_in(Section::Security).contains(&"auth_password"));
+        assert!(keys_in(Section::General).contains(&"opencode_web_port"));
+
+        let total: usize = sections.iter().map(|(_, f)| f.len()).sum();
+        assert_eq!(total, value.as_object().unwrap().len());
+    }
 }
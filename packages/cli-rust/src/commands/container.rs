@@ -0,0 +1,127 @@
+//! Container command implementation
+//!
+//! Diagnostics for the running opencode-cloud container.
+
+use anyhow::{Result, bail};
+use clap::{Args, Subcommand};
+use comfy_table::Table;
+use console::style;
+use opencode_cloud_core::bollard::service::ContainerTopResponse;
+use opencode_cloud_core::docker::{CONTAINER_NAME, container_is_running, container_top};
+
+/// Arguments for the container command
+#[derive(Args)]
+pub struct ContainerArgs {
+    #[command(subcommand)]
+    pub command: ContainerCommands,
+}
+
+/// Container subcommands
+#[derive(Subcommand)]
+pub enum ContainerCommands {
+    /// List the processes running in the container
+    Top(ContainerTopArgs),
+}
+
+/// Arguments for container top
+#[derive(Args)]
+pub struct ContainerTopArgs {
+    /// Options passed to ps inside the container (default: -ef)
+    #[arg(long, value_name = "ARGS", allow_hyphen_values = true)]
+    pub ps_args: Option<String>,
+}
+
+/// Handle container command
+pub async fn cmd_container(
+    args: &ContainerArgs,
+    maybe_host: Option<&str>,
+    quiet: bool,
+) -> Result<()> {
+    match &args.command {
+        ContainerCommands::Top(top_args) => cmd_container_top(top_args, maybe_host, quiet).await,
+    }
+}
+
+/// Print the container's process table
+async fn cmd_container_top(
+    args: &ContainerTopArgs,
+    maybe_host: Option<&str>,
+    quiet: bool,
+) -> Result<()> {
+    let (client, _host_name) = crate::resolve_docker_client(maybe_host).await?;
+    client
+        .verify_connection()
+        .await
+        .map_err(|e| anyhow::anyhow!("{e}"))?;
+
+    if !container_is_running(&client, CONTAINER_NAME).await? {
+        bail!(
+            "Container is not running. Start it with: {}",
+            style("occ start").cyan()
+        );
+    }
+
+    let top = container_top(&client, CONTAINER_NAME, args.ps_args.as_deref()).await?;
+    let table = top_table(&top);
+    println!("{table}");
+    if !quiet {
+        println!();
+        println!(
+            "  {} {}",
+            style("Processes:").dim(),
+            top.processes.as_ref().map_or(0, Vec::len)
+        );
+    }
+    Ok(())
+}
+
+/// Render a `top` response as a table, one row per process
+fn top_table(top: &ContainerTopResponse) -> Table {
+    let mut table = Table::new();
+    if let Some(titles) = &top.titles {
+        table.set_header(titles.clone());
+    }
+    for process in top.processes.iter().flatten() {
+        table.add_row(process.clone());
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn top_table_renders_titles_and_processes() {
+        let top = ContainerTopResponse {
+            titles: Some(strings(&["UID", "PID", "CMD"])),
+            processes: Some(vec![
+                strings(&["root", "1", "/sbin/tini -- opencode"]),
+                strings(&["opencode", "42", "node server.js"]),
+            ]),
+        };
+        let table = top_table(&top);
+        assert_eq!(table.row_count(), 2);
+
+        let rendered = table.to_string();
+        for cell in [
+            "UID",
+            "PID",
+            "CMD",
+            "/sbin/tini -- opencode",
+            "node server.js",
+        ] {
+            assert!(rendered.contains(cell), "missing {cell}");
+        }
+    }
+
+    #[test]
+    fn top_table_handles_empty_response() {
+        let table = top_table(&ContainerTopResponse::default());
+        assert_eq!(table.row_count(), 0);
+    }
+}
@@ -0,0 +1,392 @@
+//! Doctor command implementation
+//!
+//! Checks the local installation for common problems and, with `--fix`,
+//! applies the safe remediations. Fixes that replace or download something
+//! ask for confirmation first.
+
+use super::install::{build_service_config, resolve_executable_path};
+use anyhow::{Result, anyhow};
+use clap::{Args, ValueEnum};
+use console::style;
+use opencode_cloud_core::config::paths::{get_config_dir, get_data_dir, get_pid_path};
+use opencode_cloud_core::docker::{
+    DockerClient, IMAGE_NAME_GHCR, IMAGE_TAG_DEFAULT, ProgressReporter, RegistryAuth, image_exists,
+    pull_image,
+};
+use opencode_cloud_core::{
+    Config, get_service_manager, is_service_registration_supported, pid_file_is_stale,
+};
+use std::path::PathBuf;
+
+/// Arguments for the doctor command
+#[derive(Args)]
+pub struct DoctorArgs {
+    /// Attempt to fix the problems found
+    #[arg(long)]
+    pub fix: bool,
+
+    /// Skip a check (can be specified multiple times)
+    #[arg(long, value_enum, value_name = "CHECK", action = clap::ArgAction::Append)]
+    pub skip: Vec<Check>,
+
+    /// Apply fixes that need confirmation without prompting
+    #[arg(short, long, requires = "fix")]
+    pub yes: bool,
+}
+
+/// A doctor check, in the order they run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Check {
+    ConfigDir,
+    DataDir,
+    PidFile,
+    Docker,
+    Image,
+    ServiceUnit,
+}
+
+impl Check {
+    const ALL: [Self; 6] = [
+        Self::ConfigDir,
+        Self::DataDir,
+        Self::PidFile,
+        Self::Docker,
+        Self::Image,
+        Self::ServiceUnit,
+    ];
+
+    /// Name as accepted by `--skip`
+    fn name(self) -> &'static str {
+        match self {
+            Self::ConfigDir => "config-dir",
+            Self::DataDir => "data-dir",
+            Self::PidFile => "pid-file",
+            Self::Docker => "docker",
+            Self::Image => "image",
+            Self::ServiceUnit => "service-unit",
+        }
+    }
+
+    /// How a failure of this check can be remediated
+    fn remedy(self) -> Remedy {
+        match self {
+            Self::ConfigDir | Self::DataDir | Self::PidFile => Remedy::Automatic,
+            Self::Image | Self::ServiceUnit => Remedy::Confirm,
+            Self::Docker => Remedy::Manual(
+                "start Docker, or install it from https://docs.docker.com/get-docker/",
+            ),
+        }
+    }
+}
+
+/// How a failed check can be fixed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Remedy {
+    /// Safe to apply without asking
+    Automatic,
+    /// Downloads or replaces something, so needs confirmation
+    Confirm,
+    /// Cannot be fixed by occ; the hint says what to do
+    Manual(&'static str),
+}
+
+/// Outcome of running a check
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum CheckStatus {
+    Pass(String),
+    Fail(String),
+    /// The check does not apply here (e.g. no service installed)
+    NotApplicable(String),
+}
+
+/// What `--fix` should do for a failed check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FixAction {
+    Apply,
+    Prompt,
+    NotFixable(&'static str),
+}
+
+/// Decide how to handle a failed check in `--fix` mode
+fn fix_action(check: Check, yes: bool) -> FixAction {
+    match check.remedy() {
+        Remedy::Automatic => FixAction::Apply,
+        Remedy::Confirm if yes => FixAction::Apply,
+        Remedy::Confirm => FixAction::Prompt,
+        Remedy::Manual(hint) => FixAction::NotFixable(hint),
+    }
+}
+
+/// Checks to run, in order, without the skipped ones
+fn selected_checks(skip: &[Check]) -> Vec<Check> {
+    Check::ALL
+        .into_iter()
+        .filter(|check| !skip.contains(check))
+        .collect()
+}
+
+/// Run health checks and optionally fix what they find
+pub async fn cmd_doctor(args: &DoctorArgs, config: &Config, quiet: bool) -> Result<()> {
+    let client = DockerClient::new().ok();
+    let mut failures = 0;
+    let mut fixed = 0;
+
+    for check in selected_checks(&args.skip) {
+        let status = run_check(check, client.as_ref(), config).await;
+        let message = match &status {
+            CheckStatus::Pass(message) => {
+                if !quiet {
+                    println!("{} {}: {message}", style("✓").green(), check.name());
+                }
+                continue;
+            }
+            CheckStatus::NotApplicable(message) => {
+                if !quiet {
+                    println!("{} {}: {message}", style("-").dim(), check.name());
+                }
+                continue;
+            }
+            CheckStatus::Fail(message) => message,
+        };
+
+        println!("{} {}: {message}", style("✗").red(), check.name());
+        if !args.fix {
+            failures += 1;
+            continue;
+        }
+
+        let apply = match fix_action(check, args.yes) {
+            FixAction::Apply => true,
+            FixAction::Prompt => dialoguer::Confirm::new()
+                .with_prompt(format!("Fix {}?", check.name()))
+                .default(false)
+                .interact()?,
+            FixAction::NotFixable(hint) => {
+                println!("  {} not fixable automatically: {hint}", style("→").dim());
+                failures += 1;
+                continue;
+            }
+        };
+        if !apply {
+            println!("  {} skipped", style("→").dim());
+            failures += 1;
+            continue;
+        }
+
+        match apply_fix(check, client.as_ref(), config).await {
+            Ok(done) => {
+                println!("  {} fixed: {done}", style("→").green());
+                fixed += 1;
+            }
+            Err(e) => {
+                println!("  {} fix failed: {e}", style("→").red());
+                failures += 1;
+            }
+        }
+    }
+
+    if !quiet {
+        println!();
+        if failures == 0 {
+            let suffix = if fixed > 0 {
+                format!(" ({fixed} fixed)")
+            } else {
+                String::new()
+            };
+            println!(
+                "{} No problems found{suffix}",
+                style("Success:").green().bold()
+            );
+        } else if !args.fix {
+            println!(
+                "{} problem(s) found. Run {} to fix what can be fixed.",
+                failures,
+                style("occ doctor --fix").cyan()
+            );
+        } else {
+            println!("{failures} problem(s) remain.");
+        }
+    }
+
+    if failures > 0 {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Run one check
+async fn run_check(check: Check, client: Option<&DockerClient>, config: &Config) -> CheckStatus {
+    match check {
+        Check::ConfigDir => dir_status(get_config_dir()),
+        Check::DataDir => dir_status(get_data_dir()),
+        Check::PidFile => match get_pid_path() {
+            Some(path) if pid_file_is_stale(&path) => {
+                CheckStatus::Fail(format!("stale PID file at {}", path.display()))
+            }
+            _ => CheckStatus::Pass("no stale PID file".to_string()),
+        },
+        Check::Docker => match client {
+            Some(client) => match client.verify_connection().await {
+                Ok(()) => CheckStatus::Pass("daemon reachable".to_string()),
+                Err(e) => CheckStatus::Fail(e.to_string()),
+            },
+            None => CheckStatus::Fail("could not connect to Docker".to_string()),
+        },
+        Check::Image => {
+            let Some(client) = client else {
+                return CheckStatus::NotApplicable("Docker unavailable".to_string());
+            };
+            match image_exists(client, IMAGE_NAME_GHCR, IMAGE_TAG_DEFAULT).await {
+                Ok(true) => CheckStatus::Pass("image present".to_string()),
+                Ok(false) => CheckStatus::Fail(format!(
+                    "image {IMAGE_NAME_GHCR}:{IMAGE_TAG_DEFAULT} not found"
+                )),
+                Err(_) => CheckStatus::NotApplicable("Docker unavailable".to_string()),
+            }
+        }
+        Check::ServiceUnit => service_unit_status(config),
+    }
+}
+
+fn dir_status(dir: Option<PathBuf>) -> CheckStatus {
+    match dir {
+        Some(dir) if dir.is_dir() => CheckStatus::Pass(dir.display().to_string()),
+        Some(dir) => CheckStatus::Fail(format!("{} is missing", dir.display())),
+        None => CheckStatus::NotApplicable("no directory on this platform".to_string()),
+    }
+}
+
+/// Compare the installed service file with what `occ install` would write
+fn service_unit_status(config: &Config) -> CheckStatus {
+    if !is_service_registration_supported() {
+        return CheckStatus::NotApplicable("not supported on this platform".to_string());
+    }
+    let Ok(manager) = get_service_manager() else {
+        return CheckStatus::NotApplicable("no service manager available".to_string());
+    };
+    if !manager.is_installed().unwrap_or(false) {
+        return CheckStatus::NotApplicable("service not installed".to_string());
+    }
+
+    let expected = resolve_executable_path(None)
+        .map(|exe| build_service_config(config, exe))
+        .and_then(|service_config| manager.render_service_file(&service_config));
+    let installed = std::fs::read_to_string(manager.service_file_path());
+    match (installed, expected) {
+        (Ok(installed), Ok(expected)) if installed == expected => {
+            CheckStatus::Pass("service file up to date".to_string())
+        }
+        (Ok(_), Ok(_)) => CheckStatus::Fail(format!(
+            "{} is out of date with config",
+            manager.service_file_path().display()
+        )),
+        (Err(e), _) => CheckStatus::Fail(format!("could not read service file: {e}")),
+        (_, Err(e)) => CheckStatus::Fail(format!("could not render service file: {e}")),
+    }
+}
+
+/// Apply the fix for a failed check, returning what was done
+async fn apply_fix(check: Check, client: Option<&DockerClient>, config: &Config) -> Result<String> {
+    match check {
+        Check::ConfigDir | Check::DataDir => {
+            let dir = if check == Check::ConfigDir {
+                get_config_dir()
+            } else {
+                get_data_dir()
+            }
+            .ok_or_else(|| anyhow!("Could not determine directory"))?;
+            std::fs::create_dir_all(&dir)?;
+            Ok(format!("created {}", dir.display()))
+        }
+        Check::PidFile => {
+            let path = get_pid_path().ok_or_else(|| anyhow!("Could not determine PID path"))?;
+            std::fs::remove_file(&path)?;
+            Ok(format!("removed {}", path.display()))
+        }
+        Check::Image => {
+            let client = client.ok_or_else(|| anyhow!("Docker unavailable"))?;
+            let mut progress = ProgressReporter::with_context("Pulling prebuilt image");
+            let image = pull_image(
+                client,
+                Some(IMAGE_TAG_DEFAULT),
+                &RegistryAuth::from_config(config),
+                &mut progress,
+            )
+            .await?;
+            Ok(format!("pulled {image}"))
+        }
+        Check::ServiceUnit => {
+            let manager = get_service_manager()?;
+            let service_config = build_service_config(config, resolve_executable_path(None)?);
+            manager.install(&service_config)?;
+            Ok(format!(
+                "rewrote and reloaded {}",
+                manager.service_file_path().display()
+            ))
+        }
+        Check::Docker => Err(anyhow!("not fixable automatically")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn safe_fixes_apply_without_prompting() {
+        for check in [Check::ConfigDir, Check::DataDir, Check::PidFile] {
+            assert_eq!(fix_action(check, false), FixAction::Apply);
+        }
+    }
+
+    #[test]
+    fn destructive_fixes_need_confirmation() {
+        for check in [Check::Image, Check::ServiceUnit] {
+            assert_eq!(fix_action(check, false), FixAction::Prompt);
+            assert_eq!(fix_action(check, true), FixAction::Apply);
+        }
+    }
+
+    #[test]
+    fn docker_is_reported_not_fixable() {
+        assert!(matches!(
+            fix_action(Check::Docker, true),
+            FixAction::NotFixable(hint) if hint.contains("Docker")
+        ));
+    }
+
+    #[test]
+    fn skipped_checks_are_not_run() {
+        assert_eq!(selected_checks(&[]), Check::ALL.to_vec());
+        assert_eq!(
+            selected_checks(&[Check::Image, Check::PidFile]),
+            vec![
+                Check::ConfigDir,
+                Check::DataDir,
+                Check::Docker,
+                Check::ServiceUnit
+            ]
+        );
+    }
+
+    #[test]
+    fn check_names_match_skip_values() {
+        for check in Check::ALL {
+            let parsed = Check::from_str(check.name(), false).unwrap();
+            assert_eq!(parsed, check);
+        }
+    }
+
+    #[test]
+    fn missing_dir_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(matches!(
+            dir_status(Some(dir.path().to_path_buf())),
+            CheckStatus::Pass(_)
+        ));
+        assert!(matches!(
+            dir_status(Some(dir.path().join("missing"))),
+            CheckStatus::Fail(_)
+        ));
+    }
+}
@@ -6,19 +6,23 @@ use console::style;
 use dialoguer::Confirm;
 use indicatif::{ProgressBar, ProgressStyle};
 use opencode_cloud_core::{
-    HostConfig, HostError, detect_distro, get_docker_install_commands, host_exists_in_ssh_config,
-    install_docker, load_hosts, query_ssh_config, save_hosts, test_connection,
-    verify_docker_installed, write_ssh_config_entry,
+    HostConfig, HostError, HostsFile, SshConfigHost, StrictHostKeyChecking,
+    host_exists_in_ssh_config, list_ssh_config_hosts, load_hosts, query_ssh_config, save_hosts,
+    ssh_glob_matches, test_connection, write_ssh_config_entry,
 };
 
+use super::provision::offer_docker_installation;
+
 /// Arguments for host add command
 #[derive(Args)]
 pub struct HostAddArgs {
     /// Name to identify this host (e.g., "prod-1", "staging")
-    pub name: String,
+    #[arg(required_unless_present = "from_ssh_config")]
+    pub name: Option<String>,
 
     /// SSH hostname or IP address
-    pub hostname: String,
+    #[arg(required_unless_present = "from_ssh_config")]
+    pub hostname: Option<String>,
 
     /// SSH username (default: from SSH config or current user)
     #[arg(short, long)]
@@ -44,6 +48,13 @@ pub struct HostAddArgs {
     #[arg(short, long)]
     pub description: Option<String>,
 
+    /// SSH host key checking for this host (yes, accept-new or no)
+    ///
+    /// Used for provisioning and tunneling. `accept-new` trusts unknown keys
+    /// on first connect; `yes` requires the key to already be in known_hosts.
+    #[arg(long, value_enum, default_value_t = StrictHostKeyChecking::AcceptNew)]
+    pub strict_host_key_checking: StrictHostKeyChecking,
+
     /// Skip connection verification
     #[arg(long)]
     pub no_verify: bool,
@@ -55,22 +66,216 @@ pub struct HostAddArgs {
     /// Don't prompt to add host to SSH config
     #[arg(long)]
     pub no_ssh_config: bool,
+
+    /// Only test connectivity and Docker; don't save the host or touch SSH config
+    #[arg(long, conflicts_with = "no_verify")]
+    pub test_only: bool,
+
+    /// Import every concrete `Host` alias from ~/.ssh/config
+    ///
+    /// Wildcard entries and names that are already registered are skipped.
+    /// Connection settings come from the SSH config; --group, --description
+    /// and --strict-host-key-checking apply to every imported host.
+    #[arg(
+        long,
+        conflicts_with_all = ["name", "hostname", "user", "port", "identity_file", "jump_host", "test_only"]
+    )]
+    pub from_ssh_config: bool,
+
+    /// Only import SSH config aliases matching this glob (e.g. "prod-*")
+    #[arg(long = "match", value_name = "GLOB", requires = "from_ssh_config")]
+    pub match_glob: Option<String>,
+}
+
+/// Hosts file access used by `host add` (injectable for tests)
+trait HostStore {
+    fn load(&self) -> Result<HostsFile, HostError>;
+    fn save(&self, hosts: &HostsFile) -> Result<(), HostError>;
+}
+
+/// The real hosts file on disk
+struct FileHostStore;
+
+impl HostStore for FileHostStore {
+    fn load(&self) -> Result<HostsFile, HostError> {
+        load_hosts()
+    }
+
+    fn save(&self, hosts: &HostsFile) -> Result<(), HostError> {
+        save_hosts(hosts)
+    }
 }
 
 pub async fn cmd_host_add(args: &HostAddArgs, quiet: bool, _verbose: u8) -> Result<()> {
+    if args.from_ssh_config {
+        let entries = list_ssh_config_hosts()?;
+        return import_ssh_config_hosts(args, quiet, &FileHostStore, entries, test_connection)
+            .await;
+    }
+    add_host(args, quiet, &FileHostStore, test_connection).await
+}
+
+/// SSH config aliases split into those to import and those already registered
+#[derive(Debug, Default)]
+struct SshConfigImportPlan {
+    to_add: Vec<SshConfigHost>,
+    already_registered: Vec<String>,
+}
+
+/// Decide which SSH config aliases to import
+///
+/// Aliases not matching `glob` are dropped; registered names are skipped
+/// unless `force` is set.
+fn plan_ssh_config_import(
+    entries: Vec<SshConfigHost>,
+    hosts: &HostsFile,
+    glob: Option<&str>,
+    force: bool,
+) -> SshConfigImportPlan {
+    let mut plan = SshConfigImportPlan::default();
+    for entry in entries {
+        if glob.is_some_and(|glob| !ssh_glob_matches(glob, &entry.alias)) {
+            continue;
+        }
+        if hosts.has_host(&entry.alias) && !force {
+            plan.already_registered.push(entry.alias);
+        } else {
+            plan.to_add.push(entry);
+        }
+    }
+    plan
+}
+
+/// Build the host config for an imported SSH config alias
+fn host_config_from_ssh_entry(entry: &SshConfigHost, args: &HostAddArgs) -> HostConfig {
+    let settings = &entry.settings;
+    let mut config = HostConfig::new(&entry.hostname);
+    if let Some(user) = &settings.user {
+        config = config.with_user(user);
+    }
+    if let Some(port) = settings.port {
+        config = config.with_port(port);
+    }
+    if let Some(key) = &settings.identity_file {
+        config = config.with_identity_file(key);
+    }
+    if let Some(jump) = &settings.proxy_jump {
+        config = config.with_jump_host(jump);
+    }
+    for group in &args.group {
+        config = config.with_group(group);
+    }
+    if let Some(desc) = &args.description {
+        config = config.with_description(desc);
+    }
+    config.with_strict_host_key_checking(args.strict_host_key_checking)
+}
+
+/// `occ host add --from-ssh-config`: register SSH config aliases in bulk
+///
+/// Hosts that fail verification are reported and skipped rather than
+/// aborting the import; the hosts file is saved once at the end.
+async fn import_ssh_config_hosts(
+    args: &HostAddArgs,
+    quiet: bool,
+    store: &impl HostStore,
+    entries: Vec<SshConfigHost>,
+    verify: impl AsyncFn(&HostConfig) -> Result<String, HostError>,
+) -> Result<()> {
+    let mut hosts = store.load()?;
+    let plan = plan_ssh_config_import(entries, &hosts, args.match_glob.as_deref(), args.force);
+
+    if !quiet {
+        for name in &plan.already_registered {
+            println!(
+                "{} '{}' is already registered (use --force to overwrite)",
+                style("Skipped:").dim(),
+                name
+            );
+        }
+    }
+
+    let mut added = 0;
+    let mut failed = 0;
+    for entry in &plan.to_add {
+        let config = host_config_from_ssh_entry(entry, args);
+
+        if !args.no_verify {
+            if let Err(e) = verify(&config).await {
+                failed += 1;
+                if !quiet {
+                    eprintln!(
+                        "{} '{}' ({}): {}",
+                        style("Failed:").red(),
+                        entry.alias,
+                        entry.hostname,
+                        e
+                    );
+                }
+                continue;
+            }
+        }
+
+        hosts.add_host(&entry.alias, config);
+        added += 1;
+        if !quiet {
+            println!(
+                "{} Host '{}' ({}).",
+                style("Added:").green(),
+                style(&entry.alias).cyan(),
+                entry.hostname
+            );
+        }
+    }
+
+    if added > 0 {
+        store.save(&hosts)?;
+    }
+
+    if !quiet {
+        if plan.to_add.is_empty() && plan.already_registered.is_empty() {
+            println!("No matching hosts found in ~/.ssh/config.");
+        } else {
+            println!();
+            println!(
+                "Imported {added} host(s) from ~/.ssh/config ({} already registered, {failed} failed verification).",
+                plan.already_registered.len()
+            );
+        }
+        if failed > 0 {
+            println!(
+                "  {} Re-run with {} to add unreachable hosts anyway.",
+                style("Tip:").dim(),
+                style("--no-verify").yellow()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+async fn add_host(
+    args: &HostAddArgs,
+    quiet: bool,
+    store: &impl HostStore,
+    verify: impl AsyncFn(&HostConfig) -> Result<String, HostError>,
+) -> Result<()> {
+    let (Some(name), Some(hostname)) = (args.name.as_deref(), args.hostname.as_deref()) else {
+        bail!("NAME and HOSTNAME are required unless --from-ssh-config is given");
+    };
+
     // Load existing hosts
-    let mut hosts = load_hosts()?;
+    let mut hosts = store.load()?;
 
-    // Check if host already exists
-    if hosts.has_host(&args.name) && !args.force {
+    // Check if host already exists (irrelevant when nothing will be saved)
+    if hosts.has_host(name) && !args.force && !args.test_only {
         bail!(
-            "Host '{}' already exists. Use --force to overwrite, or choose a different name.",
-            args.name
+            "Host '{name}' already exists. Use --force to overwrite, or choose a different name."
         );
     }
 
     // Query SSH config for this hostname to auto-fill settings
-    let ssh_config_match = query_ssh_config(&args.hostname).unwrap_or_default();
+    let ssh_config_match = query_ssh_config(hostname).unwrap_or_default();
 
     if !quiet && ssh_config_match.has_settings() {
         println!(
@@ -81,7 +286,7 @@ pub async fn cmd_host_add(args: &HostAddArgs, quiet: bool, _verbose: u8) -> Resu
     }
 
     // Build host config, preferring explicit args > SSH config > defaults
-    let mut config = HostConfig::new(&args.hostname);
+    let mut config = HostConfig::new(hostname);
 
     // User: explicit arg > SSH config > current user (HostConfig default)
     let effective_user = args.user.clone().or_else(|| ssh_config_match.user.clone());
@@ -120,6 +325,7 @@ pub async fn cmd_host_add(args: &HostAddArgs, quiet: bool, _verbose: u8) -> Resu
     if let Some(desc) = &args.description {
         config = config.with_description(desc);
     }
+    config = config.with_strict_host_key_checking(args.strict_host_key_checking);
 
     // Track if user provided custom settings that aren't in SSH config
     let has_custom_settings = args.user.is_some()
@@ -146,11 +352,11 @@ pub async fn cmd_host_add(args: &HostAddArgs, quiet: bool, _verbose: u8) -> Resu
             );
             spinner.set_message(format!(
                 "Testing connection to {}@{}...",
-                config.user, args.hostname
+                config.user, hostname
             ));
             spinner.enable_steady_tick(std::time::Duration::from_millis(100));
 
-            match test_connection(&config).await {
+            match verify(&config).await {
                 Ok(docker_version) => {
                     spinner.finish_with_message(format!(
                         "{} Connected (Docker {})",
@@ -166,9 +372,15 @@ pub async fn cmd_host_add(args: &HostAddArgs, quiet: bool, _verbose: u8) -> Resu
                     ));
                     eprintln!();
 
+                    // Installing Docker changes the remote host; not part of a dry test
+                    if args.test_only {
+                        bail!("Docker is not installed on {hostname}");
+                    }
+
                     // Offer to install Docker
+                    let retry_command = format!("occ host add {hostname} {hostname}");
                     if let Some(installed) =
-                        offer_docker_installation(&config, &args.hostname, quiet)?
+                        offer_docker_installation(&config, hostname, &retry_command, quiet)?
                     {
                         if installed {
                             verification_succeeded = true;
@@ -186,7 +398,7 @@ pub async fn cmd_host_add(args: &HostAddArgs, quiet: bool, _verbose: u8) -> Resu
                     // Provide helpful tips based on the error
                     print_connection_failure_tips(
                         &config,
-                        &args.hostname,
+                        hostname,
                         args.user.is_none(),
                         args.identity_file.is_none(),
                     );
@@ -196,32 +408,46 @@ pub async fn cmd_host_add(args: &HostAddArgs, quiet: bool, _verbose: u8) -> Resu
             }
         } else {
             // Quiet mode - just test, fail silently
-            test_connection(&config).await?;
+            let docker_version = verify(&config).await?;
+            if args.test_only {
+                println!("{docker_version}");
+            }
             verification_succeeded = true;
         }
     }
 
+    if args.test_only {
+        if !quiet {
+            println!(
+                "{} Host '{}' was not saved (--test-only).",
+                style("Note:").dim(),
+                name
+            );
+        }
+        return Ok(());
+    }
+
     // Add host to config
-    let is_overwrite = hosts.has_host(&args.name);
-    hosts.add_host(&args.name, config.clone());
+    let is_overwrite = hosts.has_host(name);
+    hosts.add_host(name, config.clone());
 
     // Save
-    save_hosts(&hosts)?;
+    store.save(&hosts)?;
 
     if !quiet {
         if is_overwrite {
             println!(
                 "{} Host '{}' updated ({}).",
                 style("Updated:").yellow(),
-                style(&args.name).cyan(),
-                args.hostname
+                style(name).cyan(),
+                hostname
             );
         } else {
             println!(
                 "{} Host '{}' added ({}).",
                 style("Added:").green(),
-                style(&args.name).cyan(),
-                args.hostname
+                style(name).cyan(),
+                hostname
             );
         }
 
@@ -229,7 +455,7 @@ pub async fn cmd_host_add(args: &HostAddArgs, quiet: bool, _verbose: u8) -> Resu
             println!(
                 "  {} Connection not verified. Run {} to test.",
                 style("Note:").dim(),
-                style(format!("occ host test {}", args.name)).yellow()
+                style(format!("occ host test {name}")).yellow()
             );
         }
 
@@ -241,21 +467,20 @@ pub async fn cmd_host_add(args: &HostAddArgs, quiet: bool, _verbose: u8) -> Resu
         if verification_succeeded
             && has_custom_settings
             && !args.no_ssh_config
-            && !host_exists_in_ssh_config(&args.name)
+            && !host_exists_in_ssh_config(name)
         {
             println!();
             let should_add = Confirm::new()
                 .with_prompt(format!(
-                    "Add '{}' to ~/.ssh/config for easier SSH access?",
-                    args.name
+                    "Add '{name}' to ~/.ssh/config for easier SSH access?"
                 ))
                 .default(true)
                 .interact()?;
 
             if should_add {
                 match write_ssh_config_entry(
-                    &args.name,
-                    &args.hostname,
+                    name,
+                    hostname,
                     args.user.as_deref(),
                     args.port,
                     args.identity_file.as_deref(),
@@ -270,7 +495,7 @@ pub async fn cmd_host_add(args: &HostAddArgs, quiet: bool, _verbose: u8) -> Resu
                         println!(
                             "  {} You can now use: {}",
                             style("Tip:").dim(),
-                            style(format!("ssh {}", args.name)).yellow()
+                            style(format!("ssh {name}")).yellow()
                         );
                     }
                     Err(e) => {
@@ -288,175 +513,6 @@ pub async fn cmd_host_add(args: &HostAddArgs, quiet: bool, _verbose: u8) -> Resu
     Ok(())
 }
 
-/// Offer to install Docker on a remote host
-///
-/// Returns:
-/// - `Ok(Some(true))` - Docker was installed successfully
-/// - `Ok(Some(false))` - User declined or installation failed
-/// - `Ok(None)` - User declined installation
-fn offer_docker_installation(
-    config: &HostConfig,
-    hostname: &str,
-    quiet: bool,
-) -> Result<Option<bool>> {
-    if quiet {
-        return Ok(None);
-    }
-
-    println!(
-        "  {} Docker is not installed on {}",
-        style("Detected:").yellow(),
-        style(hostname).cyan()
-    );
-    println!();
-
-    // Detect the Linux distribution
-    let distro = match detect_distro(config) {
-        Ok(d) => d,
-        Err(e) => {
-            eprintln!(
-                "  {} Could not detect Linux distribution: {}",
-                style("Error:").red(),
-                e
-            );
-            return Ok(None);
-        }
-    };
-
-    println!(
-        "  {} {} ({})",
-        style("Distribution:").dim(),
-        distro.pretty_name,
-        distro.family
-    );
-    println!();
-
-    // Get the commands that would be run
-    let commands = match get_docker_install_commands(&distro) {
-        Ok(c) => c,
-        Err(e) => {
-            eprintln!("  {} {}", style("Error:").red(), e);
-            println!();
-            println!(
-                "  {} Install Docker manually, then re-run this command.",
-                style("Tip:").dim()
-            );
-            return Ok(None);
-        }
-    };
-
-    // Show what will be done
-    println!(
-        "  {} The following commands will be run:",
-        style("Installation:").cyan()
-    );
-    for cmd in &commands {
-        println!("    {}", style(cmd).dim());
-    }
-    println!();
-
-    // Ask for confirmation
-    let should_install = Confirm::new()
-        .with_prompt("Install Docker on the remote host?")
-        .default(true)
-        .interact()?;
-
-    if !should_install {
-        println!();
-        println!(
-            "  {} You can install Docker manually, then run:",
-            style("Tip:").dim()
-        );
-        println!(
-            "       {}",
-            style(format!("occ host add {hostname} {hostname}")).yellow()
-        );
-        return Ok(None);
-    }
-
-    println!();
-
-    // Create a spinner for installation
-    let spinner = ProgressBar::new_spinner();
-    spinner.set_style(
-        ProgressStyle::default_spinner()
-            .template("{spinner:.cyan} {msg}")
-            .expect("valid template"),
-    );
-    spinner.set_message("Installing Docker...");
-    spinner.enable_steady_tick(std::time::Duration::from_millis(100));
-
-    // Run installation with output streaming
-    match install_docker(config, &distro, |line| {
-        // Update spinner message with latest output
-        let trimmed = line.trim();
-        if !trimmed.is_empty() {
-            spinner.set_message(format!("Installing: {}", truncate_str(trimmed, 50)));
-        }
-    }) {
-        Ok(()) => {
-            spinner.finish_with_message(format!("{} Docker installed", style("✓").green()));
-        }
-        Err(e) => {
-            spinner.finish_with_message(format!("{} Installation failed: {}", style("✗").red(), e));
-            return Ok(Some(false));
-        }
-    }
-
-    println!();
-    println!(
-        "  {} Group membership changes require a new SSH session.",
-        style("Note:").yellow()
-    );
-
-    // Verify Docker is working (may need sudo if group not yet active)
-    let spinner = ProgressBar::new_spinner();
-    spinner.set_style(
-        ProgressStyle::default_spinner()
-            .template("{spinner:.cyan} {msg}")
-            .expect("valid template"),
-    );
-    spinner.set_message("Verifying Docker installation...");
-    spinner.enable_steady_tick(std::time::Duration::from_millis(100));
-
-    match verify_docker_installed(config) {
-        Ok(version) => {
-            spinner.finish_with_message(format!(
-                "{} Docker {} verified",
-                style("✓").green(),
-                version
-            ));
-            Ok(Some(true))
-        }
-        Err(e) => {
-            spinner.finish_with_message(format!("{} Verification: {}", style("!").yellow(), e));
-            println!();
-            println!(
-                "  {} Docker was installed but verification failed.",
-                style("Note:").yellow()
-            );
-            println!(
-                "       This is often because the user needs to reconnect for group membership."
-            );
-            println!(
-                "       Try: {}",
-                style("ssh <host> docker --version").yellow()
-            );
-            // Still count as success since Docker was installed
-            Ok(Some(true))
-        }
-    }
-}
-
-/// Truncate a string to a maximum length, adding "..." if truncated
-fn truncate_str(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
-        s.to_string()
-    } else {
-        format!("{}...", &s[..max_len.saturating_sub(3)])
-    }
-}
-
 /// Print helpful tips when connection verification fails
 fn print_connection_failure_tips(
     config: &HostConfig,
@@ -609,3 +665,163 @@ fn find_ssh_keys() -> Vec<String> {
     keys.sort();
     keys
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::{Cell, RefCell};
+
+    #[derive(Default)]
+    struct MemoryStore {
+        hosts: RefCell<HostsFile>,
+        saves: Cell<usize>,
+    }
+
+    impl HostStore for MemoryStore {
+        fn load(&self) -> Result<HostsFile, HostError> {
+            Ok(self.hosts.borrow().clone())
+        }
+
+        fn save(&self, hosts: &HostsFile) -> Result<(), HostError> {
+            self.saves.set(self.saves.get() + 1);
+            *self.hosts.borrow_mut() = hosts.clone();
+            Ok(())
+        }
+    }
+
+    fn args(test_only: bool) -> HostAddArgs {
+        HostAddArgs {
+            name: Some("candidate".to_string()),
+            hostname: Some("candidate.example.com".to_string()),
+            user: Some("deploy".to_string()),
+            port: None,
+            identity_file: None,
+            jump_host: None,
+            group: Vec::new(),
+            description: None,
+            strict_host_key_checking: StrictHostKeyChecking::AcceptNew,
+            no_verify: false,
+            force: false,
+            no_ssh_config: true,
+            test_only,
+            from_ssh_config: false,
+            match_glob: None,
+        }
+    }
+
+    fn import_args(match_glob: Option<&str>) -> HostAddArgs {
+        HostAddArgs {
+            name: None,
+            hostname: None,
+            user: None,
+            test_only: false,
+            from_ssh_config: true,
+            match_glob: match_glob.map(str::to_string),
+            ..args(false)
+        }
+    }
+
+    fn ssh_entry(alias: &str) -> SshConfigHost {
+        SshConfigHost {
+            alias: alias.to_string(),
+            hostname: format!("{alias}.example.com"),
+            settings: Default::default(),
+        }
+    }
+
+    async fn docker_ok(_: &HostConfig) -> Result<String, HostError> {
+        Ok("27.0.1".to_string())
+    }
+
+    #[tokio::test]
+    async fn test_only_never_saves() {
+        let store = MemoryStore::default();
+        add_host(&args(true), true, &store, docker_ok)
+            .await
+            .unwrap();
+        assert_eq!(store.saves.get(), 0);
+        assert!(!store.hosts.borrow().has_host("candidate"));
+    }
+
+    #[tokio::test]
+    async fn test_only_ignores_existing_name() {
+        let store = MemoryStore::default();
+        store
+            .hosts
+            .borrow_mut()
+            .add_host("candidate", HostConfig::new("old.example.com"));
+        add_host(&args(true), true, &store, docker_ok)
+            .await
+            .unwrap();
+        assert_eq!(store.saves.get(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_only_failure_is_an_error() {
+        let store = MemoryStore::default();
+        let result = add_host(&args(true), true, &store, async |_: &HostConfig| {
+            Err(HostError::ConnectionFailed("refused".to_string()))
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(store.saves.get(), 0);
+    }
+
+    #[tokio::test]
+    async fn regular_add_saves_once() {
+        let store = MemoryStore::default();
+        add_host(&args(false), true, &store, docker_ok)
+            .await
+            .unwrap();
+        assert_eq!(store.saves.get(), 1);
+        assert!(store.hosts.borrow().has_host("candidate"));
+    }
+
+    #[test]
+    fn import_plan_filters_glob_and_registered() {
+        let mut hosts = HostsFile::default();
+        hosts.add_host("prod-1", HostConfig::new("old.example.com"));
+        let entries = || {
+            vec![
+                ssh_entry("prod-1"),
+                ssh_entry("prod-2"),
+                ssh_entry("staging"),
+            ]
+        };
+
+        let plan = plan_ssh_config_import(entries(), &hosts, Some("prod-*"), false);
+        let to_add: Vec<_> = plan.to_add.iter().map(|e| e.alias.as_str()).collect();
+        assert_eq!(to_add, vec!["prod-2"]);
+        assert_eq!(plan.already_registered, vec!["prod-1"]);
+
+        let plan = plan_ssh_config_import(entries(), &hosts, None, true);
+        assert_eq!(plan.to_add.len(), 3);
+        assert!(plan.already_registered.is_empty());
+    }
+
+    #[tokio::test]
+    async fn import_skips_unreachable_hosts_and_saves_once() {
+        let store = MemoryStore::default();
+        let entries = vec![ssh_entry("web"), ssh_entry("db")];
+        import_ssh_config_hosts(
+            &import_args(None),
+            true,
+            &store,
+            entries,
+            async |config: &HostConfig| {
+                if config.hostname.starts_with("db") {
+                    Err(HostError::ConnectionFailed("refused".to_string()))
+                } else {
+                    Ok("27.0.1".to_string())
+                }
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(store.saves.get(), 1);
+        let hosts = store.hosts.borrow();
+        assert_eq!(hosts.get_host("web").unwrap().hostname, "web.example.com");
+        assert!(!hosts.has_host("db"));
+    }
+}
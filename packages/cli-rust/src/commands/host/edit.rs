@@ -1,9 +1,11 @@
-//! occ host edit - Edit host configuration
+//! occ host edit (alias: update) - Edit host configuration
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 use clap::Args;
 use console::style;
-use opencode_cloud_core::{load_hosts, save_hosts};
+use opencode_cloud_core::{
+    HostConfig, StrictHostKeyChecking, load_hosts, save_hosts, test_connection,
+};
 
 /// Arguments for host edit command
 #[derive(Args)]
@@ -42,18 +44,23 @@ pub struct HostEditArgs {
     /// New description (use empty string to clear)
     #[arg(short, long)]
     pub description: Option<String>,
-}
 
-pub async fn cmd_host_edit(args: &HostEditArgs, quiet: bool, _verbose: u8) -> Result<()> {
-    let mut hosts = load_hosts()?;
+    /// New SSH host key checking mode (yes, accept-new or no)
+    #[arg(long, value_enum)]
+    pub strict_host_key_checking: Option<StrictHostKeyChecking>,
 
-    let config = hosts
-        .get_host_mut(&args.name)
-        .ok_or_else(|| anyhow::anyhow!("Host '{}' not found.", args.name))?;
+    /// Test the connection with the new settings before saving
+    #[arg(long)]
+    pub verify: bool,
+}
 
+/// Apply the requested edits to `config`, leaving unspecified fields alone
+///
+/// Returns whether anything changed. Adding a group it already has and
+/// removing one it lacks are no-ops.
+fn apply_host_edits(config: &mut HostConfig, args: &HostEditArgs) -> bool {
     let mut changed = false;
 
-    // Apply changes
     if let Some(hostname) = &args.hostname {
         config.hostname = hostname.clone();
         changed = true;
@@ -110,6 +117,23 @@ pub async fn cmd_host_edit(args: &HostEditArgs, quiet: bool, _verbose: u8) -> Re
         changed = true;
     }
 
+    if let Some(mode) = args.strict_host_key_checking {
+        config.strict_host_key_checking = mode;
+        changed = true;
+    }
+
+    changed
+}
+
+pub async fn cmd_host_edit(args: &HostEditArgs, quiet: bool, _verbose: u8) -> Result<()> {
+    let mut hosts = load_hosts()?;
+
+    let config = hosts
+        .get_host_mut(&args.name)
+        .ok_or_else(|| anyhow::anyhow!("Host '{}' not found.", args.name))?;
+
+    let changed = apply_host_edits(config, args);
+
     if !changed {
         if !quiet {
             println!("No changes specified. Use --help to see available options.");
@@ -117,6 +141,18 @@ pub async fn cmd_host_edit(args: &HostEditArgs, quiet: bool, _verbose: u8) -> Re
         return Ok(());
     }
 
+    if args.verify {
+        if let Err(e) = test_connection(config).await {
+            bail!(
+                "Connection to '{}' failed with the new settings: {e}\n\nHost not updated.",
+                args.name
+            );
+        }
+        if !quiet {
+            println!("{} Connection verified", style("✓").green());
+        }
+    }
+
     // Save
     save_hosts(&hosts)?;
 
@@ -135,3 +171,89 @@ pub async fn cmd_host_edit(args: &HostEditArgs, quiet: bool, _verbose: u8) -> Re
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edit_args() -> HostEditArgs {
+        HostEditArgs {
+            name: "prod".to_string(),
+            hostname: None,
+            user: None,
+            port: None,
+            identity_file: None,
+            jump_host: None,
+            add_group: Vec::new(),
+            remove_group: Vec::new(),
+            description: None,
+            strict_host_key_checking: None,
+            verify: false,
+        }
+    }
+
+    fn host() -> HostConfig {
+        HostConfig {
+            hostname: "prod.example.com".to_string(),
+            user: "deploy".to_string(),
+            port: Some(22),
+            identity_file: Some("~/.ssh/prod".to_string()),
+            groups: vec!["web".to_string()],
+            ..HostConfig::default()
+        }
+    }
+
+    #[test]
+    fn partial_update_leaves_other_fields_intact() {
+        let mut config = host();
+        let args = HostEditArgs {
+            port: Some(2222),
+            ..edit_args()
+        };
+        assert!(apply_host_edits(&mut config, &args));
+        assert_eq!(
+            config,
+            HostConfig {
+                port: Some(2222),
+                ..host()
+            }
+        );
+    }
+
+    #[test]
+    fn empty_string_clears_optional_fields() {
+        let mut config = host();
+        let args = HostEditArgs {
+            identity_file: Some(String::new()),
+            ..edit_args()
+        };
+        assert!(apply_host_edits(&mut config, &args));
+        assert_eq!(config.identity_file, None);
+        assert_eq!(config.user, "deploy");
+    }
+
+    #[test]
+    fn group_add_and_remove() {
+        let mut config = host();
+        let args = HostEditArgs {
+            add_group: vec!["db".to_string(), "web".to_string()],
+            remove_group: vec!["web".to_string(), "missing".to_string()],
+            ..edit_args()
+        };
+        assert!(apply_host_edits(&mut config, &args));
+        assert_eq!(config.groups, vec!["db".to_string()]);
+    }
+
+    #[test]
+    fn redundant_group_edits_are_no_ops() {
+        let mut config = host();
+        let args = HostEditArgs {
+            add_group: vec!["web".to_string()],
+            remove_group: vec!["missing".to_string()],
+            ..edit_args()
+        };
+        assert!(!apply_host_edits(&mut config, &args));
+        assert!(!apply_host_edits(&mut config, &edit_args()));
+        assert_eq!(config, host());
+    }
+}
@@ -0,0 +1,51 @@
+//! occ host groups - List host groups with host counts
+
+use anyhow::Result;
+use clap::Args;
+use comfy_table::Table;
+use console::style;
+use opencode_cloud_core::load_hosts;
+
+/// Arguments for host groups command
+#[derive(Args)]
+pub struct HostGroupsArgs {}
+
+pub async fn cmd_host_groups(_args: &HostGroupsArgs, quiet: bool, _verbose: u8) -> Result<()> {
+    let hosts = load_hosts()?;
+    let groups = hosts.group_counts();
+
+    if groups.is_empty() {
+        if !quiet {
+            println!("No host groups configured.");
+            println!();
+            println!(
+                "  {} {}",
+                style("Add one with:").dim(),
+                style("occ host edit <name> --add-group <group>").yellow()
+            );
+        }
+        return Ok(());
+    }
+
+    if quiet {
+        for group in groups.keys() {
+            println!("{group}");
+        }
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.set_header(vec!["Group", "Hosts"]);
+    for (group, count) in &groups {
+        table.add_row(vec![group.to_string(), count.to_string()]);
+    }
+    println!("{table}");
+    println!();
+    println!(
+        "  {} {}",
+        style("List a group with:").dim(),
+        style("occ host list --group <group>").yellow()
+    );
+
+    Ok(())
+}
@@ -35,16 +35,7 @@ pub async fn cmd_host_list(args: &HostListArgs, quiet: bool, _verbose: u8) -> Re
     }
 
     // Filter by group if specified
-    let filtered: Vec<_> = hosts
-        .hosts
-        .iter()
-        .filter(|(_, config)| {
-            args.group
-                .as_ref()
-                .map(|g| config.groups.contains(g))
-                .unwrap_or(true)
-        })
-        .collect();
+    let filtered = hosts.hosts_in_group(args.group.as_deref());
 
     if filtered.is_empty() {
         if !quiet && !args.names_only {
@@ -71,7 +62,7 @@ pub async fn cmd_host_list(args: &HostListArgs, quiet: bool, _verbose: u8) -> Re
     ]);
 
     for (name, config) in filtered {
-        let is_default = hosts.default_host.as_deref() == Some(name.as_str());
+        let is_default = hosts.default_host.as_deref() == Some(name);
 
         let name_cell = if is_default {
             Cell::new(name).fg(Color::Cyan)
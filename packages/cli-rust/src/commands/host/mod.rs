@@ -5,9 +5,12 @@
 mod add;
 mod default;
 mod edit;
+mod groups;
 mod list;
+mod provision;
 mod remove;
 mod show;
+mod ssh;
 mod test;
 
 use anyhow::Result;
@@ -16,9 +19,12 @@ use clap::{Args, Subcommand};
 pub use add::cmd_host_add;
 pub use default::cmd_host_default;
 pub use edit::cmd_host_edit;
+pub use groups::cmd_host_groups;
 pub use list::cmd_host_list;
+pub use provision::cmd_host_provision;
 pub use remove::cmd_host_remove;
 pub use show::cmd_host_show;
+pub use ssh::cmd_host_ssh;
 pub use test::cmd_host_test;
 
 /// Host management command arguments
@@ -37,14 +43,21 @@ pub enum HostCommands {
     Remove(remove::HostRemoveArgs),
     /// List all configured hosts
     List(list::HostListArgs),
+    /// List host groups with the number of hosts in each
+    Groups(groups::HostGroupsArgs),
     /// Show details for a host
     Show(show::HostShowArgs),
-    /// Edit host configuration
+    /// Edit host configuration (only the fields given change)
+    #[command(visible_alias = "update")]
     Edit(edit::HostEditArgs),
     /// Test connection to a host
     Test(test::HostTestArgs),
     /// Set or show the default host
     Default(default::HostDefaultArgs),
+    /// Open an SSH session to a host
+    Ssh(ssh::HostSshArgs),
+    /// Install Docker on a host (no-op if already installed)
+    Provision(provision::HostProvisionArgs),
 }
 
 /// Handle host command
@@ -55,9 +68,14 @@ pub async fn cmd_host(args: &HostArgs, quiet: bool, verbose: u8) -> Result<()> {
         HostCommands::Add(add_args) => cmd_host_add(add_args, quiet, verbose).await,
         HostCommands::Remove(remove_args) => cmd_host_remove(remove_args, quiet, verbose).await,
         HostCommands::List(list_args) => cmd_host_list(list_args, quiet, verbose).await,
+        HostCommands::Groups(groups_args) => cmd_host_groups(groups_args, quiet, verbose).await,
         HostCommands::Show(show_args) => cmd_host_show(show_args, quiet, verbose).await,
         HostCommands::Edit(edit_args) => cmd_host_edit(edit_args, quiet, verbose).await,
         HostCommands::Test(test_args) => cmd_host_test(test_args, quiet, verbose).await,
         HostCommands::Default(default_args) => cmd_host_default(default_args, quiet, verbose).await,
+        HostCommands::Ssh(ssh_args) => cmd_host_ssh(ssh_args, quiet, verbose).await,
+        HostCommands::Provision(provision_args) => {
+            cmd_host_provision(provision_args, quiet, verbose).await
+        }
     }
 }
@@ -0,0 +1,302 @@
+//! occ host provision - Install Docker on an existing host
+
+use anyhow::{Result, bail};
+use clap::Args;
+use console::style;
+use dialoguer::Confirm;
+use indicatif::{ProgressBar, ProgressStyle};
+use opencode_cloud_core::{
+    HostConfig, HostError, detect_distro, get_docker_install_commands, install_docker, load_hosts,
+    verify_docker_installed,
+};
+
+/// Arguments for host provision command
+#[derive(Args)]
+pub struct HostProvisionArgs {
+    /// Name of the host to provision
+    pub name: String,
+}
+
+/// What provisioning has to do, based on the initial Docker check
+#[derive(Debug, PartialEq)]
+enum ProvisionPlan {
+    /// Docker already works on the host (carries its version)
+    AlreadyInstalled(String),
+    /// Docker is missing and should be installed
+    Install,
+}
+
+/// Decide from `verify_docker_installed` whether Docker needs installing
+///
+/// Only a missing `docker` binary leads to installation; connection and
+/// access errors are returned so they can be reported as they are.
+fn plan_provision(verify: Result<String, HostError>) -> Result<ProvisionPlan, HostError> {
+    match verify {
+        Ok(version) => Ok(ProvisionPlan::AlreadyInstalled(version)),
+        Err(HostError::DockerNotFound(_)) => Ok(ProvisionPlan::Install),
+        Err(e) => Err(e),
+    }
+}
+
+/// Install Docker on a registered host, doing nothing if it is already present
+pub async fn cmd_host_provision(args: &HostProvisionArgs, quiet: bool, _verbose: u8) -> Result<()> {
+    let hosts = load_hosts()?;
+    let config = hosts
+        .get_host(&args.name)
+        .ok_or_else(|| anyhow::anyhow!("Host '{}' not found.", args.name))?;
+
+    let spinner = (!quiet).then(|| {
+        let spinner = ProgressBar::new_spinner();
+        spinner.set_style(
+            ProgressStyle::default_spinner()
+                .template("{spinner:.cyan} {msg}")
+                .expect("valid template"),
+        );
+        spinner.set_message(format!(
+            "Checking Docker on {}...",
+            style(&args.name).cyan()
+        ));
+        spinner.enable_steady_tick(std::time::Duration::from_millis(100));
+        spinner
+    });
+
+    let plan = match plan_provision(verify_docker_installed(config)) {
+        Ok(plan) => plan,
+        Err(e) => {
+            if let Some(spinner) = spinner {
+                spinner.finish_with_message(format!("{} Docker check failed", style("✗").red()));
+            }
+            return Err(e.into());
+        }
+    };
+
+    match plan {
+        ProvisionPlan::AlreadyInstalled(version) => {
+            if let Some(spinner) = spinner {
+                spinner.finish_with_message(format!(
+                    "{} Docker {} already installed on {}; nothing to do",
+                    style("✓").green(),
+                    version,
+                    style(&args.name).cyan()
+                ));
+            }
+            Ok(())
+        }
+        ProvisionPlan::Install => {
+            if let Some(spinner) = spinner {
+                spinner
+                    .finish_with_message(format!("{} Docker not installed", style("!").yellow()));
+                eprintln!();
+            }
+            let retry_command = format!("occ host provision {}", args.name);
+            match offer_docker_installation(config, &config.hostname, &retry_command, quiet)? {
+                Some(true) => Ok(()),
+                Some(false) => bail!("Docker installation failed on '{}'", args.name),
+                None => bail!("Docker was not installed on '{}'", args.name),
+            }
+        }
+    }
+}
+
+/// Offer to install Docker on a remote host
+///
+/// `retry_command` is suggested if the user declines and installs manually.
+///
+/// Returns:
+/// - `Ok(Some(true))` - Docker was installed successfully
+/// - `Ok(Some(false))` - User declined or installation failed
+/// - `Ok(None)` - User declined installation
+pub(super) fn offer_docker_installation(
+    config: &HostConfig,
+    hostname: &str,
+    retry_command: &str,
+    quiet: bool,
+) -> Result<Option<bool>> {
+    if quiet {
+        return Ok(None);
+    }
+
+    println!(
+        "  {} Docker is not installed on {}",
+        style("Detected:").yellow(),
+        style(hostname).cyan()
+    );
+    println!();
+
+    // Detect the Linux distribution
+    let distro = match detect_distro(config) {
+        Ok(d) => d,
+        Err(e) => {
+            eprintln!(
+                "  {} Could not detect Linux distribution: {}",
+                style("Error:").red(),
+                e
+            );
+            return Ok(None);
+        }
+    };
+
+    println!(
+        "  {} {} ({})",
+        style("Distribution:").dim(),
+        distro.pretty_name,
+        distro.family
+    );
+    println!();
+
+    // Get the commands that would be run
+    let commands = match get_docker_install_commands(&distro) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("  {} {}", style("Error:").red(), e);
+            println!();
+            println!(
+                "  {} Install Docker manually, then re-run this command.",
+                style("Tip:").dim()
+            );
+            return Ok(None);
+        }
+    };
+
+    // Show what will be done
+    println!(
+        "  {} The following commands will be run:",
+        style("Installation:").cyan()
+    );
+    for cmd in &commands {
+        println!("    {}", style(cmd).dim());
+    }
+    println!();
+
+    // Ask for confirmation
+    let should_install = Confirm::new()
+        .with_prompt("Install Docker on the remote host?")
+        .default(true)
+        .interact()?;
+
+    if !should_install {
+        println!();
+        println!(
+            "  {} You can install Docker manually, then run:",
+            style("Tip:").dim()
+        );
+        println!("       {}", style(retry_command).yellow());
+        return Ok(None);
+    }
+
+    println!();
+
+    // Create a spinner for installation
+    let spinner = ProgressBar::new_spinner();
+    spinner.set_style(
+        ProgressStyle::default_spinner()
+            .template("{spinner:.cyan} {msg}")
+            .expect("valid template"),
+    );
+    spinner.set_message("Installing Docker...");
+    spinner.enable_steady_tick(std::time::Duration::from_millis(100));
+
+    // Run installation with output streaming
+    match install_docker(config, &distro, |line| {
+        // Update spinner message with latest output
+        let trimmed = line.trim();
+        if !trimmed.is_empty() {
+            spinner.set_message(format!("Installing: {}", truncate_str(trimmed, 50)));
+        }
+    }) {
+        Ok(()) => {
+            spinner.finish_with_message(format!("{} Docker installed", style("✓").green()));
+        }
+        Err(e) => {
+            spinner.finish_with_message(format!("{} Installation failed: {}", style("✗").red(), e));
+            return Ok(Some(false));
+        }
+    }
+
+    println!();
+    println!(
+        "  {} Group membership changes require a new SSH session.",
+        style("Note:").yellow()
+    );
+
+    // Verify Docker is working (may need sudo if group not yet active)
+    let spinner = ProgressBar::new_spinner();
+    spinner.set_style(
+        ProgressStyle::default_spinner()
+            .template("{spinner:.cyan} {msg}")
+            .expect("valid template"),
+    );
+    spinner.set_message("Verifying Docker installation...");
+    spinner.enable_steady_tick(std::time::Duration::from_millis(100));
+
+    match verify_docker_installed(config) {
+        Ok(version) => {
+            spinner.finish_with_message(format!(
+                "{} Docker {} verified",
+                style("✓").green(),
+                version
+            ));
+            Ok(Some(true))
+        }
+        Err(e) => {
+            spinner.finish_with_message(format!("{} Verification: {}", style("!").yellow(), e));
+            println!();
+            println!(
+                "  {} Docker was installed but verification failed.",
+                style("Note:").yellow()
+            );
+            println!(
+                "       This is often because the user needs to reconnect for group membership."
+            );
+            println!(
+                "       Try: {}",
+                style("ssh <host> docker --version").yellow()
+            );
+            // Still count as success since Docker was installed
+            Ok(Some(true))
+        }
+    }
+}
+
+/// Truncate a string to a maximum length, adding "..." if truncated
+fn truncate_str(s: &str, max_len: usize) -> String {
+    if s.len() <= max_len {
+        s.to_string()
+    } else {
+        format!("{}...", &s[..max_len.saturating_sub(3)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verified_docker_needs_no_install() {
+        assert_eq!(
+            plan_provision(Ok("27.0.1".to_string())).unwrap(),
+            ProvisionPlan::AlreadyInstalled("27.0.1".to_string())
+        );
+    }
+
+    #[test]
+    fn missing_docker_is_installed() {
+        let verify = Err(HostError::DockerNotFound("example.com".to_string()));
+        assert_eq!(plan_provision(verify).unwrap(), ProvisionPlan::Install);
+    }
+
+    #[test]
+    fn connection_errors_are_not_treated_as_missing_docker() {
+        let verify = Err(HostError::ConnectionFailed("refused".to_string()));
+        assert!(matches!(
+            plan_provision(verify),
+            Err(HostError::ConnectionFailed(_))
+        ));
+
+        let verify = Err(HostError::AuthFailed { key_hint: None });
+        assert!(matches!(
+            plan_provision(verify),
+            Err(HostError::AuthFailed { .. })
+        ));
+    }
+}
@@ -3,7 +3,7 @@
 use anyhow::Result;
 use clap::Args;
 use console::style;
-use opencode_cloud_core::load_hosts;
+use opencode_cloud_core::{StrictHostKeyChecking, load_hosts};
 
 /// Arguments for host show command
 #[derive(Args)]
@@ -58,6 +58,14 @@ pub async fn cmd_host_show(args: &HostShowArgs, quiet: bool, _verbose: u8) -> Re
         println!("  {:<15} {}", style("Jump host:").dim(), jump);
     }
 
+    if config.strict_host_key_checking != StrictHostKeyChecking::default() {
+        println!(
+            "  {:<15} {}",
+            style("Host keys:").dim(),
+            config.strict_host_key_checking.as_str()
+        );
+    }
+
     if !config.groups.is_empty() {
         println!(
             "  {:<15} {}",
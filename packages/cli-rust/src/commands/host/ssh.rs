@@ -0,0 +1,115 @@
+//! occ host ssh - Open an interactive SSH session to a host
+
+use anyhow::{Result, anyhow};
+use clap::Args;
+use opencode_cloud_core::{HostConfig, load_hosts};
+use std::process::Command;
+
+/// Arguments for host ssh command
+#[derive(Args)]
+pub struct HostSshArgs {
+    /// Name of the host to connect to
+    pub name: String,
+
+    /// Command to run instead of an interactive shell (after `--`)
+    #[arg(last = true)]
+    pub command: Vec<String>,
+}
+
+pub async fn cmd_host_ssh(args: &HostSshArgs, _quiet: bool, verbose: u8) -> Result<()> {
+    let hosts = load_hosts()?;
+
+    let config = hosts.get_host(&args.name).ok_or_else(|| {
+        anyhow!(
+            "Host '{}' not found. Run 'occ host list' to see available hosts.",
+            args.name
+        )
+    })?;
+
+    let ssh_args = build_ssh_args(config, &args.command);
+    if verbose > 0 {
+        eprintln!("ssh {}", ssh_args.join(" "));
+    }
+
+    let mut cmd = Command::new("ssh");
+    cmd.args(&ssh_args);
+    run_ssh(cmd)
+}
+
+/// Build the ssh argument vector for a host
+///
+/// Uses the stored port, identity file and jump host, followed by
+/// `user@hostname` and the optional remote command.
+fn build_ssh_args(host: &HostConfig, command: &[String]) -> Vec<String> {
+    let mut args = host.ssh_args();
+    args.extend(command.iter().cloned());
+    args
+}
+
+/// Replace the current process with ssh so the session owns the terminal
+#[cfg(unix)]
+fn run_ssh(mut cmd: Command) -> Result<()> {
+    use std::os::unix::process::CommandExt;
+
+    // exec only returns on failure
+    let err = cmd.exec();
+    Err(anyhow!("Failed to run ssh: {err}"))
+}
+
+/// Spawn ssh and wait, propagating its exit code
+#[cfg(not(unix))]
+fn run_ssh(mut cmd: Command) -> Result<()> {
+    let status = cmd
+        .status()
+        .map_err(|e| anyhow!("Failed to run ssh: {e}"))?;
+    if status.success() {
+        Ok(())
+    } else {
+        std::process::exit(status.code().unwrap_or(1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ssh_args_minimal_host() {
+        let host = HostConfig::new("example.com");
+        let expected = format!("{}@example.com", host.user);
+        assert_eq!(build_ssh_args(&host, &[]), vec![expected]);
+    }
+
+    #[test]
+    fn ssh_args_include_port_identity_and_jump() {
+        let host = HostConfig::new("10.0.0.5")
+            .with_user("deploy")
+            .with_port(2222)
+            .with_identity_file("~/.ssh/deploy_key")
+            .with_jump_host("bastion.example.com");
+
+        assert_eq!(
+            build_ssh_args(&host, &[]),
+            vec![
+                "-p",
+                "2222",
+                "-i",
+                "~/.ssh/deploy_key",
+                "-J",
+                "bastion.example.com",
+                "deploy@10.0.0.5",
+            ]
+        );
+    }
+
+    #[test]
+    fn ssh_args_append_remote_command_after_target() {
+        let host = HostConfig::new("example.com").with_user("ubuntu");
+        let command = vec!["docker".to_string(), "ps".to_string()];
+
+        assert_eq!(
+            build_ssh_args(&host, &command),
+            vec!["ubuntu@example.com", "docker", "ps"]
+        );
+    }
+}
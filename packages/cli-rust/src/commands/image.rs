@@ -0,0 +1,469 @@
+//! Image command implementation
+//!
+//! Inspects the opencode-cloud image without needing the Docker CLI, and
+//! scans it for known vulnerabilities with Trivy when it is installed.
+
+use crate::output::{format_bytes, format_docker_error};
+use anyhow::{Result, anyhow, bail};
+use clap::{Args, Subcommand, ValueEnum};
+use comfy_table::Table;
+use console::style;
+use opencode_cloud_core::bollard::models::HistoryResponseItem;
+use opencode_cloud_core::docker::{IMAGE_NAME_GHCR, IMAGE_TAG_DEFAULT, image_history};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::process::Command;
+
+/// Longest `created by` command shown in the table
+const MAX_CREATED_BY_LEN: usize = 60;
+
+/// Exit code when more vulnerabilities are found than `--max-findings` allows
+const SCAN_THRESHOLD_EXIT_CODE: i32 = 1;
+
+/// Exit code when `trivy` is not installed
+const TRIVY_MISSING_EXIT_CODE: i32 = 3;
+
+/// Arguments for the image command
+#[derive(Args)]
+pub struct ImageArgs {
+    #[command(subcommand)]
+    pub command: ImageCommands,
+}
+
+/// Image subcommands
+#[derive(Subcommand)]
+pub enum ImageCommands {
+    /// Show the layer history of the image
+    History(ImageHistoryArgs),
+    /// Scan the image for known vulnerabilities (requires trivy)
+    ///
+    /// Exits 1 when findings exceed --max-findings and 3 when trivy is not installed.
+    Scan(ImageScanArgs),
+}
+
+/// Arguments for image history
+#[derive(Args)]
+pub struct ImageHistoryArgs {
+    /// Image to inspect (default: the opencode-cloud image)
+    #[arg(long)]
+    pub image: Option<String>,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// Arguments for image scan
+#[derive(Args)]
+pub struct ImageScanArgs {
+    /// Image to scan (default: the opencode-cloud image)
+    #[arg(long)]
+    pub image: Option<String>,
+
+    /// Severities to report (comma-separated)
+    #[arg(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        default_value = "critical,high"
+    )]
+    pub severity: Vec<Severity>,
+
+    /// Findings allowed before the scan fails
+    #[arg(long, value_name = "N", default_value_t = 0)]
+    pub max_findings: usize,
+}
+
+/// Vulnerability severity as reported by Trivy, most severe first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum Severity {
+    Critical,
+    High,
+    Medium,
+    Low,
+    Unknown,
+}
+
+impl Severity {
+    /// Name used by Trivy's `--severity` flag and JSON output
+    fn as_trivy(self) -> &'static str {
+        match self {
+            Self::Critical => "CRITICAL",
+            Self::High => "HIGH",
+            Self::Medium => "MEDIUM",
+            Self::Low => "LOW",
+            Self::Unknown => "UNKNOWN",
+        }
+    }
+
+    fn from_trivy(name: &str) -> Self {
+        match name {
+            "CRITICAL" => Self::Critical,
+            "HIGH" => Self::High,
+            "MEDIUM" => Self::Medium,
+            "LOW" => Self::Low,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// The parts of `trivy image --format json` output the summary needs
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TrivyReport {
+    #[serde(default)]
+    results: Option<Vec<TrivyResult>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TrivyResult {
+    #[serde(default)]
+    vulnerabilities: Option<Vec<TrivyVulnerability>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TrivyVulnerability {
+    severity: String,
+}
+
+/// Vulnerability counts per severity
+#[derive(Debug, Default, PartialEq)]
+struct ScanSummary {
+    counts: BTreeMap<Severity, usize>,
+}
+
+impl ScanSummary {
+    /// Count findings in Trivy JSON output, keeping only `severities`
+    fn from_trivy_json(json: &str, severities: &[Severity]) -> Result<Self> {
+        let report: TrivyReport =
+            serde_json::from_str(json).map_err(|e| anyhow!("Could not parse trivy output: {e}"))?;
+        let mut counts = BTreeMap::new();
+        let vulnerabilities = report
+            .results
+            .unwrap_or_default()
+            .into_iter()
+            .flat_map(|result| result.vulnerabilities.unwrap_or_default());
+        for vulnerability in vulnerabilities {
+            let severity = Severity::from_trivy(&vulnerability.severity);
+            if severities.contains(&severity) {
+                *counts.entry(severity).or_insert(0) += 1;
+            }
+        }
+        Ok(Self { counts })
+    }
+
+    fn count(&self, severity: Severity) -> usize {
+        self.counts.get(&severity).copied().unwrap_or(0)
+    }
+
+    fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Process exit code for a scan allowing `max_findings` findings
+    fn exit_code(&self, max_findings: usize) -> i32 {
+        if self.total() > max_findings {
+            SCAN_THRESHOLD_EXIT_CODE
+        } else {
+            0
+        }
+    }
+}
+
+/// One layer of image history, as printed with `--json`
+#[derive(Debug, Serialize, PartialEq)]
+struct HistoryEntry {
+    id: String,
+    created_at: String,
+    created_by: String,
+    size: u64,
+    tags: Vec<String>,
+    comment: String,
+}
+
+impl From<HistoryResponseItem> for HistoryEntry {
+    fn from(item: HistoryResponseItem) -> Self {
+        Self {
+            id: item.id,
+            created_at: format_created_at(item.created),
+            created_by: item.created_by,
+            size: u64::try_from(item.size).unwrap_or(0),
+            tags: item.tags,
+            comment: item.comment,
+        }
+    }
+}
+
+/// Handle image command
+pub async fn cmd_image(args: &ImageArgs, maybe_host: Option<&str>, quiet: bool) -> Result<()> {
+    match &args.command {
+        ImageCommands::History(history_args) => {
+            cmd_image_history(history_args, maybe_host, quiet).await
+        }
+        ImageCommands::Scan(scan_args) => cmd_image_scan(scan_args, maybe_host, quiet),
+    }
+}
+
+/// Scan the image with Trivy and summarize the findings
+fn cmd_image_scan(args: &ImageScanArgs, maybe_host: Option<&str>, quiet: bool) -> Result<()> {
+    if maybe_host.is_some() {
+        bail!("occ image scan runs trivy locally and does not support --host");
+    }
+
+    if !trivy_installed() {
+        eprintln!("{} trivy is not installed.", style("Error:").red().bold());
+        eprintln!();
+        eprintln!("Install it, then re-run occ image scan:");
+        eprintln!("  macOS:  {}", style("brew install trivy").cyan());
+        eprintln!(
+            "  Linux:  {}",
+            style("https://trivy.dev/latest/getting-started/installation/").cyan()
+        );
+        std::process::exit(TRIVY_MISSING_EXIT_CODE);
+    }
+
+    let image = args
+        .image
+        .clone()
+        .unwrap_or_else(|| format!("{IMAGE_NAME_GHCR}:{IMAGE_TAG_DEFAULT}"));
+    let severity_filter = args
+        .severity
+        .iter()
+        .map(|severity| severity.as_trivy())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    if !quiet {
+        eprintln!("Scanning {image} with trivy ({severity_filter})...");
+    }
+    let output = Command::new("trivy")
+        .args(["image", "--quiet", "--format", "json", "--severity"])
+        .arg(&severity_filter)
+        .arg(&image)
+        .output()
+        .map_err(|e| anyhow!("Failed to run trivy: {e}"))?;
+    if !output.status.success() {
+        bail!(
+            "trivy failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    let summary =
+        ScanSummary::from_trivy_json(&String::from_utf8_lossy(&output.stdout), &args.severity)?;
+    let exit_code = summary.exit_code(args.max_findings);
+
+    if !quiet {
+        let mut severities = args.severity.clone();
+        severities.sort();
+        severities.dedup();
+        for severity in severities {
+            println!("{:<10} {}", severity.as_trivy(), summary.count(severity));
+        }
+        println!();
+        if exit_code == 0 {
+            println!(
+                "{} {} finding(s), within the allowed {}",
+                style("Passed:").green().bold(),
+                summary.total(),
+                args.max_findings
+            );
+        } else {
+            println!(
+                "{} {} finding(s), more than the allowed {}",
+                style("Failed:").red().bold(),
+                summary.total(),
+                args.max_findings
+            );
+        }
+    }
+
+    if exit_code != 0 {
+        std::process::exit(exit_code);
+    }
+    Ok(())
+}
+
+/// Whether the `trivy` binary can be run
+fn trivy_installed() -> bool {
+    Command::new("trivy").arg("--version").output().is_ok()
+}
+
+async fn cmd_image_history(
+    args: &ImageHistoryArgs,
+    maybe_host: Option<&str>,
+    quiet: bool,
+) -> Result<()> {
+    let (client, host_name) = crate::resolve_docker_client(maybe_host).await?;
+
+    client.verify_connection().await.map_err(|e| {
+        let msg = format_docker_error(&e);
+        anyhow!("{msg}")
+    })?;
+
+    let image = args
+        .image
+        .clone()
+        .unwrap_or_else(|| format!("{IMAGE_NAME_GHCR}:{IMAGE_TAG_DEFAULT}"));
+    let entries: Vec<HistoryEntry> = image_history(&client, &image)
+        .await
+        .map_err(|e| anyhow!("{}", format_docker_error(&e)))?
+        .into_iter()
+        .map(HistoryEntry::from)
+        .collect();
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&entries)?);
+        return Ok(());
+    }
+
+    if !quiet {
+        let msg = crate::format_host_message(host_name.as_deref(), &format!("History of {image}"));
+        println!("{}", style(msg).bold());
+    }
+
+    let mut table = Table::new();
+    table.set_header(vec!["Created", "Size", "Created By"]);
+    for entry in &entries {
+        table.add_row(vec![
+            entry.created_at.clone(),
+            format_bytes(entry.size),
+            format_created_by(&entry.created_by),
+        ]);
+    }
+    println!("{table}");
+
+    Ok(())
+}
+
+/// Format a layer's Unix creation time (e.g. "2026-01-01 12:00 UTC")
+fn format_created_at(created: i64) -> String {
+    chrono::DateTime::from_timestamp(created, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M UTC").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// Shorten a layer's creating command for table display
+///
+/// Drops the classic builder's shell wrapper so `/bin/sh -c #(nop) ENV X=1`
+/// reads as `ENV X=1` and `/bin/sh -c apt-get ...` as `RUN apt-get ...`.
+fn format_created_by(created_by: &str) -> String {
+    let collapsed = created_by.split_whitespace().collect::<Vec<_>>().join(" ");
+    let command = if let Some(rest) = collapsed.strip_prefix("/bin/sh -c #(nop) ") {
+        rest.to_string()
+    } else if let Some(rest) = collapsed.strip_prefix("/bin/sh -c ") {
+        format!("RUN {rest}")
+    } else {
+        collapsed
+    };
+
+    if command.chars().count() <= MAX_CREATED_BY_LEN {
+        command
+    } else {
+        let truncated: String = command.chars().take(MAX_CREATED_BY_LEN - 3).collect();
+        format!("{truncated}...")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(created_by: &str, size: i64) -> HistoryResponseItem {
+        HistoryResponseItem {
+            id: "sha256:abc".to_string(),
+            created: 1_767_268_800,
+            created_by: created_by.to_string(),
+            tags: vec!["ghcr.io/example/opencode-cloud:latest".to_string()],
+            size,
+            comment: String::new(),
+        }
+    }
+
+    #[test]
+    fn history_entry_from_response() {
+        let entry = HistoryEntry::from(item("/bin/sh -c #(nop) CMD [\"bash\"]", 2048));
+        assert_eq!(entry.created_at, "2026-01-01 12:00 UTC");
+        assert_eq!(entry.size, 2048);
+        assert_eq!(entry.tags.len(), 1);
+        assert_eq!(format_created_by(&entry.created_by), "CMD [\"bash\"]");
+    }
+
+    #[test]
+    fn negative_size_is_zero() {
+        assert_eq!(HistoryEntry::from(item("", -1)).size, 0);
+    }
+
+    #[test]
+    fn created_by_shell_commands() {
+        assert_eq!(
+            format_created_by("/bin/sh -c apt-get update &&     apt-get install -y curl"),
+            "RUN apt-get update && apt-get install -y curl"
+        );
+        assert_eq!(
+            format_created_by("RUN /bin/sh -c echo hi # buildkit"),
+            "RUN /bin/sh -c echo hi # buildkit"
+        );
+    }
+
+    #[test]
+    fn created_by_is_truncated() {
+        let long = format!("/bin/sh -c {}", "x".repeat(100));
+        let formatted = format_created_by(&long);
+        assert_eq!(formatted.chars().count(), MAX_CREATED_BY_LEN);
+        assert!(formatted.ends_with("..."));
+    }
+
+    const TRIVY_JSON: &str = r#"{
+        "SchemaVersion": 2,
+        "ArtifactName": "ghcr.io/example/opencode-cloud:latest",
+        "Results": [
+            {
+                "Target": "ubuntu 24.04",
+                "Vulnerabilities": [
+                    {"VulnerabilityID": "CVE-1", "Severity": "CRITICAL"},
+                    {"VulnerabilityID": "CVE-2", "Severity": "HIGH"},
+                    {"VulnerabilityID": "CVE-3", "Severity": "HIGH"},
+                    {"VulnerabilityID": "CVE-4", "Severity": "LOW"}
+                ]
+            },
+            {"Target": "usr/local/bin/app", "Vulnerabilities": null},
+            {"Target": "package.json"}
+        ]
+    }"#;
+
+    #[test]
+    fn trivy_summary_counts_filtered_severities() {
+        let summary =
+            ScanSummary::from_trivy_json(TRIVY_JSON, &[Severity::Critical, Severity::High])
+                .unwrap();
+        assert_eq!(summary.count(Severity::Critical), 1);
+        assert_eq!(summary.count(Severity::High), 2);
+        assert_eq!(summary.count(Severity::Low), 0);
+        assert_eq!(summary.total(), 3);
+    }
+
+    #[test]
+    fn trivy_summary_without_results() {
+        let summary =
+            ScanSummary::from_trivy_json(r#"{"SchemaVersion": 2}"#, &[Severity::High]).unwrap();
+        assert_eq!(summary.total(), 0);
+        assert!(ScanSummary::from_trivy_json("not json", &[Severity::High]).is_err());
+    }
+
+    #[test]
+    fn scan_threshold_exit_code() {
+        let summary = ScanSummary::from_trivy_json(TRIVY_JSON, &[Severity::High]).unwrap();
+        assert_eq!(summary.exit_code(0), SCAN_THRESHOLD_EXIT_CODE);
+        assert_eq!(summary.exit_code(1), SCAN_THRESHOLD_EXIT_CODE);
+        assert_eq!(summary.exit_code(2), 0);
+        assert_eq!(ScanSummary::default().exit_code(0), 0);
+    }
+
+    #[test]
+    fn invalid_timestamp() {
+        assert_eq!(format_created_at(i64::MAX), "-");
+    }
+}
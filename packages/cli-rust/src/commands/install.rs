@@ -8,10 +8,12 @@ use anyhow::{Result, anyhow};
 use clap::Args;
 use console::style;
 use dialoguer::Confirm;
+use opencode_cloud_core::Config;
 use opencode_cloud_core::config::load_config;
 use opencode_cloud_core::platform::{
     ServiceConfig, get_service_manager, is_service_registration_supported,
 };
+use std::path::{Path, PathBuf};
 
 /// Arguments for the install command
 #[derive(Args)]
@@ -23,6 +25,10 @@ pub struct InstallArgs {
     /// Show what would be done without making changes
     #[arg(long)]
     dry_run: bool,
+
+    /// Executable the service should run (default: the current binary)
+    #[arg(long, value_name = "PATH")]
+    exec_path: Option<PathBuf>,
 }
 
 /// Register the service with the platform's service manager
@@ -43,10 +49,13 @@ pub async fn cmd_install(args: &InstallArgs, quiet: bool, _verbose: u8) -> Resul
         ));
     }
 
-    // 2. Get service manager
+    // 2. Resolve the executable before touching any existing service
+    let executable_path = resolve_executable_path(args.exec_path.as_deref())?;
+
+    // 3. Get service manager
     let manager = get_service_manager()?;
 
-    // 3. Check if already installed
+    // 4. Check if already installed
     if manager.is_installed()? {
         if args.dry_run {
             println!(
@@ -81,25 +90,18 @@ pub async fn cmd_install(args: &InstallArgs, quiet: bool, _verbose: u8) -> Resul
             "Would install service at: {}",
             manager.service_file_path().display()
         );
+        println!("Executable: {}", executable_path.display());
         return Ok(());
     }
 
-    // 4. Show spinner during install
+    // 5. Show spinner during install
     let spinner = CommandSpinner::new_maybe("Installing service...", quiet);
 
-    // 5. Get executable path (current binary)
-    let executable_path = std::env::current_exe()?;
-
     // 6. Load config for restart settings
     let config = load_config()?;
 
     // 7. Build ServiceConfig
-    let service_config = ServiceConfig {
-        executable_path,
-        restart_retries: config.restart_retries,
-        restart_delay: config.restart_delay,
-        boot_mode: config.boot_mode.clone(),
-    };
+    let service_config = build_service_config(&config, executable_path);
 
     // 8. Perform install
     let result = manager.install(&service_config)?;
@@ -128,3 +130,103 @@ pub async fn cmd_install(args: &InstallArgs, quiet: bool, _verbose: u8) -> Resul
 
     Ok(())
 }
+
+/// Executable for the service: `--exec-path` if given, otherwise the current binary
+///
+/// The override is made absolute but symlinks are kept, so a packaged
+/// `/usr/local/bin/occ` link keeps working across upgrades.
+pub(super) fn resolve_executable_path(exec_path: Option<&Path>) -> Result<PathBuf> {
+    match exec_path {
+        Some(path) => validate_executable(path),
+        None => Ok(std::env::current_exe()?),
+    }
+}
+
+/// Check that `path` is an existing, executable file
+fn validate_executable(path: &Path) -> Result<PathBuf> {
+    let absolute = std::path::absolute(path)
+        .map_err(|e| anyhow!("Invalid --exec-path {}: {e}", path.display()))?;
+
+    let metadata = std::fs::metadata(&absolute)
+        .map_err(|e| anyhow!("--exec-path {} does not exist: {e}", absolute.display()))?;
+    if !metadata.is_file() {
+        return Err(anyhow!("--exec-path {} is not a file", absolute.display()));
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if metadata.permissions().mode() & 0o111 == 0 {
+            return Err(anyhow!(
+                "--exec-path {} is not executable",
+                absolute.display()
+            ));
+        }
+    }
+
+    Ok(absolute)
+}
+
+/// Build the service definition from config and the chosen executable
+pub(super) fn build_service_config(config: &Config, executable_path: PathBuf) -> ServiceConfig {
+    ServiceConfig {
+        executable_path,
+        restart_retries: config.restart_retries,
+        restart_delay: config.restart_delay,
+        boot_mode: config.boot_mode.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_nonexistent_exec_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let err = validate_executable(&dir.path().join("missing")).unwrap_err();
+        assert!(err.to_string().contains("does not exist"));
+    }
+
+    #[test]
+    fn rejects_directory_exec_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let err = validate_executable(dir.path()).unwrap_err();
+        assert!(err.to_string().contains("not a file"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn rejects_non_executable_file() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("occ");
+        std::fs::write(&path, "").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+        let err = validate_executable(&path).unwrap_err();
+        assert!(err.to_string().contains("not executable"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn override_reaches_service_config() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("occ");
+        std::fs::write(&path, "").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let executable_path = resolve_executable_path(Some(&path)).unwrap();
+        let service_config = build_service_config(&Config::default(), executable_path);
+        assert_eq!(service_config.executable_path, path);
+    }
+
+    #[test]
+    fn defaults_to_current_exe() {
+        let path = resolve_executable_path(None).unwrap();
+        assert_eq!(path, std::env::current_exe().unwrap());
+    }
+}
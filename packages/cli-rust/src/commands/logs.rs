@@ -1,20 +1,32 @@
 //! Logs command implementation
 //!
 //! Streams container logs with optional filtering, timestamps, and follow mode.
+//! With `--json`, emits one structured record per line for log shippers.
+//! With `--host` given more than once, interleaves the logs of those hosts.
 
-use crate::output::{format_docker_error_anyhow, log_level_style};
-use anyhow::{Result, anyhow};
+use super::status::parse_docker_timestamp;
+use crate::output::{LogLevel, detect_log_level, format_docker_error_anyhow, log_level_style};
+use anyhow::{Result, anyhow, bail};
 use clap::Args;
 use console::style;
-use futures_util::StreamExt;
+use futures_util::stream::{BoxStream, Stream};
+use futures_util::{StreamExt, future};
 use opencode_cloud_core::bollard::container::{LogOutput, LogsOptions};
 use opencode_cloud_core::docker::{CONTAINER_NAME, container_is_running};
+use serde::Serialize;
 
 /// Arguments for the logs command
 #[derive(Args)]
 pub struct LogsArgs {
-    /// Number of lines to show (default: 50)
-    #[arg(short = 'n', long = "lines", default_value = "50")]
+    /// Number of backlog lines to show (default: 50, "all" for everything)
+    ///
+    /// `--tail 0` prints no backlog and only streams new lines when following.
+    #[arg(
+        short = 'n',
+        long = "lines",
+        visible_alias = "tail",
+        default_value = DEFAULT_TAIL_LINES
+    )]
     pub lines: String,
 
     /// Don't follow (one-shot dump)
@@ -28,16 +40,200 @@ pub struct LogsArgs {
     /// Filter lines containing pattern
     #[arg(long)]
     pub grep: Option<String>,
+
+    /// Only show lines at or above this level (lines without a level are kept)
+    #[arg(long, value_enum, value_name = "LEVEL")]
+    pub level: Option<LogLevel>,
+
+    /// With --level, also hide lines without a recognizable level
+    #[arg(long, requires = "level")]
+    pub strict: bool,
+
+    /// Only show logs since the container last started (hides earlier runs)
+    #[arg(long)]
+    pub since_start: bool,
+
+    /// Read logs from a specific container ID or name (e.g. a stopped previous instance)
+    #[arg(long = "container-id", value_name = "ID")]
+    pub container_id: Option<String>,
+
+    /// Emit one JSON object per line: {"stream", "ts", "message"}
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// One log line as emitted by `--json`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct LogRecord {
+    /// "stdout" or "stderr"
+    stream: &'static str,
+    /// RFC 3339 timestamp from Docker
+    ts: Option<String>,
+    /// Line content without the trailing newline
+    message: String,
+}
+
+/// Partial line carried over between frames
+#[derive(Default)]
+struct PendingLine {
+    ts: Option<String>,
+    text: String,
+}
+
+/// Turns multiplexed log frames into per-line records
+///
+/// Docker frames do not align with lines: a long line may span several
+/// frames, and one frame may hold several lines. Text is buffered per
+/// stream until a newline arrives; each line keeps the timestamp of the
+/// frame it started in.
+#[derive(Default)]
+struct LogRecordDecoder {
+    stdout: PendingLine,
+    stderr: PendingLine,
+}
+
+impl LogRecordDecoder {
+    /// Decode one frame into zero or more complete records
+    fn decode(&mut self, output: LogOutput) -> Vec<LogRecord> {
+        let (stream, message) = match output {
+            LogOutput::StdOut { message } | LogOutput::Console { message } => ("stdout", message),
+            LogOutput::StdErr { message } => ("stderr", message),
+            LogOutput::StdIn { .. } => return Vec::new(),
+        };
+        let pending = match stream {
+            "stderr" => &mut self.stderr,
+            _ => &mut self.stdout,
+        };
+
+        let frame = String::from_utf8_lossy(&message);
+        let (ts, body) = split_timestamp(&frame);
+        if pending.text.is_empty() {
+            pending.ts = ts.clone();
+        }
+        pending.text.push_str(body);
+
+        let mut records = Vec::new();
+        while let Some(newline) = pending.text.find('\n') {
+            let line: String = pending.text.drain(..=newline).collect();
+            records.push(LogRecord {
+                stream,
+                ts: pending.ts.take(),
+                message: line.trim_end_matches(['\n', '\r']).to_string(),
+            });
+        }
+        if !pending.text.is_empty() && pending.ts.is_none() {
+            // Remaining text started in this frame
+            pending.ts = ts;
+        }
+        records
+    }
+
+    /// Flush any buffered partial lines (end of stream)
+    fn finish(&mut self) -> Vec<LogRecord> {
+        [("stdout", &mut self.stdout), ("stderr", &mut self.stderr)]
+            .into_iter()
+            .filter(|(_, pending)| !pending.text.is_empty())
+            .map(|(stream, pending)| {
+                let pending = std::mem::take(pending);
+                LogRecord {
+                    stream,
+                    ts: pending.ts,
+                    message: pending.text,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Split Docker's leading RFC 3339 timestamp from a frame, if present
+fn split_timestamp(frame: &str) -> (Option<String>, &str) {
+    match frame.split_once(' ') {
+        Some((ts, rest)) if chrono::DateTime::parse_from_rfc3339(ts).is_ok() => {
+            (Some(ts.to_string()), rest)
+        }
+        _ => (None, frame),
+    }
+}
+
+/// Which lines to show, from --grep, --level and --strict
+#[derive(Debug, Clone, Default)]
+struct LineFilter {
+    grep: Option<String>,
+    min_level: Option<LogLevel>,
+    strict: bool,
+}
+
+impl LineFilter {
+    fn from_args(args: &LogsArgs) -> Self {
+        Self {
+            grep: args.grep.clone(),
+            min_level: args.level,
+            strict: args.strict,
+        }
+    }
+
+    /// Whether a line passes every configured filter
+    fn matches(&self, line: &str) -> bool {
+        if self
+            .grep
+            .as_ref()
+            .is_some_and(|pattern| !line.contains(pattern))
+        {
+            return false;
+        }
+        let Some(min_level) = self.min_level else {
+            return true;
+        };
+        match detect_log_level(line) {
+            Some(level) => level >= min_level,
+            None => !self.strict,
+        }
+    }
+}
+
+/// Print records as JSON lines, applying the filter to the message
+fn print_records(records: Vec<LogRecord>, filter: &LineFilter) {
+    for record in records {
+        if !filter.matches(&record.message) {
+            continue;
+        }
+        if let Ok(json) = serde_json::to_string(&record) {
+            println!("{json}");
+        }
+    }
+}
+
+/// Default number of backlog lines
+const DEFAULT_TAIL_LINES: &str = "50";
+
+/// Container to read logs from: --container-id if given, otherwise the service container
+fn log_target(args: &LogsArgs) -> &str {
+    args.container_id.as_deref().unwrap_or(CONTAINER_NAME)
 }
 
 /// Stream logs from the opencode container
 ///
 /// By default, shows the last 50 lines and follows new output.
 /// Use --no-follow for one-shot dump.
-/// Use --grep to filter lines.
+/// Use --grep to filter lines and --level to hide lower-severity lines.
+///
+/// With several `hosts`, their logs are interleaved with each line prefixed
+/// by its host name; a host that fails is reported and the rest keep streaming.
 ///
 /// In quiet mode, outputs raw lines without status messages or colors.
-pub async fn cmd_logs(args: &LogsArgs, maybe_host: Option<&str>, quiet: bool) -> Result<()> {
+pub async fn cmd_logs(args: &LogsArgs, hosts: &[String], quiet: bool) -> Result<()> {
+    let hosts = distinct_host_names(hosts);
+    if hosts.len() > 1 {
+        if args.container_id.is_some() || args.json || args.since_start {
+            bail!(
+                "--container-id, --json and --since-start work with a single host only.\n\n\
+                 Pass --host once, or drop those flags to merge logs from several hosts."
+            );
+        }
+        return cmd_logs_merged(args, &hosts, quiet).await;
+    }
+    let maybe_host = hosts.first().map(String::as_str);
+
     // Resolve Docker client (local or remote)
     let (client, host_name) = crate::resolve_docker_client(maybe_host).await?;
 
@@ -53,14 +249,22 @@ pub async fn cmd_logs(args: &LogsArgs, maybe_host: Option<&str>, quiet: bool) ->
         .await
         .map_err(|e| format_docker_error_anyhow(&e))?;
 
+    let target = log_target(args);
+
     // Check if container exists
-    let inspect_result = client.inner().inspect_container(CONTAINER_NAME, None).await;
+    let inspect_result = client.inner().inspect_container(target, None).await;
 
-    match inspect_result {
+    let info = match inspect_result {
         Err(opencode_cloud_core::bollard::errors::Error::DockerResponseServerError {
             status_code: 404,
             ..
         }) => {
+            if args.container_id.is_some() {
+                return Err(anyhow!(
+                    "No container found with ID or name '{target}'. List containers with: {}",
+                    style("docker ps -a").cyan()
+                ));
+            }
             return Err(anyhow!(
                 "No container found. Run '{}' first.",
                 style("occ start").cyan()
@@ -69,34 +273,44 @@ pub async fn cmd_logs(args: &LogsArgs, maybe_host: Option<&str>, quiet: bool) ->
         Err(e) => {
             return Err(anyhow!("Failed to inspect container: {e}"));
         }
-        Ok(_) => {}
-    }
+        Ok(info) => info,
+    };
+
+    // --since-start: skip output from before the current run
+    let since = if args.since_start {
+        let started_at = info.state.and_then(|state| state.started_at);
+        started_at
+            .as_deref()
+            .and_then(since_start_epoch)
+            .ok_or_else(|| anyhow!("Could not determine when the container started"))?
+    } else {
+        0
+    };
 
     // Determine follow mode
     let follow = !args.no_follow;
 
     // Show status message if following
-    if !quiet && follow {
+    if !quiet && follow && !args.json {
         eprintln!("{}", style("Following logs (Ctrl+C to exit)...").dim());
         eprintln!();
     }
 
-    // Create log options
-    let options = LogsOptions::<String> {
-        stdout: true,
-        stderr: true,
-        follow,
-        tail: args.lines.clone(),
-        timestamps: args.timestamps,
-        ..Default::default()
-    };
+    let options = logs_options(args, since);
+    let filter = LineFilter::from_args(args);
 
     // Get log stream
-    let mut stream = client.inner().logs(CONTAINER_NAME, Some(options));
+    let mut stream = client.inner().logs(target, Some(options));
+    let mut decoder = args.json.then(LogRecordDecoder::default);
 
     // Process log stream
     while let Some(result) = stream.next().await {
         match result {
+            Ok(output) if decoder.is_some() => {
+                if let Some(decoder) = decoder.as_mut() {
+                    print_records(decoder.decode(output), &filter);
+                }
+            }
             Ok(output) => {
                 let line = match output {
                     LogOutput::StdOut { message } | LogOutput::StdErr { message } => {
@@ -105,11 +319,9 @@ pub async fn cmd_logs(args: &LogsArgs, maybe_host: Option<&str>, quiet: bool) ->
                     _ => continue,
                 };
 
-                // Apply grep filter
-                if let Some(ref pattern) = args.grep {
-                    if !line.contains(pattern) {
-                        continue;
-                    }
+                // Apply --grep and --level filters
+                if !filter.matches(&line) {
+                    continue;
                 }
 
                 // Print the line
@@ -127,10 +339,9 @@ pub async fn cmd_logs(args: &LogsArgs, maybe_host: Option<&str>, quiet: bool) ->
             Err(_) => {
                 // Stream error - check if container stopped
                 if follow
-                    && !container_is_running(&client, CONTAINER_NAME)
-                        .await
-                        .unwrap_or(false)
+                    && !container_is_running(&client, target).await.unwrap_or(false)
                     && !quiet
+                    && !args.json
                 {
                     eprintln!();
                     eprintln!("{}", style("Container stopped").dim());
@@ -140,9 +351,186 @@ pub async fn cmd_logs(args: &LogsArgs, maybe_host: Option<&str>, quiet: bool) ->
         }
     }
 
+    if let Some(decoder) = decoder.as_mut() {
+        print_records(decoder.finish(), &filter);
+    }
+
+    Ok(())
+}
+
+/// One event from a merged multi-host log stream
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum HostLogEvent {
+    /// A log line (without trailing newline) from `host`
+    Line { host: String, line: String },
+    /// `host` could not be reached or its stream broke; it sends nothing more
+    Failed { host: String, error: String },
+}
+
+/// The --host values in the order given, without duplicates
+fn distinct_host_names(hosts: &[String]) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for name in hosts {
+        if !names.contains(name) {
+            names.push(name.clone());
+        }
+    }
+    names
+}
+
+/// "[name] " padded so lines from hosts with different name lengths align
+fn host_prefix(host: &str, width: usize) -> String {
+    format!("[{host:<width$}] ")
+}
+
+/// Turn one host's Docker log stream into merge events
+///
+/// Lines failing `filter` are dropped. A stream error becomes a single
+/// `Failed` event and ends this host's stream without affecting others.
+fn host_log_events<S, E>(
+    host: String,
+    stream: S,
+    filter: LineFilter,
+) -> impl Stream<Item = HostLogEvent> + Send
+where
+    S: Stream<Item = Result<LogOutput, E>> + Send,
+    E: std::fmt::Display,
+{
+    stream
+        .filter_map(move |item| {
+            let event = match item {
+                Ok(LogOutput::StdOut { message } | LogOutput::StdErr { message }) => {
+                    let line = String::from_utf8_lossy(&message)
+                        .trim_end_matches(['\n', '\r'])
+                        .to_string();
+                    filter.matches(&line).then(|| HostLogEvent::Line {
+                        host: host.clone(),
+                        line,
+                    })
+                }
+                Ok(_) => None,
+                Err(e) => Some(HostLogEvent::Failed {
+                    host: host.clone(),
+                    error: e.to_string(),
+                }),
+            };
+            future::ready(event)
+        })
+        .scan(false, |failed, event| {
+            if *failed {
+                return future::ready(None);
+            }
+            *failed = matches!(event, HostLogEvent::Failed { .. });
+            future::ready(Some(event))
+        })
+}
+
+/// Connect to `host` and stream its service container logs as merge events
+fn remote_host_log_events(
+    host: String,
+    options: LogsOptions<String>,
+    filter: LineFilter,
+) -> BoxStream<'static, HostLogEvent> {
+    futures_util::stream::once(async move {
+        match crate::resolve_docker_client(Some(&host)).await {
+            Ok((client, _)) => {
+                let logs = client.inner().logs(CONTAINER_NAME, Some(options));
+                // The stream owns the client so a remote SSH tunnel stays open
+                let logs = logs.map(move |item| {
+                    let _tunnel = &client;
+                    item
+                });
+                host_log_events(host, logs, filter).boxed()
+            }
+            Err(e) => futures_util::stream::iter([HostLogEvent::Failed {
+                host,
+                error: e.to_string(),
+            }])
+            .boxed(),
+        }
+    })
+    .flatten()
+    .boxed()
+}
+
+/// Interleave the logs of several hosts, prefixing each line with its host
+///
+/// Fails only if every host fails.
+async fn cmd_logs_merged(args: &LogsArgs, hosts: &[String], quiet: bool) -> Result<()> {
+    let width = hosts.iter().map(String::len).max().unwrap_or(0);
+    let filter = LineFilter::from_args(args);
+
+    if !quiet && !args.no_follow {
+        eprintln!(
+            "{}",
+            style(format!(
+                "Following logs from {} (Ctrl+C to exit)...",
+                hosts.join(", ")
+            ))
+            .dim()
+        );
+        eprintln!();
+    }
+
+    let streams = hosts
+        .iter()
+        .map(|host| remote_host_log_events(host.clone(), logs_options(args, 0), filter.clone()));
+    let mut merged = futures_util::stream::select_all(streams);
+
+    let mut failed = 0;
+    while let Some(event) = merged.next().await {
+        match event {
+            HostLogEvent::Line { host, line } => {
+                let prefix = host_prefix(&host, width);
+                if quiet || !console::colors_enabled() {
+                    print_line(&line, Some(&prefix));
+                } else {
+                    print_styled_line(&line, Some(&style(prefix).cyan().to_string()));
+                }
+            }
+            HostLogEvent::Failed { host, error } => {
+                failed += 1;
+                eprintln!(
+                    "{}{} {error}",
+                    host_prefix(&host, width),
+                    style("log stream failed:").red()
+                );
+            }
+        }
+    }
+
+    if failed == hosts.len() {
+        return Err(anyhow!("Could not stream logs from any host"));
+    }
     Ok(())
 }
 
+/// Build Docker log options from the command arguments
+///
+/// `tail` is passed through as-is: "0" means no backlog (live lines only),
+/// "all" means the full history. `since` is a Unix timestamp (0 = no limit).
+fn logs_options(args: &LogsArgs, since: i64) -> LogsOptions<String> {
+    LogsOptions {
+        stdout: true,
+        stderr: true,
+        follow: !args.no_follow,
+        since,
+        tail: args.lines.trim().to_string(),
+        // JSON records always carry the Docker timestamp
+        timestamps: args.timestamps || args.json,
+        ..Default::default()
+    }
+}
+
+/// Unix timestamp of the container's `started_at`, for `LogsOptions.since`
+///
+/// Docker reports "0001-01-01T00:00:00Z" for a container that never started.
+fn since_start_epoch(started_at: &str) -> Option<i64> {
+    parse_docker_timestamp(started_at)
+        .map(|started| started.timestamp())
+        .filter(|&epoch| epoch > 0)
+}
+
 /// Print a log line, ensuring newline at end
 fn print_line(line: &str, prefix: Option<&str>) {
     let output = match prefix {
@@ -184,6 +572,11 @@ mod tests {
             no_follow: false,
             timestamps: false,
             grep: None,
+            level: None,
+            strict: false,
+            since_start: false,
+            container_id: None,
+            json: false,
         };
 
         assert_eq!(args.lines, "50");
@@ -222,6 +615,11 @@ mod tests {
             no_follow: false,
             timestamps: false,
             grep: None,
+            level: None,
+            strict: false,
+            since_start: false,
+            container_id: None,
+            json: false,
         };
         assert!(!args_follow.no_follow);
 
@@ -230,7 +628,293 @@ mod tests {
             no_follow: true,
             timestamps: false,
             grep: None,
+            level: None,
+            strict: false,
+            since_start: false,
+            container_id: None,
+            json: false,
         };
         assert!(args_no_follow.no_follow);
     }
+
+    #[test]
+    fn log_target_defaults_to_service_container() {
+        let args = LogsArgs {
+            lines: "50".to_string(),
+            no_follow: false,
+            timestamps: false,
+            grep: None,
+            level: None,
+            strict: false,
+            since_start: false,
+            container_id: None,
+            json: false,
+        };
+        assert_eq!(log_target(&args), CONTAINER_NAME);
+    }
+
+    #[test]
+    fn log_target_uses_container_id() {
+        let args = LogsArgs {
+            lines: "50".to_string(),
+            no_follow: true,
+            timestamps: false,
+            grep: None,
+            level: None,
+            strict: false,
+            since_start: false,
+            container_id: Some("3f2a9c1b7d4e".to_string()),
+            json: false,
+        };
+        assert_eq!(log_target(&args), "3f2a9c1b7d4e");
+    }
+
+    fn stdout(text: &str) -> LogOutput {
+        LogOutput::StdOut {
+            message: text.as_bytes().to_vec().into(),
+        }
+    }
+
+    fn stderr(text: &str) -> LogOutput {
+        LogOutput::StdErr {
+            message: text.as_bytes().to_vec().into(),
+        }
+    }
+
+    #[test]
+    fn frame_maps_to_record_with_timestamp() {
+        let mut decoder = LogRecordDecoder::default();
+        let records = decoder.decode(stderr("2026-01-02T03:04:05.123456789Z boom\n"));
+        assert_eq!(
+            records,
+            vec![LogRecord {
+                stream: "stderr",
+                ts: Some("2026-01-02T03:04:05.123456789Z".to_string()),
+                message: "boom".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn frame_without_timestamp_has_no_ts() {
+        let mut decoder = LogRecordDecoder::default();
+        let records = decoder.decode(stdout("hello world\n"));
+        assert_eq!(records[0].ts, None);
+        assert_eq!(records[0].message, "hello world");
+        assert_eq!(records[0].stream, "stdout");
+    }
+
+    #[test]
+    fn frame_with_several_lines_yields_several_records() {
+        let mut decoder = LogRecordDecoder::default();
+        let records = decoder.decode(stdout("a\nb\r\n"));
+        let messages: Vec<_> = records.iter().map(|r| r.message.as_str()).collect();
+        assert_eq!(messages, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn partial_lines_are_buffered_until_newline() {
+        let mut decoder = LogRecordDecoder::default();
+        assert!(
+            decoder
+                .decode(stdout("2026-01-01T00:00:00Z first ha"))
+                .is_empty()
+        );
+        let records = decoder.decode(stdout("2026-01-01T00:00:01Z lf\nsecond"));
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].message, "first half");
+        assert_eq!(records[0].ts.as_deref(), Some("2026-01-01T00:00:00Z"));
+
+        let rest = decoder.finish();
+        assert_eq!(rest.len(), 1);
+        assert_eq!(rest[0].message, "second");
+        assert!(decoder.finish().is_empty());
+    }
+
+    #[test]
+    fn streams_are_buffered_independently() {
+        let mut decoder = LogRecordDecoder::default();
+        assert!(decoder.decode(stdout("out ")).is_empty());
+        let records = decoder.decode(stderr("err\n"));
+        assert_eq!(records[0].stream, "stderr");
+        assert_eq!(records[0].message, "err");
+        assert_eq!(decoder.decode(stdout("done\n"))[0].message, "out done");
+    }
+
+    #[test]
+    fn record_serializes_expected_fields() {
+        let record = LogRecord {
+            stream: "stdout",
+            ts: Some("2026-01-01T00:00:00Z".to_string()),
+            message: "hi".to_string(),
+        };
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r#"{"stream":"stdout","ts":"2026-01-01T00:00:00Z","message":"hi"}"#
+        );
+    }
+
+    #[test]
+    fn tail_zero_requests_no_backlog() {
+        use clap::Parser;
+
+        #[derive(Parser)]
+        struct Cli {
+            #[command(flatten)]
+            logs: LogsArgs,
+        }
+
+        let cli = Cli::parse_from(["occ", "--tail", "0"]);
+        let options = logs_options(&cli.logs, 0);
+        assert_eq!(options.tail, "0");
+        assert!(options.follow);
+
+        let cli = Cli::parse_from(["occ"]);
+        assert_eq!(logs_options(&cli.logs, 0).tail, DEFAULT_TAIL_LINES);
+    }
+
+    #[test]
+    fn since_start_uses_started_at_epoch() {
+        assert_eq!(
+            since_start_epoch("2026-01-15T10:30:00.123456789Z"),
+            Some(1_768_473_000)
+        );
+        assert_eq!(
+            since_start_epoch("2026-01-15T10:30:00Z"),
+            Some(1_768_473_000)
+        );
+        assert_eq!(since_start_epoch("0001-01-01T00:00:00Z"), None);
+        assert_eq!(since_start_epoch("not a time"), None);
+    }
+
+    #[test]
+    fn level_filter_threshold() {
+        let filter = LineFilter {
+            min_level: Some(LogLevel::Warn),
+            ..Default::default()
+        };
+        assert!(filter.matches("ERROR db down"));
+        assert!(filter.matches("2026-01-01 WARN slow"));
+        assert!(!filter.matches("INFO ready"));
+        assert!(!filter.matches("DEBUG tick"));
+        // Lines without a level are kept unless --strict
+        assert!(filter.matches("listening on :3000"));
+    }
+
+    #[test]
+    fn level_filter_strict_hides_unleveled_lines() {
+        let filter = LineFilter {
+            min_level: Some(LogLevel::Info),
+            strict: true,
+            ..Default::default()
+        };
+        assert!(filter.matches("INFO ready"));
+        assert!(!filter.matches("listening on :3000"));
+    }
+
+    #[test]
+    fn level_filter_combines_with_grep() {
+        let filter = LineFilter {
+            grep: Some("db".to_string()),
+            min_level: Some(LogLevel::Error),
+            strict: false,
+        };
+        assert!(filter.matches("ERROR db down"));
+        assert!(!filter.matches("ERROR cache down"));
+        assert!(!filter.matches("INFO db ok"));
+    }
+
+    #[test]
+    fn no_filters_match_everything() {
+        assert!(LineFilter::default().matches("anything"));
+    }
+
+    #[test]
+    fn repeated_hosts_are_merged_once_in_order() {
+        let hosts = vec!["b".to_string(), "a".to_string(), "b".to_string()];
+        assert_eq!(distinct_host_names(&hosts), vec!["b", "a"]);
+        assert!(distinct_host_names(&[]).is_empty());
+    }
+
+    #[tokio::test]
+    async fn single_host_flags_are_rejected_with_several_hosts() {
+        let args = LogsArgs {
+            lines: "50".to_string(),
+            no_follow: true,
+            timestamps: false,
+            grep: None,
+            level: None,
+            strict: false,
+            since_start: false,
+            container_id: None,
+            json: true,
+        };
+        let hosts = vec!["a".to_string(), "b".to_string()];
+        let err = cmd_logs(&args, &hosts, true).await.unwrap_err();
+        assert!(err.to_string().contains("single host"));
+    }
+
+    #[test]
+    fn host_prefix_pads_to_width() {
+        assert_eq!(host_prefix("db", 6), "[db    ] ");
+        assert_eq!(host_prefix("prod-1", 6), "[prod-1] ");
+    }
+
+    #[tokio::test]
+    async fn host_events_are_filtered_and_trimmed() {
+        let frames = vec![
+            Ok::<_, String>(stdout("INFO ready\n")),
+            Ok(stderr("ERROR boom\n")),
+        ];
+        let filter = LineFilter {
+            min_level: Some(LogLevel::Error),
+            ..LineFilter::default()
+        };
+        let events: Vec<_> =
+            host_log_events("a".to_string(), futures_util::stream::iter(frames), filter)
+                .collect()
+                .await;
+        assert_eq!(
+            events,
+            vec![HostLogEvent::Line {
+                host: "a".to_string(),
+                line: "ERROR boom".to_string(),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn failing_host_does_not_stop_others() {
+        let good = futures_util::stream::iter(vec![
+            Ok::<_, String>(stdout("one\n")),
+            Ok(stdout("two\n")),
+            Ok(stdout("three\n")),
+        ]);
+        let bad = futures_util::stream::iter(vec![
+            Ok::<_, String>(stdout("hello\n")),
+            Err("connection reset".to_string()),
+            Ok(stdout("never shown\n")),
+        ]);
+        let merged = futures_util::stream::select_all([
+            host_log_events("good".to_string(), good, LineFilter::default()).boxed(),
+            host_log_events("bad".to_string(), bad, LineFilter::default()).boxed(),
+        ]);
+        let events: Vec<_> = merged.collect().await;
+
+        let lines_from = |host: &str| -> Vec<String> {
+            events
+                .iter()
+                .filter_map(|e| match e {
+                    HostLogEvent::Line { host: h, line } if h == host => Some(line.clone()),
+                    _ => None,
+                })
+                .collect()
+        };
+        assert_eq!(lines_from("good"), vec!["one", "two", "three"]);
+        assert_eq!(lines_from("bad"), vec!["hello"]);
+        assert!(events.contains(&HostLogEvent::Failed {
+            host: "bad".to_string(),
+            error: "connection reset".to_string(),
+        }));
+    }
 }
@@ -4,10 +4,15 @@
 
 mod cockpit;
 mod config;
+mod container;
+mod doctor;
 mod host;
+mod image;
 mod install;
 mod logs;
 mod mount;
+mod prune;
+mod reload;
 mod restart;
 mod service;
 mod setup;
@@ -20,11 +25,16 @@ mod user;
 
 pub use cockpit::{CockpitArgs, cmd_cockpit};
 pub use config::{ConfigArgs, cmd_config};
+pub use container::{ContainerArgs, cmd_container};
+pub use doctor::{DoctorArgs, cmd_doctor};
 pub use host::{HostArgs, cmd_host};
+pub use image::{ImageArgs, cmd_image};
 pub use install::{InstallArgs, cmd_install};
 pub use logs::{LogsArgs, cmd_logs};
 pub use mount::{MountArgs, cmd_mount};
+pub use prune::{PruneArgs, cmd_prune};
 pub use restart::{RestartArgs, cmd_restart};
+pub use service::{ServiceArgs, cmd_service};
 pub use setup::{SetupArgs, cmd_setup};
 pub use start::{StartArgs, cmd_start};
 pub use status::{StatusArgs, cmd_status};
@@ -4,11 +4,15 @@ use anyhow::{Result, bail};
 use clap::Args;
 use console::style;
 use opencode_cloud_core::config::{load_config, save_config};
-use opencode_cloud_core::docker::{ParsedMount, check_container_path_warning, validate_mount_path};
+use opencode_cloud_core::docker::{
+    MountError, MountSource, ParsedMount, check_container_path_warning, check_host_path_warning,
+};
+
+use super::add_mount_entry;
 
 #[derive(Args)]
 pub struct MountAddArgs {
-    /// Mount specification: /host/path:/container/path[:ro]
+    /// Mount specification: /host/path:/container/path[:ro] or volume:<name>:/container/path[:ro]
     pub mount_spec: String,
 
     /// Skip path validation (useful for paths that will exist later)
@@ -18,15 +22,25 @@ pub struct MountAddArgs {
     /// Force add even if warning about system paths
     #[arg(long, short)]
     pub force: bool,
+
+    /// Keep the host path as written instead of resolving symlinks
+    #[arg(long)]
+    pub no_resolve_symlinks: bool,
 }
 
 pub async fn cmd_mount_add(args: &MountAddArgs, quiet: bool, _verbose: u8) -> Result<()> {
-    // Parse the mount spec
-    let parsed = ParsedMount::parse(&args.mount_spec)?;
+    // Parse the mount spec (resolves ~, .., and symlinks unless disabled)
+    let parsed = ParsedMount::parse_with_options(&args.mount_spec, !args.no_resolve_symlinks)?;
+
+    if !quiet {
+        if let Some(warning) = check_host_path_warning(&args.mount_spec, &parsed) {
+            eprintln!("{}", style(&warning).yellow());
+        }
+    }
 
     // Validate host path unless --no-validate
     if !args.no_validate {
-        validate_mount_path(&parsed.host_path)?;
+        parsed.validate_source()?;
     }
 
     // Check for system path warning
@@ -45,33 +59,22 @@ pub async fn cmd_mount_add(args: &MountAddArgs, quiet: bool, _verbose: u8) -> Re
     // Load config and add mount
     let mut config = load_config()?;
 
-    // Check for duplicate (by host path)
-    let host_str = parsed.host_path.to_string_lossy();
-    let already_exists = config.mounts.iter().any(|m| {
-        ParsedMount::parse(m)
-            .map(|p| p.host_path.to_string_lossy() == host_str)
-            .unwrap_or(false)
-    });
-
-    if already_exists {
+    // Skip duplicates (same host path or container target)
+    let source = parsed.source.to_string();
+    if let Some(existing) = add_mount_entry(&mut config.mounts, &parsed) {
         if !quiet {
-            println!(
-                "Mount for {} already configured. Remove first with: occ mount remove {}",
-                style(&host_str).cyan(),
-                host_str
-            );
+            println!("{}", duplicate_mount_message(&existing, &parsed));
         }
         return Ok(());
     }
 
-    config.mounts.push(args.mount_spec.clone());
     save_config(&config)?;
 
     if !quiet {
         let mode = if parsed.read_only { "ro" } else { "rw" };
         println!(
             "Added mount: {} -> {} ({})",
-            style(&host_str).cyan(),
+            style(&source).cyan(),
             style(&parsed.container_path).cyan(),
             mode
         );
@@ -84,3 +87,56 @@ pub async fn cmd_mount_add(args: &MountAddArgs, quiet: bool, _verbose: u8) -> Re
 
     Ok(())
 }
+
+/// Explain why `parsed` was skipped in favor of the configured `existing` spec
+///
+/// A different source for the same container path is reported as a
+/// [`MountError::ContainerPathConflict`], matching what `occ start` shows.
+fn duplicate_mount_message(existing: &str, parsed: &ParsedMount) -> String {
+    let existing_mount = ParsedMount::parse_with_options(existing, false).ok();
+    if let Some(mount) = existing_mount {
+        if mount.container_path == parsed.container_path && mount.source != parsed.source {
+            return MountError::ContainerPathConflict {
+                container_path: parsed.container_path.clone(),
+                existing: existing.to_string(),
+            }
+            .to_string();
+        }
+    }
+    format!(
+        "Mount {} already configured. Remove first with: occ mount remove {}",
+        style(existing).cyan(),
+        mount_host_path(existing)
+    )
+}
+
+/// Host path of a configured mount spec, for the "remove first" hint
+fn mount_host_path(spec: &str) -> String {
+    ParsedMount::parse_with_options(spec, false)
+        .map(|p| match &p.source {
+            MountSource::Bind(path) => path.display().to_string(),
+            MountSource::Volume(_) => p.to_spec(),
+        })
+        .unwrap_or_else(|_| spec.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conflicting_container_path_uses_mount_error_message() {
+        let parsed = ParsedMount::parse_with_options("/srv/new:/workspace/app", false).unwrap();
+        let msg = duplicate_mount_message("/srv/old:/workspace/app", &parsed);
+        assert!(msg.contains("'/srv/old:/workspace/app'"));
+        assert!(msg.contains("occ mount remove /workspace/app"));
+    }
+
+    #[test]
+    fn same_host_path_is_already_configured() {
+        let parsed = ParsedMount::parse_with_options("/srv/app:/workspace/b", false).unwrap();
+        let msg = duplicate_mount_message("/srv/app:/workspace/a", &parsed);
+        assert!(msg.contains("already configured"));
+        assert!(msg.contains("occ mount remove /srv/app"));
+    }
+}
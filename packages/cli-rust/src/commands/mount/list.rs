@@ -2,10 +2,13 @@
 
 use anyhow::Result;
 use clap::Args;
-use comfy_table::{Cell, Table, presets::UTF8_FULL_CONDENSED};
+use comfy_table::{Cell, Color, Table, presets::UTF8_FULL_CONDENSED};
 use console::style;
 use opencode_cloud_core::config::load_config;
-use opencode_cloud_core::docker::ParsedMount;
+use opencode_cloud_core::docker::{
+    MountSource, ParsedMount, VOLUME_MOUNT_PREFIX, validate_mount_path,
+};
+use serde::Serialize;
 use std::path::Path;
 
 #[derive(Args)]
@@ -17,6 +20,66 @@ pub struct MountListArgs {
     /// Show resolved paths (as Docker sees them)
     #[arg(long, short)]
     pub resolved: bool,
+
+    /// Output as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// A configured mount as reported by `--json`
+#[derive(Debug, PartialEq, Eq, Serialize)]
+struct MountEntry {
+    /// Host path (empty for named-volume mounts)
+    host_path: String,
+    /// Named volume, for `volume:<name>:...` mounts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    volume: Option<String>,
+    container_path: String,
+    read_only: bool,
+    /// Whether the host path still exists as a directory (always true for volumes)
+    exists: bool,
+}
+
+impl MountEntry {
+    fn from_parsed(parsed: &ParsedMount) -> Self {
+        Self {
+            host_path: parsed
+                .host_path()
+                .map(|path| path.display().to_string())
+                .unwrap_or_default(),
+            volume: parsed.volume_name().map(str::to_string),
+            container_path: parsed.container_path.clone(),
+            read_only: parsed.read_only,
+            exists: parsed.host_path().is_none_or(host_path_exists),
+        }
+    }
+}
+
+/// Whether a mount's host path is usable (exists and is a directory)
+fn host_path_exists(path: &Path) -> bool {
+    validate_mount_path(path).is_ok()
+}
+
+/// Build JSON entries for all parseable mounts (invalid entries are skipped)
+fn mount_entries(mounts: &[String]) -> Vec<MountEntry> {
+    mounts
+        .iter()
+        .filter_map(|mount_str| ParsedMount::parse_with_options(mount_str, false).ok())
+        .map(|parsed| MountEntry::from_parsed(&parsed))
+        .collect()
+}
+
+/// Source cell: the host path (red when it no longer exists) or the volume
+fn source_cell(parsed: &ParsedMount) -> Cell {
+    let path = match &parsed.source {
+        MountSource::Bind(path) => path,
+        MountSource::Volume(name) => return Cell::new(format!("{VOLUME_MOUNT_PREFIX}{name}")),
+    };
+    if host_path_exists(path) {
+        Cell::new(path.display().to_string())
+    } else {
+        Cell::new(format!("{} (missing)", path.display())).fg(Color::Red)
+    }
 }
 
 /// Resolve a host path to what Docker will see
@@ -42,6 +105,12 @@ fn resolve_docker_path(path: &Path) -> String {
 pub async fn cmd_mount_list(args: &MountListArgs, quiet: bool, _verbose: u8) -> Result<()> {
     let config = load_config()?;
 
+    if args.json {
+        let entries = mount_entries(&config.mounts);
+        println!("{}", serde_json::to_string_pretty(&entries)?);
+        return Ok(());
+    }
+
     if config.mounts.is_empty() {
         if !quiet && !args.names_only {
             println!("No mounts configured.");
@@ -57,8 +126,8 @@ pub async fn cmd_mount_list(args: &MountListArgs, quiet: bool, _verbose: u8) ->
     // Names only mode for scripting
     if args.names_only {
         for mount_str in &config.mounts {
-            if let Ok(parsed) = ParsedMount::parse(mount_str) {
-                println!("{}", parsed.host_path.display());
+            if let Ok(parsed) = ParsedMount::parse_with_options(mount_str, false) {
+                println!("{}", parsed.source);
             }
         }
         return Ok(());
@@ -84,20 +153,23 @@ pub async fn cmd_mount_list(args: &MountListArgs, quiet: bool, _verbose: u8) ->
     }
 
     for mount_str in &config.mounts {
-        match ParsedMount::parse(mount_str) {
+        // Show host paths as configured; --resolved adds the canonical form
+        match ParsedMount::parse_with_options(mount_str, false) {
             Ok(parsed) => {
                 let mode = if parsed.read_only { "ro" } else { "rw" };
                 if args.resolved {
-                    let resolved = resolve_docker_path(&parsed.host_path);
+                    let resolved = parsed
+                        .host_path()
+                        .map_or_else(|| "-".to_string(), resolve_docker_path);
                     table.add_row(vec![
-                        Cell::new(parsed.host_path.display().to_string()),
+                        source_cell(&parsed),
                         Cell::new(resolved),
                         Cell::new(&parsed.container_path),
                         Cell::new(mode),
                     ]);
                 } else {
                     table.add_row(vec![
-                        Cell::new(parsed.host_path.display().to_string()),
+                        source_cell(&parsed),
                         Cell::new(&parsed.container_path),
                         Cell::new(mode),
                     ]);
@@ -127,3 +199,67 @@ pub async fn cmd_mount_list(args: &MountListArgs, quiet: bool, _verbose: u8) ->
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn existence_flag_reflects_host_path() {
+        let dir = std::env::temp_dir();
+        let existing = dir.display().to_string();
+        let missing = dir
+            .join("occ-mount-list-test-missing")
+            .display()
+            .to_string();
+        let mounts = vec![
+            format!("{existing}:/workspace/a:ro"),
+            format!("{missing}:/workspace/b"),
+        ];
+
+        let entries = mount_entries(&mounts);
+        assert_eq!(entries.len(), 2);
+        assert!(entries[0].exists);
+        assert!(entries[0].read_only);
+        assert!(!entries[1].exists);
+        assert!(!entries[1].read_only);
+    }
+
+    #[test]
+    fn invalid_mounts_are_skipped() {
+        let mounts = vec!["not-a-mount".to_string()];
+        assert!(mount_entries(&mounts).is_empty());
+    }
+
+    #[test]
+    fn json_serialization_fields() {
+        let entry = MountEntry {
+            host_path: "/data".to_string(),
+            volume: None,
+            container_path: "/workspace/data".to_string(),
+            read_only: true,
+            exists: false,
+        };
+        let value = serde_json::to_value(&entry).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "host_path": "/data",
+                "container_path": "/workspace/data",
+                "read_only": true,
+                "exists": false,
+            })
+        );
+    }
+
+    #[test]
+    fn volume_mounts_are_listed() {
+        let entries = mount_entries(&["volume:cache:/workspace/.cache".to_string()]);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].volume.as_deref(), Some("cache"));
+        assert!(entries[0].exists);
+
+        let value = serde_json::to_value(&entries[0]).unwrap();
+        assert_eq!(value["volume"], "cache");
+    }
+}
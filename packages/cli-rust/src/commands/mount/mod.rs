@@ -8,6 +8,7 @@ mod remove;
 
 use anyhow::Result;
 use clap::{Args, Subcommand};
+use opencode_cloud_core::docker::ParsedMount;
 
 pub use add::cmd_mount_add;
 pub use list::cmd_mount_list;
@@ -31,6 +32,52 @@ pub enum MountCommands {
     List(list::MountListArgs),
 }
 
+/// Add `parsed` to `mounts` unless it duplicates an existing entry
+///
+/// An entry is a duplicate when it uses the same host path or the same
+/// container target. Returns the existing entry in that case, leaving
+/// `mounts` unchanged. Shared by `occ mount add` and `occ config set mounts.add`.
+pub(crate) fn add_mount_entry(mounts: &mut Vec<String>, parsed: &ParsedMount) -> Option<String> {
+    let existing = mounts.iter().find(|m| {
+        ParsedMount::parse(m)
+            .map(|p| p.source == parsed.source || p.container_path == parsed.container_path)
+            .unwrap_or(false)
+    });
+    if let Some(existing) = existing {
+        return Some(existing.clone());
+    }
+
+    // Store the resolved form so the config reflects what actually gets mounted
+    mounts.push(parsed.to_spec());
+    None
+}
+
+/// Remove the mounts matching `target`, returning how many were removed
+///
+/// `target` may be a host path (as written or resolved), a container path,
+/// or a full mount spec (matched by its container target). Unparseable
+/// entries are kept. Shared by `occ mount remove` and
+/// `occ config set mounts.remove`.
+pub(crate) fn remove_mount_entries(mounts: &mut Vec<String>, target: &str) -> usize {
+    let target_container = ParsedMount::parse_with_options(target, false)
+        .map(|p| p.container_path)
+        .ok();
+    let matches = |m: &str, resolve: bool| {
+        ParsedMount::parse_with_options(m, resolve)
+            .map(|p| {
+                p.host_path()
+                    .is_some_and(|path| path.to_string_lossy() == target)
+                    || p.container_path == target
+                    || target_container.as_deref() == Some(p.container_path.as_str())
+            })
+            .unwrap_or(false)
+    };
+
+    let original_len = mounts.len();
+    mounts.retain(|m| !matches(m, false) && !matches(m, true));
+    original_len - mounts.len()
+}
+
 /// Handle mount command
 pub async fn cmd_mount(args: &MountArgs, quiet: bool, verbose: u8) -> Result<()> {
     match &args.command {
@@ -39,3 +86,53 @@ pub async fn cmd_mount(args: &MountArgs, quiet: bool, verbose: u8) -> Result<()>
         MountCommands::List(list_args) => cmd_mount_list(list_args, quiet, verbose).await,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed(spec: &str) -> ParsedMount {
+        ParsedMount::parse_with_options(spec, false).unwrap()
+    }
+
+    #[test]
+    fn add_rejects_same_host_or_container_target() {
+        let mut mounts = vec!["/srv/a:/workspace/a".to_string()];
+
+        assert_eq!(
+            add_mount_entry(&mut mounts, &parsed("/srv/b:/workspace/a")),
+            Some("/srv/a:/workspace/a".to_string())
+        );
+        assert_eq!(
+            add_mount_entry(&mut mounts, &parsed("/srv/a:/workspace/other")),
+            Some("/srv/a:/workspace/a".to_string())
+        );
+        assert_eq!(
+            add_mount_entry(&mut mounts, &parsed("/srv/b:/workspace/b:ro")),
+            None
+        );
+        assert_eq!(
+            mounts,
+            vec!["/srv/a:/workspace/a", "/srv/b:/workspace/b:ro"]
+        );
+    }
+
+    #[test]
+    fn remove_matches_host_container_or_spec() {
+        let mut mounts = vec![
+            "/srv/a:/workspace/a".to_string(),
+            "/srv/b:/workspace/b".to_string(),
+            "/srv/c:/workspace/c".to_string(),
+            "not-a-mount".to_string(),
+        ];
+
+        assert_eq!(remove_mount_entries(&mut mounts, "/srv/a"), 1);
+        assert_eq!(remove_mount_entries(&mut mounts, "/workspace/b"), 1);
+        assert_eq!(
+            remove_mount_entries(&mut mounts, "/elsewhere:/workspace/c:ro"),
+            1
+        );
+        assert_eq!(remove_mount_entries(&mut mounts, "/srv/missing"), 0);
+        assert_eq!(mounts, vec!["not-a-mount"]);
+    }
+}
@@ -4,26 +4,20 @@ use anyhow::{Result, bail};
 use clap::Args;
 use console::style;
 use opencode_cloud_core::config::{load_config, save_config};
-use opencode_cloud_core::docker::ParsedMount;
+
+use super::remove_mount_entries;
 
 #[derive(Args)]
 pub struct MountRemoveArgs {
-    /// Host path of the mount to remove
+    /// Host path (or container path) of the mount to remove
     pub host_path: String,
 }
 
 pub async fn cmd_mount_remove(args: &MountRemoveArgs, quiet: bool, _verbose: u8) -> Result<()> {
     let mut config = load_config()?;
 
-    // Find and remove mount by host path
-    let original_len = config.mounts.len();
-    config.mounts.retain(|m| {
-        ParsedMount::parse(m)
-            .map(|p| p.host_path.to_string_lossy() != args.host_path)
-            .unwrap_or(true) // Keep unparseable mounts
-    });
-
-    if config.mounts.len() == original_len {
+    // Find and remove mount by host path (as written or resolved)
+    if remove_mount_entries(&mut config.mounts, &args.host_path) == 0 {
         bail!(
             "No mount found for host path: {}\n\nList mounts with: occ mount list",
             args.host_path
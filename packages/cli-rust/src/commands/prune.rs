@@ -0,0 +1,133 @@
+//! Prune command implementation
+//!
+//! Reports and optionally removes stale opencode-cloud resources: the
+//! stopped container, old images, and orphaned volumes. Dry-run by default.
+
+use crate::output::{CommandSpinner, format_bytes, format_docker_error};
+use anyhow::{Result, anyhow};
+use clap::Args;
+use console::style;
+use opencode_cloud_core::docker::{PruneCategory, PrunePlan, execute_prune, plan_prune};
+
+/// Arguments for the prune command
+#[derive(Args, Default)]
+pub struct PruneArgs {
+    /// Only report what would be removed (default unless --yes is given)
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Actually delete the listed resources
+    #[arg(long, short)]
+    pub yes: bool,
+}
+
+/// Whether the prune should delete anything
+///
+/// Deletion requires `--yes`; an explicit `--dry-run` always wins.
+fn should_delete(args: &PruneArgs) -> bool {
+    args.yes && !args.dry_run
+}
+
+/// Report and optionally remove stale opencode-cloud resources
+pub async fn cmd_prune(args: &PruneArgs, maybe_host: Option<&str>, quiet: bool) -> Result<()> {
+    let (client, host_name) = crate::resolve_docker_client(maybe_host).await?;
+
+    client.verify_connection().await.map_err(|e| {
+        let msg = format_docker_error(&e);
+        anyhow!("{msg}")
+    })?;
+
+    let plan = plan_prune(&client).await?;
+
+    if plan.is_empty() {
+        if !quiet {
+            let msg = crate::format_host_message(host_name.as_deref(), "Nothing to prune");
+            println!("{}", style(msg).dim());
+        }
+        return Ok(());
+    }
+
+    let delete = should_delete(args);
+    if !quiet {
+        print_plan(&plan, delete);
+    }
+
+    if !delete {
+        if !quiet {
+            println!();
+            println!(
+                "Dry run: nothing was removed. Run {} to delete.",
+                style("occ prune --yes").cyan()
+            );
+        }
+        return Ok(());
+    }
+
+    let spinner = CommandSpinner::new_maybe("Removing resources...", quiet);
+    if let Err(e) = execute_prune(&client, &plan).await {
+        spinner.fail("Prune failed");
+        return Err(anyhow!("{}", format_docker_error(&e)));
+    }
+    let reclaimed: u64 = PruneCategory::ALL
+        .iter()
+        .map(|category| plan.reclaimable_bytes(*category))
+        .sum();
+    spinner.success(&crate::format_host_message(
+        host_name.as_deref(),
+        &format!("Reclaimed {}", format_bytes(reclaimed)),
+    ));
+
+    Ok(())
+}
+
+/// Print candidates grouped by category with reclaimable space
+fn print_plan(plan: &PrunePlan, delete: bool) {
+    let heading = if delete { "Removing" } else { "Would remove" };
+    for category in PruneCategory::ALL {
+        let candidates: Vec<_> = plan.in_category(category).collect();
+        if candidates.is_empty() {
+            continue;
+        }
+        println!(
+            "{} {} ({} reclaimable):",
+            heading,
+            style(category.label()).bold(),
+            format_bytes(plan.reclaimable_bytes(category))
+        );
+        for candidate in candidates {
+            println!(
+                "  {} {}",
+                candidate.name,
+                style(format_bytes(candidate.size_bytes)).dim()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_to_dry_run() {
+        assert!(!should_delete(&PruneArgs::default()));
+    }
+
+    #[test]
+    fn explicit_dry_run_never_deletes() {
+        let args = PruneArgs {
+            dry_run: true,
+            yes: true,
+        };
+        assert!(!should_delete(&args));
+    }
+
+    #[test]
+    fn yes_enables_deletion() {
+        let args = PruneArgs {
+            dry_run: false,
+            yes: true,
+        };
+        assert!(should_delete(&args));
+    }
+}
@@ -0,0 +1,201 @@
+//! Config reload for the foreground service
+//!
+//! On SIGHUP, `occ start --no-daemon` re-reads the config and applies what
+//! can change on a running container: the restart policy and memory/CPU
+//! limits go through `docker update`, and settings only read by occ itself
+//! take effect on their own. Everything else is reported as needing a
+//! restart or, for settings baked into the container at creation (ports,
+//! mounts, ...), a recreate. The classification is core's `change_impact`,
+//! which `occ config set` uses too.
+
+use anyhow::Result;
+use console::style;
+use opencode_cloud_core::Config;
+use opencode_cloud_core::config::{ChangeImpact, change_impact, config_changes};
+use opencode_cloud_core::docker::{
+    CONTAINER_NAME, DockerClient, ResourceLimits, update_resource_limits, update_restart_policy,
+};
+
+/// Changed keys split by how they can be applied
+#[derive(Debug, Default, PartialEq, Eq)]
+struct ReloadPlan {
+    /// Applied now (container updates and host-only settings)
+    live: Vec<String>,
+    /// Applied by `docker update`; a subset of `live`
+    container_updates: Vec<String>,
+    /// Left as-is until the service is restarted
+    restart: Vec<String>,
+    /// Left as-is until the container is recreated
+    recreate: Vec<String>,
+}
+
+fn plan_reload(current: &Config, new: &Config) -> ReloadPlan {
+    let mut plan = ReloadPlan::default();
+    for change in config_changes(current, new) {
+        match change_impact(&change.key, new) {
+            ChangeImpact::UpdateContainer => {
+                plan.container_updates.push(change.key.clone());
+                plan.live.push(change.key);
+            }
+            ChangeImpact::HostOnly => plan.live.push(change.key),
+            ChangeImpact::Restart => plan.restart.push(change.key),
+            ChangeImpact::Recreate => plan.recreate.push(change.key),
+        }
+    }
+    plan
+}
+
+/// Apply the live-applicable differences between `current` and `new`
+///
+/// `current` is updated to reflect what is now in effect, so settings that
+/// still need a restart or recreate keep being reported on later reloads.
+pub(super) async fn reload_config(
+    client: &DockerClient,
+    current: &mut Config,
+    new: Config,
+    quiet: bool,
+) -> Result<()> {
+    let plan = plan_reload(current, &new);
+
+    if plan.container_updates.iter().any(|k| k == "auto_restart") {
+        update_restart_policy(client, CONTAINER_NAME, new.auto_restart).await?;
+        current.auto_restart = new.auto_restart;
+    }
+    if plan
+        .container_updates
+        .iter()
+        .any(|k| k == "memory_limit_mb" || k == "cpu_limit")
+    {
+        let limits = ResourceLimits {
+            memory_mb: new.memory_limit_mb.or(current.memory_limit_mb),
+            cpus: new.cpu_limit.or(current.cpu_limit),
+        };
+        update_resource_limits(client, CONTAINER_NAME, &limits).await?;
+        current.memory_limit_mb = limits.memory_mb;
+        current.cpu_limit = limits.cpus;
+    }
+    let pending: Vec<String> = plan.restart.iter().chain(&plan.recreate).cloned().collect();
+    *current = keep_pending_keys(current, new, &pending)?;
+
+    if !quiet {
+        print_reload_summary(&plan);
+    }
+    Ok(())
+}
+
+/// `new`, with the `pending` keys left at their `current` values
+///
+/// This is what is in effect after a reload: everything live-applicable has
+/// been applied, the rest waits for a restart or recreate.
+fn keep_pending_keys(current: &Config, new: Config, pending: &[String]) -> Result<Config> {
+    let current = serde_json::to_value(current)?;
+    let mut merged = serde_json::to_value(new)?;
+    if let (Some(current), Some(merged)) = (current.as_object(), merged.as_object_mut()) {
+        for key in pending {
+            if let Some(value) = current.get(key) {
+                merged.insert(key.clone(), value.clone());
+            }
+        }
+    }
+    Ok(serde_json::from_value(merged)?)
+}
+
+fn print_reload_summary(plan: &ReloadPlan) {
+    let label = style("[reload]").cyan();
+    if plan.live.is_empty() && plan.restart.is_empty() && plan.recreate.is_empty() {
+        eprintln!("{label} Config reloaded; no changes");
+        return;
+    }
+    if !plan.live.is_empty() {
+        eprintln!("{label} Applied: {}", plan.live.join(", "));
+    }
+    if !plan.restart.is_empty() {
+        eprintln!(
+            "{label} {} {}",
+            style("Requires restart:").yellow(),
+            plan.restart.join(", ")
+        );
+    }
+    if !plan.recreate.is_empty() {
+        eprintln!(
+            "{label} {} {} (run {})",
+            style("Requires recreate:").yellow(),
+            plan.recreate.join(", "),
+            style("occ start --recreate").cyan()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_changes_is_empty_plan() {
+        assert_eq!(
+            plan_reload(&Config::default(), &Config::default()),
+            ReloadPlan::default()
+        );
+    }
+
+    #[test]
+    fn live_and_restart_changes_are_split() {
+        let current = Config::default();
+        let new = Config {
+            auto_restart: !current.auto_restart,
+            memory_limit_mb: Some(2048),
+            update_check: "never".to_string(),
+            opencode_web_port: 4000,
+            container_env: vec!["FOO=bar".to_string()],
+            ..Config::default()
+        };
+        let plan = plan_reload(&current, &new);
+
+        assert_eq!(
+            plan.container_updates,
+            vec!["auto_restart", "memory_limit_mb"]
+        );
+        assert_eq!(
+            plan.live,
+            vec!["auto_restart", "memory_limit_mb", "update_check"]
+        );
+        assert_eq!(plan.restart, vec!["container_env"]);
+        assert_eq!(plan.recreate, vec!["opencode_web_port"]);
+    }
+
+    #[test]
+    fn reloaded_config_keeps_restart_keys() {
+        let current = Config::default();
+        let new = Config {
+            startup_timeout_seconds: Some(300),
+            image_registry_auth: Some("ghcr.io=user:token".to_string()),
+            opencode_web_port: 4000,
+            ..Config::default()
+        };
+        let plan = plan_reload(&current, &new);
+        assert_eq!(
+            plan.live,
+            vec!["image_registry_auth", "startup_timeout_seconds"]
+        );
+
+        let applied = keep_pending_keys(&current, new, &plan.recreate).unwrap();
+        assert_eq!(applied.startup_timeout_seconds, Some(300));
+        assert_eq!(applied.opencode_web_port, current.opencode_web_port);
+    }
+
+    #[test]
+    fn removing_a_limit_requires_recreate() {
+        let current = Config {
+            memory_limit_mb: Some(1024),
+            cpu_limit: Some(2.0),
+            ..Config::default()
+        };
+        let new = Config {
+            cpu_limit: Some(1.0),
+            ..Config::default()
+        };
+        let plan = plan_reload(&current, &new);
+        assert_eq!(plan.container_updates, vec!["cpu_limit"]);
+        assert_eq!(plan.recreate, vec!["memory_limit_mb"]);
+    }
+}
@@ -1,14 +1,17 @@
 //! Restart command implementation
 //!
-//! Restarts the opencode service (stop + start).
+//! Restarts the opencode service (stop + start), then verifies the service
+//! becomes ready again.
 
-use crate::output::{CommandSpinner, format_docker_error, show_docker_error};
+use crate::commands::start::{show_recent_logs, startup_timeout, wait_for_service_ready};
+use crate::output::{CommandSpinner, PhaseTimer, format_docker_error, show_docker_error};
 use anyhow::{Result, anyhow};
 use clap::Args;
 use console::style;
 use opencode_cloud_core::config::load_config;
 use opencode_cloud_core::docker::{
-    CONTAINER_NAME, container_is_running, setup_and_start, stop_service,
+    CONTAINER_NAME, HealthCheckSettings, ResourceLimits, container_env, container_is_running,
+    setup_and_start, stop_service,
 };
 
 /// Arguments for the restart command
@@ -23,12 +26,20 @@ pub struct RestartArgs {
 /// 1. Connects to Docker
 /// 2. Stops the service if running
 /// 3. Starts the service
+/// 4. Waits for it to become ready, showing recent logs if it does not
+///
+/// Restart reuses the existing container, so there is no previous container
+/// to fall back to; a failed readiness check is reported as an error with
+/// the container's recent output instead of leaving the service silently down.
 pub async fn cmd_restart(
     _args: &RestartArgs,
     maybe_host: Option<&str>,
     quiet: bool,
     verbose: u8,
 ) -> Result<()> {
+    let mut timer = PhaseTimer::new(verbose);
+    timer.start("connect");
+
     // Resolve Docker client (local or remote)
     let (client, host_name) = crate::resolve_docker_client(maybe_host).await?;
 
@@ -57,6 +68,7 @@ pub async fn cmd_restart(
     let spinner = CommandSpinner::new_maybe(&msg, quiet);
 
     // Stop if running
+    timer.start("stop");
     if container_is_running(&client, CONTAINER_NAME).await? {
         spinner.update(&crate::format_host_message(
             host_name.as_deref(),
@@ -73,6 +85,7 @@ pub async fn cmd_restart(
     }
 
     // Start
+    timer.start("start");
     spinner.update(&crate::format_host_message(
         host_name.as_deref(),
         "Starting service...",
@@ -80,15 +93,40 @@ pub async fn cmd_restart(
     match setup_and_start(
         &client,
         Some(port),
-        None,
+        Some(container_env(&config)),
         Some(bind_addr),
         Some(config.cockpit_port),
         Some(config.cockpit_enabled),
         None, // bind_mounts: restart preserves existing container mounts
+        None, // labels
+        HealthCheckSettings::from_config(&config),
+        Some(config.auto_restart),
+        ResourceLimits::from_config(&config),
+        false,
     )
     .await
     {
         Ok(container_id) => {
+            timer.start("readiness");
+            let timeout = startup_timeout(None, config.startup_timeout_seconds, false);
+            let ready = wait_for_service_ready(
+                &client,
+                port,
+                None,
+                &spinner,
+                host_name.as_deref(),
+                timeout,
+            )
+            .await;
+            if let Err(e) = ready {
+                spinner.fail(&crate::format_host_message(
+                    host_name.as_deref(),
+                    "Service failed to become ready after restart",
+                ));
+                let logs = || show_recent_logs(&client, RESTART_FAILURE_LOG_LINES);
+                return Err(report_not_ready(e, logs).await);
+            }
+
             spinner.success(&crate::format_host_message(
                 host_name.as_deref(),
                 "Service restarted",
@@ -113,6 +151,47 @@ pub async fn cmd_restart(
             return Err(e.into());
         }
     }
+    timer.print_summary();
 
     Ok(())
 }
+
+/// Container log lines shown when the restarted service is not ready
+const RESTART_FAILURE_LOG_LINES: usize = 20;
+
+/// Show recent logs via `show_logs` and build the error for a restarted
+/// service that failed its readiness check
+async fn report_not_ready<F, Fut>(e: anyhow::Error, show_logs: F) -> anyhow::Error
+where
+    F: FnOnce() -> Fut,
+    Fut: std::future::Future<Output = ()>,
+{
+    eprintln!();
+    eprintln!("{}", style("Recent container logs:").yellow());
+    show_logs().await;
+    anyhow!(
+        "The service was restarted but did not become ready: {e}\n\n\
+         It is not serving requests. Inspect with: occ logs"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[tokio::test]
+    async fn failed_readiness_shows_logs_and_errors() {
+        let logs_shown = Cell::new(false);
+        let err = report_not_ready(
+            anyhow!("Service did not become ready within 30 seconds"),
+            || async { logs_shown.set(true) },
+        )
+        .await;
+
+        assert!(logs_shown.get());
+        let msg = err.to_string();
+        assert!(msg.contains("restarted but did not become ready"), "{msg}");
+        assert!(msg.contains("within 30 seconds"), "{msg}");
+    }
+}
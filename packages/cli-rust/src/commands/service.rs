@@ -1,13 +1,92 @@
-//! Shared helpers for service lifecycle commands
+//! Service command and shared helpers for service lifecycle commands
 //!
-//! Provides common stop behavior with spinner output.
+//! Provides `occ service export`, which prints the systemd unit or launchd
+//! plist `occ install` would write, and common stop behavior with spinner
+//! output.
 
+use super::install::{build_service_config, resolve_executable_path};
 use crate::output::{CommandSpinner, show_docker_error};
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use clap::{Args, Subcommand};
 use console::style;
 use opencode_cloud_core::docker::{DockerClient, stop_service};
+use opencode_cloud_core::{
+    Config, get_service_manager_for_mode, is_service_registration_supported,
+};
+use std::path::PathBuf;
 use std::time::Instant;
 
+/// Arguments for the service command
+#[derive(Args)]
+pub struct ServiceArgs {
+    #[command(subcommand)]
+    pub command: ServiceCommands,
+}
+
+/// Service subcommands
+#[derive(Subcommand)]
+pub enum ServiceCommands {
+    /// Print the systemd unit or launchd plist without installing it
+    Export(ServiceExportArgs),
+}
+
+/// Arguments for service export
+#[derive(Args)]
+pub struct ServiceExportArgs {
+    /// Render a system-level service (starts on boot) instead of boot_mode
+    #[arg(long)]
+    pub system: bool,
+
+    /// Write to a file instead of stdout
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+
+    /// Executable the service should run (default: the current binary)
+    #[arg(long, value_name = "PATH")]
+    pub exec_path: Option<PathBuf>,
+}
+
+/// Handle service command
+pub fn cmd_service(args: &ServiceArgs, config: &Config, quiet: bool) -> Result<()> {
+    match &args.command {
+        ServiceCommands::Export(export_args) => cmd_service_export(export_args, config, quiet),
+    }
+}
+
+fn cmd_service_export(args: &ServiceExportArgs, config: &Config, quiet: bool) -> Result<()> {
+    if !is_service_registration_supported() {
+        return Err(anyhow!(
+            "Service registration not supported on this platform.\n\
+             Supported platforms: Linux (systemd), macOS (launchd)"
+        ));
+    }
+
+    let executable_path = resolve_executable_path(args.exec_path.as_deref())?;
+    let mut service_config = build_service_config(config, executable_path);
+    if args.system {
+        service_config.boot_mode = "system".to_string();
+    }
+
+    let manager = get_service_manager_for_mode(&service_config.boot_mode)?;
+    let rendered = manager.render_service_file(&service_config)?;
+
+    match &args.output {
+        Some(path) => {
+            std::fs::write(path, &rendered)
+                .map_err(|e| anyhow!("Failed to write {}: {e}", path.display()))?;
+            if !quiet {
+                eprintln!(
+                    "Wrote {} (install path: {})",
+                    path.display(),
+                    style(manager.service_file_path().display()).dim()
+                );
+            }
+        }
+        None => print!("{rendered}"),
+    }
+    Ok(())
+}
+
 pub struct StopSpinnerMessages<'a> {
     pub action_message: &'a str,
     pub update_label: &'a str,
@@ -78,3 +157,37 @@ fn stop_success_message(
 
     (message, should_warn)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn export_writes_unit_with_config_restart_settings() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let exe = dir.path().join("occ");
+        std::fs::write(&exe, "").unwrap();
+        std::fs::set_permissions(&exe, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let output = dir.path().join("opencode-cloud.service");
+
+        let config = Config {
+            restart_retries: 4,
+            restart_delay: 6,
+            ..Config::default()
+        };
+        let args = ServiceExportArgs {
+            system: true,
+            output: Some(output.clone()),
+            exec_path: Some(exe.clone()),
+        };
+        cmd_service_export(&args, &config, true).unwrap();
+
+        let unit = std::fs::read_to_string(output).unwrap();
+        assert!(unit.contains(&format!("ExecStart={} start --no-daemon", exe.display())));
+        assert!(unit.contains("RestartSec=6s"));
+        assert!(unit.contains("StartLimitBurst=4"));
+    }
+}
@@ -2,15 +2,22 @@
 //!
 //! Runs the interactive setup wizard.
 
-use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, anyhow, bail};
 use clap::Args;
 use console::style;
-use dialoguer::Confirm;
+use dialoguer::{Confirm, Password};
+use opencode_cloud_core::config::{
+    ConfigFormat, display_validation_error, display_validation_warning, parse_config,
+    validate_config,
+};
 use opencode_cloud_core::docker::{CONTAINER_NAME, container_is_running};
 use opencode_cloud_core::{Config, load_config, save_config};
 
 use crate::commands::{cmd_start, cmd_stop};
-use crate::wizard::run_wizard;
+use crate::output::normalize_bind_addr;
+use crate::wizard::{create_container_user, run_wizard, verify_tty};
 
 /// Arguments for the setup command
 #[derive(Args)]
@@ -22,6 +29,10 @@ pub struct SetupArgs {
     /// Run setup for a remote host instead of local Docker
     #[arg(long)]
     pub host: Option<String>,
+
+    /// Seed the configuration from an existing config file (only the password is prompted)
+    #[arg(long, value_name = "PATH", conflicts_with = "yes")]
+    pub import: Option<PathBuf>,
 }
 
 /// Run the setup command
@@ -48,8 +59,11 @@ pub async fn cmd_setup(args: &SetupArgs, quiet: bool) -> Result<()> {
         );
     }
 
-    // Run the wizard
-    let new_config = run_wizard(existing_config.as_ref()).await?;
+    // Run the wizard, or import a config and only ask for the password
+    let new_config = match args.import.as_deref() {
+        Some(path) => import_config(path, args.host.as_deref()).await?,
+        None => run_wizard(existing_config.as_ref()).await?,
+    };
 
     // Save the config
     save_config(&new_config)?;
@@ -104,7 +118,11 @@ pub async fn cmd_setup(args: &SetupArgs, quiet: bool) -> Result<()> {
 
     // Stop first if restarting (use longer timeout for graceful shutdown)
     if action == Action::Restart {
-        let stop_args = crate::commands::StopArgs { timeout: 60 };
+        let stop_args = crate::commands::StopArgs {
+            timeout: 60,
+            all: false,
+            signal: None,
+        };
         cmd_stop(&stop_args, args.host.as_deref(), quiet).await?;
         println!();
     }
@@ -113,20 +131,137 @@ pub async fn cmd_setup(args: &SetupArgs, quiet: bool) -> Result<()> {
     let start_args = crate::commands::StartArgs {
         port: Some(new_config.opencode_web_port),
         open: false,
+        open_with: None,
+        auto_port: false,
+        save_port: false,
         no_daemon: false,
+        attach: false,
+        quiet_json: false,
         pull_sandbox_image: false,
         cached_rebuild_sandbox_image: false,
         full_rebuild_sandbox_image: false,
         ignore_version: false,
         no_update_check: false,
+        recreate: false,
+        recreate_if_unhealthy: false,
         mounts: Vec::new(),
         no_mounts: false,
+        labels: Vec::new(),
+        env: Vec::new(),
+        no_resolve_symlinks: false,
+        detach_wait: false,
+        no_security_check: false,
+        bind_address: None,
+        memory: None,
+        cpus: None,
+        wait_docker: None,
+        startup_timeout: None,
+        wait_for: None,
+        wait_for_status: None,
     };
     cmd_start(&start_args, args.host.as_deref(), quiet, 0).await?;
 
     Ok(())
 }
 
+/// Import a config file, prompting for the user's password
+///
+/// Passwords are never taken from the file: PAM users live inside the
+/// container, so the password is set again on this machine.
+async fn import_config(path: &Path, host: Option<&str>) -> Result<Config> {
+    verify_tty()?;
+    let imported = read_import_config(path)?;
+
+    let (config, credentials) = resolve_imported_config(imported, |username| {
+        Password::new()
+            .with_prompt(format!("Password for '{username}'"))
+            .with_confirmation("Confirm password", "Passwords do not match")
+            .interact()
+            .map_err(|_| anyhow!("Setup cancelled"))
+    })?;
+
+    if let Some((username, password)) = credentials {
+        let (client, _) = crate::resolve_docker_client(host).await?;
+        if container_is_running(&client, CONTAINER_NAME)
+            .await
+            .unwrap_or(false)
+        {
+            println!();
+            println!("{}", style("Creating user in container...").cyan());
+            create_container_user(&client, &username, &password).await?;
+        } else {
+            println!(
+                "{}",
+                style("Note: User will be created when container starts.").dim()
+            );
+        }
+    }
+
+    Ok(config)
+}
+
+/// Read and validate the config file given to `--import`
+fn read_import_config(path: &Path) -> Result<Config> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read config to import: {}", path.display()))?;
+    let config = parse_config(&contents, ConfigFormat::from_path(path))
+        .with_context(|| format!("Invalid config file: {}", path.display()))?;
+
+    match validate_config(&config) {
+        Ok(warnings) => {
+            for warning in &warnings {
+                display_validation_warning(warning);
+            }
+        }
+        Err(error) => {
+            display_validation_error(&error);
+            bail!("Config to import is invalid. Fix the error above and try again.");
+        }
+    }
+    Ok(config)
+}
+
+/// User whose password is prompted for after an import
+///
+/// The first tracked user, or a legacy `auth_username` that will be migrated.
+fn import_username(config: &Config) -> Option<String> {
+    config.users.first().cloned().or_else(|| {
+        config
+            .auth_username
+            .clone()
+            .filter(|username| !username.is_empty())
+    })
+}
+
+/// Turn an imported config into the config to save
+///
+/// Prompts for the password of [`import_username`] via `prompt_password`,
+/// migrates a legacy `auth_username` into `users`, and clears the legacy
+/// auth fields so no plaintext password is carried over. Returns the
+/// credentials to create in the container, if any.
+fn resolve_imported_config(
+    mut config: Config,
+    prompt_password: impl FnOnce(&str) -> Result<String>,
+) -> Result<(Config, Option<(String, String)>)> {
+    let credentials = match import_username(&config) {
+        Some(username) => {
+            let password = prompt_password(&username)?;
+            if password.is_empty() {
+                bail!("Password cannot be empty");
+            }
+            if !config.users.contains(&username) {
+                config.users.push(username.clone());
+            }
+            Some((username, password))
+        }
+        None => None,
+    };
+
+    config.auth_username = Some(String::new());
+    config.auth_password = Some(String::new());
+    Ok((config, credentials))
+}
+
 #[derive(PartialEq)]
 enum Action {
     Start,
@@ -147,11 +282,7 @@ fn show_running_status(config: &Config, host: Option<&str>) {
     println!("{}", style(msg).dim());
     println!();
 
-    let bind_addr = if config.bind == "0.0.0.0" || config.bind == "::" {
-        "127.0.0.1"
-    } else {
-        &config.bind
-    };
+    let bind_addr = normalize_bind_addr(&config.bind_address);
 
     println!(
         "URL: {}",
@@ -165,3 +296,96 @@ fn show_running_status(config: &Config, host: Option<&str>) {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn import_prompts_for_first_user() {
+        let imported = Config {
+            opencode_web_port: 4100,
+            users: vec!["alice".to_string(), "bob".to_string()],
+            ..Config::default()
+        };
+
+        let mut prompted = None;
+        let (config, credentials) = resolve_imported_config(imported, |user| {
+            prompted = Some(user.to_string());
+            Ok("s3cret".to_string())
+        })
+        .unwrap();
+
+        assert_eq!(prompted.as_deref(), Some("alice"));
+        assert_eq!(
+            credentials,
+            Some(("alice".to_string(), "s3cret".to_string()))
+        );
+        assert_eq!(config.opencode_web_port, 4100);
+        assert_eq!(config.users, vec!["alice", "bob"]);
+        assert_eq!(config.auth_password.as_deref(), Some(""));
+    }
+
+    #[test]
+    fn import_migrates_legacy_auth_without_plaintext_password() {
+        let imported = Config {
+            auth_username: Some("admin".to_string()),
+            auth_password: Some("from-file".to_string()),
+            ..Config::default()
+        };
+
+        let (config, credentials) =
+            resolve_imported_config(imported, |_| Ok("typed".to_string())).unwrap();
+
+        assert_eq!(
+            credentials,
+            Some(("admin".to_string(), "typed".to_string()))
+        );
+        assert_eq!(config.users, vec!["admin"]);
+        assert_eq!(config.auth_username.as_deref(), Some(""));
+        assert_eq!(config.auth_password.as_deref(), Some(""));
+    }
+
+    #[test]
+    fn import_without_users_skips_prompt() {
+        let imported = Config {
+            allow_unauthenticated_network: true,
+            ..Config::default()
+        };
+        let (config, credentials) =
+            resolve_imported_config(imported, |_| panic!("should not prompt")).unwrap();
+        assert_eq!(credentials, None);
+        assert!(config.users.is_empty());
+        assert!(config.allow_unauthenticated_network);
+    }
+
+    #[test]
+    fn import_rejects_empty_password() {
+        let imported = Config {
+            users: vec!["alice".to_string()],
+            ..Config::default()
+        };
+        assert!(resolve_imported_config(imported, |_| Ok(String::new())).is_err());
+    }
+
+    #[test]
+    fn read_import_config_validates() {
+        let dir = tempfile::tempdir().unwrap();
+        let good = dir.path().join("config.json");
+        std::fs::write(&good, r#"{ "version": 1, "opencode_web_port": 4200 }"#).unwrap();
+        assert_eq!(read_import_config(&good).unwrap().opencode_web_port, 4200);
+
+        let bad = dir.path().join("bad.json");
+        std::fs::write(&bad, r#"{ "version": 1, "rate_limit_attempts": 0 }"#).unwrap();
+        assert!(read_import_config(&bad).is_err());
+
+        let privileged = dir.path().join("privileged.json");
+        std::fs::write(&privileged, r#"{ "version": 1, "opencode_web_port": 80 }"#).unwrap();
+        assert_eq!(
+            read_import_config(&privileged).unwrap().opencode_web_port,
+            80
+        );
+
+        assert!(read_import_config(&dir.path().join("missing.json")).is_err());
+    }
+}
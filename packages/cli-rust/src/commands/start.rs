@@ -4,22 +4,34 @@
 
 use crate::commands::service::{StopSpinnerMessages, stop_service_with_spinner};
 use crate::output::{
-    CommandSpinner, format_cockpit_url, format_docker_error, normalize_bind_addr,
-    resolve_remote_addr, show_docker_error,
+    CommandSpinner, PhaseTimer, format_cockpit_url, format_docker_error, normalize_bind_addr,
+    print_firewall_hint, resolve_remote_addr, show_docker_error,
 };
 use anyhow::{Result, anyhow};
 use clap::Args;
 use console::style;
 use futures_util::stream::StreamExt;
 use opencode_cloud_core::bollard::container::{LogOutput, LogsOptions};
-use opencode_cloud_core::config::save_config;
+use opencode_cloud_core::config::{
+    ConfigOverrides, parse_env_entry, parse_labeled_env_entry, record_config_changes,
+    resolve_effective_config, save_config, validate_bind_address, validate_cpu_limit,
+    validate_memory_limit_mb, validate_port,
+};
 use opencode_cloud_core::docker::{
-    CONTAINER_NAME, DEFAULT_STOP_TIMEOUT_SECS, DockerClient, DockerError, IMAGE_NAME_GHCR,
-    IMAGE_TAG_DEFAULT, ImageState, ParsedMount, ProgressReporter, build_image,
-    check_container_path_warning, container_exists, container_is_running, get_cli_version,
-    get_container_bind_mounts, get_container_ports, get_image_version, image_exists, pull_image,
-    save_state, setup_and_start, validate_mount_path, versions_compatible,
+    CONTAINER_NAME, ContainerState, DEFAULT_STOP_TIMEOUT_SECS, DockerClient, DockerError,
+    HealthCheckSettings, IMAGE_NAME_GHCR, IMAGE_TAG_DEFAULT, ImageState, MountError,
+    MountSpecError, ParsedMount, ProgressReporter, RegistryAuth, ResourceLimits, build_image,
+    check_container_path_conflicts, check_container_path_warning, check_host_path_warning,
+    container_env, container_exists, container_is_running, container_state, get_cli_version,
+    get_container_bind_mounts, get_container_labels, get_container_ports,
+    get_container_resource_limits, get_image_version, image_exists, merge_container_labels,
+    parse_container_label, probe_http_status, pull_image, save_state, setup_and_start,
+    versions_compatible,
 };
+use opencode_cloud_core::events::{LifecycleEventKind, record_event};
+use opencode_cloud_core::parse_duration;
+use serde::Serialize;
+use std::io::Write;
 use std::net::{TcpListener, TcpStream};
 use std::time::{Duration, Instant};
 
@@ -27,18 +39,73 @@ use std::time::{Duration, Instant};
 #[derive(Args)]
 pub struct StartArgs {
     /// Port to bind on host (default: 3000)
-    #[arg(short, long)]
+    #[arg(short, long, value_parser = validate_port)]
     pub port: Option<u16>,
 
+    /// After the port is up, also wait for this http(s) URL to respond with 2xx
+    #[arg(long, value_name = "URL")]
+    pub wait_for: Option<String>,
+
+    /// Status code --wait-for must return (default: any 2xx)
+    #[arg(long, value_name = "CODE", requires = "wait_for")]
+    pub wait_for_status: Option<u16>,
+
+    /// Wait up to this long for the Docker daemon to become reachable (e.g. 30, 2m)
+    #[arg(long, value_name = "DURATION", value_parser = parse_duration)]
+    pub wait_docker: Option<Duration>,
+
+    /// How long to wait for the service to become ready (e.g. 90, 3m)
+    ///
+    /// Overrides config startup_timeout_seconds. The default is 60s when the
+    /// image is reused and 180s after it was pulled or built.
+    #[arg(long, value_name = "DURATION", value_parser = parse_startup_timeout)]
+    pub startup_timeout: Option<Duration>,
+
+    /// Bind address for this run only (overrides config bind_address)
+    #[arg(long, value_name = "ADDR")]
+    pub bind_address: Option<String>,
+
+    /// Memory limit in MB for this run only (overrides config memory_limit_mb)
+    #[arg(long, value_name = "MB", value_parser = validate_memory_limit_mb)]
+    pub memory: Option<u64>,
+
+    /// CPU limit in cores for this run only (overrides config cpu_limit)
+    #[arg(long, value_name = "N", value_parser = validate_cpu_limit)]
+    pub cpus: Option<f64>,
+
     /// Open browser after starting
     #[arg(long)]
     pub open: bool,
 
-    /// Run in foreground (for service managers like systemd/launchd)
-    /// Note: This is the default behavior; flag exists for compatibility
+    /// Open the URL with this browser command instead of the default (implies --open)
+    #[arg(long, value_name = "CMD")]
+    pub open_with: Option<String>,
+
+    /// If the port is in use, start on the next free port instead of failing
+    #[arg(long)]
+    pub auto_port: bool,
+
+    /// Save the port picked by --auto-port to config
+    #[arg(long, requires = "auto_port")]
+    pub save_port: bool,
+
+    /// Stay in the foreground after starting (for service managers like systemd/launchd)
+    ///
+    /// On SIGTERM or SIGINT the container is stopped gracefully and occ exits 0.
     #[arg(long)]
     pub no_daemon: bool,
 
+    /// Stream container logs in the foreground until the container exits
+    ///
+    /// Like `docker run` without `-d`: exits with the container's exit code,
+    /// and Ctrl+C stops the container.
+    #[arg(long, conflicts_with = "no_daemon")]
+    pub attach: bool,
+
+    /// Like --quiet, but on success print one JSON line with url, container_id and port
+    #[arg(long, conflicts_with = "attach")]
+    pub quiet_json: bool,
+
     /// Pull prebuilt image from registry (fast, ~2 min)
     #[arg(long)]
     pub pull_sandbox_image: bool,
@@ -59,6 +126,14 @@ pub struct StartArgs {
     #[arg(long)]
     pub no_update_check: bool,
 
+    /// Remove and recreate the container (e.g. one stuck in a bad state); volumes are kept
+    #[arg(long)]
+    pub recreate: bool,
+
+    /// Recreate the container if it is running but its healthcheck reports unhealthy
+    #[arg(long)]
+    pub recreate_if_unhealthy: bool,
+
     /// Add one-time bind mount (can be specified multiple times)
     /// Format: /host/path:/container/path[:ro]
     #[arg(long = "mount", action = clap::ArgAction::Append)]
@@ -67,6 +142,63 @@ pub struct StartArgs {
     /// Skip configured mounts (only use --mount flags if specified)
     #[arg(long)]
     pub no_mounts: bool,
+
+    /// Add a custom container label (can be specified multiple times)
+    /// Format: key=value; applied when the container is created
+    #[arg(
+        long = "label",
+        value_name = "KEY=VALUE",
+        value_parser = parse_container_label,
+        action = clap::ArgAction::Append
+    )]
+    pub labels: Vec<(String, String)>,
+
+    /// Set an environment variable for this run only (can be specified multiple times)
+    /// Format: KEY=value; wins over variables derived from config
+    #[arg(
+        long = "env",
+        value_name = "KEY=VALUE",
+        value_parser = parse_env_override,
+        action = clap::ArgAction::Append
+    )]
+    pub env: Vec<String>,
+
+    /// Use mount host paths as written instead of resolving symlinks
+    #[arg(long)]
+    pub no_resolve_symlinks: bool,
+
+    /// Return as soon as the container starts, without waiting for readiness
+    #[arg(long, alias = "no-wait")]
+    pub detach_wait: bool,
+
+    /// Skip the first-start authentication check (localhost binds only)
+    ///
+    /// For throwaway local development. Refused when the bind address is
+    /// anything other than a loopback address.
+    #[arg(long)]
+    pub no_security_check: bool,
+}
+
+/// Validate a `--env KEY=value` flag
+fn parse_env_override(value: &str) -> Result<String, String> {
+    parse_labeled_env_entry(value, "env")?;
+    Ok(value.to_string())
+}
+
+/// Apply `--env` overrides on top of the config-derived environment
+///
+/// An override replaces every base entry with the same key; among
+/// overrides, the last one for a key wins.
+fn merge_env_overrides(mut env: Vec<String>, overrides: &[String]) -> Vec<String> {
+    for entry in overrides {
+        let Ok(key) = parse_env_entry(entry) else {
+            continue;
+        };
+        let key_prefix = format!("{key}=");
+        env.retain(|e| !e.starts_with(&key_prefix));
+        env.push(entry.clone());
+    }
+    env
 }
 
 /// Collect and validate bind mounts from config and CLI flags
@@ -74,6 +206,7 @@ fn collect_bind_mounts(
     config: &opencode_cloud_core::Config,
     cli_mounts: &[String],
     no_mounts: bool,
+    resolve_symlinks: bool,
     quiet: bool,
 ) -> Result<Vec<ParsedMount>> {
     let mut all_mounts = Vec::new();
@@ -81,29 +214,29 @@ fn collect_bind_mounts(
     // Add config mounts unless --no-mounts
     if !no_mounts {
         for mount_str in &config.mounts {
-            let parsed = ParsedMount::parse(mount_str)
-                .map_err(|e| anyhow!("Invalid config mount '{mount_str}': {e}"))?;
+            let parsed = ParsedMount::parse_with_options(mount_str, resolve_symlinks)
+                .map_err(|source| mount_spec_error(mount_str, source))?;
             all_mounts.push(parsed);
         }
     }
 
     // Add CLI mounts (always, even with --no-mounts)
     for mount_str in cli_mounts {
-        let parsed = ParsedMount::parse(mount_str)
-            .map_err(|e| anyhow!("Invalid mount '{mount_str}': {e}"))?;
+        let parsed = ParsedMount::parse_with_options(mount_str, resolve_symlinks)
+            .map_err(|source| mount_spec_error(mount_str, source))?;
+        if !quiet {
+            if let Some(warning) = check_host_path_warning(mount_str, &parsed) {
+                eprintln!("{}", style(&warning).yellow());
+            }
+        }
         all_mounts.push(parsed);
     }
 
     // Validate all mount paths exist
     for parsed in &all_mounts {
-        if let Err(e) = validate_mount_path(&parsed.host_path) {
-            return Err(anyhow!(
-                "Mount path validation failed for '{}':\n  {}\n\nDid the directory move? Run: occ mount remove {}",
-                parsed.host_path.display(),
-                e,
-                parsed.host_path.display()
-            ));
-        }
+        parsed
+            .validate_source()
+            .map_err(|source| mount_spec_error(&parsed.to_spec(), source))?;
 
         // Show warnings for system paths (non-blocking)
         if !quiet {
@@ -113,9 +246,19 @@ fn collect_bind_mounts(
         }
     }
 
+    check_container_path_conflicts(&all_mounts)?;
+
     Ok(all_mounts)
 }
 
+/// Attach the offending spec to a mount error
+fn mount_spec_error(spec: &str, source: MountError) -> MountSpecError {
+    MountSpecError {
+        spec: spec.to_string(),
+        source,
+    }
+}
+
 /// Check if two host paths match, accounting for macOS path translation
 ///
 /// Docker on macOS translates paths: /tmp -> /private/tmp -> /host_mnt/private/tmp
@@ -153,7 +296,10 @@ fn mount_has_match(
     conf: &ParsedMount,
     current: &[opencode_cloud_core::docker::ContainerBindMount],
 ) -> bool {
-    let conf_host = conf.host_path.to_string_lossy();
+    let Some(conf_host) = conf.host_path() else {
+        return false;
+    };
+    let conf_host = conf_host.to_string_lossy();
 
     current.iter().any(|cur| {
         cur.target == conf.container_path
@@ -164,11 +310,14 @@ fn mount_has_match(
 
 /// Compare container bind mounts with configured mounts
 ///
-/// Returns true if the mounts match (ignoring order).
+/// Returns true if the mounts match (ignoring order). Only bind mounts are
+/// compared; named-volume mounts are not reported by the container's bind
+/// mount list, so changing one needs `occ start --recreate`.
 fn mounts_equal(
     current: &[opencode_cloud_core::docker::ContainerBindMount],
     configured: &[ParsedMount],
 ) -> bool {
+    let configured: Vec<&ParsedMount> = configured.iter().filter(|m| !m.is_volume()).collect();
     current.len() == configured.len()
         && configured.iter().all(|conf| mount_has_match(conf, current))
 }
@@ -213,6 +362,74 @@ async fn check_mount_mismatch(
     Ok(Some(true))
 }
 
+/// Check if the container's memory/CPU limits differ from the requested ones
+///
+/// Returns `Some(true)` to rebuild, `None` if no mismatch. Errors when the
+/// user declines or `quiet` prevents prompting.
+async fn check_resource_mismatch(
+    client: &DockerClient,
+    requested: ResourceLimits,
+    quiet: bool,
+) -> Result<Option<bool>> {
+    let current = get_container_resource_limits(client, CONTAINER_NAME).await?;
+    if current.matches(&requested) {
+        return Ok(None);
+    }
+
+    if quiet {
+        return Err(anyhow!(
+            "Resource limits changed ({} -> {}).\n\
+             Container must be recreated to apply them.\n\
+             Run without --quiet to be prompted, or use: occ start --recreate",
+            format_resource_limits(&current),
+            format_resource_limits(&requested)
+        ));
+    }
+
+    eprintln!();
+    eprintln!(
+        "{} {}",
+        style("Resource limits changed:").yellow().bold(),
+        style("Container must be recreated to apply them.").yellow()
+    );
+    eprintln!(
+        "  limits: {} (current) → {} (requested)",
+        style(format_resource_limits(&current)).red(),
+        style(format_resource_limits(&requested)).green()
+    );
+    eprintln!();
+    eprintln!("{}", style("Your data volumes will be preserved.").dim());
+    display_container_recreate_warning();
+    eprintln!();
+
+    let confirm = dialoguer::Confirm::new()
+        .with_prompt("Recreate container with new resource limits?")
+        .default(true)
+        .interact()?;
+
+    if !confirm {
+        return Err(anyhow!(
+            "Container not recreated. To apply the new limits, run:\n  \
+             occ start --recreate"
+        ));
+    }
+
+    Ok(Some(true))
+}
+
+/// Human-readable memory/CPU limits, e.g. "2048 MB memory, 1.5 CPUs"
+fn format_resource_limits(limits: &ResourceLimits) -> String {
+    let memory = limits.memory_mb.map_or_else(
+        || "unlimited memory".to_string(),
+        |mb| format!("{mb} MB memory"),
+    );
+    let cpus = limits.cpus.map_or_else(
+        || "unlimited CPUs".to_string(),
+        |cpus| format!("{cpus} CPUs"),
+    );
+    format!("{memory}, {cpus}")
+}
+
 /// Display mount mismatch information to user
 fn display_mount_mismatch(
     current: &[opencode_cloud_core::docker::ContainerBindMount],
@@ -263,7 +480,7 @@ fn display_configured_mounts(mounts: &[ParsedMount]) {
     eprintln!("  Configured mounts:");
     for m in mounts {
         let ro = if m.read_only { ":ro" } else { "" };
-        eprintln!("    - {}:{}{}", m.host_path.display(), m.container_path, ro);
+        eprintln!("    - {}:{}{}", m.source, m.container_path, ro);
     }
 }
 
@@ -394,8 +611,10 @@ async fn check_port_mismatch(
 
     let port_mismatch = current_opencode_port != port;
     let cockpit_mismatch = current_cockpit_port != config.cockpit_port;
+    let bind_mismatch =
+        !bind_addresses_match(current_ports.bind_address.as_deref(), &config.bind_address);
 
-    if !port_mismatch && !cockpit_mismatch {
+    if !port_mismatch && !cockpit_mismatch && !bind_mismatch {
         return Ok(None);
     }
 
@@ -412,9 +631,20 @@ async fn check_port_mismatch(
     }
 
     if quiet {
+        let current_bind = current_ports.bind_address.as_deref().unwrap_or("unknown");
+        let mismatch = if port_mismatch || cockpit_mismatch {
+            format!(
+                "Port mismatch: container uses port {current_opencode_port} but requested port {port}."
+            )
+        } else {
+            format!(
+                "Bind address mismatch: container is bound to {current_bind} but requested {}.",
+                config.bind_address
+            )
+        };
         return Err(anyhow!(
-            "Port mismatch: container uses port {current_opencode_port} but requested port {port}.\n\
-             Container must be recreated to change ports.\n\
+            "{mismatch}\n\
+             Container must be recreated to change ports or bind address.\n\
              Run without --quiet to be prompted, or manually remove with:\n  \
              occ stop && docker rm {CONTAINER_NAME}"
         ));
@@ -428,9 +658,17 @@ async fn check_port_mismatch(
         current_cockpit_port,
         config.cockpit_port,
     );
+    if bind_mismatch {
+        eprintln!(
+            "  bind address: {} (current) → {} (requested)",
+            style(current_ports.bind_address.as_deref().unwrap_or("unknown")).red(),
+            style(&config.bind_address).green()
+        );
+        eprintln!();
+    }
 
     let confirm = dialoguer::Confirm::new()
-        .with_prompt("Recreate container with new port(s)?")
+        .with_prompt("Recreate container with new port/bind settings?")
         .default(true)
         .interact()?;
 
@@ -502,6 +740,7 @@ fn display_container_recreate_warning() {
 /// Acquire Docker image (build or pull) based on configuration
 async fn acquire_image(
     client: &DockerClient,
+    auth: &RegistryAuth,
     use_prebuilt: bool,
     full_rebuild: bool,
     quiet: bool,
@@ -514,7 +753,7 @@ async fn acquire_image(
     }
 
     // Try pulling prebuilt image
-    match pull_docker_image(client, verbose).await {
+    match pull_docker_image(client, auth, verbose).await {
         Ok(registry) => {
             save_state(&ImageState::prebuilt(get_cli_version(), &registry)).ok();
             Ok(())
@@ -557,6 +796,198 @@ async fn handle_pull_failure(
     Ok(())
 }
 
+/// Verify the Docker connection, optionally waiting for the daemon to start
+///
+/// With `--wait-docker <duration>`, an unreachable daemon is polled with backoff
+/// (e.g. Docker Desktop still starting after login) before giving up.
+async fn wait_for_docker(client: &DockerClient, wait: Option<Duration>, quiet: bool) -> Result<()> {
+    let result = match wait {
+        Some(timeout) if client.verify_connection().await.is_err() => {
+            let spinner = CommandSpinner::new_maybe("Waiting for Docker to start...", quiet);
+            let result = client.wait_until_ready(timeout).await;
+            match &result {
+                Ok(()) => spinner.success("Docker is ready"),
+                Err(_) => spinner.fail(&format!(
+                    "Docker did not become ready within {}s",
+                    timeout.as_secs()
+                )),
+            }
+            result
+        }
+        Some(_) => Ok(()),
+        None => client.verify_connection().await,
+    };
+
+    result.map_err(|e| {
+        let msg = format_docker_error(&e);
+        anyhow!("{msg}")
+    })
+}
+
+/// One-shot config overrides from `occ start` flags
+fn start_overrides(args: &StartArgs) -> ConfigOverrides {
+    ConfigOverrides {
+        opencode_web_port: args.port,
+        bind_address: args.bind_address.clone(),
+        memory_limit_mb: args.memory,
+        cpu_limit: args.cpus,
+    }
+}
+
+/// Apply `occ start` overrides to the loaded config for this run
+///
+/// Goes through the same resolver as `occ config show --effective`, so the
+/// two always agree. The address is validated the same way as
+/// `occ config set bind_address`; resource limits are validated by clap.
+fn resolve_start_config(
+    config: &opencode_cloud_core::Config,
+    overrides: &ConfigOverrides,
+) -> Result<opencode_cloud_core::Config> {
+    resolve_effective_config(config, overrides)
+        .map(|effective| effective.config)
+        .map_err(|_| {
+            anyhow!(
+                "Invalid --bind-address: {}\n\
+                 Valid examples: 127.0.0.1, ::1, 0.0.0.0, ::, or localhost",
+                overrides.bind_address.as_deref().unwrap_or_default()
+            )
+        })
+}
+
+/// Outcome of the first-start authentication check
+#[derive(Debug, PartialEq, Eq)]
+enum SecurityGate {
+    /// Users are configured or unauthenticated access is allowed
+    Pass,
+    /// No auth configured, but `--no-security-check` on a localhost bind
+    Bypassed,
+    /// No auth configured; `bypass_refused` when `--no-security-check`
+    /// was given for a non-localhost bind
+    Blocked { bypass_refused: bool },
+}
+
+/// Decide whether a first start may proceed without authentication
+fn first_start_security_gate(
+    config: &opencode_cloud_core::Config,
+    no_security_check: bool,
+) -> SecurityGate {
+    if !config.users.is_empty() || config.allow_unauthenticated_network {
+        return SecurityGate::Pass;
+    }
+    match (no_security_check, config.is_localhost()) {
+        (true, true) => SecurityGate::Bypassed,
+        (true, false) => SecurityGate::Blocked {
+            bypass_refused: true,
+        },
+        (false, _) => SecurityGate::Blocked {
+            bypass_refused: false,
+        },
+    }
+}
+
+/// Display the warning for a `--no-security-check` bypass
+fn display_security_bypass_warning(bind_addr: &str) {
+    eprintln!();
+    eprintln!(
+        "{} {}",
+        style("WARNING:").yellow().bold(),
+        style("Security check skipped (--no-security-check)").yellow()
+    );
+    eprintln!(
+        "No users are configured. Anyone who can reach {} on this machine can use the service.",
+        style(bind_addr).cyan()
+    );
+    eprintln!(
+        "Add a user before exposing it: {}",
+        style("occ user add").cyan()
+    );
+    eprintln!();
+}
+
+/// Readiness timeout for this start
+///
+/// `--startup-timeout` wins over config `startup_timeout_seconds`; without
+/// either, a freshly pulled or built image gets a longer window than a reused one.
+pub(crate) fn startup_timeout(
+    flag: Option<Duration>,
+    configured: Option<u32>,
+    acquired_image: bool,
+) -> Duration {
+    flag.or_else(|| configured.map(|secs| Duration::from_secs(secs.into())))
+        .unwrap_or_else(|| {
+            Duration::from_secs(if acquired_image {
+                STARTUP_TIMEOUT_ACQUIRED_SECS
+            } else {
+                STARTUP_TIMEOUT_REUSE_SECS
+            })
+        })
+}
+
+/// Parse `--startup-timeout`, which must be at least one second
+fn parse_startup_timeout(s: &str) -> Result<Duration, String> {
+    let timeout = parse_duration(s)?;
+    if timeout.is_zero() {
+        return Err("Startup timeout must be at least 1 second".to_string());
+    }
+    Ok(timeout)
+}
+
+/// Labels from `--label` that the existing container does not carry
+fn missing_labels<'a>(
+    existing: &[(String, String)],
+    labels: &'a [(String, String)],
+) -> Vec<&'a (String, String)> {
+    labels
+        .iter()
+        .filter(|label| !existing.contains(label))
+        .collect()
+}
+
+/// Warn that `--label` values are ignored because the container is reused
+fn display_label_mismatch_warning(existing: &[(String, String)], labels: &[(String, String)]) {
+    let missing = missing_labels(existing, labels);
+    if missing.is_empty() {
+        return;
+    }
+    let missing: Vec<String> = missing.iter().map(|(k, v)| format!("{k}={v}")).collect();
+    eprintln!(
+        "{} The existing container does not have label(s) {}; labels only apply when it is created.",
+        style("Warning:").yellow().bold(),
+        missing.join(", ")
+    );
+    eprintln!(
+        "Recreate it to apply them: {}",
+        style("occ start --recreate --label ...").cyan()
+    );
+}
+
+/// Whether a container is running but failing its healthcheck
+fn is_running_unhealthy(state: &ContainerState) -> bool {
+    state.running && state.health.as_deref() == Some("unhealthy")
+}
+
+/// Whether the service is exposed to the network with no authentication configured
+fn should_warn_network_exposure(config: &opencode_cloud_core::Config) -> bool {
+    config.is_network_exposed() && config.users.is_empty() && !config.allow_unauthenticated_network
+}
+
+/// Whether a container's bound host IP matches the requested bind address
+///
+/// Compares parsed addresses so "localhost" matches "127.0.0.1". An unknown
+/// current address is treated as a match.
+fn bind_addresses_match(current: Option<&str>, requested: &str) -> bool {
+    let Some(current) = current else {
+        return true;
+    };
+    match (
+        validate_bind_address(current),
+        validate_bind_address(requested),
+    ) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => current == requested,
+    }
+}
+
 /// Display network exposure warning
 fn display_network_exposure_warning(bind_addr: &str) {
     eprintln!();
@@ -595,6 +1026,26 @@ pub async fn cmd_start(
     quiet: bool,
     verbose: u8,
 ) -> Result<()> {
+    start_with_output(args, maybe_host, quiet, verbose, &mut std::io::stdout()).await
+}
+
+/// [`cmd_start`], writing the `--quiet-json` line to `json_out`
+async fn start_with_output(
+    args: &StartArgs,
+    maybe_host: Option<&str>,
+    quiet: bool,
+    verbose: u8,
+    json_out: &mut impl Write,
+) -> Result<()> {
+    let quiet = quiet || args.quiet_json;
+    let url_probe = args
+        .wait_for
+        .as_deref()
+        .map(|url| UrlProbe::new(url, args.wait_for_status))
+        .transpose()?;
+    let mut timer = PhaseTimer::new(verbose);
+    timer.start("connect");
+
     // Resolve Docker client (local or remote)
     let (client, host_name) = crate::resolve_docker_client(maybe_host).await?;
 
@@ -607,14 +1058,16 @@ pub async fn cmd_start(
         );
     }
 
-    client.verify_connection().await.map_err(|e| {
-        let msg = format_docker_error(&e);
-        anyhow!("{msg}")
-    })?;
+    wait_for_docker(&client, args.wait_docker, quiet).await?;
+    timer.start("checks");
 
-    // Load config for port and bind_address
-    let config = opencode_cloud_core::config::load_config()?;
-    let port = args.port.unwrap_or(config.opencode_web_port);
+    // Load config for port and bind_address (with one-shot overrides applied)
+    let config = resolve_start_config(
+        &opencode_cloud_core::config::load_config()?,
+        &start_overrides(args),
+    )?;
+    let resource_limits = ResourceLimits::from_config(&config);
+    let port = config.opencode_web_port;
     let bind_addr = &config.bind_address;
     // Validate config before starting
     match opencode_cloud_core::config::validate_config(&config) {
@@ -632,7 +1085,13 @@ pub async fn cmd_start(
     }
 
     // Collect and validate bind mounts
-    let bind_mounts = collect_bind_mounts(&config, &args.mounts, args.no_mounts, quiet)?;
+    let bind_mounts = collect_bind_mounts(
+        &config,
+        &args.mounts,
+        args.no_mounts,
+        !args.no_resolve_symlinks,
+        quiet,
+    )?;
     let bind_mounts_option = if bind_mounts.is_empty() {
         None
     } else {
@@ -690,17 +1149,67 @@ pub async fn cmd_start(
     // Security check: block first start without security configured
     let is_first_start = !container_exists(&client, CONTAINER_NAME).await?;
 
-    if is_first_start && config.users.is_empty() && !config.allow_unauthenticated_network {
-        return Err(anyhow!(
-            "{}\n\n\
-             No users are configured for authentication.\n\
-             The service cannot start without security configured.\n\n\
-             Quick setup:\n  {}\n\n\
-             Or allow unauthenticated access (not recommended):\n  {}",
-            style("Security not configured").red().bold(),
-            style("occ setup").cyan(),
-            style("occ config set allow_unauthenticated_network true").dim()
-        ));
+    if is_first_start {
+        match first_start_security_gate(&config, args.no_security_check) {
+            SecurityGate::Pass => {}
+            SecurityGate::Bypassed => display_security_bypass_warning(bind_addr),
+            SecurityGate::Blocked { bypass_refused } => {
+                let refused = if bypass_refused {
+                    format!(
+                        "--no-security-check only applies to localhost binds (bind address is {bind_addr}).\n"
+                    )
+                } else {
+                    String::new()
+                };
+                return Err(anyhow!(
+                    "{}\n\n\
+                     {refused}\
+                     No users are configured for authentication.\n\
+                     The service cannot start without security configured.\n\n\
+                     Quick setup:\n  {}\n\n\
+                     Or allow unauthenticated access (not recommended):\n  {}",
+                    style("Security not configured").red().bold(),
+                    style("occ setup").cyan(),
+                    style("occ config set allow_unauthenticated_network true").dim()
+                ));
+            }
+        }
+    }
+
+    // Pick the port before comparing it with an existing container, so an
+    // --auto-port fallback is what the mismatch check sees. A port held by
+    // our own running container counts as available.
+    let own_port = if is_first_start || !container_is_running(&client, CONTAINER_NAME).await? {
+        None
+    } else {
+        get_container_ports(&client, CONTAINER_NAME)
+            .await?
+            .opencode_port
+    };
+    let port = select_port(port, args.auto_port, |p| {
+        Some(p) == own_port || check_port_available(p)
+    })?;
+    if port != config.opencode_web_port {
+        if !quiet {
+            eprintln!(
+                "{} Port {} is in use; using port {} instead",
+                style("Note:").cyan(),
+                config.opencode_web_port,
+                style(port).cyan()
+            );
+        }
+        if args.save_port {
+            let saved_config = opencode_cloud_core::config::load_config()?;
+            let updated = with_saved_port(&saved_config, port);
+            save_config(&updated)?;
+            record_config_changes(&saved_config, &updated);
+            if !quiet {
+                eprintln!(
+                    "{} Saved opencode_web_port = {port} to config",
+                    style("Note:").cyan()
+                );
+            }
+        }
     }
 
     // Check for port mismatch on existing container
@@ -719,11 +1228,60 @@ pub async fn cmd_start(
         }
     }
 
+    // Check for resource limit changes (--memory/--cpus or config)
+    if !is_first_start && !recreate_container {
+        if let Some(rebuild) = check_resource_mismatch(&client, resource_limits, quiet).await? {
+            recreate_container = rebuild;
+        }
+    }
+
+    // A running but unhealthy container is recreated instead of reported as running
+    let mut force_recreate = args.recreate;
+    if args.recreate_if_unhealthy && !is_first_start && !recreate_container && !force_recreate {
+        if let Ok(state) = container_state(&client, CONTAINER_NAME).await {
+            if is_running_unhealthy(&state) {
+                if !quiet {
+                    eprintln!(
+                        "{} Container is unhealthy; recreating it from the current image",
+                        style("Note:").cyan()
+                    );
+                }
+                force_recreate = true;
+            }
+        }
+    }
+
+    // Labels are fixed at creation: keep the existing ones across a recreate,
+    // and warn when --label cannot reach a container that is reused
+    let existing_labels = if is_first_start {
+        Vec::new()
+    } else {
+        get_container_labels(&client, CONTAINER_NAME).await?
+    };
+    let labels = merge_container_labels(&existing_labels, &args.labels);
+    if !quiet && !is_first_start && !recreate_container && !force_recreate {
+        display_label_mismatch_warning(&existing_labels, &labels);
+    }
+
     // Handle rebuild: remove existing container so a new one is created from the new image
     if recreate_container {
         handle_rebuild(&client, host_name.as_deref(), quiet, verbose).await?;
-    } else if container_is_running(&client, CONTAINER_NAME).await? {
+    } else if !force_recreate && container_is_running(&client, CONTAINER_NAME).await? {
         // Already running (idempotent behavior) - only when not rebuilding
+        if args.quiet_json {
+            let info = client
+                .inner()
+                .inspect_container(CONTAINER_NAME, None)
+                .await
+                .map_err(|e| anyhow!("Failed to inspect container: {e}"))?;
+            let summary = StartSummary::new(
+                info.id.as_deref().unwrap_or_default(),
+                port,
+                bind_addr,
+                host_name.as_deref(),
+            );
+            return write_start_json(&summary, json_out);
+        }
         return show_already_running(
             port,
             bind_addr,
@@ -734,27 +1292,25 @@ pub async fn cmd_start(
     }
 
     // Security check: warn if network exposed without authentication
-    let should_warn_exposure = !quiet
-        && config.is_network_exposed()
-        && config.users.is_empty()
-        && !config.allow_unauthenticated_network;
-
-    if should_warn_exposure {
+    if !quiet && should_warn_network_exposure(&config) {
         display_network_exposure_warning(bind_addr);
     }
 
-    // Pre-check port availability
-    if !check_port_available(port) {
-        return Err(port_in_use_error(port));
+    // The host firewall only matters for a local exposed service
+    if !quiet && host_name.is_none() && config.is_network_exposed() {
+        print_firewall_hint(port);
     }
 
     // First-run image source prompt (if no image and no flag specified)
     let image_already_exists = image_exists(&client, IMAGE_NAME_GHCR, IMAGE_TAG_DEFAULT).await?;
     if !image_already_exists && !has_image_flag && !quiet {
         let (new_use_prebuilt, updated_config) = prompt_image_source_choice(&config)?;
-        // Save config with new image_source
+        // Save the new image_source onto the on-disk config so one-shot
+        // overrides (e.g. --bind-address) are not persisted
         if updated_config.image_source != config.image_source {
-            save_config(&updated_config)?;
+            let mut saved_config = opencode_cloud_core::config::load_config()?;
+            saved_config.image_source = updated_config.image_source;
+            save_config(&saved_config)?;
         }
         // Use the choice for this run
         use_prebuilt = new_use_prebuilt;
@@ -767,8 +1323,14 @@ pub async fn cmd_start(
         || !image_exists(&client, IMAGE_NAME_GHCR, IMAGE_TAG_DEFAULT).await?;
 
     if needs_image {
+        timer.start(if use_prebuilt && !rebuild_image {
+            "pull"
+        } else {
+            "build"
+        });
         acquire_image(
             &client,
+            &RegistryAuth::from_config(&config),
             use_prebuilt && !rebuild_image,
             args.full_rebuild_sandbox_image,
             quiet,
@@ -778,15 +1340,23 @@ pub async fn cmd_start(
     }
 
     // Start container
+    timer.start("create");
+    let started_at = chrono::Utc::now().timestamp();
     let msg = crate::format_host_message(host_name.as_deref(), "Starting container...");
     let spinner = CommandSpinner::new_maybe(&msg, quiet);
     let container_id = match start_container(
         &client,
+        merge_env_overrides(container_env(&config), &args.env),
         port,
         bind_addr,
         config.cockpit_port,
         config.cockpit_enabled,
         bind_mounts_option,
+        labels,
+        HealthCheckSettings::from_config(&config),
+        config.auto_restart,
+        resource_limits,
+        force_recreate,
     )
     .await
     {
@@ -802,65 +1372,314 @@ pub async fn cmd_start(
         }
     };
 
-    // Wait for service to be ready
-    if let Err(e) = wait_for_service_ready(&client, port, &spinner, host_name.as_deref()).await {
-        spinner.fail(&crate::format_host_message(
+    // Wait for service to be ready (unless --detach-wait)
+    timer.start("readiness");
+    let timeout = startup_timeout(
+        args.startup_timeout,
+        config.startup_timeout_seconds,
+        needs_image,
+    );
+    let readiness = match await_readiness(args.detach_wait || args.attach, || {
+        wait_for_service_ready(
+            &client,
+            port,
+            url_probe.as_ref(),
+            &spinner,
             host_name.as_deref(),
-            "Service failed to become ready",
-        ));
-        eprintln!();
-        eprintln!("{}", style("Recent container logs:").yellow());
-        show_recent_logs(&client, 20).await;
-        return Err(e);
-    }
+            timeout,
+        )
+    })
+    .await
+    {
+        Ok(readiness) => readiness,
+        Err(e) => {
+            spinner.fail(&crate::format_host_message(
+                host_name.as_deref(),
+                "Service failed to become ready",
+            ));
+            eprintln!();
+            eprintln!("{}", style("Recent container logs:").yellow());
+            show_recent_logs(&client, 20).await;
+            return Err(e);
+        }
+    };
 
+    let started_message = match readiness {
+        Readiness::Ready => "Service started and ready",
+        Readiness::Skipped => "Container started",
+    };
     spinner.success(&crate::format_host_message(
         host_name.as_deref(),
-        "Service started and ready",
+        started_message,
     ));
-
-    // Show result and optionally open browser
-    show_start_result(
-        &container_id,
-        port,
-        bind_addr,
-        config.is_network_exposed(),
-        quiet,
+    record_event(
+        LifecycleEventKind::Started,
         host_name.as_deref(),
+        Some(format!("port {port}")),
     );
-    open_browser_if_requested(args.open, port, bind_addr);
-
-    Ok(())
-}
-
-/// Handle rebuild flags: remove existing container so a new one is created from the new image
-async fn handle_rebuild(
-    client: &DockerClient,
-    host_name: Option<&str>,
-    quiet: bool,
-    verbose: u8,
-) -> Result<()> {
-    if !quiet {
-        eprintln!();
-        display_container_recreate_warning();
-        eprintln!();
-    }
-
-    let exists =
-        opencode_cloud_core::docker::container::container_exists(client, CONTAINER_NAME).await?;
 
-    if !exists {
-        return Ok(());
+    // Show result and optionally open browser
+    if args.quiet_json {
+        let summary = StartSummary::new(&container_id, port, bind_addr, host_name.as_deref());
+        write_start_json(&summary, json_out)?;
+    } else {
+        show_start_result(
+            &container_id,
+            port,
+            bind_addr,
+            config.is_network_exposed(),
+            quiet,
+            host_name.as_deref(),
+        );
     }
-
-    if verbose > 0 {
-        eprintln!(
-            "{} Removing existing container for rebuild...",
-            style("[info]").cyan()
+    if readiness == Readiness::Skipped && !args.attach && !quiet {
+        println!(
+            "{}",
+            style("Readiness was not verified (--detach-wait). Check with: occ status").dim()
         );
     }
+    open_browser_if_requested(args.open, args.open_with.as_deref(), port, bind_addr, quiet);
+    timer.print_summary();
 
-    let _ = stop_service_with_spinner(
+    if args.no_daemon {
+        if !quiet {
+            println!(
+                "{}",
+                style(
+                    "Running in foreground. Send SIGTERM or press Ctrl+C to stop, SIGHUP to reload config."
+                )
+                .dim()
+            );
+        }
+        let host = host_name.as_deref();
+        let mut running_config = config.clone();
+        let shutdown = async {
+            // Created once so a SIGTERM that arrives during a reload is not missed
+            let shutdown_signal = crate::shutdown::shutdown_signal();
+            tokio::pin!(shutdown_signal);
+            let mut reload_signal = crate::shutdown::ReloadSignal::new();
+            loop {
+                tokio::select! {
+                    () = &mut shutdown_signal => break,
+                    () = reload_signal.recv() => {
+                        reload_on_signal(&client, args, &mut running_config, quiet).await;
+                    }
+                }
+            }
+        };
+        run_foreground_until_signal(shutdown, || stop_after_signal(&client, host, quiet)).await?;
+    }
+
+    if args.attach {
+        if !quiet {
+            println!(
+                "{}",
+                style("Attached to container logs. Press Ctrl+C to stop.").dim()
+            );
+        }
+        let host = host_name.as_deref();
+        let outcome = run_attached(
+            follow_container_logs(&client, started_at),
+            crate::shutdown::shutdown_signal(),
+            || stop_after_signal(&client, host, quiet),
+        )
+        .await?;
+        if let AttachOutcome::Exited(code) = outcome {
+            if !quiet {
+                eprintln!(
+                    "{}",
+                    style(format!("Container exited with code {code}")).dim()
+                );
+            }
+            if code != 0 {
+                std::process::exit(i32::try_from(code).unwrap_or(1));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Stop the service after a shutdown signal (`--no-daemon` and `--attach`)
+async fn stop_after_signal(client: &DockerClient, host: Option<&str>, quiet: bool) -> Result<()> {
+    stop_service_with_spinner(
+        client,
+        host,
+        quiet,
+        false,
+        FOREGROUND_STOP_TIMEOUT_SECS,
+        StopSpinnerMessages {
+            action_message: "Stopping service...",
+            update_label: "Stopping service",
+            success_base_message: "Service stopped",
+            failure_message: "Failed to stop",
+        },
+    )
+    .await?;
+    record_event(
+        LifecycleEventKind::Stopped,
+        host,
+        Some("shutdown signal".to_string()),
+    );
+    Ok(())
+}
+
+/// Re-read the config on SIGHUP and apply what can change live
+///
+/// The same `occ start` overrides are layered on, so a reload never undoes
+/// `--port`/`--memory` flags. Failures are reported and the service keeps
+/// running with its current settings.
+async fn reload_on_signal(
+    client: &DockerClient,
+    args: &StartArgs,
+    running_config: &mut opencode_cloud_core::Config,
+    quiet: bool,
+) {
+    let result = async {
+        let new = resolve_start_config(
+            &opencode_cloud_core::config::load_config()?,
+            &start_overrides(args),
+        )?;
+        super::reload::reload_config(client, running_config, new, quiet).await
+    }
+    .await;
+    if let Err(e) = result {
+        eprintln!("{} Config reload failed: {e}", style("[reload]").yellow());
+    }
+}
+
+/// How an `--attach` session ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AttachOutcome {
+    /// The container exited on its own with this exit code
+    Exited(i64),
+    /// A shutdown signal arrived and the container was stopped
+    Interrupted,
+}
+
+/// Follow logs until the container exits, or stop it on `signal`
+///
+/// `logs` resolves to the container's exit code once the log stream ends.
+async fn run_attached<F, Fut>(
+    logs: impl std::future::Future<Output = Result<i64>>,
+    signal: impl std::future::Future<Output = ()>,
+    stop: F,
+) -> Result<AttachOutcome>
+where
+    F: FnOnce() -> Fut,
+    Fut: std::future::Future<Output = Result<()>>,
+{
+    tokio::select! {
+        code = logs => Ok(AttachOutcome::Exited(code?)),
+        () = signal => {
+            stop().await?;
+            Ok(AttachOutcome::Interrupted)
+        }
+    }
+}
+
+/// Print container logs since `since` (unix seconds) until the stream ends
+///
+/// Returns the container's exit code once it has stopped.
+async fn follow_container_logs(client: &DockerClient, since: i64) -> Result<i64> {
+    let options = LogsOptions::<String> {
+        follow: true,
+        stdout: true,
+        stderr: true,
+        since,
+        ..Default::default()
+    };
+
+    let mut stream = client.inner().logs(CONTAINER_NAME, Some(options));
+    while let Some(output) = stream.next().await {
+        match output? {
+            LogOutput::StdOut { message } | LogOutput::Console { message } => {
+                print!("{}", String::from_utf8_lossy(&message));
+            }
+            LogOutput::StdErr { message } => {
+                eprint!("{}", String::from_utf8_lossy(&message));
+            }
+            LogOutput::StdIn { .. } => {}
+        }
+    }
+
+    let info = client
+        .inner()
+        .inspect_container(CONTAINER_NAME, None)
+        .await?;
+    Ok(info.state.and_then(|s| s.exit_code).unwrap_or(0))
+}
+
+/// Graceful stop timeout used when a foreground `--no-daemon` or `--attach` run is signalled
+const FOREGROUND_STOP_TIMEOUT_SECS: i64 = 30;
+
+/// Wait for `signal`, then run `stop` (the `--no-daemon` shutdown path)
+///
+/// The container is stopped but not removed, so the next start reuses it.
+async fn run_foreground_until_signal<F, Fut>(
+    signal: impl std::future::Future<Output = ()>,
+    stop: F,
+) -> Result<()>
+where
+    F: FnOnce() -> Fut,
+    Fut: std::future::Future<Output = Result<()>>,
+{
+    signal.await;
+    stop().await
+}
+
+/// Outcome of the post-start readiness phase
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Readiness {
+    /// The service passed the readiness checks
+    Ready,
+    /// Readiness checks were skipped (--detach-wait)
+    Skipped,
+}
+
+/// Run the readiness wait unless `detach_wait` is set
+///
+/// The wait (including fatal-error log scanning) is passed in so callers
+/// and tests can substitute it.
+async fn await_readiness<F, Fut>(detach_wait: bool, wait: F) -> Result<Readiness>
+where
+    F: FnOnce() -> Fut,
+    Fut: std::future::Future<Output = Result<()>>,
+{
+    if detach_wait {
+        return Ok(Readiness::Skipped);
+    }
+    wait().await.map(|()| Readiness::Ready)
+}
+
+/// Handle rebuild flags: remove existing container so a new one is created from the new image
+async fn handle_rebuild(
+    client: &DockerClient,
+    host_name: Option<&str>,
+    quiet: bool,
+    verbose: u8,
+) -> Result<()> {
+    if !quiet {
+        eprintln!();
+        display_container_recreate_warning();
+        eprintln!();
+    }
+
+    let exists =
+        opencode_cloud_core::docker::container::container_exists(client, CONTAINER_NAME).await?;
+
+    if !exists {
+        return Ok(());
+    }
+
+    if verbose > 0 {
+        eprintln!(
+            "{} Removing existing container for rebuild...",
+            style("[info]").cyan()
+        );
+    }
+
+    let _ = stop_service_with_spinner(
         client,
         host_name,
         quiet,
@@ -927,11 +1746,40 @@ fn show_already_running(
 fn port_in_use_error(port: u16) -> anyhow::Error {
     let mut msg = format!("Port {port} is already in use");
     if let Some(p) = find_next_available_port(port) {
-        msg.push_str(&format!(". Try: occ start --port {p}"));
+        msg.push_str(&format!(
+            ". Try: occ start --port {p} (or --auto-port to pick one automatically)"
+        ));
     }
     anyhow!(msg)
 }
 
+/// Pick the port to start on
+///
+/// Returns `requested` when it is free. Otherwise, with `auto_port`, the
+/// next free port after it; without, the port-in-use error.
+fn select_port(requested: u16, auto_port: bool, is_available: impl Fn(u16) -> bool) -> Result<u16> {
+    if is_available(requested) {
+        return Ok(requested);
+    }
+    if !auto_port {
+        return Err(port_in_use_error(requested));
+    }
+    next_available_port(requested, is_available).ok_or_else(|| {
+        anyhow!("Port {requested} is already in use and no free port was found after it")
+    })
+}
+
+/// The on-disk config with `opencode_web_port` set to the port picked by `--auto-port`
+fn with_saved_port(
+    saved_config: &opencode_cloud_core::Config,
+    port: u16,
+) -> opencode_cloud_core::Config {
+    opencode_cloud_core::Config {
+        opencode_web_port: port,
+        ..saved_config.clone()
+    }
+}
+
 /// Build the Docker image with progress reporting
 ///
 /// If `no_cache` is true, builds from scratch ignoring Docker layer cache.
@@ -980,7 +1828,11 @@ async fn build_docker_image(client: &DockerClient, no_cache: bool, verbose: u8)
 
 /// Pull the Docker image with progress reporting
 /// Returns the registry name on success (for provenance tracking)
-async fn pull_docker_image(client: &DockerClient, verbose: u8) -> Result<String> {
+async fn pull_docker_image(
+    client: &DockerClient,
+    auth: &RegistryAuth,
+    verbose: u8,
+) -> Result<String> {
     if verbose > 0 {
         eprintln!(
             "{} Pulling prebuilt Docker image from registry...",
@@ -989,7 +1841,7 @@ async fn pull_docker_image(client: &DockerClient, verbose: u8) -> Result<String>
     }
 
     let mut progress = ProgressReporter::with_context("Pulling prebuilt image");
-    let full_image = pull_image(client, Some(IMAGE_TAG_DEFAULT), &mut progress).await?;
+    let full_image = pull_image(client, Some(IMAGE_TAG_DEFAULT), auth, &mut progress).await?;
 
     // Extract registry from full image name
     let registry = if full_image.starts_with("ghcr.io") {
@@ -1063,22 +1915,34 @@ fn prompt_image_source_choice(
 }
 
 /// Start the container, returning the container ID or error
+#[allow(clippy::too_many_arguments)]
 async fn start_container(
     client: &DockerClient,
+    env_vars: Vec<String>,
     port: u16,
     bind_address: &str,
     cockpit_port: u16,
     cockpit_enabled: bool,
     bind_mounts: Option<Vec<ParsedMount>>,
+    labels: Vec<(String, String)>,
+    health_check: Option<HealthCheckSettings>,
+    auto_restart: bool,
+    resource_limits: ResourceLimits,
+    force_recreate: bool,
 ) -> Result<String, DockerError> {
     setup_and_start(
         client,
         Some(port),
-        None,
+        Some(env_vars),
         Some(bind_address),
         Some(cockpit_port),
         Some(cockpit_enabled),
         bind_mounts,
+        Some(labels),
+        health_check,
+        Some(auto_restart),
+        resource_limits,
+        force_recreate,
     )
     .await
 }
@@ -1096,6 +1960,33 @@ async fn show_logs_if_container_exists(client: &DockerClient) {
     show_recent_logs(client, 20).await;
 }
 
+/// Result of a successful start, as printed by `--quiet-json`
+#[derive(Debug, Serialize)]
+struct StartSummary {
+    url: String,
+    container_id: String,
+    port: u16,
+}
+
+impl StartSummary {
+    fn new(container_id: &str, port: u16, bind_addr: &str, host_name: Option<&str>) -> Self {
+        let addr = resolve_remote_addr(host_name).unwrap_or_else(|| bind_addr.to_string());
+        Self {
+            url: format!("http://{addr}:{port}"),
+            container_id: container_id.to_string(),
+            port,
+        }
+    }
+}
+
+/// Write the `--quiet-json` line for a successful start
+///
+/// Only called once the service is up; failed starts write nothing.
+fn write_start_json(summary: &StartSummary, out: &mut impl Write) -> Result<()> {
+    writeln!(out, "{}", serde_json::to_string(summary)?)?;
+    Ok(())
+}
+
 /// Display the start result
 fn show_start_result(
     container_id: &str,
@@ -1161,15 +2052,36 @@ fn show_start_result(
 }
 
 /// Open browser if requested
-fn open_browser_if_requested(should_open: bool, port: u16, bind_addr: &str) {
-    if !should_open {
-        return;
-    }
-
+fn open_browser_if_requested(
+    should_open: bool,
+    open_with: Option<&str>,
+    port: u16,
+    bind_addr: &str,
+    quiet: bool,
+) {
     // For network-exposed addresses like 0.0.0.0, use localhost for browser
     let browser_addr = normalize_bind_addr(bind_addr);
     let url = format!("http://{browser_addr}:{port}");
-    if let Err(e) = webbrowser::open(&url) {
+
+    let result = match browser_open_plan(should_open, open_with, is_headless()) {
+        BrowserOpen::Skip => return,
+        BrowserOpen::Headless => {
+            if !quiet {
+                println!(
+                    "{}",
+                    style(format!(
+                        "No display detected; open {url} from another machine"
+                    ))
+                    .dim()
+                );
+            }
+            return;
+        }
+        BrowserOpen::Default => webbrowser::open(&url).map_err(|e| e.to_string()),
+        BrowserOpen::Command(cmd) => spawn_browser_command(&cmd, &url),
+    };
+
+    if let Err(e) = result {
         eprintln!(
             "{} Failed to open browser: {}",
             style("Warning:").yellow(),
@@ -1178,6 +2090,66 @@ fn open_browser_if_requested(should_open: bool, port: u16, bind_addr: &str) {
     }
 }
 
+/// How to open the browser after starting
+#[derive(Debug, PartialEq, Eq)]
+enum BrowserOpen {
+    /// Neither --open nor --open-with was given
+    Skip,
+    /// Use the system default browser
+    Default,
+    /// --open without a display; print a note instead of failing
+    Headless,
+    /// Launch the --open-with command
+    Command(String),
+}
+
+fn browser_open_plan(should_open: bool, open_with: Option<&str>, headless: bool) -> BrowserOpen {
+    match open_with {
+        Some(cmd) => BrowserOpen::Command(cmd.to_string()),
+        None if !should_open => BrowserOpen::Skip,
+        None if headless => BrowserOpen::Headless,
+        None => BrowserOpen::Default,
+    }
+}
+
+/// Build the program and arguments for `--open-with`, appending the URL
+///
+/// The command is split on whitespace so `"firefox --new-window"` works.
+fn browser_command(cmd: &str, url: &str) -> Option<(String, Vec<String>)> {
+    let mut parts = cmd.split_whitespace().map(str::to_string);
+    let program = parts.next()?;
+    let mut args: Vec<String> = parts.collect();
+    args.push(url.to_string());
+    Some((program, args))
+}
+
+fn spawn_browser_command(cmd: &str, url: &str) -> Result<(), String> {
+    let (program, args) =
+        browser_command(cmd, url).ok_or_else(|| "--open-with command is empty".to_string())?;
+    std::process::Command::new(&program)
+        .args(&args)
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("{program}: {e}"))
+}
+
+/// Whether this machine has no graphical display to open a browser on
+fn is_headless() -> bool {
+    is_headless_env(
+        std::env::consts::OS,
+        std::env::var_os("DISPLAY").is_some_and(|v| !v.is_empty()),
+        std::env::var_os("WAYLAND_DISPLAY").is_some_and(|v| !v.is_empty()),
+    )
+}
+
+/// Only Linux is treated as headless; macOS and Windows always have a desktop
+fn is_headless_env(os: &str, has_display: bool, has_wayland_display: bool) -> bool {
+    os == "linux" && !has_display && !has_wayland_display
+}
+
 /// Check if a port is available for binding
 fn check_port_available(port: u16) -> bool {
     TcpListener::bind(("127.0.0.1", port)).is_ok()
@@ -1185,12 +2157,21 @@ fn check_port_available(port: u16) -> bool {
 
 /// Find the next available port starting from the given port
 fn find_next_available_port(start: u16) -> Option<u16> {
-    (start..start.saturating_add(100)).find(|&p| check_port_available(p))
+    next_available_port(start, check_port_available)
 }
 
-/// Configuration for health check waiting
+/// First port in the 100 ports from `start` that `is_available` accepts
+fn next_available_port(start: u16, is_available: impl Fn(u16) -> bool) -> Option<u16> {
+    (start..start.saturating_add(100)).find(|&p| is_available(p))
+}
+
+/// Readiness timeout when the existing image is reused
 /// Note: 60 seconds allows time for systemd to boot and start all services
-const HEALTH_CHECK_TIMEOUT_SECS: u64 = 60;
+const STARTUP_TIMEOUT_REUSE_SECS: u64 = 60;
+/// Readiness timeout after pulling or building the image, whose first boot is slower
+const STARTUP_TIMEOUT_ACQUIRED_SECS: u64 = 180;
+
+/// Configuration for health check waiting
 const HEALTH_CHECK_INTERVAL_MS: u64 = 500;
 const HEALTH_CHECK_CONSECUTIVE_REQUIRED: u32 = 3;
 
@@ -1241,14 +2222,18 @@ async fn check_for_fatal_errors(client: &DockerClient) -> Option<String> {
 /// Returns Ok(()) when the service is ready, or Err if timeout is reached or fatal error detected.
 /// Requires multiple consecutive successful connections to avoid false positives.
 /// Also monitors container logs for fatal errors to fail fast.
-async fn wait_for_service_ready(
+///
+/// With a [`UrlProbe`] (`--wait-for`), the URL must also respond before the
+/// same startup timeout expires.
+pub(crate) async fn wait_for_service_ready(
     client: &DockerClient,
     port: u16,
+    url_probe: Option<&UrlProbe>,
     spinner: &CommandSpinner,
     _host_name: Option<&str>,
+    timeout: Duration,
 ) -> Result<()> {
     let start = Instant::now();
-    let timeout = Duration::from_secs(HEALTH_CHECK_TIMEOUT_SECS);
     let interval = Duration::from_millis(HEALTH_CHECK_INTERVAL_MS);
     let log_check_interval = Duration::from_secs(1);
 
@@ -1260,7 +2245,8 @@ async fn wait_for_service_ready(
     loop {
         if start.elapsed() > timeout {
             return Err(anyhow!(
-                "Service did not become ready within {HEALTH_CHECK_TIMEOUT_SECS} seconds. Check logs with: occ logs"
+                "Service did not become ready within {} seconds. Check logs with: occ logs",
+                timeout.as_secs()
             ));
         }
 
@@ -1281,7 +2267,21 @@ async fn wait_for_service_ready(
         if connected {
             consecutive_success += 1;
             if consecutive_success >= HEALTH_CHECK_CONSECUTIVE_REQUIRED {
-                return Ok(());
+                let Some(probe) = url_probe else {
+                    return Ok(());
+                };
+                let on_attempt = |status: Option<u16>| {
+                    let last =
+                        status.map_or_else(|| "no response".to_string(), |s| format!("HTTP {s}"));
+                    spinner.update(&format!(
+                        "Waiting for {} ({last}, {}s)",
+                        probe.url,
+                        start.elapsed().as_secs()
+                    ));
+                };
+                let fetch = || probe_http_status(&probe.url, Duration::from_secs(2));
+                return wait_for_url_ready(probe, start + timeout, interval, on_attempt, fetch)
+                    .await;
             }
             spinner.update(&format!(
                 "Service responding ({consecutive_success}/{HEALTH_CHECK_CONSECUTIVE_REQUIRED})"
@@ -1298,8 +2298,73 @@ async fn wait_for_service_ready(
     }
 }
 
+/// Custom readiness URL from `--wait-for`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct UrlProbe {
+    url: String,
+    /// Exact status required; None accepts any 2xx
+    expected_status: Option<u16>,
+}
+
+impl UrlProbe {
+    /// Validate the URL scheme (http or https) and build the probe
+    fn new(url: &str, expected_status: Option<u16>) -> Result<Self> {
+        let url = url.trim();
+        if !(url.starts_with("http://") || url.starts_with("https://")) {
+            return Err(anyhow!(
+                "Invalid --wait-for URL: {url}\nOnly http:// and https:// URLs are supported"
+            ));
+        }
+        Ok(Self {
+            url: url.to_string(),
+            expected_status,
+        })
+    }
+
+    /// Whether a response status counts as ready
+    fn is_ready(&self, status: u16) -> bool {
+        match self.expected_status {
+            Some(expected) => status == expected,
+            None => (200..300).contains(&status),
+        }
+    }
+}
+
+/// Poll a readiness URL until it returns the expected status or `deadline` passes
+///
+/// `fetch` performs one request and returns its status; `on_attempt` is
+/// called with the status of each unsuccessful attempt (None when the
+/// request failed outright).
+async fn wait_for_url_ready<F, Fut, E>(
+    probe: &UrlProbe,
+    deadline: Instant,
+    interval: Duration,
+    mut on_attempt: impl FnMut(Option<u16>),
+    mut fetch: F,
+) -> Result<()>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = std::result::Result<u16, E>>,
+{
+    loop {
+        let status = fetch().await.ok();
+        if status.is_some_and(|s| probe.is_ready(s)) {
+            return Ok(());
+        }
+        if Instant::now() >= deadline {
+            let last = status.map_or_else(|| "no response".to_string(), |s| format!("HTTP {s}"));
+            return Err(anyhow!(
+                "{} did not become ready within the startup timeout (last: {last}). Check logs with: occ logs",
+                probe.url
+            ));
+        }
+        on_attempt(status);
+        tokio::time::sleep(interval).await;
+    }
+}
+
 /// Show recent container logs for debugging
-async fn show_recent_logs(client: &DockerClient, lines: usize) {
+pub(crate) async fn show_recent_logs(client: &DockerClient, lines: usize) {
     let options = LogsOptions::<String> {
         stdout: true,
         stderr: true,
@@ -1330,9 +2395,404 @@ async fn show_recent_logs(client: &DockerClient, lines: usize) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use opencode_cloud_core::docker::ContainerBindMount;
+    use opencode_cloud_core::docker::{ContainerBindMount, MountSource};
     use std::path::PathBuf;
 
+    fn state(running: bool, health: Option<&str>) -> ContainerState {
+        ContainerState {
+            status: if running { "running" } else { "exited" }.to_string(),
+            running,
+            exit_code: None,
+            started_at: None,
+            finished_at: None,
+            health: health.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn label_mismatch_lists_only_missing_labels() {
+        let pair = |k: &str, v: &str| (k.to_string(), v.to_string());
+        let existing = vec![pair("env", "staging"), pair("team", "infra")];
+        let labels = merge_container_labels(&existing, &[pair("env", "prod")]);
+        assert_eq!(
+            missing_labels(&existing, &labels),
+            vec![&pair("env", "prod")]
+        );
+
+        let unchanged = merge_container_labels(&existing, &[]);
+        assert!(missing_labels(&existing, &unchanged).is_empty());
+    }
+
+    #[test]
+    fn startup_timeout_default_depends_on_image_acquisition() {
+        assert_eq!(startup_timeout(None, None, false), Duration::from_secs(60));
+        assert_eq!(startup_timeout(None, None, true), Duration::from_secs(180));
+    }
+
+    #[test]
+    fn startup_timeout_flag_rejects_zero() {
+        assert_eq!(parse_startup_timeout("90"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_startup_timeout("3m"), Ok(Duration::from_secs(180)));
+        assert!(parse_startup_timeout("0").is_err());
+        assert!(parse_startup_timeout("0s").is_err());
+    }
+
+    #[test]
+    fn startup_timeout_config_and_flag_override_default() {
+        assert_eq!(
+            startup_timeout(None, Some(300), false),
+            Duration::from_secs(300)
+        );
+        assert_eq!(
+            startup_timeout(Some(Duration::from_secs(45)), Some(300), true),
+            Duration::from_secs(45)
+        );
+    }
+
+    #[test]
+    fn unhealthy_running_container_is_recreated() {
+        assert!(is_running_unhealthy(&state(true, Some("unhealthy"))));
+    }
+
+    #[test]
+    fn healthy_or_stopped_container_is_left_alone() {
+        assert!(!is_running_unhealthy(&state(true, Some("healthy"))));
+        assert!(!is_running_unhealthy(&state(true, Some("starting"))));
+        assert!(!is_running_unhealthy(&state(true, None)));
+        assert!(!is_running_unhealthy(&state(false, Some("unhealthy"))));
+    }
+
+    fn overrides(port: Option<u16>, bind_address: Option<&str>) -> ConfigOverrides {
+        ConfigOverrides {
+            opencode_web_port: port,
+            bind_address: bind_address.map(str::to_string),
+            ..ConfigOverrides::default()
+        }
+    }
+
+    #[test]
+    fn quiet_json_success_is_one_json_line() {
+        let summary = StartSummary::new("abc123def456", 3000, "127.0.0.1", None);
+        let mut out = Vec::new();
+        write_start_json(&summary, &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert_eq!(text.lines().count(), 1);
+        assert!(text.ends_with('\n'));
+
+        let value: serde_json::Value = serde_json::from_str(text.trim()).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "url": "http://127.0.0.1:3000",
+                "container_id": "abc123def456",
+                "port": 3000,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn quiet_json_failure_prints_nothing() {
+        #[derive(clap::Parser)]
+        struct Cli {
+            #[command(flatten)]
+            args: StartArgs,
+        }
+        let cli = <Cli as clap::Parser>::try_parse_from([
+            "start",
+            "--quiet-json",
+            "--wait-for",
+            "ftp://localhost/health",
+        ])
+        .unwrap();
+
+        let mut out = Vec::new();
+        let err = start_with_output(&cli.args, None, false, 0, &mut out)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Invalid --wait-for URL"), "{err}");
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn free_port_is_used_as_is() {
+        assert_eq!(select_port(3000, false, |_| true).unwrap(), 3000);
+        assert_eq!(select_port(3000, true, |_| true).unwrap(), 3000);
+    }
+
+    #[test]
+    fn busy_port_fails_without_auto_port() {
+        let err = select_port(3000, false, |p| p != 3000).unwrap_err();
+        assert!(err.to_string().contains("Port 3000 is already in use"));
+    }
+
+    #[test]
+    fn auto_port_falls_back_to_next_free_port() {
+        let busy = [3000, 3001, 3002];
+        assert_eq!(
+            select_port(3000, true, |p| !busy.contains(&p)).unwrap(),
+            3003
+        );
+        assert!(select_port(3000, true, |_| false).is_err());
+    }
+
+    #[test]
+    fn save_port_writes_chosen_port_only() {
+        let saved = opencode_cloud_core::Config {
+            bind_address: "0.0.0.0".to_string(),
+            ..opencode_cloud_core::Config::default()
+        };
+        let updated = with_saved_port(&saved, 3003);
+        assert_eq!(updated.opencode_web_port, 3003);
+        assert_eq!(updated.bind_address, "0.0.0.0");
+        assert_eq!(
+            opencode_cloud_core::config::config_changes(&saved, &updated)
+                .iter()
+                .map(|change| change.key.as_str())
+                .collect::<Vec<_>>(),
+            vec!["opencode_web_port"]
+        );
+    }
+
+    #[test]
+    fn env_override_parsing() {
+        assert_eq!(parse_env_override("FOO=bar").unwrap(), "FOO=bar");
+        assert_eq!(parse_env_override("FOO=a=b").unwrap(), "FOO=a=b");
+        assert_eq!(parse_env_override("EMPTY=").unwrap(), "EMPTY=");
+        assert!(parse_env_override("FOO").is_err());
+        assert!(parse_env_override("=bar").is_err());
+        assert!(parse_env_override("1FOO=bar").is_err());
+        let err = parse_env_override("MY-VAR=x").unwrap_err();
+        assert!(err.starts_with("env key 'MY-VAR'"), "{err}");
+    }
+
+    #[test]
+    fn env_overrides_win_over_config() {
+        let config = opencode_cloud_core::Config {
+            container_env: vec!["GREETING=config".to_string(), "KEEP=1".to_string()],
+            ..opencode_cloud_core::Config::default()
+        };
+        let base = container_env(&config);
+        assert!(base.contains(&"GREETING=config".to_string()));
+
+        let overrides = vec![
+            "GREETING=cli".to_string(),
+            "DEBUG=1".to_string(),
+            "DEBUG=2".to_string(),
+        ];
+        let merged = merge_env_overrides(base.clone(), &overrides);
+        assert!(merged.contains(&"GREETING=cli".to_string()));
+        assert!(!merged.contains(&"GREETING=config".to_string()));
+        assert!(merged.contains(&"KEEP=1".to_string()));
+        assert!(merged.contains(&"DEBUG=2".to_string()));
+        assert!(!merged.contains(&"DEBUG=1".to_string()));
+        assert_eq!(merge_env_overrides(base.clone(), &[]), base);
+    }
+
+    #[test]
+    fn bind_address_override_is_validated() {
+        let config = opencode_cloud_core::Config::default();
+        assert!(resolve_start_config(&config, &overrides(None, Some("not-an-ip"))).is_err());
+
+        let config = resolve_start_config(&config, &overrides(None, Some("::1"))).unwrap();
+        assert_eq!(config.bind_address, "::1");
+
+        let config = resolve_start_config(&config, &overrides(None, None)).unwrap();
+        assert_eq!(config.bind_address, "::1");
+    }
+
+    #[test]
+    fn port_override_wins_over_config() {
+        let config = opencode_cloud_core::Config {
+            opencode_web_port: 4000,
+            ..Default::default()
+        };
+        assert_eq!(
+            resolve_start_config(&config, &overrides(Some(5000), None))
+                .unwrap()
+                .opencode_web_port,
+            5000
+        );
+        assert_eq!(
+            resolve_start_config(&config, &overrides(None, None))
+                .unwrap()
+                .opencode_web_port,
+            4000
+        );
+    }
+
+    #[test]
+    fn bind_address_override_triggers_exposure_warning() {
+        let config = opencode_cloud_core::Config::default();
+        assert!(!should_warn_network_exposure(&config));
+
+        let mut config = resolve_start_config(&config, &overrides(None, Some("0.0.0.0"))).unwrap();
+        assert!(should_warn_network_exposure(&config));
+
+        config.users.push("admin".to_string());
+        assert!(!should_warn_network_exposure(&config));
+    }
+
+    #[test]
+    fn resource_flags_win_over_config() {
+        let config = opencode_cloud_core::Config {
+            memory_limit_mb: Some(1024),
+            cpu_limit: Some(2.0),
+            ..Default::default()
+        };
+        let flags = ConfigOverrides {
+            memory_limit_mb: Some(4096),
+            cpu_limit: Some(0.5),
+            ..ConfigOverrides::default()
+        };
+
+        let limits = ResourceLimits::from_config(&resolve_start_config(&config, &flags).unwrap());
+        assert_eq!(limits.memory_mb, Some(4096));
+        assert_eq!(limits.cpus, Some(0.5));
+
+        let limits = ResourceLimits::from_config(
+            &resolve_start_config(&config, &ConfigOverrides::default()).unwrap(),
+        );
+        assert_eq!(limits.memory_mb, Some(1024));
+        assert_eq!(limits.cpus, Some(2.0));
+    }
+
+    #[test]
+    fn resource_limits_are_formatted() {
+        assert_eq!(
+            format_resource_limits(&ResourceLimits::default()),
+            "unlimited memory, unlimited CPUs"
+        );
+        let limits = ResourceLimits {
+            memory_mb: Some(512),
+            cpus: Some(1.5),
+        };
+        assert_eq!(format_resource_limits(&limits), "512 MB memory, 1.5 CPUs");
+    }
+
+    #[test]
+    fn security_bypass_only_on_localhost() {
+        let local = opencode_cloud_core::Config::default();
+        assert_eq!(
+            first_start_security_gate(&local, true),
+            SecurityGate::Bypassed
+        );
+        assert_eq!(
+            first_start_security_gate(&local, false),
+            SecurityGate::Blocked {
+                bypass_refused: false
+            }
+        );
+
+        let exposed = opencode_cloud_core::Config {
+            bind_address: "0.0.0.0".to_string(),
+            ..opencode_cloud_core::Config::default()
+        };
+        assert_eq!(
+            first_start_security_gate(&exposed, true),
+            SecurityGate::Blocked {
+                bypass_refused: true
+            }
+        );
+
+        let secured = opencode_cloud_core::Config {
+            users: vec!["admin".to_string()],
+            ..exposed
+        };
+        assert_eq!(
+            first_start_security_gate(&secured, false),
+            SecurityGate::Pass
+        );
+    }
+
+    #[test]
+    fn bind_addresses_match_normalizes() {
+        assert!(bind_addresses_match(Some("127.0.0.1"), "localhost"));
+        assert!(bind_addresses_match(None, "0.0.0.0"));
+        assert!(!bind_addresses_match(Some("127.0.0.1"), "0.0.0.0"));
+    }
+
+    #[test]
+    fn url_probe_accepts_http_and_https_only() {
+        assert!(UrlProbe::new("http://localhost:8080/ready", None).is_ok());
+        assert!(UrlProbe::new("https://example.com/health", None).is_ok());
+        assert!(UrlProbe::new("localhost:8080", None).is_err());
+        assert!(UrlProbe::new("ftp://example.com", None).is_err());
+    }
+
+    #[test]
+    fn url_probe_status_matching() {
+        let any_2xx = UrlProbe::new("http://localhost/", None).unwrap();
+        assert!(any_2xx.is_ready(200));
+        assert!(any_2xx.is_ready(204));
+        assert!(!any_2xx.is_ready(302));
+        assert!(!any_2xx.is_ready(503));
+
+        let exact = UrlProbe::new("http://localhost/", Some(401)).unwrap();
+        assert!(exact.is_ready(401));
+        assert!(!exact.is_ready(200));
+    }
+
+    #[tokio::test]
+    async fn url_probe_succeeds_after_failures() {
+        let probe = UrlProbe::new("http://localhost/ready", None).unwrap();
+        let responses = std::cell::RefCell::new(vec![Ok(200), Ok(503), Err(())]);
+        let mut attempts = Vec::new();
+
+        let result = wait_for_url_ready(
+            &probe,
+            Instant::now() + Duration::from_secs(5),
+            Duration::from_millis(1),
+            |status| attempts.push(status),
+            || {
+                let next = responses.borrow_mut().pop().unwrap();
+                async move { next }
+            },
+        )
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(attempts, vec![None, Some(503)]);
+    }
+
+    #[tokio::test]
+    async fn url_probe_times_out() {
+        let probe = UrlProbe::new("http://localhost/ready", Some(204)).unwrap();
+        let result = wait_for_url_ready(
+            &probe,
+            Instant::now() + Duration::from_millis(10),
+            Duration::from_millis(1),
+            |_| {},
+            || async { Ok::<u16, ()>(200) },
+        )
+        .await;
+
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("did not become ready"));
+        assert!(err.contains("HTTP 200"));
+    }
+
+    #[tokio::test]
+    async fn detach_wait_bypasses_readiness_wait() {
+        let called = std::cell::Cell::new(false);
+        let result = await_readiness(true, || async {
+            called.set(true);
+            Err(anyhow!("should not run"))
+        })
+        .await
+        .unwrap();
+        assert_eq!(result, Readiness::Skipped);
+        assert!(!called.get());
+    }
+
+    #[tokio::test]
+    async fn readiness_wait_runs_by_default() {
+        let ready = await_readiness(false, || async { Ok(()) }).await.unwrap();
+        assert_eq!(ready, Readiness::Ready);
+
+        let failed = await_readiness(false, || async { Err(anyhow!("timeout")) }).await;
+        assert!(failed.is_err());
+    }
+
     #[test]
     fn port_check_returns_false_for_privileged_ports() {
         // Port 1 is privileged and typically unavailable
@@ -1401,7 +2861,7 @@ mod tests {
 
     fn make_parsed_mount(host: &str, container: &str, read_only: bool) -> ParsedMount {
         ParsedMount {
-            host_path: PathBuf::from(host),
+            source: MountSource::Bind(PathBuf::from(host)),
             container_path: container.to_string(),
             read_only,
         }
@@ -1516,4 +2976,117 @@ mod tests {
         let configured = vec![make_parsed_mount("/var", "/mnt/var", false)];
         assert!(!mounts_equal(&current, &configured));
     }
+
+    #[test]
+    fn browser_command_appends_url() {
+        let (program, args) =
+            browser_command("firefox --new-window", "http://127.0.0.1:3000").unwrap();
+        assert_eq!(program, "firefox");
+        assert_eq!(args, vec!["--new-window", "http://127.0.0.1:3000"]);
+    }
+
+    #[test]
+    fn browser_command_rejects_empty() {
+        assert_eq!(browser_command("   ", "http://127.0.0.1:3000"), None);
+    }
+
+    #[test]
+    fn headless_only_on_linux_without_display() {
+        assert!(is_headless_env("linux", false, false));
+        assert!(!is_headless_env("linux", true, false));
+        assert!(!is_headless_env("linux", false, true));
+        assert!(!is_headless_env("macos", false, false));
+    }
+
+    #[test]
+    fn browser_open_plan_branches() {
+        assert_eq!(browser_open_plan(false, None, false), BrowserOpen::Skip);
+        assert_eq!(browser_open_plan(true, None, false), BrowserOpen::Default);
+        assert_eq!(browser_open_plan(true, None, true), BrowserOpen::Headless);
+        assert_eq!(
+            browser_open_plan(false, Some("chromium"), true),
+            BrowserOpen::Command("chromium".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn foreground_stops_after_signal() {
+        let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+        let stopped = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let flag = stopped.clone();
+
+        let run = tokio::spawn(run_foreground_until_signal(
+            async {
+                rx.await.ok();
+            },
+            move || async move {
+                flag.store(true, std::sync::atomic::Ordering::SeqCst);
+                Ok(())
+            },
+        ));
+
+        tokio::task::yield_now().await;
+        assert!(!stopped.load(std::sync::atomic::Ordering::SeqCst));
+
+        tx.send(()).unwrap();
+        run.await.unwrap().unwrap();
+        assert!(stopped.load(std::sync::atomic::Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn foreground_propagates_stop_error() {
+        let result =
+            run_foreground_until_signal(async {}, || async { Err(anyhow!("stop failed")) }).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn attach_returns_exit_code_without_stopping() {
+        let stopped = std::sync::atomic::AtomicBool::new(false);
+        let outcome = run_attached(async { Ok(3) }, std::future::pending(), || async {
+            stopped.store(true, std::sync::atomic::Ordering::SeqCst);
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(outcome, AttachOutcome::Exited(3));
+        assert!(!stopped.load(std::sync::atomic::Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn attach_stops_container_on_signal() {
+        let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+        let stopped = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let flag = stopped.clone();
+
+        let run = tokio::spawn(run_attached(
+            std::future::pending(),
+            async {
+                rx.await.ok();
+            },
+            move || async move {
+                flag.store(true, std::sync::atomic::Ordering::SeqCst);
+                Ok(())
+            },
+        ));
+
+        tokio::task::yield_now().await;
+        assert!(!stopped.load(std::sync::atomic::Ordering::SeqCst));
+
+        tx.send(()).unwrap();
+        assert_eq!(run.await.unwrap().unwrap(), AttachOutcome::Interrupted);
+        assert!(stopped.load(std::sync::atomic::Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn attach_propagates_log_stream_error() {
+        let result = run_attached(
+            async { Err(anyhow!("stream failed")) },
+            std::future::pending(),
+            || async { Ok(()) },
+        )
+        .await;
+        assert!(result.is_err());
+    }
 }
@@ -4,24 +4,172 @@
 //! port bindings, uptime, health status, and security configuration.
 
 use crate::output::{
-    format_cockpit_url, format_docker_error_anyhow, resolve_remote_addr, state_style,
+    format_bytes, format_cockpit_url, format_docker_error_anyhow, resolve_remote_addr, state_style,
 };
 use anyhow::{Result, anyhow};
 use clap::Args;
 use console::style;
 use opencode_cloud_core::Config;
-use opencode_cloud_core::bollard::service::MountTypeEnum;
+use opencode_cloud_core::bollard::errors::Error as BollardError;
+use opencode_cloud_core::bollard::service::{ContainerInspectResponse, MountTypeEnum};
 use opencode_cloud_core::config;
 use opencode_cloud_core::docker::{
-    CONTAINER_NAME, HealthError, OPENCODE_WEB_PORT, ParsedMount, check_health, get_cli_version,
+    CONTAINER_NAME, ContainerState, DockerClient, DockerError, HealthError, OPENCODE_WEB_PORT,
+    ParsedMount, check_health, exec_command, get_cli_version, get_container_stats,
     get_image_version, load_state,
 };
-use opencode_cloud_core::platform::{get_service_manager, is_service_registration_supported};
+use opencode_cloud_core::events::{
+    LifecycleEvent, LifecycleEventKind, MAX_EVENTS, recent_events, record_event,
+};
+use opencode_cloud_core::platform::{
+    ServiceStatus, get_service_manager, is_service_registration_supported,
+};
 use std::time::Duration;
 
+/// Clock difference between host and container beyond which status warns
+const MAX_CLOCK_SKEW_SECS: i64 = 30;
+
 /// Arguments for the status command
 #[derive(Args)]
-pub struct StatusArgs {}
+pub struct StatusArgs {
+    /// Show live CPU and memory usage (takes about a second)
+    #[arg(long, alias = "container-stats")]
+    pub stats: bool,
+
+    /// Show the last N starts, stops, crashes and updates (default 10)
+    #[arg(long, value_name = "N", num_args = 0..=1, default_missing_value = "10")]
+    pub history: Option<usize>,
+
+    /// Exit 0 only if running and healthy (2 if running but unhealthy, 1 if not running)
+    ///
+    /// Prints nothing unless --verbose is given; intended for monitors.
+    #[arg(long, conflicts_with_all = ["stats", "history"])]
+    pub check: bool,
+
+    /// Print only the service URL (exit 1 with no output if not running)
+    #[arg(long, conflicts_with_all = ["stats", "history", "check", "port_only"])]
+    pub url_only: bool,
+
+    /// Print only the host port (exit 1 with no output if not running)
+    #[arg(long, conflicts_with_all = ["stats", "history", "check"])]
+    pub port_only: bool,
+}
+
+/// Result of `occ status --check`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CheckOutcome {
+    Healthy,
+    Unhealthy,
+    NotRunning,
+}
+
+impl CheckOutcome {
+    fn new(running: bool, healthy: bool) -> Self {
+        match (running, healthy) {
+            (false, _) => Self::NotRunning,
+            (true, true) => Self::Healthy,
+            (true, false) => Self::Unhealthy,
+        }
+    }
+
+    /// Process exit code for this outcome
+    fn exit_code(self) -> i32 {
+        match self {
+            Self::Healthy => 0,
+            Self::NotRunning => 1,
+            Self::Unhealthy => 2,
+        }
+    }
+
+    fn describe(self) -> &'static str {
+        match self {
+            Self::Healthy => "running and healthy",
+            Self::Unhealthy => "running but unhealthy",
+            Self::NotRunning => "not running",
+        }
+    }
+}
+
+/// Host port the service container publishes for the web UI
+///
+/// Falls back to the default port when the binding is missing.
+fn published_web_port(info: &ContainerInspectResponse) -> u16 {
+    info.network_settings
+        .as_ref()
+        .and_then(|ns| ns.ports.as_ref())
+        .and_then(|ports| ports.get("3000/tcp"))
+        .and_then(|bindings| bindings.as_ref())
+        .and_then(|bindings| bindings.first())
+        .and_then(|binding| binding.host_port.as_ref())
+        .and_then(|p| p.parse::<u16>().ok())
+        .unwrap_or(OPENCODE_WEB_PORT)
+}
+
+/// URL of the web UI: the remote host address when known, otherwise localhost,
+/// followed by the reverse-proxy base path if one is configured
+fn service_url(remote_addr: Option<&str>, port: u16, base_path: Option<&str>) -> String {
+    let path = base_path.map_or("", |path| path.trim_end_matches('/'));
+    format!("http://{}:{port}{path}", remote_addr.unwrap_or("127.0.0.1"))
+}
+
+/// Inspect attempts before a transient error is reported
+const INSPECT_ATTEMPTS: u32 = 3;
+
+/// Pause between inspect attempts
+const INSPECT_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// Whether an inspect error may clear up on retry
+///
+/// Client errors (404 for a missing container and other 4xx) are definitive;
+/// connection failures and server errors are often a daemon restarting.
+fn is_transient_inspect_error(err: &BollardError) -> bool {
+    !matches!(
+        err,
+        BollardError::DockerResponseServerError { status_code, .. } if *status_code < 500
+    )
+}
+
+/// Run `inspect`, retrying transient errors up to `attempts` times in total
+async fn inspect_with_retry<T>(
+    inspect: impl AsyncFn() -> Result<T, BollardError>,
+    attempts: u32,
+    delay: Duration,
+) -> Result<T, BollardError> {
+    let mut attempt = 1;
+    loop {
+        match inspect().await {
+            Err(e) if attempt < attempts && is_transient_inspect_error(&e) => {
+                tracing::debug!("Container inspect failed (attempt {attempt}): {e}");
+                attempt += 1;
+                tokio::time::sleep(delay).await;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Describe a failed inspect, separating an unreachable daemon from a failed query
+fn inspect_error(err: BollardError, host_name: Option<&str>) -> anyhow::Error {
+    let err = DockerError::from(err);
+    if err.is_daemon_unreachable() {
+        let msg = crate::format_host_message(host_name, "Docker daemon unreachable");
+        anyhow!("{msg}: {err}")
+    } else {
+        anyhow!("Failed to query container status: {err}")
+    }
+}
+
+/// Print the `--check` outcome when verbose, then exit with its code
+fn exit_with_check(outcome: CheckOutcome, verbose: u8) -> ! {
+    if verbose > 0 {
+        eprintln!(
+            "{} Service is {}",
+            style("[check]").cyan(),
+            outcome.describe()
+        );
+    }
+    std::process::exit(outcome.exit_code());
+}
 
 /// Show the status of the opencode service
 ///
@@ -30,20 +178,25 @@ pub struct StatusArgs {}
 /// - URL (if running)
 /// - Container name and ID
 /// - Image name
-/// - Uptime (if running)
+/// - Uptime (if running), with a warning if the container clock has drifted
 /// - Port binding
 /// - Health status (if available)
+/// - CPU and memory usage (with --stats)
+/// - Recent lifecycle events (with --history)
 /// - Config file path
 ///
 /// In quiet mode:
 /// - Exits 0 if running
 /// - Exits 1 if stopped
 /// - No output
+///
+/// With `--check`, exits 0 only when running and healthy, 2 when running
+/// but unhealthy and 1 when not running.
 pub async fn cmd_status(
-    _args: &StatusArgs,
+    args: &StatusArgs,
     maybe_host: Option<&str>,
     quiet: bool,
-    _verbose: u8,
+    verbose: u8,
 ) -> Result<()> {
     // Resolve Docker client (local or remote)
     let (client, host_name) = crate::resolve_docker_client(maybe_host).await?;
@@ -55,7 +208,8 @@ pub async fn cmd_status(
         .map_err(|e| format_docker_error_anyhow(&e))?;
 
     // Show host header if remote
-    if !quiet && host_name.is_some() {
+    let scripted = args.check || args.url_only || args.port_only;
+    if !quiet && !scripted && host_name.is_some() {
         println!(
             "{}",
             crate::format_host_message(host_name.as_deref(), "Status")
@@ -63,8 +217,13 @@ pub async fn cmd_status(
         println!();
     }
 
-    // Check if container exists
-    let inspect_result = client.inner().inspect_container(CONTAINER_NAME, None).await;
+    // Check if container exists, riding out brief daemon hiccups
+    let inspect_result = inspect_with_retry(
+        async || client.inner().inspect_container(CONTAINER_NAME, None).await,
+        INSPECT_ATTEMPTS,
+        INSPECT_RETRY_DELAY,
+    )
+    .await;
 
     let info = match inspect_result {
         Ok(info) => info,
@@ -72,32 +231,45 @@ pub async fn cmd_status(
             status_code: 404,
             ..
         }) => {
-            if quiet {
+            if args.check {
+                exit_with_check(CheckOutcome::NotRunning, verbose);
+            }
+            if quiet || args.url_only || args.port_only {
                 std::process::exit(1);
             }
             println!("{}", style("No service found.").yellow());
             println!();
             println!("Run '{}' to start the service.", style("occ start").cyan());
+            if let Some(limit) = args.history {
+                display_history_section(limit);
+            }
             return Ok(());
         }
-        Err(e) => {
-            return Err(anyhow!("Failed to inspect container: {e}"));
-        }
+        Err(e) => return Err(inspect_error(e, host_name.as_deref())),
     };
 
     // Extract state information
-    let state = info.state.as_ref();
-    let status = state
-        .and_then(|s| s.status.as_ref())
-        .map(|s| s.to_string())
-        .unwrap_or_else(|| "unknown".to_string());
-    let running = state.and_then(|s| s.running).unwrap_or(false);
-    let started_at = state.and_then(|s| s.started_at.clone());
-    let finished_at = state.and_then(|s| s.finished_at.clone());
-    let health = state
-        .and_then(|s| s.health.as_ref())
-        .and_then(|h| h.status.as_ref())
-        .map(|s| s.to_string());
+    let ContainerState {
+        status,
+        running,
+        exit_code,
+        started_at,
+        finished_at,
+        health,
+    } = ContainerState::from_inspect(&info);
+
+    // An exited container we last saw started means it died on its own
+    let last_event = recent_events(MAX_EVENTS)
+        .into_iter()
+        .rev()
+        .find(|e| e.host.as_deref() == host_name.as_deref());
+    if is_crash(running, exit_code, last_event.as_ref()) {
+        record_event(
+            LifecycleEventKind::CrashDetected,
+            host_name.as_deref(),
+            exit_code.map(|code| format!("exit code {code}")),
+        );
+    }
 
     // Extract container info
     let container_id = info.id.as_deref().unwrap_or("unknown");
@@ -109,16 +281,7 @@ pub async fn cmd_status(
         .unwrap_or_else(|| "unknown".to_string());
 
     // Extract port binding
-    let host_port = info
-        .network_settings
-        .as_ref()
-        .and_then(|ns| ns.ports.as_ref())
-        .and_then(|ports| ports.get("3000/tcp"))
-        .and_then(|bindings| bindings.as_ref())
-        .and_then(|bindings| bindings.first())
-        .and_then(|binding| binding.host_port.as_ref())
-        .and_then(|p| p.parse::<u16>().ok())
-        .unwrap_or(OPENCODE_WEB_PORT);
+    let host_port = published_web_port(&info);
 
     // Extract bind mounts from container
     let container_mounts = info
@@ -127,6 +290,38 @@ pub async fn cmd_status(
         .and_then(|hc| hc.mounts.clone())
         .unwrap_or_default();
 
+    // Check mode: local hosts are probed directly; remote hosts rely on
+    // Docker's own healthcheck status
+    if args.check {
+        let healthy = if !running {
+            false
+        } else if host_name.is_none() {
+            check_health(host_port).await.is_ok()
+        } else {
+            health.as_deref() == Some("healthy")
+        };
+        exit_with_check(CheckOutcome::new(running, healthy), verbose);
+    }
+
+    let base_path = config::load_config().ok().and_then(|cfg| cfg.base_path);
+
+    // Scriptable single-value output
+    if args.url_only || args.port_only {
+        if !running {
+            std::process::exit(1);
+        }
+        if args.port_only {
+            println!("{host_port}");
+        } else {
+            let remote_addr = resolve_remote_addr(host_name.as_deref());
+            println!(
+                "{}",
+                service_url(remote_addr.as_deref(), host_port, base_path.as_deref())
+            );
+        }
+        return Ok(());
+    }
+
     // Quiet mode: just exit with appropriate code
     if quiet {
         if running {
@@ -150,16 +345,16 @@ pub async fn cmd_status(
     if running {
         // For remote hosts, show both container-local and remote-accessible URLs
         if let Some(ref remote_addr) = maybe_remote_addr {
-            let remote_url = format!("http://{remote_addr}:{host_port}");
+            let remote_url = service_url(Some(remote_addr), host_port, base_path.as_deref());
             println!("Remote URL:  {}", style(&remote_url).cyan());
-            let local_url = format!("http://127.0.0.1:{host_port}");
+            let local_url = service_url(None, host_port, base_path.as_deref());
             println!(
                 "Local URL:   {} {}",
                 style(&local_url).dim(),
                 style("(on remote host)").dim()
             );
         } else {
-            let url = format!("http://127.0.0.1:{host_port}");
+            let url = service_url(None, host_port, base_path.as_deref());
             println!("URL:         {}", style(&url).cyan());
         }
 
@@ -229,6 +424,10 @@ pub async fn cmd_status(
     // Load config early for reuse in multiple sections
     let config = config::load_config().ok();
 
+    if let Some(motd) = config.as_ref().and_then(|cfg| cfg.motd.as_deref()) {
+        println!("MOTD:        {}", style(motd).yellow());
+    }
+
     if running {
         // Calculate and display uptime
         if let Some(ref started) = started_at {
@@ -238,6 +437,13 @@ pub async fn cmd_status(
             }
         }
 
+        if let Some(warning) = container_clock_skew(&client)
+            .await
+            .and_then(clock_skew_warning)
+        {
+            println!("{} {warning}", style("Warning:").yellow().bold());
+        }
+
         println!(
             "Port:        {} -> container:3000",
             style(host_port.to_string()).cyan()
@@ -311,6 +517,13 @@ pub async fn cmd_status(
                 style("no").yellow().to_string()
             };
             println!("Installed:   {install_status}");
+
+            // Surface auto-start that silently failed
+            if installed {
+                if let Ok(service_status) = manager.status() {
+                    println!("Service:     {}", style_service_status(service_status));
+                }
+            }
         }
     }
 
@@ -323,11 +536,20 @@ pub async fn cmd_status(
         display_mounts_section(&container_mounts, &config_mounts);
     }
 
+    // Show Resources section when requested
+    if running && args.stats {
+        display_resources_section(&client).await;
+    }
+
     // Show Security section (container exists, whether running or stopped)
     if let Some(ref cfg) = config {
         display_security_section(cfg);
     }
 
+    if let Some(limit) = args.history {
+        display_history_section(limit);
+    }
+
     // If stopped, show when it stopped
     if !running {
         if let Some(ref finished) = finished_at {
@@ -347,21 +569,8 @@ pub async fn cmd_status(
 ///
 /// Returns (duration since start, human-readable start time) or None if parsing fails
 fn parse_uptime(started_at: &str) -> Option<(Duration, String)> {
-    // Docker timestamps are in format: "2024-01-15T10:30:00.123456789Z"
-    // We need to handle this format and calculate uptime
-
-    // Parse the timestamp - handle both with and without fractional seconds
-    let timestamp = if started_at.contains('.') {
-        // Has fractional seconds
-        chrono::DateTime::parse_from_rfc3339(started_at).ok()?
-    } else {
-        // No fractional seconds - add .0 for parsing
-        let fixed = started_at.replace('Z', ".0Z");
-        chrono::DateTime::parse_from_rfc3339(&fixed).ok()?
-    };
-
     let now = chrono::Utc::now();
-    let started = timestamp.with_timezone(&chrono::Utc);
+    let started = parse_docker_timestamp(started_at)?;
 
     if now < started {
         return None;
@@ -375,14 +584,23 @@ fn parse_uptime(started_at: &str) -> Option<(Duration, String)> {
 
 /// Parse timestamp for display (without calculating duration)
 fn parse_timestamp_display(timestamp: &str) -> Option<String> {
+    let ts = parse_docker_timestamp(timestamp)?;
+    Some(ts.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+}
+
+/// Parse a Docker state timestamp such as `started_at`
+///
+/// Docker timestamps look like "2024-01-15T10:30:00.123456789Z"; ones
+/// without fractional seconds are accepted too.
+pub(crate) fn parse_docker_timestamp(timestamp: &str) -> Option<chrono::DateTime<chrono::Utc>> {
     let ts = if timestamp.contains('.') {
         chrono::DateTime::parse_from_rfc3339(timestamp).ok()?
     } else {
+        // No fractional seconds - add .0 for parsing
         let fixed = timestamp.replace('Z', ".0Z");
         chrono::DateTime::parse_from_rfc3339(&fixed).ok()?
     };
-
-    Some(ts.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+    Some(ts.with_timezone(&chrono::Utc))
 }
 
 /// Format a duration in a human-readable way
@@ -457,8 +675,10 @@ fn display_mounts_section(
         // Needs path matching to handle macOS translation (/tmp -> /host_mnt/private/tmp)
         // Must match both source AND target paths to be considered from config
         let is_from_config = config_parsed.iter().any(|conf| {
-            let conf_host = conf.host_path.to_string_lossy();
-            host_paths_match(source, &conf_host) && target == conf.container_path
+            conf.host_path().is_some_and(|conf_host| {
+                host_paths_match(source, &conf_host.to_string_lossy())
+                    && target == conf.container_path
+            })
         });
         let source_tag = if is_from_config {
             style("(config)").dim()
@@ -476,6 +696,17 @@ fn display_mounts_section(
     }
 }
 
+/// Colorize the init system's view of the service
+fn style_service_status(status: ServiceStatus) -> console::StyledObject<&'static str> {
+    let label = status.label();
+    match status {
+        ServiceStatus::Active => style(label).green(),
+        ServiceStatus::Failed => style(label).red(),
+        ServiceStatus::Inactive => style(label).yellow(),
+        ServiceStatus::Unknown => style(label).dim(),
+    }
+}
+
 /// Check if two host paths match, accounting for macOS path translation
 ///
 /// Docker on macOS translates paths: /tmp -> /private/tmp -> /host_mnt/private/tmp
@@ -508,6 +739,95 @@ fn host_paths_match(container_path: &str, configured_path: &str) -> bool {
     false
 }
 
+/// Seconds the container clock is ahead of the host (negative when behind)
+///
+/// Best-effort: `None` when the exec fails or its output is not a timestamp.
+async fn container_clock_skew(client: &DockerClient) -> Option<i64> {
+    let output = exec_command(client, CONTAINER_NAME, vec!["date", "-u", "+%s"])
+        .await
+        .ok()?;
+    clock_skew_secs(chrono::Utc::now().timestamp(), &output)
+}
+
+/// Skew between the host epoch and `date -u +%s` output from the container
+fn clock_skew_secs(host_epoch: i64, container_output: &str) -> Option<i64> {
+    let container_epoch: i64 = container_output.trim().parse().ok()?;
+    Some(container_epoch - host_epoch)
+}
+
+/// Warning for a skew beyond [`MAX_CLOCK_SKEW_SECS`]
+fn clock_skew_warning(skew_secs: i64) -> Option<String> {
+    if skew_secs.abs() <= MAX_CLOCK_SKEW_SECS {
+        return None;
+    }
+    let direction = if skew_secs > 0 { "ahead of" } else { "behind" };
+    Some(format!(
+        "Container clock is {} {direction} the host; this can break logins. \
+         Run 'occ restart' to resync it.",
+        format_duration(Duration::from_secs(skew_secs.unsigned_abs()))
+    ))
+}
+
+/// Display the Resources section of status output
+async fn display_resources_section(client: &DockerClient) {
+    println!();
+    println!("{}", style("Resources").bold());
+    println!("{}", style("---------").dim());
+
+    match get_container_stats(client, CONTAINER_NAME).await {
+        Ok(usage) => {
+            println!("CPU:         {:.1}%", usage.cpu_percent);
+            println!(
+                "Memory:      {} / {} ({:.1}%)",
+                format_bytes(usage.memory_used_bytes),
+                format_bytes(usage.memory_limit_bytes),
+                usage.memory_percent()
+            );
+        }
+        Err(e) => println!("{}", style(format!("Unavailable: {e}")).dim()),
+    }
+}
+
+/// Whether a stopped container should be recorded as crashed
+///
+/// Only a non-zero exit after our own last recorded start counts, so
+/// repeated `occ status` calls record a crash once.
+fn is_crash(running: bool, exit_code: Option<i64>, last: Option<&LifecycleEvent>) -> bool {
+    !running
+        && exit_code.is_some_and(|code| code != 0)
+        && last.is_some_and(|e| e.event == LifecycleEventKind::Started)
+}
+
+/// Display the History section of status output
+fn display_history_section(limit: usize) {
+    println!();
+    println!("{}", style("History").bold());
+    println!("{}", style("-------").dim());
+
+    let events = recent_events(limit);
+    if events.is_empty() {
+        println!("{}", style("No lifecycle events recorded yet").dim());
+        return;
+    }
+    for event in &events {
+        println!("{}", format_event_line(event));
+    }
+}
+
+/// Render one history line: timestamp, event, then optional detail and host
+fn format_event_line(event: &LifecycleEvent) -> String {
+    let timestamp =
+        parse_timestamp_display(&event.timestamp).unwrap_or_else(|| event.timestamp.clone());
+    let mut line = format!("{timestamp}  {:<14}", event.event.label());
+    if let Some(ref detail) = event.detail {
+        line.push_str(&format!("  {detail}"));
+    }
+    if let Some(ref host) = event.host {
+        line.push_str(&format!("  [{host}]"));
+    }
+    line.trim_end().to_string()
+}
+
 /// Display the Security section of status output
 fn display_security_section(config: &Config) {
     println!();
@@ -564,12 +884,172 @@ fn display_security_section(config: &Config) {
 mod tests {
     use super::*;
 
+    fn inspect_with_port(host_port: Option<&str>) -> ContainerInspectResponse {
+        let bindings = host_port.map(|port| {
+            vec![opencode_cloud_core::bollard::service::PortBinding {
+                host_ip: Some("127.0.0.1".to_string()),
+                host_port: Some(port.to_string()),
+            }]
+        });
+        ContainerInspectResponse {
+            network_settings: Some(opencode_cloud_core::bollard::service::NetworkSettings {
+                ports: Some(std::collections::HashMap::from([(
+                    "3000/tcp".to_string(),
+                    bindings,
+                )])),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn published_port_and_url_from_inspect() {
+        let info = inspect_with_port(Some("4100"));
+        assert_eq!(published_web_port(&info), 4100);
+        assert_eq!(service_url(None, 4100, None), "http://127.0.0.1:4100");
+        assert_eq!(
+            service_url(Some("10.0.0.5"), 4100, None),
+            "http://10.0.0.5:4100"
+        );
+    }
+
+    #[test]
+    fn url_includes_base_path() {
+        assert_eq!(
+            service_url(None, 3000, Some("/opencode")),
+            "http://127.0.0.1:3000/opencode"
+        );
+        assert_eq!(
+            service_url(Some("10.0.0.5"), 3000, Some("/opencode/")),
+            "http://10.0.0.5:3000/opencode"
+        );
+        assert_eq!(service_url(None, 3000, Some("/")), "http://127.0.0.1:3000");
+    }
+
+    #[test]
+    fn published_port_falls_back_to_default() {
+        assert_eq!(
+            published_web_port(&inspect_with_port(None)),
+            OPENCODE_WEB_PORT
+        );
+        assert_eq!(
+            published_web_port(&ContainerInspectResponse::default()),
+            OPENCODE_WEB_PORT
+        );
+    }
+
+    #[test]
+    fn check_outcome_exit_codes() {
+        assert_eq!(CheckOutcome::new(true, true).exit_code(), 0);
+        assert_eq!(CheckOutcome::new(true, false).exit_code(), 2);
+        assert_eq!(CheckOutcome::new(false, false).exit_code(), 1);
+        assert_eq!(CheckOutcome::new(false, true), CheckOutcome::NotRunning);
+    }
+
+    fn server_error(status_code: u16) -> BollardError {
+        BollardError::DockerResponseServerError {
+            status_code,
+            message: "boom".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn inspect_retries_transient_errors_then_succeeds() {
+        let calls = std::cell::Cell::new(0);
+        let result = inspect_with_retry(
+            async || {
+                calls.set(calls.get() + 1);
+                if calls.get() < 3 {
+                    Err(server_error(500))
+                } else {
+                    Ok("info")
+                }
+            },
+            3,
+            Duration::ZERO,
+        )
+        .await;
+        assert_eq!(result.unwrap(), "info");
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn inspect_does_not_retry_missing_container() {
+        let calls = std::cell::Cell::new(0);
+        let result: Result<(), _> = inspect_with_retry(
+            async || {
+                calls.set(calls.get() + 1);
+                Err(server_error(404))
+            },
+            3,
+            Duration::ZERO,
+        )
+        .await;
+        assert!(matches!(
+            result,
+            Err(BollardError::DockerResponseServerError {
+                status_code: 404,
+                ..
+            })
+        ));
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[tokio::test]
+    async fn inspect_gives_up_after_attempts() {
+        let calls = std::cell::Cell::new(0);
+        let result: Result<(), _> = inspect_with_retry(
+            async || {
+                calls.set(calls.get() + 1);
+                Err(server_error(503))
+            },
+            3,
+            Duration::ZERO,
+        )
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn inspect_errors_are_classified() {
+        let refused = BollardError::IOError {
+            err: std::io::Error::new(std::io::ErrorKind::ConnectionRefused, "connection refused"),
+        };
+        let msg = inspect_error(refused, None).to_string();
+        assert!(msg.starts_with("Docker daemon unreachable"), "{msg}");
+
+        let msg = inspect_error(server_error(500), None).to_string();
+        assert!(msg.starts_with("Failed to query container status"), "{msg}");
+    }
+
     #[test]
     fn format_duration_seconds() {
         assert_eq!(format_duration(Duration::from_secs(30)), "30s");
         assert_eq!(format_duration(Duration::from_secs(59)), "59s");
     }
 
+    #[test]
+    fn clock_skew_from_container_output() {
+        assert_eq!(clock_skew_secs(1_700_000_000, "1700000045\n"), Some(45));
+        assert_eq!(clock_skew_secs(1_700_000_000, "1699999990"), Some(-10));
+        assert_eq!(clock_skew_secs(1_700_000_000, "date: not found"), None);
+    }
+
+    #[test]
+    fn clock_skew_warns_beyond_threshold() {
+        assert_eq!(clock_skew_warning(0), None);
+        assert_eq!(clock_skew_warning(MAX_CLOCK_SKEW_SECS), None);
+        assert_eq!(clock_skew_warning(-MAX_CLOCK_SKEW_SECS), None);
+
+        let ahead = clock_skew_warning(90).unwrap();
+        assert!(ahead.contains("1m 30s ahead of the host"), "{ahead}");
+        let behind = clock_skew_warning(-3600).unwrap();
+        assert!(behind.contains("1h behind the host"), "{behind}");
+        assert!(behind.contains("occ restart"));
+    }
+
     #[test]
     fn format_duration_minutes() {
         assert_eq!(format_duration(Duration::from_secs(60)), "1m");
@@ -619,4 +1099,49 @@ mod tests {
         assert!(display.contains("2024-01-15"));
         assert!(display.contains("10:30:00"));
     }
+
+    fn lifecycle_event(kind: LifecycleEventKind, detail: Option<&str>) -> LifecycleEvent {
+        LifecycleEvent {
+            timestamp: "2026-01-15T10:30:00+00:00".to_string(),
+            event: kind,
+            host: None,
+            detail: detail.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn format_event_lines() {
+        let started = lifecycle_event(LifecycleEventKind::Started, Some("port 3000"));
+        assert_eq!(
+            format_event_line(&started),
+            "2026-01-15 10:30:00 UTC  started         port 3000"
+        );
+
+        let stopped = lifecycle_event(LifecycleEventKind::Stopped, None);
+        assert_eq!(
+            format_event_line(&stopped),
+            "2026-01-15 10:30:00 UTC  stopped"
+        );
+
+        let crash = LifecycleEvent {
+            host: Some("prod".to_string()),
+            ..lifecycle_event(LifecycleEventKind::CrashDetected, Some("exit code 137"))
+        };
+        assert_eq!(
+            format_event_line(&crash),
+            "2026-01-15 10:30:00 UTC  crash detected  exit code 137  [prod]"
+        );
+    }
+
+    #[test]
+    fn crash_only_after_recorded_start() {
+        let started = lifecycle_event(LifecycleEventKind::Started, None);
+        let crashed = lifecycle_event(LifecycleEventKind::CrashDetected, None);
+
+        assert!(is_crash(false, Some(137), Some(&started)));
+        assert!(!is_crash(false, Some(137), Some(&crashed)));
+        assert!(!is_crash(false, Some(0), Some(&started)));
+        assert!(!is_crash(true, Some(137), Some(&started)));
+        assert!(!is_crash(false, Some(1), None));
+    }
 }
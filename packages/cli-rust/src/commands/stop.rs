@@ -1,7 +1,9 @@
 //! Stop command implementation
 //!
 //! Stops the opencode service with a graceful timeout.
-//! Docker sends SIGTERM first, then SIGKILL if timeout expires.
+//! Docker sends SIGTERM first, then SIGKILL if timeout expires. With
+//! `--signal`, that signal is sent first and the container gets the timeout
+//! to exit on its own before the regular stop.
 
 use crate::commands::service::{StopSpinnerMessages, stop_service_with_spinner};
 use crate::output::format_docker_error;
@@ -9,8 +11,11 @@ use anyhow::{Result, anyhow};
 use clap::Args;
 use console::style;
 use opencode_cloud_core::docker::{
-    CONTAINER_NAME, DEFAULT_STOP_TIMEOUT_SECS, container_is_running,
+    CONTAINER_NAME, DEFAULT_STOP_TIMEOUT_SECS, DockerClient, container_is_running,
+    list_managed_containers, parse_stop_signal, signal_container, stop_container,
 };
+use opencode_cloud_core::events::{LifecycleEventKind, record_event};
+use std::future::Future;
 
 /// Arguments for the stop command
 #[derive(Args, Default)]
@@ -18,6 +23,14 @@ pub struct StopArgs {
     /// Graceful shutdown timeout in seconds (default: 30)
     #[arg(long, short, default_value_t = DEFAULT_STOP_TIMEOUT_SECS)]
     pub timeout: i64,
+
+    /// Stop every opencode-cloud container, not just the default one
+    #[arg(long)]
+    pub all: bool,
+
+    /// Send this signal first (e.g. INT, SIGQUIT, 3), then stop if still running
+    #[arg(long, value_name = "SIG", value_parser = parse_stop_signal)]
+    pub signal: Option<String>,
 }
 
 /// Stop the opencode service
@@ -36,6 +49,17 @@ pub async fn cmd_stop(args: &StopArgs, maybe_host: Option<&str>, quiet: bool) ->
         anyhow!("{msg}")
     })?;
 
+    if args.all {
+        return stop_all(
+            &client,
+            args.timeout,
+            args.signal.as_deref(),
+            host_name.as_deref(),
+            quiet,
+        )
+        .await;
+    }
+
     // Check if already stopped (idempotent behavior)
     if !container_is_running(&client, CONTAINER_NAME).await? {
         if !quiet {
@@ -46,6 +70,19 @@ pub async fn cmd_stop(args: &StopArgs, maybe_host: Option<&str>, quiet: bool) ->
         return Ok(());
     }
 
+    if let Some(signal) = &args.signal {
+        if !quiet {
+            let msg = crate::format_host_message(
+                host_name.as_deref(),
+                &format!("Sending {signal} ({}s to exit)...", args.timeout),
+            );
+            println!("{}", style(msg).dim());
+        }
+        signal_container(&client, CONTAINER_NAME, signal, args.timeout)
+            .await
+            .map_err(|e| anyhow!("{}", format_docker_error(&e)))?;
+    }
+
     stop_service_with_spinner(
         &client,
         host_name.as_deref(),
@@ -60,6 +97,120 @@ pub async fn cmd_stop(args: &StopArgs, maybe_host: Option<&str>, quiet: bool) ->
         },
     )
     .await?;
+    record_event(LifecycleEventKind::Stopped, host_name.as_deref(), None);
 
     Ok(())
 }
+
+/// Stop every running opencode-managed container, reporting each result
+///
+/// Failures are reported and skipped; the command fails at the end if any
+/// container could not be stopped.
+async fn stop_all(
+    client: &DockerClient,
+    timeout: i64,
+    signal: Option<&str>,
+    host_name: Option<&str>,
+    quiet: bool,
+) -> Result<()> {
+    let names: Vec<String> = list_managed_containers(client)
+        .await?
+        .into_iter()
+        .filter(|c| c.running)
+        .map(|c| c.name)
+        .collect();
+
+    if names.is_empty() {
+        if !quiet {
+            let msg = crate::format_host_message(host_name, "No opencode containers are running");
+            println!("{}", style(msg).dim());
+        }
+        return Ok(());
+    }
+
+    let results = stop_each(&names, |name| async move {
+        if let Some(signal) = signal {
+            let exited = signal_container(client, &name, signal, timeout)
+                .await
+                .map_err(|e| anyhow!("{}", format_docker_error(&e)))?;
+            if exited {
+                return Ok(());
+            }
+        }
+        stop_container(client, &name, Some(timeout))
+            .await
+            .map_err(|e| anyhow!("{}", format_docker_error(&e)))
+    })
+    .await;
+
+    for (name, result) in &results {
+        match result {
+            Ok(()) => {
+                record_event(LifecycleEventKind::Stopped, host_name, Some(name.clone()));
+                if !quiet {
+                    println!("{} {name} stopped", style("✓").green());
+                }
+            }
+            Err(e) => eprintln!("{} {name}: {e}", style("✗").red()),
+        }
+    }
+
+    let failed = results.iter().filter(|(_, r)| r.is_err()).count();
+    if failed > 0 {
+        return Err(anyhow!(
+            "Failed to stop {failed} of {} containers",
+            results.len()
+        ));
+    }
+    Ok(())
+}
+
+/// Run `stop` for each name in order, continuing past failures
+async fn stop_each<F, Fut>(names: &[String], stop: F) -> Vec<(String, Result<()>)>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    let mut results = Vec::with_capacity(names.len());
+    for name in names {
+        let result = stop(name.clone()).await;
+        results.push((name.clone(), result));
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn stop_each_continues_past_failures() {
+        let names = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let attempted = std::sync::Mutex::new(Vec::new());
+
+        let results = stop_each(&names, |name| {
+            attempted.lock().unwrap().push(name.clone());
+            async move {
+                if name == "b" {
+                    Err(anyhow!("daemon said no"))
+                } else {
+                    Ok(())
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(*attempted.lock().unwrap(), names);
+        assert_eq!(results.len(), 3);
+        assert!(results[0].1.is_ok());
+        assert_eq!(results[1].0, "b");
+        assert!(results[1].1.is_err());
+        assert!(results[2].1.is_ok());
+    }
+
+    #[tokio::test]
+    async fn stop_each_empty() {
+        let results = stop_each(&[], |_| async { Ok(()) }).await;
+        assert!(results.is_empty());
+    }
+}
@@ -1,7 +1,8 @@
 //! Uninstall command implementation
 //!
 //! Removes the opencode-cloud service registration from the platform's
-//! service manager (systemd on Linux, launchd on macOS).
+//! service manager (systemd on Linux, launchd on macOS). The container is
+//! left running unless `--stop` (or `--volumes`) is given.
 
 use crate::output::CommandSpinner;
 use anyhow::{Result, anyhow};
@@ -18,7 +19,15 @@ use opencode_cloud_core::platform::{get_service_manager, is_service_registration
 /// Arguments for the uninstall command
 #[derive(Args)]
 pub struct UninstallArgs {
-    /// Also remove Docker volumes (data deletion - requires --force)
+    /// Keep the container running after removing the service (default)
+    #[arg(long, conflicts_with_all = ["stop", "volumes"])]
+    keep_container: bool,
+
+    /// Also stop the container
+    #[arg(long)]
+    stop: bool,
+
+    /// Also remove Docker volumes (data deletion - requires --force, implies --stop)
     #[arg(long)]
     volumes: bool,
 
@@ -30,7 +39,7 @@ pub struct UninstallArgs {
 /// Remove the service registration from the platform's service manager
 ///
 /// This command:
-/// 1. Stops the container if running
+/// 1. Stops the container if running (only with --stop or --volumes)
 /// 2. Removes the service registration (systemd unit or launchd plist)
 /// 3. Optionally removes Docker volumes (with --volumes --force)
 ///
@@ -79,11 +88,14 @@ pub async fn cmd_uninstall(args: &UninstallArgs, quiet: bool, _verbose: u8) -> R
         }
     }
 
-    // 6. Stop container if running (using existing stop logic)
-    let spinner = CommandSpinner::new_maybe("Stopping service...", quiet);
-    // Try to stop - ignore errors if not running
-    let _ = stop_container_if_running().await;
-    spinner.success("Service stopped");
+    // 6. Stop container if requested (using existing stop logic)
+    let container_action = container_action(args);
+    if container_action == ContainerAction::Stop {
+        let spinner = CommandSpinner::new_maybe("Stopping service...", quiet);
+        // Try to stop - ignore errors if not running
+        let _ = stop_container_if_running().await;
+        spinner.success("Service stopped");
+    }
 
     // 7. Uninstall service registration
     let spinner = CommandSpinner::new_maybe("Removing service registration...", quiet);
@@ -107,6 +119,12 @@ pub async fn cmd_uninstall(args: &UninstallArgs, quiet: bool, _verbose: u8) -> R
         }
         println!();
         println!("Service will no longer start automatically.");
+        if container_action == ContainerAction::Keep {
+            println!(
+                "The container was left running. Stop it with: {}",
+                style("occ stop").cyan()
+            );
+        }
 
         // 10. Show remaining files for manual cleanup
         let config_dir = get_config_dir()
@@ -128,6 +146,27 @@ pub async fn cmd_uninstall(args: &UninstallArgs, quiet: bool, _verbose: u8) -> R
     Ok(())
 }
 
+/// What uninstall does with the container
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ContainerAction {
+    /// Leave it as it is (the default)
+    Keep,
+    /// Stop it before removing the registration
+    Stop,
+}
+
+/// Decide the container action from the flags
+///
+/// Removing volumes needs the container stopped, so `--volumes` implies
+/// `--stop`. Clap rejects `--keep-container` together with either.
+fn container_action(args: &UninstallArgs) -> ContainerAction {
+    if args.stop || args.volumes {
+        ContainerAction::Stop
+    } else {
+        ContainerAction::Keep
+    }
+}
+
 /// Stop container if running (helper)
 async fn stop_container_if_running() -> Result<()> {
     // Similar to cmd_stop but ignores "not running" state
@@ -162,3 +201,43 @@ async fn remove_volumes() -> Result<()> {
     remove_all_volumes(&client).await?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser)]
+    struct Cli {
+        #[command(flatten)]
+        args: UninstallArgs,
+    }
+
+    fn parse(flags: &[&str]) -> Result<UninstallArgs, clap::Error> {
+        let argv = std::iter::once("uninstall").chain(flags.iter().copied());
+        Cli::try_parse_from(argv).map(|cli| cli.args)
+    }
+
+    #[test]
+    fn container_action_matrix() {
+        for (flags, expected) in [
+            (&[][..], ContainerAction::Keep),
+            (&["--keep-container"][..], ContainerAction::Keep),
+            (&["--stop"][..], ContainerAction::Stop),
+            (&["--volumes", "--force"][..], ContainerAction::Stop),
+            (
+                &["--stop", "--volumes", "--force"][..],
+                ContainerAction::Stop,
+            ),
+        ] {
+            let args = parse(flags).unwrap();
+            assert_eq!(container_action(&args), expected, "{flags:?}");
+        }
+    }
+
+    #[test]
+    fn keep_container_conflicts_with_stop_and_volumes() {
+        assert!(parse(&["--keep-container", "--stop"]).is_err());
+        assert!(parse(&["--keep-container", "--volumes", "--force"]).is_err());
+    }
+}
@@ -10,10 +10,12 @@ use dialoguer::Confirm;
 use opencode_cloud_core::config::load_config;
 use opencode_cloud_core::docker::update::tag_current_as_previous;
 use opencode_cloud_core::docker::{
-    CONTAINER_NAME, DockerClient, IMAGE_TAG_DEFAULT, ImageState, ProgressReporter, build_image,
-    create_user, get_cli_version, has_previous_image, pull_image, rollback_image, save_state,
-    setup_and_start, stop_service,
+    CONTAINER_NAME, DockerClient, HealthCheckSettings, IMAGE_TAG_DEFAULT, ImageState,
+    ProgressReporter, RegistryAuth, ResourceLimits, build_image, container_env, create_user,
+    get_cli_version, get_container_labels, has_previous_image, pull_image, rollback_image,
+    save_state, setup_and_start, stop_service,
 };
+use opencode_cloud_core::events::{LifecycleEventKind, record_event};
 
 /// Arguments for the update command
 #[derive(Args)]
@@ -101,7 +103,7 @@ async fn handle_update(
     skip_confirm: bool,
     quiet: bool,
     verbose: u8,
-    _host_name: Option<&str>,
+    host_name: Option<&str>,
 ) -> Result<()> {
     let port = config.opencode_web_port;
     let bind_addr = &config.bind_address;
@@ -136,6 +138,8 @@ async fn handle_update(
     if verbose > 0 {
         eprintln!("{} Stopping service...", style("[1/5]").cyan());
     }
+    // Keep the user's `occ start --label` values on the recreated container
+    let labels = get_container_labels(client, CONTAINER_NAME).await?;
     let spinner = CommandSpinner::new_maybe("Stopping service...", quiet);
     if let Err(e) = stop_service(client, true, None).await {
         spinner.fail("Failed to stop service");
@@ -208,9 +212,14 @@ async fn handle_update(
             ProgressReporter::with_context("Updating image")
         };
 
-        let full_image = pull_image(client, Some(IMAGE_TAG_DEFAULT), &mut progress)
-            .await
-            .map_err(|e| anyhow!("Failed to pull image: {e}"))?;
+        let full_image = pull_image(
+            client,
+            Some(IMAGE_TAG_DEFAULT),
+            &RegistryAuth::from_config(config),
+            &mut progress,
+        )
+        .await
+        .map_err(|e| anyhow!("Failed to pull image: {e}"))?;
 
         // Determine registry and save provenance
         let registry = if full_image.starts_with("ghcr.io") {
@@ -229,11 +238,16 @@ async fn handle_update(
     if let Err(e) = setup_and_start(
         client,
         Some(port),
-        None,
+        Some(container_env(config)),
         Some(bind_addr),
         Some(config.cockpit_port),
         Some(config.cockpit_enabled),
         None, // bind_mounts: update recreates without bind mounts (user can restart with mounts)
+        Some(labels),
+        HealthCheckSettings::from_config(config),
+        Some(config.auto_restart),
+        ResourceLimits::from_config(config),
+        false,
     )
     .await
     {
@@ -247,6 +261,11 @@ async fn handle_update(
         eprintln!("{} Recreating users...", style("[4/5]").cyan());
     }
     recreate_users(client, config, quiet).await?;
+    record_event(
+        LifecycleEventKind::Updated,
+        host_name,
+        Some(format!("v{}", get_cli_version())),
+    );
 
     // Step 5: Show success
     if verbose > 0 {
@@ -287,7 +306,7 @@ async fn handle_rollback(
     skip_confirm: bool,
     quiet: bool,
     verbose: u8,
-    _host_name: Option<&str>,
+    host_name: Option<&str>,
 ) -> Result<()> {
     let port = config.opencode_web_port;
     let bind_addr = &config.bind_address;
@@ -329,6 +348,8 @@ async fn handle_rollback(
     if verbose > 0 {
         eprintln!("{} Stopping service...", style("[1/4]").cyan());
     }
+    // Keep the user's `occ start --label` values on the recreated container
+    let labels = get_container_labels(client, CONTAINER_NAME).await?;
     let spinner = CommandSpinner::new_maybe("Stopping service...", quiet);
     if let Err(e) = stop_service(client, true, None).await {
         spinner.fail("Failed to stop service");
@@ -355,11 +376,16 @@ async fn handle_rollback(
     if let Err(e) = setup_and_start(
         client,
         Some(port),
-        None,
+        Some(container_env(config)),
         Some(bind_addr),
         Some(config.cockpit_port),
         Some(config.cockpit_enabled),
         None, // bind_mounts: rollback recreates without bind mounts (user can restart with mounts)
+        Some(labels),
+        HealthCheckSettings::from_config(config),
+        Some(config.auto_restart),
+        ResourceLimits::from_config(config),
+        false,
     )
     .await
     {
@@ -373,6 +399,11 @@ async fn handle_rollback(
         eprintln!("{} Recreating users...", style("[4/4]").cyan());
     }
     recreate_users(client, config, quiet).await?;
+    record_event(
+        LifecycleEventKind::Updated,
+        host_name,
+        Some("rolled back to previous image".to_string()),
+    );
 
     // Show success
     if !quiet {
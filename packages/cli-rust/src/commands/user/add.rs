@@ -7,7 +7,7 @@ use clap::Args;
 use console::style;
 use dialoguer::{Input, Password};
 use opencode_cloud_core::docker::{
-    CONTAINER_NAME, DockerClient, create_user, set_user_password, user_exists,
+    CONTAINER_NAME, DockerClient, create_user, grant_admin, set_user_password, user_exists,
 };
 use opencode_cloud_core::{load_config, save_config};
 use rand::Rng;
@@ -22,6 +22,10 @@ pub struct UserAddArgs {
     /// Generate a random secure password instead of prompting
     #[arg(long, short)]
     pub generate: bool,
+
+    /// Grant admin rights (adds the user to the sudo/wheel group)
+    #[arg(long)]
+    pub admin: bool,
 }
 
 /// Generate a secure random password
@@ -117,6 +121,13 @@ pub async fn cmd_user_add(
     // Set password
     set_user_password(client, CONTAINER_NAME, &username, &password).await?;
 
+    // Grant admin rights if requested
+    let admin_group = if args.admin {
+        Some(grant_admin(client, CONTAINER_NAME, &username).await?)
+    } else {
+        None
+    };
+
     // Update config - add username to users array
     let mut config = load_config()?;
     if !config.users.contains(&username) {
@@ -132,6 +143,10 @@ pub async fn cmd_user_add(
             username
         );
 
+        if let Some(ref group) = admin_group {
+            println!("  Admin:    added to '{}' group", style(group).cyan());
+        }
+
         if args.generate {
             println!();
             println!("  Password: {}", style(&password).cyan());
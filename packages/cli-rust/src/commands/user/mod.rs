@@ -7,6 +7,7 @@ mod enable;
 mod list;
 mod passwd;
 mod remove;
+mod sync;
 
 use anyhow::{Result, bail};
 use clap::{Args, Subcommand};
@@ -17,6 +18,7 @@ pub use enable::{cmd_user_disable, cmd_user_enable};
 pub use list::cmd_user_list;
 pub use passwd::cmd_user_passwd;
 pub use remove::cmd_user_remove;
+pub use sync::cmd_user_sync;
 
 /// User management command arguments
 #[derive(Args)]
@@ -40,6 +42,8 @@ pub enum UserCommands {
     Enable(enable::UserEnableArgs),
     /// Disable a user account
     Disable(enable::UserDisableArgs),
+    /// Reconcile tracked users with the container's accounts
+    Sync(sync::UserSyncArgs),
 }
 
 /// Handle user command
@@ -79,5 +83,6 @@ pub async fn cmd_user(
         UserCommands::Disable(disable_args) => {
             cmd_user_disable(&client, disable_args, quiet, verbose).await
         }
+        UserCommands::Sync(sync_args) => cmd_user_sync(&client, sync_args, quiet, verbose).await,
     }
 }
@@ -9,7 +9,7 @@ use dialoguer::Confirm;
 use opencode_cloud_core::docker::{
     CONTAINER_NAME, DockerClient, delete_user, list_users, user_exists,
 };
-use opencode_cloud_core::{load_config, save_config};
+use opencode_cloud_core::{Config, load_config, save_config};
 
 /// Arguments for the user remove command
 #[derive(Args)]
@@ -20,6 +20,10 @@ pub struct UserRemoveArgs {
     /// Skip confirmation prompt
     #[arg(long, short)]
     pub force: bool,
+
+    /// Allow removing the last user while the service is network-exposed
+    #[arg(long)]
+    pub allow_lockout: bool,
 }
 
 /// System user that cannot be removed (container depends on it)
@@ -65,6 +69,29 @@ pub async fn cmd_user_remove(
         );
     }
 
+    // Removing the last user of a network-exposed service locks everyone out
+    if requires_lockout_confirmation(is_last_user, &config, args.allow_lockout) {
+        eprintln!(
+            "{} '{username}' is the last user and the service is network-exposed ({}).",
+            style("Warning:").yellow().bold(),
+            config.bind_address
+        );
+        eprintln!("Removing it will lock everyone out of the web UI.");
+        let confirm = Confirm::new()
+            .with_prompt("Remove the last user anyway?")
+            .default(false)
+            .interact()
+            .unwrap_or(false);
+
+        if !confirm {
+            bail!(
+                "Refusing to remove the last user of a network-exposed service.\n\n\
+                To remove it anyway:\n  \
+                occ user remove {username} --force --allow-lockout"
+            );
+        }
+    }
+
     // Confirm removal
     if !args.force {
         let confirm = Confirm::new()
@@ -85,8 +112,9 @@ pub async fn cmd_user_remove(
     delete_user(client, CONTAINER_NAME, username).await?;
 
     // Update config - remove username from users array
-    config.users.retain(|u| u != username);
-    save_config(&config)?;
+    if remove_tracked_user(&mut config, username) {
+        save_config(&config)?;
+    }
 
     // Display success
     if !quiet {
@@ -99,3 +127,72 @@ pub async fn cmd_user_remove(
 
     Ok(())
 }
+
+/// Whether removal needs an explicit lockout confirmation
+///
+/// Only applies when no users would remain, the service is reachable from
+/// the network, and unauthenticated access is not deliberately allowed.
+fn requires_lockout_confirmation(is_last_user: bool, config: &Config, allow_lockout: bool) -> bool {
+    is_last_user
+        && !allow_lockout
+        && config.is_network_exposed()
+        && !config.allow_unauthenticated_network
+}
+
+/// Drop `username` from the tracked users, returning whether it was present
+fn remove_tracked_user(config: &mut Config, username: &str) -> bool {
+    let before = config.users.len();
+    config.users.retain(|u| u != username);
+    config.users.len() != before
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exposed_config() -> Config {
+        Config {
+            bind_address: "0.0.0.0".to_string(),
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn lockout_guard_on_last_user_when_exposed() {
+        assert!(requires_lockout_confirmation(
+            true,
+            &exposed_config(),
+            false
+        ));
+    }
+
+    #[test]
+    fn lockout_guard_bypassed() {
+        let config = exposed_config();
+        assert!(!requires_lockout_confirmation(true, &config, true));
+        assert!(!requires_lockout_confirmation(false, &config, false));
+        assert!(!requires_lockout_confirmation(
+            true,
+            &Config::default(),
+            false
+        ));
+
+        let unauth = Config {
+            allow_unauthenticated_network: true,
+            ..exposed_config()
+        };
+        assert!(!requires_lockout_confirmation(true, &unauth, false));
+    }
+
+    #[test]
+    fn remove_tracked_user_syncs_config() {
+        let mut config = Config {
+            users: vec!["alice".to_string(), "bob".to_string()],
+            ..Config::default()
+        };
+        assert!(remove_tracked_user(&mut config, "alice"));
+        assert_eq!(config.users, vec!["bob".to_string()]);
+        assert!(!remove_tracked_user(&mut config, "carol"));
+        assert_eq!(config.users, vec!["bob".to_string()]);
+    }
+}
@@ -0,0 +1,144 @@
+//! User sync subcommand
+//!
+//! Reconciles the tracked users in config with the container's live accounts.
+
+use anyhow::Result;
+use clap::Args;
+use console::style;
+use opencode_cloud_core::docker::{CONTAINER_NAME, DockerClient, list_live_usernames};
+use opencode_cloud_core::{load_config, save_config};
+
+/// Arguments for the user sync command
+#[derive(Args)]
+pub struct UserSyncArgs {
+    /// Update config.users to match the container
+    #[arg(long)]
+    pub write: bool,
+}
+
+/// Container service account, present in every image and never tracked
+const SERVICE_USER: &str = "opencode";
+
+/// Differences between the container's accounts and `config.users`
+#[derive(Debug, Default, PartialEq)]
+struct UserSyncDiff {
+    /// Live accounts missing from config
+    added: Vec<String>,
+    /// Tracked users no longer present in the container
+    removed: Vec<String>,
+}
+
+impl UserSyncDiff {
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Compare live container users against the configured list
+fn reconcile_users(live: &[String], configured: &[String]) -> UserSyncDiff {
+    let added = live
+        .iter()
+        .filter(|u| u.as_str() != SERVICE_USER && !configured.contains(u))
+        .cloned()
+        .collect();
+    let removed = configured
+        .iter()
+        .filter(|u| !live.contains(u))
+        .cloned()
+        .collect();
+    UserSyncDiff { added, removed }
+}
+
+/// Apply a diff to the configured list, keeping the existing order
+fn apply_user_diff(configured: &mut Vec<String>, diff: &UserSyncDiff) {
+    configured.retain(|u| !diff.removed.contains(u));
+    configured.extend(diff.added.iter().cloned());
+}
+
+/// Reconcile config.users with the container's /etc/passwd
+pub async fn cmd_user_sync(
+    client: &DockerClient,
+    args: &UserSyncArgs,
+    quiet: bool,
+    _verbose: u8,
+) -> Result<()> {
+    let live = list_live_usernames(client, CONTAINER_NAME).await?;
+    let mut config = load_config()?;
+    let diff = reconcile_users(&live, &config.users);
+
+    if diff.is_empty() {
+        if !quiet {
+            println!("Config users match the container.");
+        }
+        return Ok(());
+    }
+
+    if !quiet {
+        for user in &diff.added {
+            println!(
+                "{} {user} (in container, not in config)",
+                style("+").green()
+            );
+        }
+        for user in &diff.removed {
+            println!("{} {user} (in config, not in container)", style("-").red());
+        }
+    }
+
+    if args.write {
+        apply_user_diff(&mut config.users, &diff);
+        save_config(&config)?;
+        if !quiet {
+            println!(
+                "{} Updated config users ({} added, {} removed)",
+                style("Success:").green().bold(),
+                diff.added.len(),
+                diff.removed.len()
+            );
+        }
+    } else if !quiet {
+        println!();
+        println!(
+            "Run {} to update config.",
+            style("occ user sync --write").cyan()
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn reconcile_reports_additions_and_removals() {
+        let live = names(&["opencode", "alice", "carol"]);
+        let configured = names(&["alice", "bob"]);
+        let diff = reconcile_users(&live, &configured);
+        assert_eq!(diff.added, names(&["carol"]));
+        assert_eq!(diff.removed, names(&["bob"]));
+    }
+
+    #[test]
+    fn reconcile_in_sync_is_empty() {
+        let live = names(&["opencode", "alice"]);
+        let diff = reconcile_users(&live, &names(&["alice"]));
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn apply_diff_keeps_order() {
+        let mut configured = names(&["alice", "bob", "dave"]);
+        let diff = UserSyncDiff {
+            added: names(&["carol"]),
+            removed: names(&["bob"]),
+        };
+        apply_user_diff(&mut configured, &diff);
+        assert_eq!(configured, names(&["alice", "dave", "carol"]));
+    }
+}
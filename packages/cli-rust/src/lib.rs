@@ -4,15 +4,18 @@
 
 mod commands;
 mod output;
+mod shutdown;
 pub mod wizard;
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 use clap::{Parser, Subcommand};
 use console::style;
+use opencode_cloud_core::docker::{DockerEndpoint, docker_endpoint, set_docker_endpoint};
 use opencode_cloud_core::{
     DockerClient, InstanceLock, SingletonError, config, get_version, load_config, load_hosts,
     save_config,
 };
+use std::io::IsTerminal;
 
 /// Manage your opencode cloud service
 #[derive(Parser)]
@@ -36,9 +39,26 @@ struct Cli {
     #[arg(long, global = true)]
     no_color: bool,
 
-    /// Target remote host (overrides default_host)
+    /// Print progress as plain lines instead of spinners (auto-enabled when stderr is not a TTY)
     #[arg(long, global = true)]
-    host: Option<String>,
+    plain: bool,
+
+    /// Target remote host (overrides default_host)
+    ///
+    /// `occ logs` accepts it more than once to merge logs from several hosts.
+    #[arg(long, global = true, value_name = "HOST")]
+    host: Vec<String>,
+
+    /// Docker endpoint to use instead of auto-detection and DOCKER_HOST
+    /// (unix:///path, tcp://host:port or ssh://[user@]host[:port])
+    #[arg(
+        long,
+        global = true,
+        value_name = "ENDPOINT",
+        visible_alias = "docker-host",
+        conflicts_with = "host"
+    )]
+    socket: Option<DockerEndpoint>,
 }
 
 #[derive(Subcommand)]
@@ -57,6 +77,8 @@ enum Commands {
     Install(commands::InstallArgs),
     /// Remove service registration
     Uninstall(commands::UninstallArgs),
+    /// Inspect the service registration files
+    Service(commands::ServiceArgs),
     /// Manage configuration
     Config(commands::ConfigArgs),
     /// Run interactive setup wizard
@@ -67,10 +89,18 @@ enum Commands {
     Mount(commands::MountArgs),
     /// Update to the latest version or rollback
     Update(commands::UpdateArgs),
+    /// Remove stale containers, images and volumes
+    Prune(commands::PruneArgs),
+    /// Inspect the opencode-cloud image
+    Image(commands::ImageArgs),
+    /// Inspect the running container
+    Container(commands::ContainerArgs),
     /// Open Cockpit web console
     Cockpit(commands::CockpitArgs),
     /// Manage remote hosts
     Host(commands::HostArgs),
+    /// Check the local installation for problems (and fix them with --fix)
+    Doctor(commands::DoctorArgs),
 }
 
 /// Get the ASCII banner for help display
@@ -95,6 +125,18 @@ fn get_banner() -> &'static str {
 pub async fn resolve_docker_client(
     maybe_host: Option<&str>,
 ) -> anyhow::Result<(DockerClient, Option<String>)> {
+    // An explicit --socket endpoint overrides hosts and auto-detection
+    if let Some(endpoint) = docker_endpoint() {
+        return match endpoint.ssh_host_config() {
+            Some(host_config) => {
+                let name = host_config.hostname.clone();
+                let client = DockerClient::connect_remote(&host_config, &name).await?;
+                Ok((client, Some(name)))
+            }
+            None => Ok((DockerClient::new()?, None)),
+        };
+    }
+
     let hosts = load_hosts().unwrap_or_default();
 
     // Determine target host
@@ -144,6 +186,15 @@ pub fn run() -> Result<()> {
         console::set_colors_enabled(false);
     }
 
+    // Configure progress output (spinners redraw in place, which clutters CI logs)
+    if cli.plain || !std::io::stderr().is_terminal() {
+        opencode_cloud_core::docker::set_plain_output(true);
+    }
+
+    if let Some(endpoint) = cli.socket.clone() {
+        set_docker_endpoint(endpoint);
+    }
+
     eprintln!(
         "{} This tool is still a work in progress and may be unstable. Follow updates at https://github.com/pRizz/opencode-cloud. Stability will be announced at some point.",
         style("Warning:").yellow().bold()
@@ -199,8 +250,14 @@ pub fn run() -> Result<()> {
         eprintln!("{} Data: {}", style("[info]").cyan(), data_dir);
     }
 
-    // Store host flag for command handlers
-    let target_host = cli.host.clone();
+    // Store host flag for command handlers; only `logs` takes several hosts
+    if cli.host.len() > 1 && !matches!(cli.command, Some(Commands::Logs(_))) {
+        bail!(
+            "--host can only be given once for this command.\n\n\
+             Only 'occ logs' can merge output from several hosts."
+        );
+    }
+    let target_host = cli.host.first().cloned();
 
     // Check if wizard needed (missing auth and not running setup/config command)
     let needs_wizard = !config.has_required_auth()
@@ -226,7 +283,15 @@ pub fn run() -> Result<()> {
         return Ok(());
     }
 
-    match cli.command {
+    // Only interactive service commands show the update notice
+    let show_update_notice = !cli.quiet
+        && std::io::stderr().is_terminal()
+        && !matches!(
+            cli.command,
+            None | Some(Commands::Config(_)) | Some(Commands::Setup(_))
+        );
+
+    let result = match cli.command {
         Some(Commands::Start(args)) => {
             let rt = tokio::runtime::Runtime::new()?;
             rt.block_on(commands::cmd_start(
@@ -260,7 +325,7 @@ pub fn run() -> Result<()> {
         }
         Some(Commands::Logs(args)) => {
             let rt = tokio::runtime::Runtime::new()?;
-            rt.block_on(commands::cmd_logs(&args, target_host.as_deref(), cli.quiet))
+            rt.block_on(commands::cmd_logs(&args, &cli.host, cli.quiet))
         }
         Some(Commands::Install(args)) => {
             let rt = tokio::runtime::Runtime::new()?;
@@ -270,7 +335,11 @@ pub fn run() -> Result<()> {
             let rt = tokio::runtime::Runtime::new()?;
             rt.block_on(commands::cmd_uninstall(&args, cli.quiet, cli.verbose))
         }
-        Some(Commands::Config(cmd)) => commands::cmd_config(cmd, &config, cli.quiet),
+        Some(Commands::Service(args)) => commands::cmd_service(&args, &config, cli.quiet),
+        Some(Commands::Config(cmd)) => {
+            let rt = tokio::runtime::Runtime::new()?;
+            rt.block_on(commands::cmd_config(cmd, &config, cli.quiet))
+        }
         Some(Commands::Setup(args)) => {
             let rt = tokio::runtime::Runtime::new()?;
             rt.block_on(commands::cmd_setup(&args, cli.quiet))
@@ -297,6 +366,30 @@ pub fn run() -> Result<()> {
                 cli.verbose,
             ))
         }
+        Some(Commands::Prune(args)) => {
+            let rt = tokio::runtime::Runtime::new()?;
+            rt.block_on(commands::cmd_prune(
+                &args,
+                target_host.as_deref(),
+                cli.quiet,
+            ))
+        }
+        Some(Commands::Image(args)) => {
+            let rt = tokio::runtime::Runtime::new()?;
+            rt.block_on(commands::cmd_image(
+                &args,
+                target_host.as_deref(),
+                cli.quiet,
+            ))
+        }
+        Some(Commands::Container(args)) => {
+            let rt = tokio::runtime::Runtime::new()?;
+            rt.block_on(commands::cmd_container(
+                &args,
+                target_host.as_deref(),
+                cli.quiet,
+            ))
+        }
         Some(Commands::Cockpit(args)) => {
             let rt = tokio::runtime::Runtime::new()?;
             rt.block_on(commands::cmd_cockpit(
@@ -309,6 +402,10 @@ pub fn run() -> Result<()> {
             let rt = tokio::runtime::Runtime::new()?;
             rt.block_on(commands::cmd_host(&args, cli.quiet, cli.verbose))
         }
+        Some(Commands::Doctor(args)) => {
+            let rt = tokio::runtime::Runtime::new()?;
+            rt.block_on(commands::cmd_doctor(&args, &config, cli.quiet))
+        }
         None => {
             // No command - show a welcome message and hint to use --help
             if !cli.quiet {
@@ -322,6 +419,35 @@ pub fn run() -> Result<()> {
             }
             Ok(())
         }
+    };
+
+    if result.is_ok() && show_update_notice {
+        print_update_notice(&config);
+    }
+
+    result
+}
+
+/// Print a one-line notice when a newer CLI release is available
+///
+/// Best-effort: network or cache errors are logged and otherwise ignored.
+fn print_update_notice(config: &opencode_cloud_core::Config) {
+    let Ok(rt) = tokio::runtime::Runtime::new() else {
+        return;
+    };
+    match rt.block_on(opencode_cloud_core::check_cli_update(&config.update_check)) {
+        Ok(Some(update)) => {
+            eprintln!();
+            eprintln!(
+                "{} opencode-cloud {} is available (you have {}). See {}",
+                style("Update:").cyan().bold(),
+                style(&update.latest_version).green(),
+                update.current_version,
+                style(&update.release_url).dim()
+            );
+        }
+        Ok(None) => {}
+        Err(e) => tracing::debug!("CLI update check failed: {e}"),
     }
 }
 
@@ -1,9 +1,53 @@
 //! Color utilities for CLI output
 //!
-//! Provides consistent color styling for service states and log levels.
+//! Provides consistent color styling for service states and log levels,
+//! plus log level detection for filtering.
 
+use clap::ValueEnum;
 use console::{Style, StyledObject};
 
+/// How many leading whitespace-separated tokens are searched for a level
+///
+/// Enough to skip a timestamp and a logger name, e.g.
+/// `2026-01-01T12:00:00Z server [WARN] ...`.
+const LEVEL_TOKEN_WINDOW: usize = 4;
+
+/// Log severity, ordered from least to most severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    /// Parse a single level token such as `INFO`, `[warn]`, `ERROR:` or `level=debug`
+    fn from_token(token: &str) -> Option<Self> {
+        let token = token.strip_prefix("level=").unwrap_or(token);
+        let word = token.trim_matches(|c: char| !c.is_ascii_alphabetic());
+        match word.to_ascii_lowercase().as_str() {
+            "trace" => Some(Self::Trace),
+            "debug" => Some(Self::Debug),
+            "info" => Some(Self::Info),
+            "warn" | "warning" => Some(Self::Warn),
+            "error" | "err" | "fatal" | "crit" | "critical" | "panic" => Some(Self::Error),
+            _ => None,
+        }
+    }
+}
+
+/// Detect the level of a log line from its leading tokens
+///
+/// Only the first few tokens are considered so that words like "error" in
+/// the message body do not count as a level.
+pub fn detect_log_level(line: &str) -> Option<LogLevel> {
+    line.split_whitespace()
+        .take(LEVEL_TOKEN_WINDOW)
+        .find_map(LogLevel::from_token)
+}
+
 /// Style a service state string with appropriate colors
 ///
 /// - "running" -> green bold
@@ -21,26 +65,25 @@ pub fn state_style(state: &str) -> StyledObject<String> {
     style.apply_to(state.to_string())
 }
 
-/// Style a log line based on detected log level
+/// Style a log line based on its level, as found by [`detect_log_level`]
 ///
-/// - Contains "ERROR" or "error" -> red
-/// - Contains "WARN" or "warn" -> yellow
-/// - Contains "INFO" or "info" -> cyan
-/// - Contains "DEBUG" or "debug" -> dim
-/// - else -> unstyled
+/// - error -> red
+/// - warn -> yellow
+/// - info -> cyan
+/// - debug, trace -> dim
+/// - no level -> unstyled
 pub fn log_level_style(line: &str) -> StyledObject<&str> {
-    let style = if line.contains("ERROR") || line.contains("error") {
-        Style::new().red()
-    } else if line.contains("WARN") || line.contains("warn") {
-        Style::new().yellow()
-    } else if line.contains("INFO") || line.contains("info") {
-        Style::new().cyan()
-    } else if line.contains("DEBUG") || line.contains("debug") {
-        Style::new().dim()
-    } else {
-        Style::new()
-    };
-    style.apply_to(line)
+    level_style(detect_log_level(line)).apply_to(line)
+}
+
+fn level_style(level: Option<LogLevel>) -> Style {
+    match level {
+        Some(LogLevel::Error) => Style::new().red(),
+        Some(LogLevel::Warn) => Style::new().yellow(),
+        Some(LogLevel::Info) => Style::new().cyan(),
+        Some(LogLevel::Debug | LogLevel::Trace) => Style::new().dim(),
+        None => Style::new(),
+    }
 }
 
 #[cfg(test)]
@@ -116,4 +159,53 @@ mod tests {
         let styled = log_level_style("plain log line");
         assert!(styled.to_string().contains("plain log line"));
     }
+
+    #[test]
+    fn log_level_style_ignores_words_in_message_body() {
+        let line = "request to /api took long and then an error happened";
+        assert_eq!(level_style(detect_log_level(line)), Style::new());
+        assert_eq!(
+            level_style(detect_log_level("2024-01-01 ERROR: failed")),
+            Style::new().red()
+        );
+    }
+
+    #[test]
+    fn detect_level_tokens() {
+        assert_eq!(detect_log_level("INFO started"), Some(LogLevel::Info));
+        assert_eq!(
+            detect_log_level("2024-01-01 ERROR: something failed"),
+            Some(LogLevel::Error)
+        );
+        assert_eq!(
+            detect_log_level("2026-01-01T12:00:00Z server [warn] slow request"),
+            Some(LogLevel::Warn)
+        );
+        assert_eq!(
+            detect_log_level("ts=1 level=debug msg=x"),
+            Some(LogLevel::Debug)
+        );
+        assert_eq!(detect_log_level("WARNING disk low"), Some(LogLevel::Warn));
+        assert_eq!(detect_log_level("FATAL boom"), Some(LogLevel::Error));
+        assert_eq!(detect_log_level("trace: enter"), Some(LogLevel::Trace));
+    }
+
+    #[test]
+    fn detect_level_ignores_message_body() {
+        assert_eq!(detect_log_level("plain log line"), None);
+        assert_eq!(detect_log_level(""), None);
+        assert_eq!(
+            detect_log_level("request to /api took long and then an error happened"),
+            None
+        );
+        assert_eq!(detect_log_level("information only"), None);
+    }
+
+    #[test]
+    fn log_levels_are_ordered() {
+        assert!(LogLevel::Trace < LogLevel::Debug);
+        assert!(LogLevel::Debug < LogLevel::Info);
+        assert!(LogLevel::Info < LogLevel::Warn);
+        assert!(LogLevel::Warn < LogLevel::Error);
+    }
 }
@@ -69,6 +69,15 @@ pub fn format_docker_error(e: &DockerError) -> String {
                 style("https://github.com/pRizz/opencode-cloud#troubleshooting").dim()
             )
         }
+        DockerError::ImageNotFound(image) => {
+            format!(
+                "{}\n\n  {}\n  {}\n  {}",
+                style(format!("Image not found: {image}")).red().bold(),
+                "The sandbox image must be pulled or built before the container can be created:",
+                style("  Pull:  occ start --pull-sandbox-image").cyan(),
+                style("  Build: occ start --cached-rebuild-sandbox-image").cyan(),
+            )
+        }
         _ => e.to_string(),
     }
 }
@@ -133,6 +142,15 @@ mod tests {
         assert!(msg.contains("--port"));
     }
 
+    #[test]
+    fn format_docker_error_image_not_found() {
+        let error =
+            DockerError::ImageNotFound("ghcr.io/prizz/opencode-cloud-sandbox:dev".to_string());
+        let msg = format_docker_error(&error);
+        assert!(msg.contains("ghcr.io/prizz/opencode-cloud-sandbox:dev"));
+        assert!(msg.contains("--pull-sandbox-image"));
+    }
+
     #[test]
     fn format_docker_error_anyhow_wraps_correctly() {
         let error = DockerError::NotRunning;
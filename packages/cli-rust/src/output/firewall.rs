@@ -0,0 +1,132 @@
+//! Host firewall hints for network exposure
+//!
+//! When the service is bound to all interfaces, an active ufw or firewalld
+//! can still block the port. This detects them (best effort, Linux only) and
+//! prints the command that would open the port. The firewall is never changed.
+
+use console::style;
+use std::process::Command;
+
+/// ufw's own on/off switch; `ufw enable`/`ufw disable` rewrite it
+const UFW_CONF_PATH: &str = "/etc/ufw/ufw.conf";
+
+/// A host firewall that may block the exposed port
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Firewall {
+    Ufw,
+    Firewalld,
+}
+
+impl Firewall {
+    /// Name of the firewall, which is also its systemd unit
+    fn name(self) -> &'static str {
+        match self {
+            Self::Ufw => "ufw",
+            Self::Firewalld => "firewalld",
+        }
+    }
+
+    /// Commands that allow TCP traffic to `port`
+    pub fn allow_commands(self, port: u16) -> Vec<String> {
+        match self {
+            Self::Ufw => vec![format!("sudo ufw allow {port}/tcp")],
+            Self::Firewalld => vec![
+                format!("sudo firewall-cmd --permanent --add-port={port}/tcp"),
+                "sudo firewall-cmd --reload".to_string(),
+            ],
+        }
+    }
+}
+
+/// Detect an active host firewall (None on other platforms)
+///
+/// firewalld filters whenever its unit runs. The ufw unit is active on
+/// Ubuntu even while ufw is disabled, so ufw also needs `ENABLED=yes` in
+/// its config (`ufw status` would need root).
+pub fn detect_firewall() -> Option<Firewall> {
+    if !cfg!(target_os = "linux") {
+        return None;
+    }
+    [Firewall::Ufw, Firewall::Firewalld]
+        .into_iter()
+        .find(|firewall| is_active(*firewall))
+}
+
+fn is_active(firewall: Firewall) -> bool {
+    match firewall {
+        Firewall::Ufw => {
+            std::fs::read_to_string(UFW_CONF_PATH).is_ok_and(|conf| ufw_enabled(&conf))
+                && unit_is_active(firewall.name())
+        }
+        Firewall::Firewalld => unit_is_active(firewall.name()),
+    }
+}
+
+/// Whether ufw.conf contents switch the firewall on (`ENABLED=yes`)
+fn ufw_enabled(conf: &str) -> bool {
+    conf.lines()
+        .filter_map(|line| line.trim().strip_prefix("ENABLED="))
+        .next_back()
+        .is_some_and(|value| {
+            value
+                .trim()
+                .trim_matches(|c| c == '"' || c == '\'')
+                .eq_ignore_ascii_case("yes")
+        })
+}
+
+fn unit_is_active(unit: &str) -> bool {
+    Command::new("systemctl")
+        .args(["is-active", "--quiet", unit])
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+/// Print how to open `port` if an active firewall is detected
+pub fn print_firewall_hint(port: u16) {
+    let Some(firewall) = detect_firewall() else {
+        return;
+    };
+    eprintln!(
+        "{} {} is active and may block port {port}. To allow it:",
+        style("Note:").cyan(),
+        firewall.name()
+    );
+    for command in firewall.allow_commands(port) {
+        eprintln!("  {}", style(command).cyan());
+    }
+    eprintln!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ufw_hint_allows_the_port() {
+        assert_eq!(
+            Firewall::Ufw.allow_commands(3000),
+            vec!["sudo ufw allow 3000/tcp"]
+        );
+    }
+
+    #[test]
+    fn ufw_enabled_reads_the_config_switch() {
+        assert!(ufw_enabled("# comment\nENABLED=yes\nLOGLEVEL=low\n"));
+        assert!(ufw_enabled("ENABLED=\"yes\""));
+        assert!(!ufw_enabled("ENABLED=no\nLOGLEVEL=low\n"));
+        assert!(!ufw_enabled("# ENABLED=yes\n"));
+        assert!(!ufw_enabled(""));
+    }
+
+    #[test]
+    fn firewalld_hint_adds_port_and_reloads() {
+        assert_eq!(
+            Firewall::Firewalld.allow_commands(8443),
+            vec![
+                "sudo firewall-cmd --permanent --add-port=8443/tcp",
+                "sudo firewall-cmd --reload"
+            ]
+        );
+    }
+}
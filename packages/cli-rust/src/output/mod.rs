@@ -3,15 +3,22 @@
 //! This module provides terminal output helpers including spinners
 //! with elapsed time display for long-running operations, color
 //! utilities for consistent state and log level styling, centralized
-//! error formatting for Docker errors, and URL formatting helpers
-//! for consistent URL display.
+//! error formatting for Docker errors, URL formatting helpers
+//! for consistent URL display, host firewall hints, per-phase
+//! timing for `-vv`, and human-readable byte sizes.
 
+pub mod bytes;
 pub mod colors;
 pub mod errors;
+pub mod firewall;
 pub mod spinner;
+pub mod timing;
 pub mod urls;
 
-pub use colors::{log_level_style, state_style};
+pub use bytes::format_bytes;
+pub use colors::{LogLevel, detect_log_level, log_level_style, state_style};
 pub use errors::{format_docker_error, format_docker_error_anyhow, show_docker_error};
+pub use firewall::print_firewall_hint;
 pub use spinner::CommandSpinner;
+pub use timing::PhaseTimer;
 pub use urls::{format_cockpit_url, normalize_bind_addr, resolve_remote_addr};
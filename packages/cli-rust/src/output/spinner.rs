@@ -4,12 +4,15 @@
 //! animated spinner and elapsed time indicator.
 
 use indicatif::{ProgressBar, ProgressStyle};
+use opencode_cloud_core::docker::plain_output_enabled;
 use std::time::Duration;
 
 /// A spinner for command operations with elapsed time display
 ///
 /// The spinner shows an animated indicator with a message and elapsed time.
 /// It respects quiet mode by becoming a no-op when quiet is enabled.
+/// In plain output mode it prints the start and final messages as single
+/// lines and skips intermediate updates.
 ///
 /// # Example
 ///
@@ -20,6 +23,7 @@ use std::time::Duration;
 /// ```
 pub struct CommandSpinner {
     bar: Option<ProgressBar>,
+    plain: bool,
 }
 
 impl CommandSpinner {
@@ -28,6 +32,14 @@ impl CommandSpinner {
     /// The spinner starts ticking immediately at 100ms intervals.
     /// Shows: `spinner message (MM:SS)` with both minutes and seconds
     pub fn new(message: &str) -> Self {
+        if plain_output_enabled() {
+            eprintln!("{message}");
+            return Self {
+                bar: None,
+                plain: true,
+            };
+        }
+
         let bar = ProgressBar::new_spinner();
         bar.set_style(
             ProgressStyle::with_template("{spinner:.green} {msg} ({elapsed_precise:.dim})")
@@ -36,7 +48,10 @@ impl CommandSpinner {
         );
         bar.set_message(message.to_string());
         bar.enable_steady_tick(Duration::from_millis(100));
-        Self { bar: Some(bar) }
+        Self {
+            bar: Some(bar),
+            plain: false,
+        }
     }
 
     /// Create a spinner that respects quiet mode
@@ -44,7 +59,10 @@ impl CommandSpinner {
     /// If `quiet` is true, returns a no-op spinner that doesn't output anything.
     pub fn new_maybe(message: &str, quiet: bool) -> Self {
         if quiet {
-            Self { bar: None }
+            Self {
+                bar: None,
+                plain: false,
+            }
         } else {
             Self::new(message)
         }
@@ -59,6 +77,9 @@ impl CommandSpinner {
 
     /// Finish the spinner with a success message (green checkmark)
     pub fn success(self, message: &str) {
+        if self.plain {
+            eprintln!("{} {}", console::style("\u{2713}").green(), message);
+        }
         if let Some(bar) = self.bar {
             bar.finish_with_message(format!(
                 "{} {}",
@@ -70,6 +91,9 @@ impl CommandSpinner {
 
     /// Finish the spinner with a failure message (red X)
     pub fn fail(self, message: &str) {
+        if self.plain {
+            eprintln!("{} {}", console::style("\u{2717}").red(), message);
+        }
         if let Some(bar) = self.bar {
             bar.finish_with_message(format!("{} {}", console::style("\u{2717}").red(), message));
         }
@@ -0,0 +1,128 @@
+//! Per-phase timing for verbose output
+//!
+//! Commands mark the start of each phase (connect, pull, create, readiness,
+//! ...) on a [`PhaseTimer`]; at `-vv` a one-line summary is printed at the
+//! end. Below that verbosity the timer is disabled and never reads the clock.
+
+use std::time::{Duration, Instant};
+
+use console::style;
+
+/// Verbosity at which timing summaries are printed
+const TIMING_VERBOSITY: u8 = 2;
+
+/// Records how long each named phase of a command takes
+#[derive(Debug)]
+pub struct PhaseTimer {
+    enabled: bool,
+    phases: Vec<(&'static str, Duration)>,
+    current: Option<(&'static str, Instant)>,
+}
+
+impl PhaseTimer {
+    /// Create a timer that is only active at `-vv` or higher
+    pub fn new(verbose: u8) -> Self {
+        Self {
+            enabled: verbose >= TIMING_VERBOSITY,
+            phases: Vec::new(),
+            current: None,
+        }
+    }
+
+    /// End the current phase (if any) and start timing `phase`
+    pub fn start(&mut self, phase: &'static str) {
+        if !self.enabled {
+            return;
+        }
+        self.finish();
+        self.current = Some((phase, Instant::now()));
+    }
+
+    /// End the current phase
+    pub fn finish(&mut self) {
+        if let Some((phase, started)) = self.current.take() {
+            self.record(phase, started.elapsed());
+        }
+    }
+
+    /// Add `elapsed` to `phase`, merging repeated phases into one entry
+    pub fn record(&mut self, phase: &'static str, elapsed: Duration) {
+        if !self.enabled {
+            return;
+        }
+        match self.phases.iter_mut().find(|(name, _)| *name == phase) {
+            Some((_, total)) => *total += elapsed,
+            None => self.phases.push((phase, elapsed)),
+        }
+    }
+
+    /// Summary like "connect 0.3s, create 0.1s, readiness 4.2s"
+    ///
+    /// `None` when disabled or nothing was recorded.
+    pub fn summary(&self) -> Option<String> {
+        if self.phases.is_empty() {
+            return None;
+        }
+        let parts: Vec<String> = self
+            .phases
+            .iter()
+            .map(|(name, elapsed)| format!("{name} {:.1}s", elapsed.as_secs_f64()))
+            .collect();
+        Some(parts.join(", "))
+    }
+
+    /// Finish the current phase and print the summary to stderr
+    pub fn print_summary(&mut self) {
+        self.finish();
+        if let Some(summary) = self.summary() {
+            eprintln!("{} {}", style("[timing]").cyan(), summary);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_below_vv() {
+        let mut timer = PhaseTimer::new(1);
+        timer.start("connect");
+        timer.record("pull", Duration::from_secs(3));
+        timer.finish();
+        assert!(timer.current.is_none());
+        assert_eq!(timer.summary(), None);
+    }
+
+    #[test]
+    fn records_in_order_and_formats() {
+        let mut timer = PhaseTimer::new(2);
+        timer.record("connect", Duration::from_millis(300));
+        timer.record("create", Duration::from_millis(120));
+        timer.record("readiness", Duration::from_millis(4200));
+        assert_eq!(
+            timer.summary().unwrap(),
+            "connect 0.3s, create 0.1s, readiness 4.2s"
+        );
+    }
+
+    #[test]
+    fn repeated_phases_accumulate() {
+        let mut timer = PhaseTimer::new(3);
+        timer.record("pull", Duration::from_millis(500));
+        timer.record("connect", Duration::from_millis(100));
+        timer.record("pull", Duration::from_millis(700));
+        assert_eq!(timer.summary().unwrap(), "pull 1.2s, connect 0.1s");
+    }
+
+    #[test]
+    fn start_closes_previous_phase() {
+        let mut timer = PhaseTimer::new(2);
+        timer.start("connect");
+        timer.start("create");
+        timer.finish();
+        let names: Vec<_> = timer.phases.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, vec!["connect", "create"]);
+        assert!(timer.current.is_none());
+    }
+}
@@ -9,6 +9,7 @@
 
 #![allow(dead_code)]
 
+use opencode_cloud_core::config::validate_bind_address;
 use opencode_cloud_core::load_hosts;
 
 /// Resolve the remote address for a host by looking up its configuration.
@@ -46,10 +47,9 @@ pub fn resolve_remote_addr(host_name: Option<&str>) -> Option<String> {
 ///
 /// A display-friendly address string
 pub fn normalize_bind_addr(bind_addr: &str) -> &str {
-    if bind_addr == "0.0.0.0" || bind_addr == "::" {
-        "127.0.0.1"
-    } else {
-        bind_addr
+    match validate_bind_address(bind_addr) {
+        Ok(ip) if ip.is_unspecified() => "127.0.0.1",
+        _ => bind_addr,
     }
 }
 
@@ -0,0 +1,76 @@
+//! Shutdown signal handling for foreground mode
+//!
+//! `occ start --no-daemon` stays in the foreground under systemd/launchd and
+//! waits here for SIGTERM (service stop) or SIGINT (Ctrl+C), and for SIGHUP
+//! (config reload), using tokio's signal support.
+
+/// Wait until SIGTERM or SIGINT (Ctrl+C) is received
+///
+/// On non-unix platforms only Ctrl+C is watched. If a handler cannot be
+/// installed, that signal is simply never reported.
+pub async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            tracing::debug!("Failed to listen for Ctrl+C: {e}");
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        use tokio::signal::unix::{SignalKind, signal};
+        match signal(SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                terminate.recv().await;
+            }
+            Err(e) => {
+                tracing::debug!("Failed to listen for SIGTERM: {e}");
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        () = ctrl_c => {}
+        () = terminate => {}
+    }
+}
+
+/// Listener for SIGHUP (config reload requests)
+///
+/// Create it once and call [`ReloadSignal::recv`] in a loop, so a SIGHUP
+/// that arrives while a reload is being applied is not lost. Listening
+/// replaces SIGHUP's default (terminate), so only create this on the
+/// long-running foreground path.
+pub struct ReloadSignal {
+    #[cfg(unix)]
+    hangup: Option<tokio::signal::unix::Signal>,
+}
+
+impl ReloadSignal {
+    pub fn new() -> Self {
+        #[cfg(unix)]
+        {
+            use tokio::signal::unix::{SignalKind, signal};
+            let hangup = signal(SignalKind::hangup())
+                .map_err(|e| tracing::debug!("Failed to listen for SIGHUP: {e}"))
+                .ok();
+            Self { hangup }
+        }
+        #[cfg(not(unix))]
+        Self {}
+    }
+
+    /// Wait for the next SIGHUP; never resolves on non-unix platforms
+    pub async fn recv(&mut self) {
+        #[cfg(unix)]
+        if let Some(hangup) = &mut self.hangup {
+            if hangup.recv().await.is_some() {
+                return;
+            }
+        }
+        std::future::pending::<()>().await;
+    }
+}
@@ -12,3 +12,40 @@ export function getVersionJs(): string;
  * Get the long version string with build info for Node.js consumers
  */
 export function getVersionLongJs(): string;
+
+/**
+ * Options for starting the service from Node.js
+ */
+export interface StartServiceOptions {
+  /** Host port for the web UI (defaults to the container default) */
+  port?: number
+  /** Bind mounts, each `/host/path:/container/path[:ro]` */
+  mounts?: Array<string>
+}
+
+/**
+ * Start the opencode service for Node.js consumers
+ *
+ * Resolves to the container ID. Invalid mounts reject with an error that
+ * names the offending mount string.
+ */
+export function startServiceJs(options?: StartServiceOptions | undefined | null): Promise<string>;
+
+/**
+ * A config lint finding for Node.js consumers
+ */
+export interface LintFindingJs {
+  /** "low", "medium" or "high" */
+  severity: string
+  /** Stable rule identifier, e.g. "no-users" */
+  code: string
+  /** What is wrong */
+  message: string
+  /** Command or setting that addresses it */
+  fixHint: string
+}
+
+/**
+ * Lint the saved config for security best practices
+ */
+export function lintConfigJs(): Array<LintFindingJs>;
@@ -140,7 +140,9 @@ if (!nativeBinding) {
   throw new Error(`Failed to load native binding`)
 }
 
-const { getVersionJs, getVersionLongJs } = nativeBinding
+const { getVersionJs, getVersionLongJs, lintConfigJs, startServiceJs } = nativeBinding
 
 module.exports.getVersionJs = getVersionJs
 module.exports.getVersionLongJs = getVersionLongJs
+module.exports.lintConfigJs = lintConfigJs
+module.exports.startServiceJs = startServiceJs
@@ -0,0 +1,275 @@
+//! Config change audit log
+//!
+//! When `audit_log_enabled` is set, each config change is appended to
+//! `config-audit.log` in the data directory as a single line:
+//!
+//! ```text
+//! 2026-01-01T12:00:00+00:00 bind_address: "127.0.0.1" -> "0.0.0.0"
+//! ```
+//!
+//! Secret values are masked. Writing is best-effort: failures are logged
+//! and never prevent the change itself.
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+
+use serde_json::Value;
+
+use super::paths::get_audit_log_path;
+use super::schema::Config;
+use super::validation::redact_env_entry;
+
+/// Config keys whose values must never be written to the audit log
+const SECRET_KEYS: &[&str] = &["auth_password", "image_registry_auth"];
+
+/// Placeholder written in place of secret values
+const MASKED_VALUE: &str = "********";
+
+/// A single changed config field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigChange {
+    /// Canonical config key (field name in the config file)
+    pub key: String,
+    /// Previous value, rendered for the log
+    pub old: String,
+    /// New value, rendered for the log
+    pub new: String,
+}
+
+/// Compute the changed fields between two configs
+///
+/// Values are rendered as compact JSON with secrets masked. Keys are
+/// returned in sorted order.
+pub fn config_changes(old: &Config, new: &Config) -> Vec<ConfigChange> {
+    let (Ok(Value::Object(old_map)), Ok(Value::Object(new_map))) =
+        (serde_json::to_value(old), serde_json::to_value(new))
+    else {
+        return Vec::new();
+    };
+
+    let mut keys: Vec<&String> = old_map.keys().chain(new_map.keys()).collect();
+    keys.sort();
+    keys.dedup();
+
+    keys.into_iter()
+        .filter_map(|key| {
+            let old_value = old_map.get(key).unwrap_or(&Value::Null);
+            let new_value = new_map.get(key).unwrap_or(&Value::Null);
+            (old_value != new_value).then(|| ConfigChange {
+                key: key.clone(),
+                old: render_value(key, old_value),
+                new: render_value(key, new_value),
+            })
+        })
+        .collect()
+}
+
+/// Render a value for the log, masking secrets
+///
+/// `container_env` entries whose name looks secret (TOKEN, KEY, ...) keep
+/// the name but have their value redacted.
+fn render_value(key: &str, value: &Value) -> String {
+    let is_set = !matches!(value, Value::Null) && value.as_str() != Some("");
+    if SECRET_KEYS.contains(&key) && is_set {
+        return MASKED_VALUE.to_string();
+    }
+    if key == "container_env" {
+        if let Value::Array(entries) = value {
+            let redacted: Vec<Value> = entries
+                .iter()
+                .map(|entry| match entry {
+                    Value::String(s) => Value::String(redact_env_entry(s)),
+                    other => other.clone(),
+                })
+                .collect();
+            return Value::Array(redacted).to_string();
+        }
+    }
+    value.to_string()
+}
+
+/// Format one audit line (without trailing newline)
+pub fn format_audit_line(timestamp: &str, change: &ConfigChange) -> String {
+    format!(
+        "{timestamp} {}: {} -> {}",
+        change.key, change.old, change.new
+    )
+}
+
+/// Record the changes between two configs in the audit log
+///
+/// Does nothing unless auditing is enabled in either config, so that
+/// turning the flag off is itself recorded. Errors are logged and ignored.
+pub fn record_config_changes(old: &Config, new: &Config) {
+    record_config_changes_to(get_audit_log_path().as_deref(), old, new);
+}
+
+fn record_config_changes_to(path: Option<&Path>, old: &Config, new: &Config) {
+    if !old.audit_log_enabled && !new.audit_log_enabled {
+        return;
+    }
+
+    let Some(path) = path else {
+        tracing::warn!("Could not determine audit log path; config change not recorded");
+        return;
+    };
+
+    if let Err(e) = append_changes(path, &config_changes(old, new)) {
+        tracing::warn!("Failed to write config audit log {}: {e}", path.display());
+    }
+}
+
+/// Append audit lines for the given changes to `path`
+fn append_changes(path: &Path, changes: &[ConfigChange]) -> std::io::Result<()> {
+    if changes.is_empty() {
+        return Ok(());
+    }
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let timestamp = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, false);
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    for change in changes {
+        writeln!(file, "{}", format_audit_line(&timestamp, change))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changes_lists_only_modified_fields() {
+        let old = Config::default();
+        let new = Config {
+            opencode_web_port: 3100,
+            ..Config::default()
+        };
+
+        assert_eq!(
+            config_changes(&old, &new),
+            vec![ConfigChange {
+                key: "opencode_web_port".to_string(),
+                old: "3000".to_string(),
+                new: "3100".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_audit_line_masks_secrets() {
+        let old = Config {
+            auth_password: Some("old-secret".to_string()),
+            ..Config::default()
+        };
+        let new = Config {
+            auth_password: Some("new-secret".to_string()),
+            ..Config::default()
+        };
+
+        let changes = config_changes(&old, &new);
+        assert_eq!(changes.len(), 1);
+        let line = format_audit_line("2026-01-01T00:00:00Z", &changes[0]);
+        assert_eq!(
+            line,
+            "2026-01-01T00:00:00Z auth_password: ******** -> ********"
+        );
+        assert!(!line.contains("secret"));
+    }
+
+    #[test]
+    fn test_audit_line_masks_secret_env_values() {
+        let old = Config::default();
+        let new = Config {
+            container_env: vec!["GITHUB_TOKEN=ghp_abc123".to_string(), "TZ=UTC".to_string()],
+            ..Config::default()
+        };
+
+        let changes = config_changes(&old, &new);
+        assert_eq!(changes.len(), 1);
+        let line = format_audit_line("2026-01-01T00:00:00Z", &changes[0]);
+        assert_eq!(
+            line,
+            r#"2026-01-01T00:00:00Z container_env: [] -> ["GITHUB_TOKEN=***","TZ=UTC"]"#
+        );
+        assert!(!line.contains("ghp_abc123"));
+    }
+
+    #[test]
+    fn test_unset_secret_is_not_masked() {
+        let old = Config::default();
+        let new = Config {
+            auth_password: Some("pw".to_string()),
+            ..Config::default()
+        };
+        let changes = config_changes(&old, &new);
+        assert_eq!(changes[0].old, "null");
+        assert_eq!(changes[0].new, MASKED_VALUE);
+    }
+
+    #[test]
+    fn test_string_values_are_quoted() {
+        let old = Config::default();
+        let new = Config {
+            bind_address: "0.0.0.0".to_string(),
+            ..Config::default()
+        };
+        let line = format_audit_line("ts", &config_changes(&old, &new)[0]);
+        assert_eq!(line, "ts bind_address: \"127.0.0.1\" -> \"0.0.0.0\"");
+    }
+
+    #[test]
+    fn test_append_writes_one_line_per_change() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config-audit.log");
+        let new = Config {
+            opencode_web_port: 3100,
+            trust_proxy: true,
+            ..Config::default()
+        };
+
+        append_changes(&path, &config_changes(&Config::default(), &new)).unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(contents.lines().count(), 2);
+    }
+
+    #[test]
+    fn test_no_changes_writes_nothing() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config-audit.log");
+
+        append_changes(&path, &[]).unwrap();
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_disabled_flag_writes_nothing() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config-audit.log");
+        let new = Config {
+            opencode_web_port: 3100,
+            ..Config::default()
+        };
+
+        record_config_changes_to(Some(&path), &Config::default(), &new);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_disabling_the_flag_is_recorded() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config-audit.log");
+        let old = Config {
+            audit_log_enabled: true,
+            ..Config::default()
+        };
+
+        record_config_changes_to(Some(&path), &old, &Config::default());
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(contents.contains("audit_log_enabled: true -> false"));
+    }
+}
@@ -0,0 +1,223 @@
+//! Effective runtime configuration
+//!
+//! `occ start` layers its one-shot flags (`--port`, `--bind-address`,
+//! `--memory`, `--cpus`) over
+//! the config file, which itself falls back to built-in defaults. This
+//! module performs that merge in one place and records where each field's
+//! value came from, so `occ config show --effective` reports exactly what
+//! `occ start` would use.
+
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+use super::schema::{Config, validate_bind_address};
+
+/// Where an effective config value came from, lowest precedence first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ConfigSource {
+    /// Built-in default (not changed in the config file)
+    Default,
+    /// Set in the config file
+    File,
+    /// Overridden by a command-line flag for this run
+    Flag,
+}
+
+impl ConfigSource {
+    /// Short label for display
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::File => "config file",
+            Self::Flag => "flag",
+        }
+    }
+}
+
+/// One-shot overrides from `occ start` flags
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConfigOverrides {
+    /// `--port`
+    pub opencode_web_port: Option<u16>,
+    /// `--bind-address`
+    pub bind_address: Option<String>,
+    /// `--memory` (megabytes)
+    pub memory_limit_mb: Option<u64>,
+    /// `--cpus`
+    pub cpu_limit: Option<f64>,
+}
+
+/// Fully merged config plus the source of each field
+#[derive(Debug, Clone, PartialEq)]
+pub struct EffectiveConfig {
+    /// The config as `occ start` would use it
+    pub config: Config,
+    /// Source of each top-level field, keyed by config file name
+    pub sources: BTreeMap<String, ConfigSource>,
+}
+
+impl EffectiveConfig {
+    /// Source of a field (fields not in the map are defaults)
+    pub fn source(&self, key: &str) -> ConfigSource {
+        self.sources
+            .get(key)
+            .copied()
+            .unwrap_or(ConfigSource::Default)
+    }
+}
+
+/// Merge `overrides` over the loaded config and annotate each field
+///
+/// A field counts as coming from the config file when it differs from the
+/// built-in default. Override values are validated the same way as
+/// `occ config set`.
+pub fn resolve_effective_config(
+    file_config: &Config,
+    overrides: &ConfigOverrides,
+) -> Result<EffectiveConfig, String> {
+    let mut config = file_config.clone();
+    let mut flagged = Vec::new();
+
+    if let Some(port) = overrides.opencode_web_port {
+        config.opencode_web_port = port;
+        flagged.push("opencode_web_port");
+    }
+    if let Some(ref addr) = overrides.bind_address {
+        validate_bind_address(addr)?;
+        config.bind_address = addr.trim().to_string();
+        flagged.push("bind_address");
+    }
+    if let Some(mb) = overrides.memory_limit_mb {
+        config.memory_limit_mb = Some(mb);
+        flagged.push("memory_limit_mb");
+    }
+    if let Some(cpus) = overrides.cpu_limit {
+        config.cpu_limit = Some(cpus);
+        flagged.push("cpu_limit");
+    }
+
+    let mut sources = field_sources(file_config);
+    for key in flagged {
+        sources.insert(key.to_string(), ConfigSource::Flag);
+    }
+
+    Ok(EffectiveConfig { config, sources })
+}
+
+/// Mark each field as default or file by comparing against `Config::default()`
+fn field_sources(config: &Config) -> BTreeMap<String, ConfigSource> {
+    let (Ok(Value::Object(current)), Ok(Value::Object(defaults))) = (
+        serde_json::to_value(config),
+        serde_json::to_value(Config::default()),
+    ) else {
+        return BTreeMap::new();
+    };
+
+    current
+        .into_iter()
+        .map(|(key, value)| {
+            let source = if defaults.get(&key) == Some(&value) {
+                ConfigSource::Default
+            } else {
+                ConfigSource::File
+            };
+            (key, source)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_are_annotated_as_default() {
+        let effective =
+            resolve_effective_config(&Config::default(), &ConfigOverrides::default()).unwrap();
+        assert_eq!(effective.config, Config::default());
+        assert!(
+            effective
+                .sources
+                .values()
+                .all(|s| *s == ConfigSource::Default)
+        );
+    }
+
+    #[test]
+    fn file_values_are_annotated_as_file() {
+        let file = Config {
+            opencode_web_port: 4000,
+            cockpit_enabled: !Config::default().cockpit_enabled,
+            ..Config::default()
+        };
+        let effective = resolve_effective_config(&file, &ConfigOverrides::default()).unwrap();
+        assert_eq!(effective.source("opencode_web_port"), ConfigSource::File);
+        assert_eq!(effective.source("cockpit_enabled"), ConfigSource::File);
+        assert_eq!(effective.source("bind_address"), ConfigSource::Default);
+    }
+
+    #[test]
+    fn flags_take_precedence_over_file() {
+        let file = Config {
+            opencode_web_port: 4000,
+            bind_address: "0.0.0.0".to_string(),
+            ..Config::default()
+        };
+        let overrides = ConfigOverrides {
+            opencode_web_port: Some(5000),
+            bind_address: Some(" ::1 ".to_string()),
+            ..ConfigOverrides::default()
+        };
+        let effective = resolve_effective_config(&file, &overrides).unwrap();
+
+        assert_eq!(effective.config.opencode_web_port, 5000);
+        assert_eq!(effective.config.bind_address, "::1");
+        assert_eq!(effective.source("opencode_web_port"), ConfigSource::Flag);
+        assert_eq!(effective.source("bind_address"), ConfigSource::Flag);
+    }
+
+    #[test]
+    fn flag_matching_default_is_still_a_flag() {
+        let overrides = ConfigOverrides {
+            opencode_web_port: Some(Config::default().opencode_web_port),
+            ..ConfigOverrides::default()
+        };
+        let effective = resolve_effective_config(&Config::default(), &overrides).unwrap();
+        assert_eq!(effective.source("opencode_web_port"), ConfigSource::Flag);
+    }
+
+    #[test]
+    fn invalid_bind_override_is_rejected() {
+        let overrides = ConfigOverrides {
+            bind_address: Some("not-an-ip".to_string()),
+            ..ConfigOverrides::default()
+        };
+        assert!(resolve_effective_config(&Config::default(), &overrides).is_err());
+    }
+
+    #[test]
+    fn resource_limit_flags_override_file() {
+        let file = Config {
+            memory_limit_mb: Some(1024),
+            cpu_limit: Some(1.0),
+            ..Config::default()
+        };
+        let overrides = ConfigOverrides {
+            memory_limit_mb: Some(4096),
+            ..ConfigOverrides::default()
+        };
+        let effective = resolve_effective_config(&file, &overrides).unwrap();
+
+        assert_eq!(effective.config.memory_limit_mb, Some(4096));
+        assert_eq!(effective.config.cpu_limit, Some(1.0));
+        assert_eq!(effective.source("memory_limit_mb"), ConfigSource::Flag);
+        assert_eq!(effective.source("cpu_limit"), ConfigSource::File);
+    }
+
+    #[test]
+    fn source_precedence_order() {
+        assert!(ConfigSource::Default < ConfigSource::File);
+        assert!(ConfigSource::File < ConfigSource::Flag);
+    }
+}
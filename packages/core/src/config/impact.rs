@@ -0,0 +1,114 @@
+//! How changed config keys reach a running service
+//!
+//! Shared by `occ config set`, which tells the user what a change needs, and
+//! the foreground reload, which applies what it can without a restart.
+
+use super::schema::Config;
+
+/// Config keys only read by occ itself, never by the container
+///
+/// Changing one of these takes effect on the next occ command without
+/// touching the running container.
+pub const HOST_ONLY_KEYS: &[&str] = &[
+    "version",
+    "update_check",
+    "audit_log_enabled",
+    "image_source",
+    "image_registry_auth",
+    "startup_timeout_seconds",
+];
+
+/// Whether `key` (a canonical config key) is only read by occ itself
+pub fn is_host_only_key(key: &str) -> bool {
+    HOST_ONLY_KEYS.contains(&key)
+}
+
+/// What it takes for a changed config key to reach the running service
+///
+/// Ordered from least to most disruptive, so the `max` of several changes
+/// is the step that applies all of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ChangeImpact {
+    /// Only read by occ itself; nothing to do for the container
+    HostOnly,
+    /// Applied to the running container in place with `docker update`
+    UpdateContainer,
+    /// Picked up when the service restarts
+    Restart,
+    /// Fixed when the container is created, so it must be recreated
+    Recreate,
+}
+
+/// Classify a changed canonical config key, given the config it changed to
+///
+/// Docker can raise or lower memory/CPU limits in place but not remove
+/// them, so clearing `memory_limit_mb`/`cpu_limit` needs a recreate.
+pub fn change_impact(key: &str, new: &Config) -> ChangeImpact {
+    match key {
+        "auto_restart" => ChangeImpact::UpdateContainer,
+        "memory_limit_mb" if new.memory_limit_mb.is_some() => ChangeImpact::UpdateContainer,
+        "cpu_limit" if new.cpu_limit.is_some() => ChangeImpact::UpdateContainer,
+        // Port bindings, mounts, limits and the base path env var are set
+        // at container creation
+        "opencode_web_port" | "bind" | "bind_address" | "cockpit_port" | "mounts"
+        | "memory_limit_mb" | "cpu_limit" | "base_path" => ChangeImpact::Recreate,
+        _ if is_host_only_key(key) => ChangeImpact::HostOnly,
+        _ => ChangeImpact::Restart,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn host_only_keys_are_config_fields() {
+        let value = serde_json::to_value(Config::default()).unwrap();
+        let fields = value.as_object().unwrap();
+        for key in HOST_ONLY_KEYS {
+            assert!(fields.contains_key(*key), "{key} is not a config field");
+        }
+    }
+
+    #[test]
+    fn keys_are_classified_by_how_they_apply() {
+        let limited = Config {
+            memory_limit_mb: Some(2048),
+            cpu_limit: Some(1.5),
+            ..Config::default()
+        };
+        let unlimited = Config::default();
+
+        for key in ["update_check", "startup_timeout_seconds", "image_source"] {
+            assert_eq!(
+                change_impact(key, &limited),
+                ChangeImpact::HostOnly,
+                "{key}"
+            );
+        }
+        for key in ["auto_restart", "memory_limit_mb", "cpu_limit"] {
+            assert_eq!(
+                change_impact(key, &limited),
+                ChangeImpact::UpdateContainer,
+                "{key}"
+            );
+        }
+        for key in ["memory_limit_mb", "cpu_limit"] {
+            assert_eq!(
+                change_impact(key, &unlimited),
+                ChangeImpact::Recreate,
+                "{key}"
+            );
+        }
+        for key in ["opencode_web_port", "bind_address", "mounts", "base_path"] {
+            assert_eq!(
+                change_impact(key, &limited),
+                ChangeImpact::Recreate,
+                "{key}"
+            );
+        }
+        for key in ["restart_retries", "users", "motd", "trust_proxy"] {
+            assert_eq!(change_impact(key, &limited), ChangeImpact::Restart, "{key}");
+        }
+    }
+}
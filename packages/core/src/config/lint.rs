@@ -0,0 +1,250 @@
+//! Advisory security linting
+//!
+//! Unlike [`validate_config`](super::validate_config), which rejects configs
+//! that cannot work, lint rules flag configs that work but are risky. Each
+//! finding carries a stable code so scripts can filter on it. Used by
+//! `occ config lint` and the Node.js bindings.
+
+use serde::Serialize;
+
+use super::schema::Config;
+
+/// `rate_limit_attempts` above this is reported as weak brute-force protection
+pub const HIGH_RATE_LIMIT_ATTEMPTS: u32 = 20;
+
+/// How serious a lint finding is, lowest first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintSeverity {
+    /// Worth a look, usually fine
+    Low,
+    /// Likely a mistake for most deployments
+    Medium,
+    /// Exposes the service; `occ config lint` exits non-zero
+    High,
+}
+
+impl LintSeverity {
+    /// Short label for display
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Low => "low",
+            Self::Medium => "medium",
+            Self::High => "high",
+        }
+    }
+}
+
+/// A single lint result
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LintFinding {
+    /// How serious the finding is
+    pub severity: LintSeverity,
+    /// Stable identifier, e.g. "no-users"
+    pub code: &'static str,
+    /// What is wrong
+    pub message: String,
+    /// Command or setting that addresses it
+    pub fix_hint: String,
+}
+
+/// Run all lint rules, most severe findings first
+pub fn lint_config(config: &Config) -> Vec<LintFinding> {
+    let mut findings: Vec<LintFinding> = [
+        lint_unauthenticated_network,
+        lint_no_users,
+        lint_exposed_without_proxy,
+        lint_high_rate_limit,
+    ]
+    .iter()
+    .filter_map(|rule| rule(config))
+    .collect();
+
+    findings.sort_by(|a, b| b.severity.cmp(&a.severity));
+    findings
+}
+
+/// Whether any finding is high severity
+pub fn has_high_severity(findings: &[LintFinding]) -> bool {
+    findings.iter().any(|f| f.severity == LintSeverity::High)
+}
+
+fn lint_unauthenticated_network(config: &Config) -> Option<LintFinding> {
+    if !config.allow_unauthenticated_network {
+        return None;
+    }
+    let severity = if config.is_network_exposed() {
+        LintSeverity::High
+    } else {
+        LintSeverity::Medium
+    };
+    Some(LintFinding {
+        severity,
+        code: "unauthenticated-network",
+        message: "allow_unauthenticated_network is enabled; anyone who can reach the \
+                  service can use it without logging in"
+            .to_string(),
+        fix_hint: "occ config set allow_unauthenticated_network false".to_string(),
+    })
+}
+
+fn lint_no_users(config: &Config) -> Option<LintFinding> {
+    if config.has_required_auth() {
+        return None;
+    }
+    let severity = if config.is_network_exposed() {
+        LintSeverity::High
+    } else {
+        LintSeverity::Medium
+    };
+    Some(LintFinding {
+        severity,
+        code: "no-users",
+        message: "No users are configured for authentication".to_string(),
+        fix_hint: "occ user add".to_string(),
+    })
+}
+
+fn lint_exposed_without_proxy(config: &Config) -> Option<LintFinding> {
+    if !config.is_network_exposed() || config.trust_proxy {
+        return None;
+    }
+    Some(LintFinding {
+        severity: LintSeverity::Medium,
+        code: "exposed-without-tls-proxy",
+        message: "Service is exposed on all interfaces without a trusted reverse proxy; \
+                  traffic (including logins) is plain HTTP unless a TLS proxy is in front"
+            .to_string(),
+        fix_hint: "Put a TLS-terminating proxy in front, then: occ config set trust_proxy true"
+            .to_string(),
+    })
+}
+
+fn lint_high_rate_limit(config: &Config) -> Option<LintFinding> {
+    if config.rate_limit_attempts <= HIGH_RATE_LIMIT_ATTEMPTS {
+        return None;
+    }
+    Some(LintFinding {
+        severity: LintSeverity::Low,
+        code: "high-rate-limit",
+        message: format!(
+            "rate_limit_attempts is {} (above {HIGH_RATE_LIMIT_ATTEMPTS}); login brute-force \
+             protection is weak",
+            config.rate_limit_attempts
+        ),
+        fix_hint: "occ config set rate_limit_attempts 5".to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secured() -> Config {
+        Config {
+            users: vec!["admin".to_string()],
+            ..Config::default()
+        }
+    }
+
+    fn codes(config: &Config) -> Vec<&'static str> {
+        lint_config(config).iter().map(|f| f.code).collect()
+    }
+
+    #[test]
+    fn secured_local_config_is_clean() {
+        assert!(lint_config(&secured()).is_empty());
+    }
+
+    #[test]
+    fn unauthenticated_network_fires() {
+        let local = Config {
+            allow_unauthenticated_network: true,
+            ..secured()
+        };
+        let finding = lint_unauthenticated_network(&local).unwrap();
+        assert_eq!(finding.severity, LintSeverity::Medium);
+
+        let exposed = Config {
+            bind_address: "0.0.0.0".to_string(),
+            ..local
+        };
+        let finding = lint_unauthenticated_network(&exposed).unwrap();
+        assert_eq!(finding.severity, LintSeverity::High);
+        assert_eq!(finding.code, "unauthenticated-network");
+    }
+
+    #[test]
+    fn no_users_fires() {
+        let finding = lint_no_users(&Config::default()).unwrap();
+        assert_eq!(finding.severity, LintSeverity::Medium);
+
+        let exposed = Config {
+            bind_address: "::".to_string(),
+            ..Config::default()
+        };
+        assert_eq!(
+            lint_no_users(&exposed).unwrap().severity,
+            LintSeverity::High
+        );
+        assert!(lint_no_users(&secured()).is_none());
+    }
+
+    #[test]
+    fn exposed_without_proxy_fires() {
+        let exposed = Config {
+            bind_address: "0.0.0.0".to_string(),
+            ..secured()
+        };
+        assert_eq!(codes(&exposed), vec!["exposed-without-tls-proxy"]);
+
+        let proxied = Config {
+            trust_proxy: true,
+            ..exposed
+        };
+        assert!(lint_config(&proxied).is_empty());
+    }
+
+    #[test]
+    fn high_rate_limit_fires() {
+        let config = Config {
+            rate_limit_attempts: HIGH_RATE_LIMIT_ATTEMPTS + 1,
+            ..secured()
+        };
+        let findings = lint_config(&config);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].code, "high-rate-limit");
+        assert_eq!(findings[0].severity, LintSeverity::Low);
+
+        let at_threshold = Config {
+            rate_limit_attempts: HIGH_RATE_LIMIT_ATTEMPTS,
+            ..secured()
+        };
+        assert!(lint_config(&at_threshold).is_empty());
+    }
+
+    #[test]
+    fn findings_sorted_by_severity() {
+        let config = Config {
+            bind_address: "0.0.0.0".to_string(),
+            rate_limit_attempts: 100,
+            ..Config::default()
+        };
+        let findings = lint_config(&config);
+        assert_eq!(
+            codes(&config),
+            vec!["no-users", "exposed-without-tls-proxy", "high-rate-limit"]
+        );
+        assert!(has_high_severity(&findings));
+        assert!(!has_high_severity(&findings[1..]));
+    }
+
+    #[test]
+    fn finding_serializes_lowercase_severity() {
+        let finding = lint_no_users(&Config::default()).unwrap();
+        let json = serde_json::to_value(&finding).unwrap();
+        assert_eq!(json["severity"], "medium");
+        assert_eq!(json["code"], "no-users");
+        assert_eq!(json["fix_hint"], "occ user add");
+    }
+}
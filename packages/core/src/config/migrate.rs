@@ -0,0 +1,202 @@
+//! Config file migrations
+//!
+//! Migrations operate on the raw parsed file (before deserializing into
+//! [`Config`]) so they can rename, drop or fill in keys that the current
+//! schema would otherwise reject. They run automatically on every load and
+//! explicitly via `occ config migrate`.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde_json::{Map, Value};
+
+use super::schema::Config;
+use super::{ConfigFormat, parse_config_value, save_config_to};
+
+/// Config version written by this CLI
+pub const CURRENT_CONFIG_VERSION: u32 = 1;
+
+/// Keys that were removed from the schema and are dropped on load
+const DEPRECATED_KEYS: &[&str] = &["opencode_commit"];
+
+/// Outcome of running the migration pipeline
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationReport {
+    /// Version found in the file, if any
+    pub from_version: Option<u64>,
+    /// Version after migrating
+    pub to_version: u32,
+    /// Human-readable description of each applied step, in order
+    pub steps: Vec<String>,
+}
+
+impl MigrationReport {
+    /// Whether the file was already current
+    pub fn is_noop(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
+/// Migrate a raw config value in place to the current schema
+pub fn migrate_config(value: &mut Value) -> Result<MigrationReport> {
+    let obj = value
+        .as_object_mut()
+        .ok_or_else(|| anyhow::anyhow!("Config file must contain an object"))?;
+
+    let from_version = obj.get("version").and_then(Value::as_u64);
+    let mut steps = Vec::new();
+    steps.extend(drop_deprecated_keys(obj));
+    steps.extend(set_current_version(obj, from_version));
+
+    Ok(MigrationReport {
+        from_version,
+        to_version: CURRENT_CONFIG_VERSION,
+        steps,
+    })
+}
+
+fn drop_deprecated_keys(obj: &mut Map<String, Value>) -> Vec<String> {
+    DEPRECATED_KEYS
+        .iter()
+        .filter(|key| obj.remove(**key).is_some())
+        .map(|key| format!("Removed deprecated key '{key}'"))
+        .collect()
+}
+
+/// Stamp the current version on files that are missing it or older
+///
+/// Newer versions are left alone so an older CLI does not downgrade them.
+fn set_current_version(obj: &mut Map<String, Value>, from: Option<u64>) -> Option<String> {
+    if from.is_some_and(|v| v >= u64::from(CURRENT_CONFIG_VERSION)) {
+        return None;
+    }
+    obj.insert("version".to_string(), Value::from(CURRENT_CONFIG_VERSION));
+    Some(match from {
+        Some(v) => format!("Updated version {v} -> {CURRENT_CONFIG_VERSION}"),
+        None => format!("Added missing version {CURRENT_CONFIG_VERSION}"),
+    })
+}
+
+/// Migrate the config file at `path`
+///
+/// The migrated config must deserialize cleanly before anything is written.
+/// Unless `dry_run` is set, a file that needed changes is rewritten in its
+/// original format, keeping a `.bak` copy of the previous contents.
+pub fn migrate_config_file(path: &Path, dry_run: bool) -> Result<MigrationReport> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read config file: {}", path.display()))?;
+    let format = ConfigFormat::from_path(path);
+
+    let mut value = parse_config_value(&contents, format)?;
+    let report = migrate_config(&mut value)?;
+    let config: Config = serde_json::from_value(value).with_context(|| {
+        format!(
+            "Invalid configuration in {} after migration",
+            path.display()
+        )
+    })?;
+
+    if !dry_run && !report.is_noop() {
+        save_config_to(path, &config)?;
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::serialize_config;
+
+    #[test]
+    fn current_config_is_noop() {
+        let mut value = serde_json::to_value(Config::default()).unwrap();
+        let before = value.clone();
+        let report = migrate_config(&mut value).unwrap();
+        assert!(report.is_noop());
+        assert_eq!(report.from_version, Some(1));
+        assert_eq!(value, before);
+    }
+
+    #[test]
+    fn old_config_reports_steps() {
+        let mut value = serde_json::json!({ "opencode_commit": "abc" });
+        let report = migrate_config(&mut value).unwrap();
+        assert_eq!(
+            report.steps,
+            vec![
+                "Removed deprecated key 'opencode_commit'".to_string(),
+                "Added missing version 1".to_string(),
+            ]
+        );
+        assert_eq!(report.from_version, None);
+        assert_eq!(report.to_version, CURRENT_CONFIG_VERSION);
+        assert_eq!(value, serde_json::json!({ "version": 1 }));
+    }
+
+    #[test]
+    fn older_version_is_bumped() {
+        let mut value = serde_json::json!({ "version": 0 });
+        let report = migrate_config(&mut value).unwrap();
+        assert_eq!(report.steps, vec!["Updated version 0 -> 1".to_string()]);
+    }
+
+    #[test]
+    fn newer_version_is_left_alone() {
+        let mut value = serde_json::json!({ "version": 2 });
+        assert!(migrate_config(&mut value).unwrap().is_noop());
+        assert_eq!(value["version"], 2);
+    }
+
+    #[test]
+    fn non_object_is_rejected() {
+        assert!(migrate_config(&mut serde_json::json!([1, 2])).is_err());
+    }
+
+    #[test]
+    fn migrate_file_writes_backup() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.json");
+        let old = r#"{ "version": 1, "opencode_commit": "abc" }"#;
+        fs::write(&path, old).unwrap();
+
+        let report = migrate_config_file(&path, false).unwrap();
+
+        assert_eq!(report.steps.len(), 1);
+        assert_eq!(
+            fs::read_to_string(dir.path().join("config.json.bak")).unwrap(),
+            old
+        );
+        assert!(
+            !fs::read_to_string(&path)
+                .unwrap()
+                .contains("opencode_commit")
+        );
+    }
+
+    #[test]
+    fn migrate_file_dry_run_does_not_write() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let old = "version = 1\nopencode_commit = \"abc\"\n";
+        fs::write(&path, old).unwrap();
+
+        let report = migrate_config_file(&path, true).unwrap();
+
+        assert!(!report.is_noop());
+        assert_eq!(fs::read_to_string(&path).unwrap(), old);
+        assert!(!dir.path().join("config.toml.bak").exists());
+    }
+
+    #[test]
+    fn migrate_current_file_is_noop() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.json");
+        let current = serialize_config(&Config::default(), ConfigFormat::Jsonc).unwrap();
+        fs::write(&path, &current).unwrap();
+
+        assert!(migrate_config_file(&path, false).unwrap().is_noop());
+        assert_eq!(fs::read_to_string(&path).unwrap(), current);
+        assert!(!dir.path().join("config.json.bak").exists());
+    }
+}
@@ -1,24 +1,47 @@
 //! Configuration management for opencode-cloud
 //!
-//! Handles loading, saving, and validating the JSONC configuration file.
+//! Handles loading, saving, and validating the configuration file.
+//! JSONC is the default format; TOML is used when the file ends in `.toml`.
 //! Creates default config if missing, validates against schema.
 
+pub mod audit;
+pub mod effective;
+pub mod impact;
+pub mod lint;
+pub mod migrate;
 pub mod paths;
 pub mod schema;
+pub mod snapshot;
 pub mod validation;
 
 use std::fs::{self, File};
 use std::io::{Read, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use jsonc_parser::parse_to_serde_value;
 
-pub use paths::{get_config_dir, get_config_path, get_data_dir, get_hosts_path, get_pid_path};
-pub use schema::{Config, validate_bind_address};
+pub use audit::{config_changes, record_config_changes};
+pub use effective::{ConfigOverrides, ConfigSource, EffectiveConfig, resolve_effective_config};
+pub use impact::{ChangeImpact, HOST_ONLY_KEYS, change_impact, is_host_only_key};
+pub use lint::{LintFinding, LintSeverity, has_high_severity, lint_config};
+pub use migrate::{CURRENT_CONFIG_VERSION, MigrationReport, migrate_config, migrate_config_file};
+pub use paths::{
+    get_audit_log_path, get_config_dir, get_config_path, get_data_dir, get_hosts_path, get_pid_path,
+};
+pub use schema::{
+    Config, MIN_MEMORY_LIMIT_MB, RESTART_DELAY_RANGE, RESTART_RETRIES_RANGE, clamp_to_range,
+    validate_base_path, validate_bind_address, validate_cpu_limit, validate_image_registry_auth,
+    validate_memory_limit_mb, validate_port,
+};
+pub use snapshot::{
+    ConfigSnapshot, create_config_snapshot, find_config_snapshot, list_config_snapshots,
+    restore_config_snapshot, snapshot_timestamp_now,
+};
 pub use validation::{
-    ValidationError, ValidationWarning, display_validation_error, display_validation_warning,
-    validate_config,
+    ValidationError, ValidationWarning, cockpit_users_warning, display_validation_error,
+    display_validation_warning, is_secret_env_key, parse_env_entry, parse_labeled_env_entry,
+    redact_env_entry, validate_config,
 };
 
 /// Ensure the config directory exists
@@ -59,10 +82,64 @@ pub fn ensure_data_dir() -> Result<PathBuf> {
     Ok(data_dir)
 }
 
+/// On-disk config file format, chosen by file extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    /// JSON with comments (`.json` / `.jsonc`)
+    Jsonc,
+    /// TOML (`.toml`)
+    Toml,
+}
+
+impl ConfigFormat {
+    /// Detect the format from a path's extension, defaulting to JSONC
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("toml") => ConfigFormat::Toml,
+            _ => ConfigFormat::Jsonc,
+        }
+    }
+}
+
+/// Parse config file contents into a raw value without migrating
+fn parse_config_value(contents: &str, format: ConfigFormat) -> Result<serde_json::Value> {
+    match format {
+        ConfigFormat::Jsonc => parse_to_serde_value(contents, &Default::default())
+            .map_err(|e| anyhow::anyhow!("Invalid JSONC in config file: {e}"))?
+            .ok_or_else(|| anyhow::anyhow!("Config file is empty")),
+        ConfigFormat::Toml => toml::from_str::<serde_json::Value>(contents)
+            .map_err(|e| anyhow::anyhow!("Invalid TOML in config file: {e}")),
+    }
+}
+
+/// Parse config file contents in the given format
+///
+/// The file is migrated to the current schema before deserializing; any
+/// remaining unknown key is rejected by `deny_unknown_fields`.
+pub fn parse_config(contents: &str, format: ConfigFormat) -> Result<Config> {
+    let mut parsed_value = parse_config_value(contents, format)?;
+    migrate_config(&mut parsed_value)?;
+
+    // Deserialize into Config struct (deny_unknown_fields will reject unknown keys)
+    Ok(serde_json::from_value(parsed_value)?)
+}
+
+/// Serialize config in the given format
+pub fn serialize_config(config: &Config, format: ConfigFormat) -> Result<String> {
+    match format {
+        ConfigFormat::Jsonc => {
+            serde_json::to_string_pretty(config).context("Failed to serialize configuration")
+        }
+        ConfigFormat::Toml => {
+            toml::to_string_pretty(config).context("Failed to serialize configuration as TOML")
+        }
+    }
+}
+
 /// Load configuration from the config file
 ///
 /// If the config file doesn't exist, creates a new one with default values.
-/// Supports JSONC (JSON with comments).
+/// Supports JSONC (JSON with comments) and TOML, chosen by file extension.
 /// Rejects unknown fields for strict validation.
 pub fn load_config() -> Result<Config> {
     let config_path =
@@ -87,53 +164,62 @@ pub fn load_config() -> Result<Config> {
     file.read_to_string(&mut contents)
         .with_context(|| format!("Failed to read config file: {}", config_path.display()))?;
 
-    // Parse JSONC (JSON with comments)
-    let mut parsed_value = parse_to_serde_value(&contents, &Default::default())
-        .map_err(|e| anyhow::anyhow!("Invalid JSONC in config file: {e}"))?
-        .ok_or_else(|| anyhow::anyhow!("Config file is empty"))?;
-
-    // Drop deprecated keys that were removed from the schema.
-    if let Some(obj) = parsed_value.as_object_mut() {
-        obj.remove("opencode_commit");
-    }
-
-    // Deserialize into Config struct (deny_unknown_fields will reject unknown keys)
-    let config: Config = serde_json::from_value(parsed_value).with_context(|| {
-        format!(
-            "Invalid configuration in {}. Check for unknown fields or invalid values.",
-            config_path.display()
-        )
-    })?;
+    let config =
+        parse_config(&contents, ConfigFormat::from_path(&config_path)).with_context(|| {
+            format!(
+                "Invalid configuration in {}. Check for unknown fields or invalid values.",
+                config_path.display()
+            )
+        })?;
 
     Ok(config)
 }
 
 /// Save configuration to the config file
 ///
-/// Creates a backup of the existing config (config.json.bak) before overwriting.
+/// Creates a backup of the existing config (e.g. config.json.bak) before overwriting.
+/// Writes in the format matching the existing file's extension.
 /// Ensures the config directory exists.
 pub fn save_config(config: &Config) -> Result<()> {
     ensure_config_dir()?;
 
     let config_path =
         get_config_path().ok_or_else(|| anyhow::anyhow!("Could not determine config file path"))?;
+    save_config_to(&config_path, config)
+}
 
-    // Create backup if file exists
+/// Copy the config file to its `.bak` sibling before it is replaced
+///
+/// Does nothing if there is no config file yet.
+pub(crate) fn backup_config_file(config_path: &Path) -> Result<()> {
     if config_path.exists() {
-        let backup_path = config_path.with_extension("json.bak");
-        fs::copy(&config_path, &backup_path)
+        let backup_extension = format!(
+            "{}.bak",
+            config_path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .unwrap_or("json")
+        );
+        let backup_path = config_path.with_extension(backup_extension);
+        fs::copy(config_path, &backup_path)
             .with_context(|| format!("Failed to create backup at: {}", backup_path.display()))?;
         tracing::debug!("Created config backup: {}", backup_path.display());
     }
+    Ok(())
+}
+
+/// Write `config` to `config_path`, backing up any existing file first
+fn save_config_to(config_path: &Path, config: &Config) -> Result<()> {
+    backup_config_file(config_path)?;
 
     // Serialize with pretty formatting
-    let json = serde_json::to_string_pretty(config).context("Failed to serialize configuration")?;
+    let contents = serialize_config(config, ConfigFormat::from_path(config_path))?;
 
     // Write to file
-    let mut file = File::create(&config_path)
+    let mut file = File::create(config_path)
         .with_context(|| format!("Failed to create config file: {}", config_path.display()))?;
 
-    file.write_all(json.as_bytes())
+    file.write_all(contents.as_bytes())
         .with_context(|| format!("Failed to write config file: {}", config_path.display()))?;
 
     tracing::debug!("Saved config to: {}", config_path.display());
@@ -169,6 +255,83 @@ mod tests {
         assert!(pid_path.ends_with("opencode-cloud.pid"));
     }
 
+    const EQUIVALENT_JSON: &str = r#"{
+        // comments are allowed in JSONC
+        "version": 1,
+        "opencode_web_port": 3100,
+        "bind_address": "0.0.0.0",
+        "users": ["admin"],
+        "container_env": ["FOO=bar"]
+    }"#;
+
+    const EQUIVALENT_TOML: &str = r#"
+        # comments are allowed in TOML too
+        version = 1
+        opencode_web_port = 3100
+        bind_address = "0.0.0.0"
+        users = ["admin"]
+        container_env = ["FOO=bar"]
+    "#;
+
+    #[test]
+    fn test_config_format_from_path() {
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("config.toml")),
+            ConfigFormat::Toml
+        );
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("config.jsonc")),
+            ConfigFormat::Jsonc
+        );
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("config.json")),
+            ConfigFormat::Jsonc
+        );
+    }
+
+    #[test]
+    fn test_toml_matches_equivalent_json() {
+        let from_json = parse_config(EQUIVALENT_JSON, ConfigFormat::Jsonc).unwrap();
+        let from_toml = parse_config(EQUIVALENT_TOML, ConfigFormat::Toml).unwrap();
+        assert_eq!(from_json, from_toml);
+
+        // Missing fields fall back to defaults in both formats
+        assert_eq!(from_toml.opencode_web_port, 3100);
+        assert_eq!(
+            from_toml.rate_limit_attempts,
+            Config::default().rate_limit_attempts
+        );
+        assert_eq!(from_toml.boot_mode, Config::default().boot_mode);
+    }
+
+    #[test]
+    fn test_toml_rejects_unknown_fields() {
+        let result = parse_config("version = 1\nnot_a_field = true\n", ConfigFormat::Toml);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_toml_drops_deprecated_keys() {
+        let config = parse_config(
+            "version = 1\nopencode_commit = \"abc\"\n",
+            ConfigFormat::Toml,
+        )
+        .unwrap();
+        assert_eq!(config.version, 1);
+    }
+
+    #[test]
+    fn test_toml_roundtrip() {
+        let config = Config {
+            auth_username: Some("admin".to_string()),
+            mounts: vec!["/tmp/a:/workspace/a".to_string()],
+            ..Config::default()
+        };
+        let toml = serialize_config(&config, ConfigFormat::Toml).unwrap();
+        let parsed = parse_config(&toml, ConfigFormat::Toml).unwrap();
+        assert_eq!(config, parsed);
+    }
+
     // Note: Integration tests for load_config/save_config that modify the real
     // filesystem are run via CLI commands rather than unit tests to avoid
     // test isolation issues with environment variable manipulation in Rust 2024.
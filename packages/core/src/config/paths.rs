@@ -4,7 +4,13 @@
 //! - Linux/macOS: ~/.config/opencode-cloud/ and ~/.local/share/opencode-cloud/
 //! - Windows: %APPDATA%\opencode-cloud\ and %LOCALAPPDATA%\opencode-cloud\
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+/// Config file names, checked in order when locating an existing config
+///
+/// `config.json` comes first so existing installs keep their file; new installs
+/// also default to it.
+const CONFIG_FILE_NAMES: [&str; 3] = ["config.json", "config.jsonc", "config.toml"];
 
 /// Get the configuration directory path
 ///
@@ -60,9 +66,19 @@ pub fn get_data_dir() -> Option<PathBuf> {
 
 /// Get the full path to the config file
 ///
-/// Returns: `{config_dir}/config.json`
+/// Returns the first of `config.json`, `config.jsonc` or `config.toml` that
+/// exists in the config directory, falling back to `{config_dir}/config.json`.
 pub fn get_config_path() -> Option<PathBuf> {
-    get_config_dir().map(|d| d.join("config.json"))
+    get_config_dir().map(|d| resolve_config_file(&d))
+}
+
+/// Pick the config file within `dir`, preferring files that already exist
+fn resolve_config_file(dir: &Path) -> PathBuf {
+    CONFIG_FILE_NAMES
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.exists())
+        .unwrap_or_else(|| dir.join(CONFIG_FILE_NAMES[0]))
 }
 
 /// Get the full path to the PID lock file
@@ -72,6 +88,27 @@ pub fn get_pid_path() -> Option<PathBuf> {
     get_data_dir().map(|d| d.join("opencode-cloud.pid"))
 }
 
+/// Get the full path to the config change audit log
+///
+/// Returns: `{data_dir}/config-audit.log`
+pub fn get_audit_log_path() -> Option<PathBuf> {
+    get_data_dir().map(|d| d.join("config-audit.log"))
+}
+
+/// Get the full path to the lifecycle event history
+///
+/// Returns: `{data_dir}/events.jsonl`
+pub fn get_events_path() -> Option<PathBuf> {
+    get_data_dir().map(|d| d.join("events.jsonl"))
+}
+
+/// Get the full path to the CLI update check cache
+///
+/// Returns: `{data_dir}/update-check.json`
+pub fn get_update_check_cache_path() -> Option<PathBuf> {
+    get_data_dir().map(|d| d.join("update-check.json"))
+}
+
 /// Get the full path to the hosts configuration file
 ///
 /// Returns: `{config_dir}/hosts.json`
@@ -106,6 +143,32 @@ mod tests {
         assert!(path.unwrap().ends_with("config.json"));
     }
 
+    #[test]
+    fn test_resolve_config_file_defaults_to_json() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(
+            resolve_config_file(dir.path()),
+            dir.path().join("config.json")
+        );
+    }
+
+    #[test]
+    fn test_resolve_config_file_finds_toml() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("config.toml"), "version = 1\n").unwrap();
+        assert_eq!(
+            resolve_config_file(dir.path()),
+            dir.path().join("config.toml")
+        );
+
+        // An existing JSON config still takes precedence
+        std::fs::write(dir.path().join("config.json"), "{}").unwrap();
+        assert_eq!(
+            resolve_config_file(dir.path()),
+            dir.path().join("config.json")
+        );
+    }
+
     #[test]
     fn test_pid_path_ends_with_pid() {
         let path = get_pid_path();
@@ -4,6 +4,8 @@
 
 use serde::{Deserialize, Serialize};
 use std::net::{IpAddr, Ipv4Addr};
+use std::ops::RangeInclusive;
+
 /// Main configuration structure for opencode-cloud
 ///
 /// Serialized to/from `~/.config/opencode-cloud/config.json`
@@ -111,7 +113,9 @@ pub struct Config {
     #[serde(default = "default_image_source")]
     pub image_source: String,
 
-    /// When to check for updates: 'always' (every start), 'once' (once per version), 'never'
+    /// When to check for updates: 'always' (every start), 'daily', 'weekly', 'once' (once per version), 'never'
+    ///
+    /// Also controls how often the CLI queries GitHub for a newer release.
     #[serde(default = "default_update_check")]
     pub update_check: String,
 
@@ -119,6 +123,69 @@ pub struct Config {
     /// Format: ["/host/path:/container/path", "/host:/mnt:ro"]
     #[serde(default)]
     pub mounts: Vec<String>,
+
+    /// Inject a Docker healthcheck into the container (default: true)
+    ///
+    /// Probes the opencode web UI from inside the container so `occ status`
+    /// reports real health even when the image defines no HEALTHCHECK.
+    #[serde(default = "default_health_check_enabled")]
+    pub health_check_enabled: bool,
+
+    /// Seconds between health check probes (default: 30)
+    #[serde(default = "default_health_check_interval")]
+    pub health_check_interval: u32,
+
+    /// Seconds before a single health check probe is considered failed (default: 5)
+    #[serde(default = "default_health_check_timeout")]
+    pub health_check_timeout: u32,
+
+    /// Consecutive failed probes before the container is marked unhealthy (default: 3)
+    #[serde(default = "default_health_check_retries")]
+    pub health_check_retries: u32,
+
+    /// Append config changes to config-audit.log in the data dir (default: false)
+    ///
+    /// Secrets are masked. Logging is best-effort and never blocks a change.
+    #[serde(default)]
+    pub audit_log_enabled: bool,
+
+    /// Timezone for the container, injected as TZ (e.g. "Europe/Berlin")
+    /// Use "auto" to detect the host timezone; unset leaves the container on UTC
+    #[serde(default)]
+    pub timezone: Option<String>,
+
+    /// Locale for the container, injected as LANG and LC_ALL (e.g. "en_US.UTF-8")
+    #[serde(default)]
+    pub locale: Option<String>,
+
+    /// Memory limit for the container in megabytes (unset = no limit)
+    /// Overridable for a single run with `occ start --memory`
+    #[serde(default)]
+    pub memory_limit_mb: Option<u64>,
+
+    /// CPU limit for the container in cores, e.g. 1.5 (unset = no limit)
+    /// Overridable for a single run with `occ start --cpus`
+    #[serde(default)]
+    pub cpu_limit: Option<f64>,
+
+    /// Credentials for pulling from a private registry, as "registry=username:password"
+    /// Only sent to that registry; ~/.docker/config.json logins are used otherwise
+    #[serde(default)]
+    pub image_registry_auth: Option<String>,
+
+    /// Message of the day shown to sandbox users, injected as OPENCODE_MOTD
+    #[serde(default)]
+    pub motd: Option<String>,
+
+    /// Seconds `occ start` waits for the service to become ready
+    /// (unset = 60s when reusing the image, 180s after pulling or building it)
+    #[serde(default)]
+    pub startup_timeout_seconds: Option<u32>,
+
+    /// Path prefix when served behind a reverse proxy (e.g. "/opencode"),
+    /// injected as OPENCODE_BASE_PATH
+    #[serde(default)]
+    pub base_path: Option<String>,
 }
 
 fn default_opencode_web_port() -> u16 {
@@ -173,6 +240,22 @@ fn default_update_check() -> String {
     "always".to_string()
 }
 
+fn default_health_check_enabled() -> bool {
+    true
+}
+
+fn default_health_check_interval() -> u32 {
+    30
+}
+
+fn default_health_check_timeout() -> u32 {
+    5
+}
+
+fn default_health_check_retries() -> u32 {
+    3
+}
+
 /// Validate and parse a bind address string
 ///
 /// Accepts:
@@ -202,6 +285,86 @@ pub fn validate_bind_address(addr: &str) -> Result<IpAddr, String> {
     })
 }
 
+/// Validate and parse a host port string
+///
+/// Accepts 1-65535. Port 0 is rejected: Docker would pick a random port,
+/// which breaks the URL display and readiness checks that assume a fixed port.
+pub fn validate_port(port: &str) -> Result<u16, String> {
+    match port.trim().parse::<u16>() {
+        Ok(0) => {
+            Err("Port 0 (random port) is not supported. Use a port between 1-65535.".to_string())
+        }
+        Ok(port) => Ok(port),
+        Err(_) => Err(format!(
+            "Invalid port number: {port}. Must be a number between 1-65535."
+        )),
+    }
+}
+
+/// Smallest memory limit Docker accepts, in megabytes
+pub const MIN_MEMORY_LIMIT_MB: u64 = 6;
+
+/// Validate and parse a container memory limit in megabytes
+///
+/// Docker rejects limits below 6 MB, so those are caught here instead of at
+/// container creation.
+pub fn validate_memory_limit_mb(value: &str) -> Result<u64, String> {
+    match value.trim().parse::<u64>() {
+        Ok(mb) if mb >= MIN_MEMORY_LIMIT_MB => Ok(mb),
+        _ => Err(format!(
+            "Invalid memory limit: {value}. Must be a whole number of megabytes (at least {MIN_MEMORY_LIMIT_MB})."
+        )),
+    }
+}
+
+/// Validate a reverse-proxy base path (e.g. "/opencode")
+///
+/// Must start with `/` and contain no whitespace.
+pub fn validate_base_path(value: &str) -> Result<String, String> {
+    let path = value.trim();
+    if !path.starts_with('/') {
+        return Err(format!(
+            "Invalid base path: {value}. Must start with '/' (e.g. /opencode)."
+        ));
+    }
+    if path.chars().any(char::is_whitespace) {
+        return Err(format!(
+            "Invalid base path: {value}. Must not contain spaces."
+        ));
+    }
+    Ok(path.to_string())
+}
+
+/// Validate and parse a container CPU limit in cores (e.g. "0.5", "2")
+pub fn validate_cpu_limit(value: &str) -> Result<f64, String> {
+    match value.trim().parse::<f64>() {
+        Ok(cpus) if cpus.is_finite() && cpus >= 0.01 => Ok(cpus),
+        _ => Err(format!(
+            "Invalid CPU limit: {value}. Must be a number of cores of at least 0.01 (e.g. 0.5, 2)."
+        )),
+    }
+}
+
+/// Accepted `restart_retries` values
+///
+/// 0 would make systemd give up immediately; very large counts turn a crash
+/// loop into an endless one.
+pub const RESTART_RETRIES_RANGE: RangeInclusive<u32> = 1..=50;
+
+/// Accepted `restart_delay` values, in seconds
+pub const RESTART_DELAY_RANGE: RangeInclusive<u32> = 1..=300;
+
+/// Clamp `value` into `range`, returning the result and whether it changed
+pub fn clamp_to_range(value: u32, range: &RangeInclusive<u32>) -> (u32, bool) {
+    let clamped = value.clamp(*range.start(), *range.end());
+    (clamped, clamped != value)
+}
+
+/// Validate an `image_registry_auth` value ("registry=username:password")
+pub fn validate_image_registry_auth(value: &str) -> Result<(), String> {
+    crate::docker::RegistryLogin::parse(value).map(|_| ())
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
@@ -226,6 +389,19 @@ impl Default for Config {
             image_source: default_image_source(),
             update_check: default_update_check(),
             mounts: Vec::new(),
+            health_check_enabled: default_health_check_enabled(),
+            health_check_interval: default_health_check_interval(),
+            health_check_timeout: default_health_check_timeout(),
+            health_check_retries: default_health_check_retries(),
+            audit_log_enabled: false,
+            timezone: None,
+            locale: None,
+            memory_limit_mb: None,
+            cpu_limit: None,
+            image_registry_auth: None,
+            motd: None,
+            startup_timeout_seconds: None,
+            base_path: None,
         }
     }
 }
@@ -259,29 +435,29 @@ impl Config {
         }
     }
 
+    /// Resolve the canonical bind IP from `bind_address`
+    ///
+    /// This is the single source of truth for exposure checks and the
+    /// container's host port binding ("localhost" resolves to 127.0.0.1,
+    /// brackets around IPv6 addresses are stripped).
+    pub fn effective_bind_ip(&self) -> Result<IpAddr, String> {
+        validate_bind_address(&self.bind_address)
+    }
+
     /// Check if the bind address exposes the service to the network
     ///
-    /// Returns true if bind_address is "0.0.0.0" (IPv4 all interfaces) or
-    /// "::" (IPv6 all interfaces).
+    /// Returns true if the effective bind IP is unspecified ("0.0.0.0" or "::").
+    /// Invalid addresses are not considered exposed.
     pub fn is_network_exposed(&self) -> bool {
-        match validate_bind_address(&self.bind_address) {
-            Ok(IpAddr::V4(ip)) => ip.is_unspecified(),
-            Ok(IpAddr::V6(ip)) => ip.is_unspecified(),
-            Err(_) => false, // Invalid addresses are not considered exposed
-        }
+        self.effective_bind_ip().is_ok_and(|ip| ip.is_unspecified())
     }
 
     /// Check if the bind address is localhost-only
     ///
-    /// Returns true if bind_address is "127.0.0.1", "::1", or "localhost".
+    /// Returns true if the effective bind IP is a loopback address
+    /// ("127.0.0.1", "::1", or "localhost").
     pub fn is_localhost(&self) -> bool {
-        match validate_bind_address(&self.bind_address) {
-            Ok(ip) => ip.is_loopback(),
-            Err(_) => {
-                // Also check for "localhost" string directly
-                self.bind_address.eq_ignore_ascii_case("localhost")
-            }
-        }
+        self.effective_bind_ip().is_ok_and(|ip| ip.is_loopback())
     }
 }
 
@@ -310,6 +486,10 @@ mod tests {
         assert_eq!(config.rate_limit_window_seconds, 60);
         assert!(config.users.is_empty());
         assert!(config.mounts.is_empty());
+        assert!(config.health_check_enabled);
+        assert_eq!(config.health_check_interval, 30);
+        assert_eq!(config.health_check_timeout, 5);
+        assert_eq!(config.health_check_retries, 3);
     }
 
     #[test]
@@ -367,6 +547,19 @@ mod tests {
             image_source: default_image_source(),
             update_check: default_update_check(),
             mounts: Vec::new(),
+            health_check_enabled: false,
+            health_check_interval: 60,
+            health_check_timeout: 10,
+            health_check_retries: 5,
+            audit_log_enabled: true,
+            timezone: Some("auto".to_string()),
+            locale: Some("en_US.UTF-8".to_string()),
+            memory_limit_mb: Some(2048),
+            cpu_limit: Some(1.5),
+            image_registry_auth: Some("ghcr.io=bot:token".to_string()),
+            motd: Some("Maintenance tonight".to_string()),
+            startup_timeout_seconds: Some(300),
+            base_path: Some("/opencode".to_string()),
         };
         let json = serde_json::to_string(&config).unwrap();
         let parsed: Config = serde_json::from_str(&json).unwrap();
@@ -378,6 +571,8 @@ mod tests {
         assert!(parsed.trust_proxy);
         assert_eq!(parsed.rate_limit_attempts, 10);
         assert_eq!(parsed.users, vec!["admin"]);
+        assert!(!parsed.health_check_enabled);
+        assert_eq!(parsed.health_check_interval, 60);
     }
 
     #[test]
@@ -562,6 +757,88 @@ mod tests {
         assert!(!config.is_network_exposed());
     }
 
+    #[test]
+    fn test_effective_bind_ip_matches_exposure_checks() {
+        let cases = [
+            ("127.0.0.1", false, true),
+            ("localhost", false, true),
+            ("::1", false, true),
+            ("[::1]", false, true),
+            ("0.0.0.0", true, false),
+            ("::", true, false),
+            ("192.168.1.100", false, false),
+        ];
+        for (addr, exposed, localhost) in cases {
+            let config = Config {
+                bind_address: addr.to_string(),
+                ..Config::default()
+            };
+            let ip = config.effective_bind_ip().unwrap();
+            assert_eq!(ip.is_unspecified(), exposed, "{addr}");
+            assert_eq!(ip.is_loopback(), localhost, "{addr}");
+            assert_eq!(config.is_network_exposed(), exposed, "{addr}");
+            assert_eq!(config.is_localhost(), localhost, "{addr}");
+        }
+    }
+
+    #[test]
+    fn test_effective_bind_ip_invalid() {
+        let config = Config {
+            bind_address: "not-an-ip".to_string(),
+            ..Config::default()
+        };
+        assert!(config.effective_bind_ip().is_err());
+        assert!(!config.is_network_exposed());
+        assert!(!config.is_localhost());
+    }
+
+    #[test]
+    fn test_validate_port_rejects_zero() {
+        let err = validate_port("0").unwrap_err();
+        assert!(err.contains("not supported"));
+    }
+
+    #[test]
+    fn test_validate_port_range() {
+        assert_eq!(validate_port("1"), Ok(1));
+        assert_eq!(validate_port(" 3000 "), Ok(3000));
+        assert_eq!(validate_port("65535"), Ok(65535));
+        assert!(validate_port("65536").is_err());
+        assert!(validate_port("-1").is_err());
+        assert!(validate_port("http").is_err());
+    }
+
+    #[test]
+    fn test_validate_base_path() {
+        assert_eq!(
+            validate_base_path(" /opencode "),
+            Ok("/opencode".to_string())
+        );
+        assert_eq!(validate_base_path("/a/b/"), Ok("/a/b/".to_string()));
+        assert!(validate_base_path("opencode").is_err());
+        assert!(validate_base_path("/open code").is_err());
+        assert!(validate_base_path("").is_err());
+    }
+
+    #[test]
+    fn test_validate_memory_limit_mb() {
+        assert_eq!(validate_memory_limit_mb(" 2048 "), Ok(2048));
+        assert_eq!(validate_memory_limit_mb("6"), Ok(6));
+        assert!(validate_memory_limit_mb("5").is_err());
+        assert!(validate_memory_limit_mb("1.5").is_err());
+        assert!(validate_memory_limit_mb("2g").is_err());
+    }
+
+    #[test]
+    fn test_validate_cpu_limit() {
+        assert_eq!(validate_cpu_limit("1.5"), Ok(1.5));
+        assert_eq!(validate_cpu_limit("2"), Ok(2.0));
+        assert!(validate_cpu_limit("0").is_err());
+        assert!(validate_cpu_limit("-1").is_err());
+        assert!(validate_cpu_limit("inf").is_err());
+        assert!(validate_cpu_limit("NaN").is_err());
+    }
+
     // Tests for is_localhost
 
     #[test]
@@ -719,4 +996,14 @@ mod tests {
         let config: Config = serde_json::from_str(json).unwrap();
         assert!(config.mounts.is_empty());
     }
+
+    #[test]
+    fn test_restart_settings_clamped_to_range() {
+        assert_eq!(clamp_to_range(0, &RESTART_RETRIES_RANGE), (1, true));
+        assert_eq!(clamp_to_range(3, &RESTART_RETRIES_RANGE), (3, false));
+        assert_eq!(clamp_to_range(50, &RESTART_RETRIES_RANGE), (50, false));
+        assert_eq!(clamp_to_range(1000, &RESTART_RETRIES_RANGE), (50, true));
+        assert_eq!(clamp_to_range(u32::MAX, &RESTART_DELAY_RANGE), (300, true));
+        assert_eq!(clamp_to_range(0, &RESTART_DELAY_RANGE), (1, true));
+    }
 }
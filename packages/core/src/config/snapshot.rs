@@ -0,0 +1,393 @@
+//! Timestamped config snapshots
+//!
+//! `save_config` keeps a single `.bak` that the next save overwrites.
+//! Snapshots are explicit, timestamped copies of the config file kept next
+//! to it (e.g. `config.20260301-142530.json`), giving `occ config backup`
+//! and `occ config restore` a real history to choose from. Two snapshots
+//! taken within the same second get a numeric suffix
+//! (`config.20260301-142530-2.json`) instead of overwriting each other.
+
+use std::fs::{self, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use chrono::NaiveDateTime;
+
+use super::schema::Config;
+use super::{ConfigFormat, backup_config_file, parse_config, serialize_config};
+
+/// chrono format of the timestamp embedded in snapshot file names
+pub const SNAPSHOT_TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S";
+
+/// A snapshot file found next to the config file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigSnapshot {
+    /// Timestamp from the file name, e.g. "20260301-142530", including any
+    /// same-second suffix ("20260301-142530-2")
+    pub timestamp: String,
+    /// Full path to the snapshot file
+    pub path: PathBuf,
+}
+
+/// Snapshot file name for `config_path` taken at `timestamp`
+///
+/// Keeps the config file's stem and extension: `config.toml` becomes
+/// `config.20260301-142530.toml`.
+pub fn snapshot_file_name(config_path: &Path, timestamp: &str) -> String {
+    let stem = config_stem(config_path);
+    let ext = config_path
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("json");
+    format!("{stem}.{timestamp}.{ext}")
+}
+
+fn config_stem(config_path: &Path) -> &str {
+    config_path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("config")
+}
+
+/// Extract the timestamp from a snapshot file name, if it is one
+///
+/// `stem` is the config file's stem, so only snapshots of that file match.
+fn parse_snapshot_timestamp<'a>(file_name: &'a str, stem: &str) -> Option<&'a str> {
+    let rest = file_name.strip_prefix(stem)?.strip_prefix('.')?;
+    let (timestamp, ext) = rest.split_once('.')?;
+    if !matches!(ext, "json" | "jsonc" | "toml") {
+        return None;
+    }
+    let (time, _) = split_snapshot_suffix(timestamp)?;
+    NaiveDateTime::parse_from_str(time, SNAPSHOT_TIMESTAMP_FORMAT).ok()?;
+    Some(timestamp)
+}
+
+/// Split "20260301-142530-2" into its time and same-second sequence number
+///
+/// A timestamp without a suffix is the first snapshot of that second.
+fn split_snapshot_suffix(timestamp: &str) -> Option<(&str, u32)> {
+    match timestamp.rsplit_once('-') {
+        Some((time, seq)) if time.contains('-') => {
+            let seq = seq.parse().ok().filter(|n| *n >= 2)?;
+            Some((time, seq))
+        }
+        _ => Some((timestamp, 1)),
+    }
+}
+
+/// Copy the config file at `config_path` to a new snapshot stamped `timestamp`
+///
+/// Returns the snapshot path. An existing snapshot is never overwritten: if
+/// one already has this timestamp, a `-2`, `-3`, ... suffix is added. Fails
+/// if there is no config file to back up.
+pub fn create_config_snapshot(config_path: &Path, timestamp: &str) -> Result<PathBuf> {
+    let mut source = fs::File::open(config_path)
+        .with_context(|| format!("No config file at {}", config_path.display()))?;
+
+    for seq in 1u32.. {
+        let stamped = if seq == 1 {
+            timestamp.to_string()
+        } else {
+            format!("{timestamp}-{seq}")
+        };
+        let snapshot_path = config_path.with_file_name(snapshot_file_name(config_path, &stamped));
+        let mut target = match OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&snapshot_path)
+        {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => {
+                return Err(e).with_context(|| {
+                    format!("Failed to write snapshot: {}", snapshot_path.display())
+                });
+            }
+        };
+        io::copy(&mut source, &mut target)
+            .with_context(|| format!("Failed to write snapshot: {}", snapshot_path.display()))?;
+        tracing::debug!("Created config snapshot: {}", snapshot_path.display());
+        return Ok(snapshot_path);
+    }
+    bail!("No free snapshot name for timestamp {timestamp}")
+}
+
+/// Snapshot timestamp for the current local time
+pub fn snapshot_timestamp_now() -> String {
+    chrono::Local::now()
+        .format(SNAPSHOT_TIMESTAMP_FORMAT)
+        .to_string()
+}
+
+/// List snapshots of the config file at `config_path`, oldest first
+pub fn list_config_snapshots(config_path: &Path) -> Result<Vec<ConfigSnapshot>> {
+    let Some(config_dir) = config_path.parent() else {
+        return Ok(Vec::new());
+    };
+    let stem = config_stem(config_path);
+    if !config_dir.exists() {
+        return Ok(Vec::new());
+    }
+    let entries = fs::read_dir(config_dir)
+        .with_context(|| format!("Failed to read {}", config_dir.display()))?;
+
+    let mut snapshots: Vec<ConfigSnapshot> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name();
+            let timestamp = parse_snapshot_timestamp(name.to_str()?, stem)?;
+            Some(ConfigSnapshot {
+                timestamp: timestamp.to_string(),
+                path: entry.path(),
+            })
+        })
+        .collect();
+    snapshots.sort_by(|a, b| sort_key(a).cmp(&sort_key(b)).then(a.path.cmp(&b.path)));
+    Ok(snapshots)
+}
+
+/// Order snapshots by time, then by same-second sequence number
+fn sort_key(snapshot: &ConfigSnapshot) -> (&str, u32) {
+    split_snapshot_suffix(&snapshot.timestamp).unwrap_or_default()
+}
+
+/// Pick the snapshot matching `timestamp`
+///
+/// Accepts the full timestamp or a unique prefix of it (e.g. "20260301"),
+/// and "latest" for the newest snapshot.
+pub fn find_config_snapshot<'a>(
+    snapshots: &'a [ConfigSnapshot],
+    timestamp: &str,
+) -> Result<&'a ConfigSnapshot, String> {
+    if timestamp == "latest" {
+        return snapshots
+            .last()
+            .ok_or_else(|| "No config snapshots found".to_string());
+    }
+    if let Some(exact) = snapshots.iter().find(|s| s.timestamp == timestamp) {
+        return Ok(exact);
+    }
+
+    let matches: Vec<_> = snapshots
+        .iter()
+        .filter(|s| s.timestamp.starts_with(timestamp))
+        .collect();
+    match matches.as_slice() {
+        [only] => Ok(only),
+        [] => Err(format!("No config snapshot matches '{timestamp}'")),
+        _ => Err(format!(
+            "'{timestamp}' matches {} snapshots; use the full timestamp",
+            matches.len()
+        )),
+    }
+}
+
+/// Replace the config file at `config_path` with `snapshot`
+///
+/// The snapshot must parse as a valid config before anything is written.
+/// A snapshot in another format (e.g. a `.json` snapshot restored over
+/// `config.toml`) is converted. The file being replaced is kept as a `.bak`,
+/// as `save_config` does. Returns the restored config.
+pub fn restore_config_snapshot(config_path: &Path, snapshot: &ConfigSnapshot) -> Result<Config> {
+    let contents = fs::read_to_string(&snapshot.path)
+        .with_context(|| format!("Failed to read snapshot: {}", snapshot.path.display()))?;
+    let snapshot_format = ConfigFormat::from_path(&snapshot.path);
+    let config = parse_config(&contents, snapshot_format).with_context(|| {
+        format!(
+            "Snapshot {} is not a valid configuration",
+            snapshot.path.display()
+        )
+    })?;
+
+    let target_format = ConfigFormat::from_path(config_path);
+    let contents = if target_format == snapshot_format {
+        contents
+    } else {
+        serialize_config(&config, target_format)?
+    };
+    backup_config_file(config_path)?;
+    fs::write(config_path, contents)
+        .with_context(|| format!("Failed to write config file: {}", config_path.display()))?;
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(timestamp: &str) -> ConfigSnapshot {
+        ConfigSnapshot {
+            timestamp: timestamp.to_string(),
+            path: PathBuf::from(format!("config.{timestamp}.json")),
+        }
+    }
+
+    #[test]
+    fn snapshot_names_keep_extension() {
+        assert_eq!(
+            snapshot_file_name(Path::new("/c/config.json"), "20260301-142530"),
+            "config.20260301-142530.json"
+        );
+        assert_eq!(
+            snapshot_file_name(Path::new("/c/config.toml"), "20260301-142530"),
+            "config.20260301-142530.toml"
+        );
+        assert_eq!(snapshot_timestamp_now().len(), "20260301-142530".len());
+    }
+
+    #[test]
+    fn only_snapshot_names_are_recognized() {
+        let parse = |name| parse_snapshot_timestamp(name, "config");
+        assert_eq!(
+            parse("config.20260301-142530.json"),
+            Some("20260301-142530")
+        );
+        assert_eq!(
+            parse("config.20260301-142530-2.json"),
+            Some("20260301-142530-2")
+        );
+        assert_eq!(parse("config.json"), None);
+        assert_eq!(parse("config.json.bak"), None);
+        assert_eq!(parse("config.20261341-000000.json"), None);
+        assert_eq!(parse("config.20260301-142530.txt"), None);
+        assert_eq!(parse("config.20260301-142530-x.json"), None);
+        assert_eq!(parse("other.20260301-142530.json"), None);
+        assert_eq!(
+            parse_snapshot_timestamp("settings.20260301-142530.toml", "settings"),
+            Some("20260301-142530")
+        );
+    }
+
+    #[test]
+    fn list_is_sorted_oldest_first() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in [
+            "config.20260302-090000.json",
+            "config.20260301-142530-2.json",
+            "config.20260301-142530.json",
+            "config.json",
+            "config.json.bak",
+        ] {
+            fs::write(dir.path().join(name), "{}").unwrap();
+        }
+        let timestamps: Vec<_> = list_config_snapshots(&dir.path().join("config.json"))
+            .unwrap()
+            .into_iter()
+            .map(|s| s.timestamp)
+            .collect();
+        assert_eq!(
+            timestamps,
+            vec!["20260301-142530", "20260301-142530-2", "20260302-090000"]
+        );
+    }
+
+    #[test]
+    fn find_selects_exact_prefix_and_latest() {
+        let snapshots = vec![
+            snapshot("20260301-142530"),
+            snapshot("20260301-180000"),
+            snapshot("20260302-090000"),
+        ];
+        assert_eq!(
+            find_config_snapshot(&snapshots, "20260301-180000")
+                .unwrap()
+                .timestamp,
+            "20260301-180000"
+        );
+        assert_eq!(
+            find_config_snapshot(&snapshots, "20260302")
+                .unwrap()
+                .timestamp,
+            "20260302-090000"
+        );
+        assert_eq!(
+            find_config_snapshot(&snapshots, "latest")
+                .unwrap()
+                .timestamp,
+            "20260302-090000"
+        );
+        assert!(find_config_snapshot(&snapshots, "20260301").is_err());
+        assert!(find_config_snapshot(&snapshots, "2025").is_err());
+        assert!(find_config_snapshot(&[], "latest").is_err());
+    }
+
+    #[test]
+    fn backup_and_restore_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir.path().join("config.json");
+        let original = Config {
+            opencode_web_port: 4100,
+            ..Config::default()
+        };
+        fs::write(
+            &config_path,
+            serialize_config(&original, ConfigFormat::Jsonc).unwrap(),
+        )
+        .unwrap();
+
+        create_config_snapshot(&config_path, "20260301-142530").unwrap();
+        fs::write(
+            &config_path,
+            serialize_config(&Config::default(), ConfigFormat::Jsonc).unwrap(),
+        )
+        .unwrap();
+
+        let replaced = fs::read_to_string(&config_path).unwrap();
+
+        let snapshots = list_config_snapshots(&config_path).unwrap();
+        let chosen = find_config_snapshot(&snapshots, "20260301").unwrap();
+        let restored = restore_config_snapshot(&config_path, chosen).unwrap();
+        assert_eq!(restored, original);
+        let on_disk = fs::read_to_string(&config_path).unwrap();
+        assert_eq!(
+            parse_config(&on_disk, ConfigFormat::Jsonc).unwrap(),
+            original
+        );
+        assert_eq!(
+            fs::read_to_string(dir.path().join("config.json.bak")).unwrap(),
+            replaced
+        );
+    }
+
+    #[test]
+    fn same_second_snapshots_do_not_overwrite() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir.path().join("config.json");
+        fs::write(&config_path, "{ \"opencode_web_port\": 4100 }").unwrap();
+        let first = create_config_snapshot(&config_path, "20260301-142530").unwrap();
+
+        // Backup, then restore (which snapshots the current config) in the same second
+        fs::write(&config_path, "{ \"opencode_web_port\": 4200 }").unwrap();
+        let second = create_config_snapshot(&config_path, "20260301-142530").unwrap();
+
+        assert_ne!(first, second);
+        assert!(fs::read_to_string(&first).unwrap().contains("4100"));
+        assert!(fs::read_to_string(&second).unwrap().contains("4200"));
+
+        let snapshots = list_config_snapshots(&config_path).unwrap();
+        assert_eq!(snapshots.len(), 2);
+        let latest = find_config_snapshot(&snapshots, "latest").unwrap();
+        assert_eq!(latest.path, second);
+        assert_eq!(
+            find_config_snapshot(&snapshots, "20260301-142530")
+                .unwrap()
+                .path,
+            first
+        );
+    }
+
+    #[test]
+    fn invalid_snapshot_is_not_restored() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir.path().join("config.json");
+        fs::write(&config_path, "{}").unwrap();
+        let bad = dir.path().join("config.20260301-142530.json");
+        fs::write(&bad, r#"{ "not_a_field": 1 }"#).unwrap();
+
+        let snapshots = list_config_snapshots(&config_path).unwrap();
+        assert!(restore_config_snapshot(&config_path, &snapshots[0]).is_err());
+        assert_eq!(fs::read_to_string(&config_path).unwrap(), "{}");
+    }
+}
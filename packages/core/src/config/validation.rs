@@ -2,7 +2,10 @@
 //!
 //! Validates the configuration and provides exact commands to fix issues.
 
-use super::schema::{Config, validate_bind_address};
+use super::schema::{
+    Config, validate_base_path, validate_bind_address, validate_cpu_limit,
+    validate_image_registry_auth, validate_memory_limit_mb,
+};
 use console::style;
 
 /// A configuration validation error with an actionable fix command
@@ -36,16 +39,6 @@ pub struct ValidationWarning {
 pub fn validate_config(config: &Config) -> Result<Vec<ValidationWarning>, ValidationError> {
     let mut warnings = Vec::new();
 
-    // Port validation
-    if config.opencode_web_port < 1024 {
-        return Err(ValidationError {
-            field: "opencode_web_port".to_string(),
-            message: "Port must be >= 1024 (non-privileged)".to_string(),
-            fix_command: "occ config set opencode_web_port 3000".to_string(),
-        });
-    }
-    // Note: No need to check > 65535 - u16 type enforces this limit
-
     // Bind address validation
     if let Err(msg) = validate_bind_address(&config.bind_address) {
         return Err(ValidationError {
@@ -81,8 +74,112 @@ pub fn validate_config(config: &Config) -> Result<Vec<ValidationWarning>, Valida
         });
     }
 
+    // Health check validation (only relevant when the check is injected)
+    if config.health_check_enabled {
+        if config.health_check_interval == 0 {
+            return Err(ValidationError {
+                field: "health_check_interval".to_string(),
+                message: "health_check_interval must be > 0".to_string(),
+                fix_command: "occ config set health_check_interval 30".to_string(),
+            });
+        }
+
+        if config.health_check_timeout == 0 {
+            return Err(ValidationError {
+                field: "health_check_timeout".to_string(),
+                message: "health_check_timeout must be > 0".to_string(),
+                fix_command: "occ config set health_check_timeout 5".to_string(),
+            });
+        }
+
+        if config.health_check_retries == 0 {
+            return Err(ValidationError {
+                field: "health_check_retries".to_string(),
+                message: "health_check_retries must be > 0".to_string(),
+                fix_command: "occ config set health_check_retries 3".to_string(),
+            });
+        }
+    }
+
+    // Resource limits (hand-edited files bypass `occ config set` validation)
+    if let Some(mb) = config.memory_limit_mb {
+        if let Err(msg) = validate_memory_limit_mb(&mb.to_string()) {
+            return Err(ValidationError {
+                field: "memory_limit_mb".to_string(),
+                message: msg,
+                fix_command: "occ config set memory_limit_mb --unset".to_string(),
+            });
+        }
+    }
+
+    if let Some(cpus) = config.cpu_limit {
+        if let Err(msg) = validate_cpu_limit(&cpus.to_string()) {
+            return Err(ValidationError {
+                field: "cpu_limit".to_string(),
+                message: msg,
+                fix_command: "occ config set cpu_limit --unset".to_string(),
+            });
+        }
+    }
+
+    if let Some(auth) = &config.image_registry_auth {
+        if let Err(msg) = validate_image_registry_auth(auth) {
+            return Err(ValidationError {
+                field: "image_registry_auth".to_string(),
+                message: msg,
+                fix_command: "occ config set image_registry_auth registry=username:password"
+                    .to_string(),
+            });
+        }
+    }
+
+    if let Some(path) = &config.base_path {
+        if let Err(msg) = validate_base_path(path) {
+            return Err(ValidationError {
+                field: "base_path".to_string(),
+                message: msg,
+                fix_command: "occ config set base_path --unset".to_string(),
+            });
+        }
+    }
+
+    // container_env entries must be KEY=value with a valid variable name
+    for entry in &config.container_env {
+        if let Err(msg) = parse_env_entry(entry) {
+            return Err(ValidationError {
+                field: "container_env".to_string(),
+                message: msg,
+                fix_command: format!("occ config env remove '{entry}'"),
+            });
+        }
+    }
+
     // Warnings (non-fatal)
 
+    // Privileged ports are allowed but may need root/admin to bind
+    // Note: No need to check > 65535 - u16 type enforces this limit
+    if config.opencode_web_port < 1024 {
+        warnings.push(ValidationWarning {
+            field: "opencode_web_port".to_string(),
+            message: format!(
+                "Port {} is privileged (<1024) and may require root/admin to bind",
+                config.opencode_web_port
+            ),
+            fix_command: "occ config set opencode_web_port 3000".to_string(),
+        });
+    }
+
+    if config.cockpit_enabled && config.cockpit_port < 1024 {
+        warnings.push(ValidationWarning {
+            field: "cockpit_port".to_string(),
+            message: format!(
+                "Cockpit port {} is privileged (<1024) and may require root/admin to bind",
+                config.cockpit_port
+            ),
+            fix_command: "occ config set cockpit_port 9090".to_string(),
+        });
+    }
+
     // Network exposure without auth
     if config.is_network_exposed()
         && config.users.is_empty()
@@ -95,6 +192,31 @@ pub fn validate_config(config: &Config) -> Result<Vec<ValidationWarning>, Valida
         });
     }
 
+    // Trusting proxy headers while local-only is usually a leftover setting
+    if config.trust_proxy && config.is_localhost() {
+        warnings.push(ValidationWarning {
+            field: "trust_proxy".to_string(),
+            message: "trust_proxy is enabled but the service is bound to localhost; \
+                      disable it unless a reverse proxy runs on this host"
+                .to_string(),
+            fix_command: "occ config set trust_proxy false".to_string(),
+        });
+    }
+
+    // Network exposed without trusting a proxy: client IPs become the proxy's IP
+    if config.is_network_exposed() && !config.trust_proxy {
+        warnings.push(ValidationWarning {
+            field: "trust_proxy".to_string(),
+            message: "Network exposed with trust_proxy disabled; if a reverse proxy is in \
+                      front, client IPs will not be preserved"
+                .to_string(),
+            fix_command: "occ config set trust_proxy true".to_string(),
+        });
+    }
+
+    // Cockpit logs in with container users, so it is unusable without one
+    warnings.extend(cockpit_users_warning(config));
+
     // Legacy auth fields present
     if let Some(ref username) = config.auth_username {
         if !username.is_empty() {
@@ -118,9 +240,95 @@ pub fn validate_config(config: &Config) -> Result<Vec<ValidationWarning>, Valida
         }
     }
 
+    // Duplicate container_env keys: Docker keeps the last value
+    for key in duplicate_env_keys(&config.container_env) {
+        warnings.push(ValidationWarning {
+            field: "container_env".to_string(),
+            message: format!("container_env sets {key} more than once; the last value wins"),
+            fix_command: format!("occ config env set {key}=<value>"),
+        });
+    }
+
     Ok(warnings)
 }
 
+/// Warning for Cockpit enabled while no container users are configured
+///
+/// Cockpit authenticates against the container's system users, so without
+/// one its login page cannot be passed.
+pub fn cockpit_users_warning(config: &Config) -> Option<ValidationWarning> {
+    (config.cockpit_enabled && config.users.is_empty()).then(|| ValidationWarning {
+        field: "cockpit_enabled".to_string(),
+        message: "Cockpit is enabled but no users are configured; Cockpit logins need a \
+                  container user"
+            .to_string(),
+        fix_command: "occ user add".to_string(),
+    })
+}
+
+/// Split a `KEY=value` environment entry, returning the key
+///
+/// The key must be a valid variable name: letters, digits and underscores,
+/// not starting with a digit. The value may be empty.
+pub fn parse_env_entry(entry: &str) -> Result<&str, String> {
+    parse_labeled_env_entry(entry, "container_env")
+}
+
+/// Like [`parse_env_entry`], naming the entry's source as `label` in errors
+pub fn parse_labeled_env_entry<'a>(entry: &'a str, label: &str) -> Result<&'a str, String> {
+    let Some((key, _value)) = entry.split_once('=') else {
+        return Err(format!(
+            "{label} entry '{entry}' must be in KEY=value format"
+        ));
+    };
+    if key.is_empty() {
+        return Err(format!("{label} entry '{entry}' has an empty key"));
+    }
+    let valid = key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !key.starts_with(|c: char| c.is_ascii_digit());
+    if !valid {
+        return Err(format!(
+            "{label} key '{key}' is not a valid variable name \
+             (use letters, digits and '_', not starting with a digit)"
+        ));
+    }
+    Ok(key)
+}
+
+/// Substrings that mark a container_env variable name as secret
+const SECRET_ENV_PATTERNS: &[&str] = &["TOKEN", "SECRET", "KEY", "PASSWORD"];
+
+/// Placeholder shown instead of a secret container_env value
+const REDACTED_ENV_VALUE: &str = "***";
+
+/// Whether an environment variable name looks like it holds a secret
+pub fn is_secret_env_key(name: &str) -> bool {
+    let upper = name.to_ascii_uppercase();
+    SECRET_ENV_PATTERNS
+        .iter()
+        .any(|pattern| upper.contains(pattern))
+}
+
+/// Redact the value of a `KEY=value` entry if the key looks secret
+pub fn redact_env_entry(entry: &str) -> String {
+    match entry.split_once('=') {
+        Some((name, _)) if is_secret_env_key(name) => format!("{name}={REDACTED_ENV_VALUE}"),
+        _ => entry.to_string(),
+    }
+}
+
+/// Keys that appear more than once in `container_env`, in first-seen order
+fn duplicate_env_keys(entries: &[String]) -> Vec<&str> {
+    let mut seen = std::collections::HashSet::new();
+    let mut duplicates = Vec::new();
+    for key in entries.iter().filter_map(|e| parse_env_entry(e).ok()) {
+        if !seen.insert(key) && !duplicates.contains(&key) {
+            duplicates.push(key);
+        }
+    }
+    duplicates
+}
+
 /// Display a validation error with styled formatting
 pub fn display_validation_error(error: &ValidationError) {
     eprintln!();
@@ -162,16 +370,103 @@ mod tests {
     }
 
     #[test]
-    fn test_port_too_low() {
+    fn test_privileged_port_warns() {
         let config = Config {
             opencode_web_port: 80,
             ..Config::default()
         };
-        let result = validate_config(&config);
-        assert!(result.is_err());
-        let err = result.unwrap_err();
-        assert_eq!(err.field, "opencode_web_port");
-        assert!(err.message.contains("1024"));
+        let warnings = validate_config(&config).unwrap();
+        let warning = warnings
+            .iter()
+            .find(|w| w.field == "opencode_web_port")
+            .unwrap();
+        assert!(warning.message.contains("1024"));
+    }
+
+    #[test]
+    fn test_privileged_cockpit_port_warns() {
+        let config = Config {
+            cockpit_enabled: true,
+            cockpit_port: 443,
+            ..Config::default()
+        };
+        let warnings = validate_config(&config).unwrap();
+        assert!(warnings.iter().any(|w| w.field == "cockpit_port"));
+    }
+
+    #[test]
+    fn test_invalid_resource_limits() {
+        let config = Config {
+            memory_limit_mb: Some(1),
+            ..Config::default()
+        };
+        assert_eq!(
+            validate_config(&config).unwrap_err().field,
+            "memory_limit_mb"
+        );
+
+        let config = Config {
+            cpu_limit: Some(0.0),
+            ..Config::default()
+        };
+        assert_eq!(validate_config(&config).unwrap_err().field, "cpu_limit");
+    }
+
+    #[test]
+    fn test_secret_env_keys_detected() {
+        for name in [
+            "GITHUB_TOKEN",
+            "AWS_SECRET_ACCESS_KEY",
+            "OPENAI_API_KEY",
+            "DB_PASSWORD",
+            "client_secret",
+        ] {
+            assert!(is_secret_env_key(name), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_ordinary_env_keys_visible() {
+        for name in ["TZ", "LANG", "NODE_ENV", "HOME", "PATH"] {
+            assert!(!is_secret_env_key(name), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_redact_env_entry() {
+        assert_eq!(redact_env_entry("API_KEY=abc123"), "API_KEY=***");
+        assert_eq!(redact_env_entry("TOKEN=a=b"), "TOKEN=***");
+        assert_eq!(redact_env_entry("TZ=UTC"), "TZ=UTC");
+        assert_eq!(redact_env_entry("NO_EQUALS_KEY"), "NO_EQUALS_KEY");
+    }
+
+    #[test]
+    fn test_cockpit_without_users_warns() {
+        let config = Config {
+            cockpit_enabled: true,
+            ..Config::default()
+        };
+        let warnings = validate_config(&config).unwrap();
+        assert!(warnings.iter().any(|w| w.field == "cockpit_enabled"));
+        assert_eq!(
+            cockpit_users_warning(&config).unwrap().fix_command,
+            "occ user add"
+        );
+    }
+
+    #[test]
+    fn test_cockpit_with_users_does_not_warn() {
+        let config = Config {
+            cockpit_enabled: true,
+            users: vec!["alice".to_string()],
+            ..Config::default()
+        };
+        assert!(cockpit_users_warning(&config).is_none());
+        let warnings = validate_config(&config).unwrap();
+        assert!(!warnings.iter().any(|w| w.field == "cockpit_enabled"));
+
+        let disabled = Config::default();
+        assert!(cockpit_users_warning(&disabled).is_none());
     }
 
     // Note: No test for port > 65535 - u16 type enforces this limit at compile time
@@ -224,6 +519,38 @@ mod tests {
         assert_eq!(err.field, "rate_limit_window_seconds");
     }
 
+    #[test]
+    fn test_health_check_interval_zero() {
+        let config = Config {
+            health_check_interval: 0,
+            ..Config::default()
+        };
+        let err = validate_config(&config).unwrap_err();
+        assert_eq!(err.field, "health_check_interval");
+    }
+
+    #[test]
+    fn test_health_check_retries_zero() {
+        let config = Config {
+            health_check_retries: 0,
+            ..Config::default()
+        };
+        let err = validate_config(&config).unwrap_err();
+        assert_eq!(err.field, "health_check_retries");
+    }
+
+    #[test]
+    fn test_health_check_zero_values_ignored_when_disabled() {
+        let config = Config {
+            health_check_enabled: false,
+            health_check_interval: 0,
+            health_check_timeout: 0,
+            health_check_retries: 0,
+            ..Config::default()
+        };
+        assert!(validate_config(&config).is_ok());
+    }
+
     #[test]
     fn test_network_exposed_without_auth_warning() {
         let config = Config {
@@ -243,6 +570,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_trust_proxy_on_localhost_warning() {
+        let config = Config {
+            trust_proxy: true,
+            ..Config::default()
+        };
+        let warnings = validate_config(&config).unwrap();
+        assert!(
+            warnings
+                .iter()
+                .any(|w| w.field == "trust_proxy" && w.message.contains("localhost"))
+        );
+    }
+
+    #[test]
+    fn test_network_exposed_without_trust_proxy_warning() {
+        let config = Config {
+            bind_address: "0.0.0.0".to_string(),
+            users: vec!["admin".to_string()],
+            ..Config::default()
+        };
+        let warnings = validate_config(&config).unwrap();
+        assert!(
+            warnings
+                .iter()
+                .any(|w| w.field == "trust_proxy" && w.fix_command.ends_with("true"))
+        );
+    }
+
+    #[test]
+    fn test_no_trust_proxy_warning_for_sensible_configs() {
+        // Local-only without proxy trust (default)
+        let warnings = validate_config(&Config::default()).unwrap();
+        assert!(!warnings.iter().any(|w| w.field == "trust_proxy"));
+
+        // Network exposed behind a trusted proxy
+        let config = Config {
+            bind_address: "0.0.0.0".to_string(),
+            trust_proxy: true,
+            users: vec!["admin".to_string()],
+            ..Config::default()
+        };
+        let warnings = validate_config(&config).unwrap();
+        assert!(!warnings.iter().any(|w| w.field == "trust_proxy"));
+    }
+
     #[test]
     fn test_legacy_auth_username_warning() {
         let config = Config {
@@ -268,4 +641,57 @@ mod tests {
         assert!(!warnings.is_empty());
         assert!(warnings.iter().any(|w| w.field == "auth_password"));
     }
+
+    fn env_config(entries: &[&str]) -> Config {
+        Config {
+            container_env: entries.iter().map(|e| e.to_string()).collect(),
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn test_container_env_missing_equals() {
+        let err = validate_config(&env_config(&["FOObar"])).unwrap_err();
+        assert_eq!(err.field, "container_env");
+        assert!(err.message.contains("KEY=value"));
+        assert_eq!(err.fix_command, "occ config env remove 'FOObar'");
+    }
+
+    #[test]
+    fn test_container_env_empty_key() {
+        let err = validate_config(&env_config(&["=value"])).unwrap_err();
+        assert!(err.message.contains("empty key"));
+    }
+
+    #[test]
+    fn test_container_env_invalid_names() {
+        for entry in ["1FOO=x", "FOO-BAR=x", "FOO BAR=x", "FÖO=x"] {
+            let err = validate_config(&env_config(&[entry])).unwrap_err();
+            assert!(
+                err.message.contains("not a valid variable name"),
+                "{entry}: {}",
+                err.message
+            );
+        }
+    }
+
+    #[test]
+    fn test_container_env_valid_entries() {
+        let config = env_config(&["FOO=1", "_BAR=", "baz_2=a=b"]);
+        let warnings = validate_config(&config).unwrap();
+        assert!(!warnings.iter().any(|w| w.field == "container_env"));
+    }
+
+    #[test]
+    fn test_container_env_duplicate_key_warning() {
+        let config = env_config(&["FOO=1", "BAR=x", "FOO=2", "FOO=3"]);
+        let warnings: Vec<_> = validate_config(&config)
+            .unwrap()
+            .into_iter()
+            .filter(|w| w.field == "container_env")
+            .collect();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("FOO"));
+        assert!(warnings[0].message.contains("last value wins"));
+    }
 }
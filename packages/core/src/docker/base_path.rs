@@ -0,0 +1,41 @@
+//! Reverse-proxy base path
+//!
+//! Builds the `OPENCODE_BASE_PATH` environment variable from the `base_path`
+//! config field, so the app generates asset and link URLs under the sub-path
+//! a reverse proxy serves it from.
+
+use crate::config::Config;
+
+/// Environment variable carrying the base path into the container
+pub const BASE_PATH_ENV_VAR: &str = "OPENCODE_BASE_PATH";
+
+/// Environment variable for the configured base path
+///
+/// Empty when no base path is set.
+pub fn base_path_env(config: &Config) -> Vec<String> {
+    config
+        .base_path
+        .as_deref()
+        .map(|path| format!("{BASE_PATH_ENV_VAR}={path}"))
+        .into_iter()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_base_path_is_injected() {
+        let config = Config {
+            base_path: Some("/opencode".to_string()),
+            ..Config::default()
+        };
+        assert_eq!(base_path_env(&config), vec!["OPENCODE_BASE_PATH=/opencode"]);
+    }
+
+    #[test]
+    fn absent_base_path_injects_nothing() {
+        assert!(base_path_env(&Config::default()).is_empty());
+    }
+}
@@ -6,9 +6,45 @@
 use bollard::Docker;
 use std::time::Duration;
 
+use super::endpoint::{DockerEndpoint, docker_endpoint};
 use super::error::DockerError;
 use crate::host::{HostConfig, SshTunnel};
 
+/// Request timeout used by `DockerClient::new` (matches Bollard's default)
+const DEFAULT_TIMEOUT_SECS: u64 = 120;
+
+/// Connect to the local daemon, honoring the `--socket` override
+fn connect_local(timeout_secs: u64) -> Result<Docker, DockerError> {
+    let docker = match docker_endpoint() {
+        None => Docker::connect_with_local_defaults(),
+        #[cfg(unix)]
+        Some(DockerEndpoint::Unix(path)) => Docker::connect_with_unix(
+            &path.to_string_lossy(),
+            timeout_secs,
+            bollard::API_DEFAULT_VERSION,
+        ),
+        #[cfg(not(unix))]
+        Some(DockerEndpoint::Unix(_)) => {
+            return Err(DockerError::InvalidEndpoint(
+                "unix sockets are not supported on this platform".to_string(),
+            ));
+        }
+        Some(DockerEndpoint::Tcp(addr)) => Docker::connect_with_http(
+            &format!("http://{addr}"),
+            timeout_secs,
+            bollard::API_DEFAULT_VERSION,
+        ),
+        Some(endpoint @ DockerEndpoint::Ssh { .. }) => {
+            return Err(DockerError::InvalidEndpoint(format!(
+                "{endpoint}: SSH endpoints are only supported by commands that accept --host"
+            )));
+        }
+    };
+    docker
+        .map(|docker| docker.with_timeout(Duration::from_secs(timeout_secs)))
+        .map_err(|e| DockerError::Connection(e.to_string()))
+}
+
 /// Docker client wrapper with connection handling
 pub struct DockerClient {
     inner: Docker,
@@ -21,11 +57,11 @@ pub struct DockerClient {
 impl DockerClient {
     /// Create new client connecting to local Docker daemon
     ///
-    /// Uses platform-appropriate socket (Unix socket on Linux/macOS).
+    /// Uses platform-appropriate socket (Unix socket on Linux/macOS), or the
+    /// endpoint set with `set_docker_endpoint`.
     /// Returns a clear error if Docker is not running or accessible.
     pub fn new() -> Result<Self, DockerError> {
-        let docker = Docker::connect_with_local_defaults()
-            .map_err(|e| DockerError::Connection(e.to_string()))?;
+        let docker = connect_local(DEFAULT_TIMEOUT_SECS)?;
 
         Ok(Self {
             inner: docker,
@@ -39,9 +75,7 @@ impl DockerClient {
     /// Use for long-running operations like image builds.
     /// Default timeout is 120 seconds; build timeout should be 600+ seconds.
     pub fn with_timeout(timeout_secs: u64) -> Result<Self, DockerError> {
-        let docker = Docker::connect_with_local_defaults()
-            .map_err(|e| DockerError::Connection(e.to_string()))?
-            .with_timeout(Duration::from_secs(timeout_secs));
+        let docker = connect_local(timeout_secs)?;
 
         Ok(Self {
             inner: docker,
@@ -154,6 +188,19 @@ impl DockerClient {
         Ok(())
     }
 
+    /// Wait for the Docker daemon to accept connections
+    ///
+    /// Polls `verify_connection` with exponential backoff (250ms up to 2s)
+    /// until it succeeds or `timeout` elapses. Useful right after login when
+    /// Docker Desktop is still starting. Returns the last connection error
+    /// on timeout.
+    pub async fn wait_until_ready(&self, timeout: Duration) -> Result<(), DockerError> {
+        poll_until_ready(timeout, READY_POLL_INITIAL_DELAY, || {
+            self.verify_connection()
+        })
+        .await
+    }
+
     /// Get Docker version info (useful for debugging)
     pub async fn version(&self) -> Result<String, DockerError> {
         let version = self.inner.version().await.map_err(DockerError::from)?;
@@ -183,6 +230,46 @@ impl DockerClient {
     }
 }
 
+/// First delay between readiness polls (doubles up to `READY_POLL_MAX_DELAY`)
+const READY_POLL_INITIAL_DELAY: Duration = Duration::from_millis(250);
+
+/// Longest delay between readiness polls
+const READY_POLL_MAX_DELAY: Duration = Duration::from_secs(2);
+
+/// Call `probe` until it succeeds or `timeout` elapses, backing off between attempts
+///
+/// The probe always runs at least once. Sleeps never overshoot the deadline.
+async fn poll_until_ready<F, Fut>(
+    timeout: Duration,
+    initial_delay: Duration,
+    mut probe: F,
+) -> Result<(), DockerError>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<(), DockerError>>,
+{
+    let deadline = tokio::time::Instant::now() + timeout;
+    let mut delay = initial_delay;
+    let mut attempt = 1u32;
+
+    loop {
+        let err = match probe().await {
+            Ok(()) => return Ok(()),
+            Err(e) => e,
+        };
+
+        let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+        if remaining.is_zero() {
+            return Err(err);
+        }
+
+        tracing::debug!("Docker not ready (attempt {attempt}): {err}");
+        tokio::time::sleep(delay.min(remaining)).await;
+        delay = (delay * 2).min(READY_POLL_MAX_DELAY);
+        attempt += 1;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -202,6 +289,52 @@ mod tests {
         drop(result);
     }
 
+    #[tokio::test]
+    async fn poll_until_ready_succeeds_after_failures() {
+        let mut calls = 0;
+        let result = poll_until_ready(Duration::from_secs(5), Duration::from_millis(1), || {
+            calls += 1;
+            let ready = calls > 3;
+            async move {
+                if ready {
+                    Ok(())
+                } else {
+                    Err(DockerError::NotRunning)
+                }
+            }
+        })
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(calls, 4);
+    }
+
+    #[tokio::test]
+    async fn poll_until_ready_times_out() {
+        let mut calls = 0;
+        let result = poll_until_ready(Duration::from_millis(20), Duration::from_millis(1), || {
+            calls += 1;
+            async { Err(DockerError::NotRunning) }
+        })
+        .await;
+
+        assert!(matches!(result, Err(DockerError::NotRunning)));
+        assert!(calls > 1);
+    }
+
+    #[tokio::test]
+    async fn poll_until_ready_zero_timeout_probes_once() {
+        let mut calls = 0;
+        let result = poll_until_ready(Duration::ZERO, Duration::from_millis(1), || {
+            calls += 1;
+            async { Err(DockerError::NotRunning) }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(calls, 1);
+    }
+
     #[test]
     fn test_host_name_methods() {
         // Local client has no host name
@@ -4,17 +4,23 @@
 //! Docker containers for the opencode-cloud service.
 
 use super::dockerfile::{IMAGE_NAME_GHCR, IMAGE_TAG_DEFAULT};
+use super::health::HealthCheckSettings;
 use super::mount::ParsedMount;
+use super::resources::ResourceLimits;
 use super::volume::{
-    MOUNT_CONFIG, MOUNT_PROJECTS, MOUNT_SESSION, VOLUME_CONFIG, VOLUME_PROJECTS, VOLUME_SESSION,
+    MOUNT_CONFIG, MOUNT_PROJECTS, MOUNT_SESSION, VOLUME_CONFIG, VOLUME_MANAGED_LABEL,
+    VOLUME_PROJECTS, VOLUME_SESSION,
 };
 use super::{DockerClient, DockerError};
+use crate::config::validate_bind_address;
 use bollard::container::{
-    Config, CreateContainerOptions, RemoveContainerOptions, StartContainerOptions,
-    StopContainerOptions,
+    Config, CreateContainerOptions, KillContainerOptions, ListContainersOptions,
+    RemoveContainerOptions, StartContainerOptions, StopContainerOptions, TopOptions,
+    UpdateContainerOptions,
 };
 use bollard::service::{
-    HostConfig, Mount, MountPointTypeEnum, MountTypeEnum, PortBinding, PortMap,
+    ContainerInspectResponse, ContainerSummary, ContainerTopResponse, HostConfig, Mount,
+    MountPointTypeEnum, MountTypeEnum, PortBinding, PortMap, RestartPolicy, RestartPolicyNameEnum,
 };
 use std::collections::HashMap;
 use tracing::debug;
@@ -25,6 +31,20 @@ pub const CONTAINER_NAME: &str = "opencode-cloud";
 /// Default port for opencode web UI
 pub const OPENCODE_WEB_PORT: u16 = 3000;
 
+/// Label marking a container as created by opencode-cloud (same key as volumes)
+pub const CONTAINER_MANAGED_LABEL: &str = VOLUME_MANAGED_LABEL;
+
+/// An opencode-cloud container found on the Docker daemon
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManagedContainer {
+    /// Container name (without the leading '/')
+    pub name: String,
+    /// Container ID
+    pub id: String,
+    /// Whether the container is currently running
+    pub running: bool,
+}
+
 /// Create the opencode container with volume mounts
 ///
 /// Does not start the container - use start_container after creation.
@@ -40,6 +60,11 @@ pub const OPENCODE_WEB_PORT: u16 = 3000;
 /// * `cockpit_port` - Port to bind on host for Cockpit (defaults to 9090)
 /// * `cockpit_enabled` - Whether to enable Cockpit port mapping (defaults to true)
 /// * `bind_mounts` - User-defined bind mounts from config and CLI flags (optional)
+/// * `labels` - Extra container labels; managed labels take precedence (optional)
+/// * `health_check` - Docker healthcheck to inject (None leaves the image default)
+/// * `auto_restart` - Docker restart policy from `auto_restart` (None leaves Docker's default)
+/// * `resource_limits` - Memory and CPU limits (default is unlimited)
+/// * `force_recreate` - Remove an existing container with the same name first (volumes are kept)
 #[allow(clippy::too_many_arguments)]
 pub async fn create_container(
     client: &DockerClient,
@@ -51,6 +76,11 @@ pub async fn create_container(
     cockpit_port: Option<u16>,
     cockpit_enabled: Option<bool>,
     bind_mounts: Option<Vec<ParsedMount>>,
+    labels: Option<Vec<(String, String)>>,
+    health_check: Option<HealthCheckSettings>,
+    auto_restart: Option<bool>,
+    resource_limits: ResourceLimits,
+    force_recreate: bool,
 ) -> Result<String, DockerError> {
     let container_name = name.unwrap_or(CONTAINER_NAME);
     let default_image = format!("{IMAGE_NAME_GHCR}:{IMAGE_TAG_DEFAULT}");
@@ -64,13 +94,6 @@ pub async fn create_container(
         container_name, image_name, port, cockpit_port_val, cockpit_enabled_val
     );
 
-    // Check if container already exists
-    if container_exists(client, container_name).await? {
-        return Err(DockerError::Container(format!(
-            "Container '{container_name}' already exists. Remove it first with 'occ stop --remove' or use a different name."
-        )));
-    }
-
     // Check if image exists
     let image_parts: Vec<&str> = image_name.split(':').collect();
     let (image_repo, image_tag) = if image_parts.len() == 2 {
@@ -79,10 +102,22 @@ pub async fn create_container(
         (image_name, "latest")
     };
 
-    if !super::image::image_exists(client, image_repo, image_tag).await? {
-        return Err(DockerError::Container(format!(
-            "Image '{image_name}' not found. Run 'occ pull' first to download the image."
-        )));
+    let image_present = super::image::image_exists(client, image_repo, image_tag).await?;
+
+    // Handle a leftover container with the same name
+    let exists = container_exists(client, container_name).await?;
+    if plan_replace_existing(
+        container_name,
+        image_name,
+        exists,
+        image_present,
+        force_recreate,
+    )? {
+        debug!(
+            "Removing existing container {} before recreate",
+            container_name
+        );
+        remove_container(client, container_name, true).await?;
     }
 
     // Create volume mounts
@@ -118,14 +153,21 @@ pub async fn create_container(
     }
 
     // Create port bindings (default to localhost for security)
-    let bind_addr = bind_address.unwrap_or("127.0.0.1");
+    // Bind on the resolved IP so "localhost" and "[::1]" reach Docker as plain IPs
+    let bind_addr = bind_address
+        .map(|addr| {
+            validate_bind_address(addr)
+                .map(|ip| ip.to_string())
+                .unwrap_or_else(|_| addr.to_string())
+        })
+        .unwrap_or_else(|| "127.0.0.1".to_string());
     let mut port_bindings: PortMap = HashMap::new();
 
     // opencode web port
     port_bindings.insert(
         "3000/tcp".to_string(),
         Some(vec![PortBinding {
-            host_ip: Some(bind_addr.to_string()),
+            host_ip: Some(bind_addr.clone()),
             host_port: Some(port.to_string()),
         }]),
     );
@@ -136,7 +178,7 @@ pub async fn create_container(
         port_bindings.insert(
             "9090/tcp".to_string(),
             Some(vec![PortBinding {
-                host_ip: Some(bind_addr.to_string()),
+                host_ip: Some(bind_addr.clone()),
                 host_port: Some(cockpit_port_val.to_string()),
             }]),
         );
@@ -152,7 +194,7 @@ pub async fn create_container(
     // Create host config
     // When Cockpit is enabled, add systemd-specific settings (requires Linux host)
     // When Cockpit is disabled, use simpler tini-based config (works everywhere)
-    let host_config = if cockpit_enabled_val {
+    let mut host_config = if cockpit_enabled_val {
         HostConfig {
             mounts: Some(mounts),
             port_bindings: Some(port_bindings),
@@ -188,6 +230,9 @@ pub async fn create_container(
         }
     };
 
+    host_config.restart_policy = auto_restart.map(restart_policy);
+    resource_limits.apply(&mut host_config);
+
     // Build environment variables
     // Add USE_SYSTEMD=1 when Cockpit is enabled to tell entrypoint to use systemd
     let final_env = if cockpit_enabled_val {
@@ -205,7 +250,9 @@ pub async fn create_container(
         working_dir: Some("/workspace".to_string()),
         exposed_ports: Some(exposed_ports),
         env: final_env,
+        healthcheck: health_check.map(|settings| settings.to_health_config()),
         host_config: Some(host_config),
+        labels: Some(container_labels(labels.unwrap_or_default())),
         ..Default::default()
     };
 
@@ -234,6 +281,30 @@ pub async fn create_container(
     Ok(response.id)
 }
 
+/// Map an image-existence check to `ImageNotFound` so callers can offer to pull or build
+fn require_image(image_name: &str, present: bool) -> Result<(), DockerError> {
+    if present {
+        Ok(())
+    } else {
+        Err(DockerError::ImageNotFound(image_name.to_string()))
+    }
+}
+
+/// Check the image, then decide whether an existing container is removed
+///
+/// The image is required first so a forced recreate never removes the
+/// container when no replacement can be created.
+fn plan_replace_existing(
+    name: &str,
+    image_name: &str,
+    exists: bool,
+    image_present: bool,
+    force_recreate: bool,
+) -> Result<bool, DockerError> {
+    require_image(image_name, image_present)?;
+    should_remove_existing(name, exists, force_recreate)
+}
+
 /// Start an existing container
 pub async fn start_container(client: &DockerClient, name: &str) -> Result<(), DockerError> {
     debug!("Starting container: {}", name);
@@ -282,6 +353,235 @@ pub async fn stop_container(
     Ok(())
 }
 
+/// Signals `occ stop --signal` accepts by name, without the `SIG` prefix
+const STOP_SIGNAL_NAMES: &[&str] = &[
+    "HUP", "INT", "QUIT", "ABRT", "KILL", "USR1", "USR2", "TERM", "WINCH", "PWR",
+];
+
+/// Parse a stop signal given as a name (`INT`, `SIGINT`, `sigint`) or number
+///
+/// Returns the value Docker's kill API expects: `SIGINT` for names, the
+/// number as given for numbers (1-64).
+pub fn parse_stop_signal(value: &str) -> Result<String, String> {
+    let value = value.trim();
+    if let Ok(number) = value.parse::<u8>() {
+        return if (1..=64).contains(&number) {
+            Ok(number.to_string())
+        } else {
+            Err(format!("Invalid signal number {number}: expected 1-64"))
+        };
+    }
+
+    let upper = value.to_ascii_uppercase();
+    let name = upper.strip_prefix("SIG").unwrap_or(&upper);
+    if STOP_SIGNAL_NAMES.contains(&name) {
+        Ok(format!("SIG{name}"))
+    } else {
+        Err(format!(
+            "Unknown signal '{value}'. Expected a number or one of: {}",
+            STOP_SIGNAL_NAMES.join(", ")
+        ))
+    }
+}
+
+/// Send `signal` to a container and wait up to `timeout_secs` for it to exit
+///
+/// Returns whether the container exited in time; callers fall back to
+/// [`stop_container`] when it did not.
+pub async fn signal_container(
+    client: &DockerClient,
+    name: &str,
+    signal: &str,
+    timeout_secs: i64,
+) -> Result<bool, DockerError> {
+    debug!("Sending {} to container {}", signal, name);
+    client
+        .inner()
+        .kill_container(name, Some(KillContainerOptions { signal }))
+        .await
+        .map_err(|e| DockerError::Container(format!("Failed to signal container {name}: {e}")))?;
+
+    let deadline =
+        tokio::time::Instant::now() + std::time::Duration::from_secs(timeout_secs.max(0) as u64);
+    loop {
+        if !container_is_running(client, name).await? {
+            return Ok(true);
+        }
+        if tokio::time::Instant::now() >= deadline {
+            return Ok(false);
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+    }
+}
+
+/// List every opencode-cloud container, running or not, sorted by name
+pub async fn list_managed_containers(
+    client: &DockerClient,
+) -> Result<Vec<ManagedContainer>, DockerError> {
+    let options = ListContainersOptions::<String> {
+        all: true,
+        ..Default::default()
+    };
+    let containers = client
+        .inner()
+        .list_containers(Some(options))
+        .await
+        .map_err(|e| DockerError::Container(format!("Failed to list containers: {e}")))?;
+    Ok(select_managed_containers(&containers))
+}
+
+/// Parse a `key=value` container label
+///
+/// The key must be non-empty and free of whitespace; the value may be empty.
+pub fn parse_container_label(value: &str) -> Result<(String, String), String> {
+    let (key, val) = value
+        .split_once('=')
+        .ok_or_else(|| format!("Invalid label '{value}': expected key=value"))?;
+    if key.is_empty() || key.chars().any(char::is_whitespace) {
+        return Err(format!(
+            "Invalid label '{value}': key must be non-empty without whitespace"
+        ));
+    }
+    Ok((key.to_string(), val.to_string()))
+}
+
+/// Merge user labels with the managed ones, which always win
+fn container_labels(extra: Vec<(String, String)>) -> HashMap<String, String> {
+    let mut labels: HashMap<String, String> = extra.into_iter().collect();
+    labels.insert(CONTAINER_MANAGED_LABEL.to_string(), "true".to_string());
+    labels
+}
+
+/// User labels from a container's label map, without the managed label, sorted by key
+fn user_labels(labels: HashMap<String, String>) -> Vec<(String, String)> {
+    let mut labels: Vec<_> = labels
+        .into_iter()
+        .filter(|(key, _)| key != CONTAINER_MANAGED_LABEL)
+        .collect();
+    labels.sort();
+    labels
+}
+
+/// Get the user labels of an existing container (empty if it does not exist)
+///
+/// Used to carry `occ start --label` values over when the container is recreated.
+pub async fn get_container_labels(
+    client: &DockerClient,
+    name: &str,
+) -> Result<Vec<(String, String)>, DockerError> {
+    debug!("Getting container labels: {}", name);
+
+    match client.inner().inspect_container(name, None).await {
+        Ok(info) => Ok(user_labels(
+            info.config.and_then(|c| c.labels).unwrap_or_default(),
+        )),
+        Err(bollard::errors::Error::DockerResponseServerError {
+            status_code: 404, ..
+        }) => Ok(Vec::new()),
+        Err(e) => Err(DockerError::Container(format!(
+            "Failed to inspect container {name}: {e}"
+        ))),
+    }
+}
+
+/// Labels for a recreated container: the existing ones, overridden by `requested`
+pub fn merge_container_labels(
+    existing: &[(String, String)],
+    requested: &[(String, String)],
+) -> Vec<(String, String)> {
+    let mut merged: HashMap<String, String> = existing.iter().cloned().collect();
+    merged.extend(requested.iter().cloned());
+    user_labels(merged)
+}
+
+/// Keep containers carrying the managed label
+///
+/// The default container is also kept when unlabeled, since releases before
+/// the label existed created it without one.
+fn select_managed_containers(containers: &[ContainerSummary]) -> Vec<ManagedContainer> {
+    let mut managed: Vec<ManagedContainer> = containers
+        .iter()
+        .filter_map(|c| {
+            let name = c
+                .names
+                .as_ref()?
+                .first()?
+                .trim_start_matches('/')
+                .to_string();
+            let labeled = c
+                .labels
+                .as_ref()
+                .and_then(|labels| labels.get(CONTAINER_MANAGED_LABEL))
+                .is_some_and(|v| v == "true");
+            if !labeled && name != CONTAINER_NAME {
+                return None;
+            }
+            Some(ManagedContainer {
+                id: c.id.clone().unwrap_or_default(),
+                running: c.state.as_deref() == Some("running"),
+                name,
+            })
+        })
+        .collect();
+    managed.sort_by(|a, b| a.name.cmp(&b.name));
+    managed
+}
+
+/// Docker restart policy for the `auto_restart` setting
+///
+/// `unless-stopped` brings the container back after crashes and daemon
+/// restarts, but respects an explicit `occ stop`.
+pub fn restart_policy(auto_restart: bool) -> RestartPolicy {
+    let name = if auto_restart {
+        RestartPolicyNameEnum::UNLESS_STOPPED
+    } else {
+        RestartPolicyNameEnum::NO
+    };
+    RestartPolicy {
+        name: Some(name),
+        maximum_retry_count: None,
+    }
+}
+
+/// Update options that change only the restart policy
+pub fn restart_policy_update(auto_restart: bool) -> UpdateContainerOptions<String> {
+    UpdateContainerOptions {
+        restart_policy: Some(restart_policy(auto_restart)),
+        ..Default::default()
+    }
+}
+
+/// Change the restart policy of an existing container without recreating it
+pub async fn update_restart_policy(
+    client: &DockerClient,
+    name: &str,
+    auto_restart: bool,
+) -> Result<(), DockerError> {
+    debug!(
+        "Updating restart policy of {} (auto_restart={})",
+        name, auto_restart
+    );
+    client
+        .inner()
+        .update_container(name, restart_policy_update(auto_restart))
+        .await
+        .map_err(|e| DockerError::Container(format!("Failed to update restart policy: {e}")))
+}
+
+/// Change the memory/CPU limits of an existing container without recreating it
+pub async fn update_resource_limits(
+    client: &DockerClient,
+    name: &str,
+    limits: &ResourceLimits,
+) -> Result<(), DockerError> {
+    debug!("Updating resource limits of {} ({:?})", name, limits);
+    client
+        .inner()
+        .update_container(name, limits.update_options())
+        .await
+        .map_err(|e| DockerError::Container(format!("Failed to update resource limits: {e}")))
+}
+
 /// Remove a container
 ///
 /// # Arguments
@@ -311,6 +611,25 @@ pub async fn remove_container(
     Ok(())
 }
 
+/// Decide what to do when creating a container whose name may already be taken
+///
+/// Returns `Ok(true)` when the existing container should be removed first,
+/// `Ok(false)` when there is nothing in the way, and an error when it exists
+/// and `force_recreate` is not set.
+fn should_remove_existing(
+    name: &str,
+    exists: bool,
+    force_recreate: bool,
+) -> Result<bool, DockerError> {
+    match (exists, force_recreate) {
+        (false, _) => Ok(false),
+        (true, true) => Ok(true),
+        (true, false) => Err(DockerError::Container(format!(
+            "Container '{name}' already exists. Remove it first with 'occ stop --remove', or run 'occ start --recreate'."
+        ))),
+    }
+}
+
 /// Check if container exists
 pub async fn container_exists(client: &DockerClient, name: &str) -> Result<bool, DockerError> {
     debug!("Checking if container exists: {}", name);
@@ -344,19 +663,53 @@ pub async fn container_is_running(client: &DockerClient, name: &str) -> Result<b
     }
 }
 
-/// Get container state (running, stopped, etc.)
-pub async fn container_state(client: &DockerClient, name: &str) -> Result<String, DockerError> {
-    debug!("Getting container state: {}", name);
+/// Container state from a single inspect
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerState {
+    /// Docker status (e.g. "running", "exited"), or "unknown"
+    pub status: String,
+    /// Whether the container is running
+    pub running: bool,
+    /// Exit code of the last run
+    pub exit_code: Option<i64>,
+    /// When the container last started (RFC 3339)
+    pub started_at: Option<String>,
+    /// When the container last stopped (RFC 3339)
+    pub finished_at: Option<String>,
+    /// Healthcheck status (e.g. "healthy"), if the container has a healthcheck
+    pub health: Option<String>,
+}
 
-    match client.inner().inspect_container(name, None).await {
-        Ok(info) => {
-            let state = info
-                .state
-                .and_then(|s| s.status)
+impl ContainerState {
+    /// Extract the state fields from an inspect response
+    pub fn from_inspect(info: &ContainerInspectResponse) -> Self {
+        let state = info.state.as_ref();
+        Self {
+            status: state
+                .and_then(|s| s.status.as_ref())
                 .map(|s| s.to_string())
-                .unwrap_or_else(|| "unknown".to_string());
-            Ok(state)
+                .unwrap_or_else(|| "unknown".to_string()),
+            running: state.and_then(|s| s.running).unwrap_or(false),
+            exit_code: state.and_then(|s| s.exit_code),
+            started_at: state.and_then(|s| s.started_at.clone()),
+            finished_at: state.and_then(|s| s.finished_at.clone()),
+            health: state
+                .and_then(|s| s.health.as_ref())
+                .and_then(|h| h.status.as_ref())
+                .map(|s| s.to_string()),
         }
+    }
+}
+
+/// Get container state (status, exit code, health, etc.)
+pub async fn container_state(
+    client: &DockerClient,
+    name: &str,
+) -> Result<ContainerState, DockerError> {
+    debug!("Getting container state: {}", name);
+
+    match client.inner().inspect_container(name, None).await {
+        Ok(info) => Ok(ContainerState::from_inspect(&info)),
         Err(bollard::errors::Error::DockerResponseServerError {
             status_code: 404, ..
         }) => Err(DockerError::Container(format!(
@@ -368,6 +721,25 @@ pub async fn container_state(client: &DockerClient, name: &str) -> Result<String
     }
 }
 
+/// List the processes running in a container
+///
+/// `ps_args` is passed to `ps` inside the container (Docker defaults to `-ef`).
+/// Fails with [`DockerError::Container`] if the container is not running.
+pub async fn container_top(
+    client: &DockerClient,
+    name: &str,
+    ps_args: Option<&str>,
+) -> Result<ContainerTopResponse, DockerError> {
+    debug!("Listing processes in container: {}", name);
+
+    let options = ps_args.map(|ps_args| TopOptions { ps_args });
+    client
+        .inner()
+        .top_processes(name, options)
+        .await
+        .map_err(|e| DockerError::Container(format!("Failed to list processes in {name}: {e}")))
+}
+
 /// Container port configuration
 #[derive(Debug, Clone)]
 pub struct ContainerPorts {
@@ -375,6 +747,8 @@ pub struct ContainerPorts {
     pub opencode_port: Option<u16>,
     /// Host port for Cockpit (mapped from container port 9090)
     pub cockpit_port: Option<u16>,
+    /// Host IP the opencode port is bound to (e.g. "127.0.0.1")
+    pub bind_address: Option<String>,
 }
 
 /// A bind mount from an existing container
@@ -410,12 +784,16 @@ pub async fn get_container_ports(
         .unwrap_or_default();
 
     // Extract opencode port (3000/tcp -> host port)
-    let opencode_port = port_bindings
+    let opencode_binding = port_bindings
         .get("3000/tcp")
         .and_then(|bindings| bindings.as_ref())
-        .and_then(|bindings| bindings.first())
+        .and_then(|bindings| bindings.first());
+    let opencode_port = opencode_binding
         .and_then(|binding| binding.host_port.as_ref())
         .and_then(|port_str| port_str.parse::<u16>().ok());
+    let bind_address = opencode_binding
+        .and_then(|binding| binding.host_ip.clone())
+        .filter(|ip| !ip.is_empty());
 
     // Extract cockpit port (9090/tcp -> host port)
     let cockpit_port = port_bindings
@@ -428,9 +806,30 @@ pub async fn get_container_ports(
     Ok(ContainerPorts {
         opencode_port,
         cockpit_port,
+        bind_address,
     })
 }
 
+/// Get the memory and CPU limits of an existing container
+pub async fn get_container_resource_limits(
+    client: &DockerClient,
+    name: &str,
+) -> Result<ResourceLimits, DockerError> {
+    debug!("Getting container resource limits: {}", name);
+
+    let info = client
+        .inner()
+        .inspect_container(name, None)
+        .await
+        .map_err(|e| DockerError::Container(format!("Failed to inspect container {name}: {e}")))?;
+
+    Ok(info
+        .host_config
+        .as_ref()
+        .map(ResourceLimits::from_host_config)
+        .unwrap_or_default())
+}
+
 /// Get bind mounts from an existing container
 ///
 /// Returns only user-defined bind mounts (excludes system mounts like cgroup).
@@ -470,6 +869,227 @@ pub async fn get_container_bind_mounts(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use bollard::service::{
+        ContainerState as InspectState, ContainerStateStatusEnum, Health, HealthStatusEnum,
+    };
+
+    #[test]
+    fn stop_signal_names_map_to_docker_names() {
+        for input in ["INT", "SIGINT", "sigint", "int", " Int "] {
+            assert_eq!(parse_stop_signal(input).unwrap(), "SIGINT", "{input}");
+        }
+        assert_eq!(parse_stop_signal("quit").unwrap(), "SIGQUIT");
+        assert_eq!(parse_stop_signal("SIGTERM").unwrap(), "SIGTERM");
+    }
+
+    #[test]
+    fn stop_signal_numbers_pass_through() {
+        assert_eq!(parse_stop_signal("2").unwrap(), "2");
+        assert_eq!(parse_stop_signal("15").unwrap(), "15");
+        assert!(parse_stop_signal("0").is_err());
+        assert!(parse_stop_signal("65").is_err());
+        assert!(parse_stop_signal("300").is_err());
+    }
+
+    #[test]
+    fn unknown_stop_signal_is_rejected() {
+        let err = parse_stop_signal("SIGBOGUS").unwrap_err();
+        assert!(err.contains("Unknown signal 'SIGBOGUS'"), "{err}");
+        assert!(err.contains("INT"), "{err}");
+        assert!(parse_stop_signal("").is_err());
+    }
+
+    #[test]
+    fn container_state_from_inspect() {
+        let info = ContainerInspectResponse {
+            state: Some(InspectState {
+                status: Some(ContainerStateStatusEnum::EXITED),
+                running: Some(false),
+                exit_code: Some(137),
+                started_at: Some("2026-01-02T03:04:05Z".to_string()),
+                finished_at: Some("2026-01-02T04:05:06Z".to_string()),
+                health: Some(Health {
+                    status: Some(HealthStatusEnum::UNHEALTHY),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            ContainerState::from_inspect(&info),
+            ContainerState {
+                status: "exited".to_string(),
+                running: false,
+                exit_code: Some(137),
+                started_at: Some("2026-01-02T03:04:05Z".to_string()),
+                finished_at: Some("2026-01-02T04:05:06Z".to_string()),
+                health: Some("unhealthy".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn container_state_without_state_is_unknown() {
+        let state = ContainerState::from_inspect(&ContainerInspectResponse::default());
+        assert_eq!(state.status, "unknown");
+        assert!(!state.running);
+        assert_eq!(state.exit_code, None);
+        assert_eq!(state.health, None);
+    }
+
+    fn summary(name: &str, state: &str, labels: &[(&str, &str)]) -> ContainerSummary {
+        ContainerSummary {
+            id: Some(format!("id-{name}")),
+            names: Some(vec![format!("/{name}")]),
+            state: Some(state.to_string()),
+            labels: Some(
+                labels
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+            ),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn managed_containers_filtered_by_label() {
+        let containers = vec![
+            summary("postgres", "running", &[]),
+            summary("opencode-b", "exited", &[(CONTAINER_MANAGED_LABEL, "true")]),
+            summary(
+                "opencode-a",
+                "running",
+                &[(CONTAINER_MANAGED_LABEL, "true")],
+            ),
+            summary("other", "running", &[(CONTAINER_MANAGED_LABEL, "false")]),
+            summary(CONTAINER_NAME, "running", &[]),
+        ];
+
+        let managed = select_managed_containers(&containers);
+
+        let names: Vec<_> = managed.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["opencode-a", "opencode-b", CONTAINER_NAME]);
+        assert!(managed[0].running);
+        assert!(!managed[1].running);
+        assert_eq!(managed[0].id, "id-opencode-a");
+    }
+
+    #[test]
+    fn unnamed_containers_are_skipped() {
+        let container = ContainerSummary {
+            labels: Some(HashMap::from([(
+                CONTAINER_MANAGED_LABEL.to_string(),
+                "true".to_string(),
+            )])),
+            ..Default::default()
+        };
+        assert!(select_managed_containers(&[container]).is_empty());
+    }
+
+    #[test]
+    fn container_label_parsing() {
+        assert_eq!(
+            parse_container_label("team=infra"),
+            Ok(("team".to_string(), "infra".to_string()))
+        );
+        assert_eq!(
+            parse_container_label("note=a=b"),
+            Ok(("note".to_string(), "a=b".to_string()))
+        );
+        assert_eq!(
+            parse_container_label("empty="),
+            Ok(("empty".to_string(), String::new()))
+        );
+        assert!(parse_container_label("novalue").is_err());
+        assert!(parse_container_label("=value").is_err());
+        assert!(parse_container_label("bad key=value").is_err());
+    }
+
+    #[test]
+    fn user_labels_exclude_managed_label() {
+        let labels = HashMap::from([
+            ("team".to_string(), "infra".to_string()),
+            (CONTAINER_MANAGED_LABEL.to_string(), "true".to_string()),
+            ("env".to_string(), "prod".to_string()),
+        ]);
+        assert_eq!(
+            user_labels(labels),
+            vec![
+                ("env".to_string(), "prod".to_string()),
+                ("team".to_string(), "infra".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn requested_labels_override_existing() {
+        let pair = |k: &str, v: &str| (k.to_string(), v.to_string());
+        let merged = merge_container_labels(
+            &[pair("env", "staging"), pair("team", "infra")],
+            &[pair("env", "prod")],
+        );
+        assert_eq!(merged, vec![pair("env", "prod"), pair("team", "infra")]);
+    }
+
+    #[test]
+    fn user_labels_do_not_override_managed_label() {
+        let labels = container_labels(vec![
+            ("env".to_string(), "prod".to_string()),
+            (CONTAINER_MANAGED_LABEL.to_string(), "false".to_string()),
+        ]);
+        assert_eq!(labels.get("env").map(String::as_str), Some("prod"));
+        assert_eq!(
+            labels.get(CONTAINER_MANAGED_LABEL).map(String::as_str),
+            Some("true")
+        );
+        assert_eq!(labels.len(), 2);
+    }
+
+    #[test]
+    fn restart_policy_update_from_bool() {
+        let enabled = restart_policy_update(true);
+        assert_eq!(
+            enabled.restart_policy.unwrap().name,
+            Some(RestartPolicyNameEnum::UNLESS_STOPPED)
+        );
+        assert_eq!(enabled.memory, None);
+        assert_eq!(enabled.cpu_shares, None);
+
+        let disabled = restart_policy_update(false);
+        assert_eq!(
+            disabled.restart_policy.unwrap().name,
+            Some(RestartPolicyNameEnum::NO)
+        );
+    }
+
+    #[test]
+    fn restart_policy_update_serializes_only_policy() {
+        let json = serde_json::to_value(restart_policy_update(true)).unwrap();
+        assert_eq!(
+            json["RestartPolicy"],
+            serde_json::json!({ "Name": "unless-stopped" })
+        );
+        assert!(json["Memory"].is_null());
+    }
+
+    #[test]
+    fn existing_container_errors_without_force() {
+        let err = should_remove_existing("opencode-cloud", true, false).unwrap_err();
+        assert!(err.to_string().contains("already exists"));
+    }
+
+    #[test]
+    fn existing_container_is_removed_with_force() {
+        assert!(should_remove_existing("opencode-cloud", true, true).unwrap());
+    }
+
+    #[test]
+    fn missing_container_is_created_directly() {
+        assert!(!should_remove_existing("opencode-cloud", false, false).unwrap());
+        assert!(!should_remove_existing("opencode-cloud", false, true).unwrap());
+    }
 
     #[test]
     fn container_constants_are_correct() {
@@ -477,6 +1097,30 @@ mod tests {
         assert_eq!(OPENCODE_WEB_PORT, 3000);
     }
 
+    #[test]
+    fn require_image_missing_returns_image_not_found() {
+        let image = format!("{IMAGE_NAME_GHCR}:{IMAGE_TAG_DEFAULT}");
+        match require_image(&image, false) {
+            Err(DockerError::ImageNotFound(name)) => assert_eq!(name, image),
+            other => panic!("Expected ImageNotFound, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn force_recreate_keeps_container_when_image_missing() {
+        let image = format!("{IMAGE_NAME_GHCR}:{IMAGE_TAG_DEFAULT}");
+        match plan_replace_existing("opencode-cloud", &image, true, false, true) {
+            Err(DockerError::ImageNotFound(name)) => assert_eq!(name, image),
+            other => panic!("Expected ImageNotFound, got: {other:?}"),
+        }
+        assert!(plan_replace_existing("opencode-cloud", &image, true, true, true).unwrap());
+    }
+
+    #[test]
+    fn require_image_present_is_ok() {
+        assert!(require_image("ubuntu:24.04", true).is_ok());
+    }
+
     #[test]
     fn default_image_format() {
         let expected = format!("{IMAGE_NAME_GHCR}:{IMAGE_TAG_DEFAULT}");
@@ -0,0 +1,238 @@
+//! Explicit Docker endpoint selection
+//!
+//! By default the Docker client auto-detects the local socket (honoring
+//! `DOCKER_HOST`). The CLI's `--socket` flag replaces that with an explicit
+//! endpoint for the whole invocation: a unix socket, a TCP daemon, or an
+//! SSH host reached through a tunnel.
+
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use super::error::DockerError;
+use crate::host::HostConfig;
+
+/// Process-wide endpoint override (set from the `--socket` flag)
+static DOCKER_ENDPOINT: OnceLock<DockerEndpoint> = OnceLock::new();
+
+/// Use `endpoint` for every Docker client created afterwards
+///
+/// Like `set_plain_output`, the CLI calls this once at startup. Later calls
+/// are ignored.
+pub fn set_docker_endpoint(endpoint: DockerEndpoint) {
+    let _ = DOCKER_ENDPOINT.set(endpoint);
+}
+
+/// The endpoint override, if one was set
+pub fn docker_endpoint() -> Option<&'static DockerEndpoint> {
+    DOCKER_ENDPOINT.get()
+}
+
+/// A Docker daemon address
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DockerEndpoint {
+    /// Unix socket path (`unix:///path` or a bare absolute path)
+    Unix(PathBuf),
+    /// TCP daemon (`tcp://host:port`), stored as `host:port`
+    Tcp(String),
+    /// Remote daemon over SSH (`ssh://[user@]host[:port]`)
+    Ssh {
+        /// SSH user (defaults to the current user)
+        user: Option<String>,
+        /// SSH hostname or IP
+        host: String,
+        /// SSH port (defaults to 22)
+        port: Option<u16>,
+    },
+}
+
+impl DockerEndpoint {
+    /// Host config for opening an SSH tunnel, if this is an SSH endpoint
+    pub fn ssh_host_config(&self) -> Option<HostConfig> {
+        let Self::Ssh { user, host, port } = self else {
+            return None;
+        };
+        let mut config = HostConfig::new(host.clone());
+        if let Some(user) = user {
+            config = config.with_user(user.clone());
+        }
+        if let Some(port) = port {
+            config = config.with_port(*port);
+        }
+        Some(config)
+    }
+}
+
+impl fmt::Display for DockerEndpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unix(path) => write!(f, "unix://{}", path.display()),
+            Self::Tcp(addr) => write!(f, "tcp://{addr}"),
+            Self::Ssh { user, host, port } => {
+                write!(f, "ssh://")?;
+                if let Some(user) = user {
+                    write!(f, "{user}@")?;
+                }
+                write!(f, "{host}")?;
+                if let Some(port) = port {
+                    write!(f, ":{port}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl FromStr for DockerEndpoint {
+    type Err = DockerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let invalid = |reason: &str| DockerError::InvalidEndpoint(format!("'{s}': {reason}"));
+
+        if s.starts_with('/') {
+            return Ok(Self::Unix(PathBuf::from(s)));
+        }
+
+        let Some((scheme, rest)) = s.split_once("://") else {
+            return Err(invalid("expected unix://, tcp:// or ssh://"));
+        };
+
+        match scheme {
+            "unix" => {
+                if !rest.starts_with('/') {
+                    return Err(invalid("unix socket path must be absolute"));
+                }
+                Ok(Self::Unix(PathBuf::from(rest)))
+            }
+            "tcp" => {
+                let (host, port) = split_host_port(rest).map_err(|e| invalid(&e))?;
+                let port = port.ok_or_else(|| invalid("tcp endpoints need host:port"))?;
+                Ok(Self::Tcp(format!("{host}:{port}")))
+            }
+            "ssh" => {
+                let (user, host_port) = match rest.split_once('@') {
+                    Some(("", _)) => return Err(invalid("empty ssh user")),
+                    Some((user, host_port)) => (Some(user.to_string()), host_port),
+                    None => (None, rest),
+                };
+                let (host, port) = split_host_port(host_port).map_err(|e| invalid(&e))?;
+                Ok(Self::Ssh {
+                    user,
+                    host: host.to_string(),
+                    port,
+                })
+            }
+            other => Err(invalid(&format!(
+                "unsupported scheme '{other}://' (expected unix://, tcp:// or ssh://)"
+            ))),
+        }
+    }
+}
+
+/// Split `host[:port]`, rejecting empty hosts, paths and invalid ports
+fn split_host_port(s: &str) -> Result<(&str, Option<u16>), String> {
+    if s.contains('/') {
+        return Err("unexpected path after host".to_string());
+    }
+    let (host, port) = match s.rsplit_once(':') {
+        Some((host, port)) => {
+            let port = port
+                .parse::<u16>()
+                .ok()
+                .filter(|p| *p != 0)
+                .ok_or_else(|| format!("invalid port '{port}'"))?;
+            (host, Some(port))
+        }
+        None => (s, None),
+    };
+    if host.is_empty() {
+        return Err("missing host".to_string());
+    }
+    Ok((host, port))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Result<DockerEndpoint, DockerError> {
+        s.parse()
+    }
+
+    #[test]
+    fn unix_forms() {
+        let expected = DockerEndpoint::Unix(PathBuf::from("/run/user/1000/docker.sock"));
+        assert_eq!(
+            parse("unix:///run/user/1000/docker.sock").unwrap(),
+            expected
+        );
+        assert_eq!(parse("/run/user/1000/docker.sock").unwrap(), expected);
+        assert!(parse("unix://relative.sock").is_err());
+    }
+
+    #[test]
+    fn tcp_forms() {
+        assert_eq!(
+            parse("tcp://10.0.0.5:2375").unwrap(),
+            DockerEndpoint::Tcp("10.0.0.5:2375".to_string())
+        );
+        assert!(parse("tcp://10.0.0.5").is_err());
+        assert!(parse("tcp://:2375").is_err());
+        assert!(parse("tcp://host:0").is_err());
+        assert!(parse("tcp://host:2375/path").is_err());
+    }
+
+    #[test]
+    fn ssh_forms() {
+        assert_eq!(
+            parse("ssh://deploy@build-box:2222").unwrap(),
+            DockerEndpoint::Ssh {
+                user: Some("deploy".to_string()),
+                host: "build-box".to_string(),
+                port: Some(2222),
+            }
+        );
+        assert_eq!(
+            parse("ssh://build-box").unwrap(),
+            DockerEndpoint::Ssh {
+                user: None,
+                host: "build-box".to_string(),
+                port: None,
+            }
+        );
+        assert!(parse("ssh://@build-box").is_err());
+        assert!(parse("ssh://").is_err());
+    }
+
+    #[test]
+    fn unsupported_scheme_is_rejected() {
+        let err = parse("npipe:////./pipe/docker_engine").unwrap_err();
+        assert!(err.to_string().contains("unsupported scheme 'npipe://'"));
+        assert!(parse("localhost:2375").is_err());
+    }
+
+    #[test]
+    fn display_roundtrips() {
+        for s in [
+            "unix:///var/run/docker.sock",
+            "tcp://10.0.0.5:2375",
+            "ssh://deploy@build-box:2222",
+        ] {
+            assert_eq!(parse(s).unwrap().to_string(), s);
+        }
+    }
+
+    #[test]
+    fn ssh_host_config() {
+        let config = parse("ssh://deploy@build-box:2222")
+            .unwrap()
+            .ssh_host_config()
+            .unwrap();
+        assert_eq!(config.hostname, "build-box");
+        assert_eq!(config.user, "deploy");
+        assert_eq!(config.port, Some(2222));
+        assert!(parse("tcp://h:1").unwrap().ssh_host_config().is_none());
+    }
+}
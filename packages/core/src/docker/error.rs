@@ -12,6 +12,10 @@ pub enum DockerError {
     #[error("Docker connection failed: {0}")]
     Connection(String),
 
+    /// Docker endpoint given with `--socket` is malformed or unsupported
+    #[error("Invalid Docker endpoint {0}")]
+    InvalidEndpoint(String),
+
     /// Docker daemon is not running
     #[error("Docker daemon not running. Start Docker Desktop or the Docker service.")]
     NotRunning,
@@ -38,6 +42,10 @@ pub enum DockerError {
     #[error("Container operation failed: {0}")]
     Container(String),
 
+    /// Required image is not present locally (carries the full image name)
+    #[error("Image '{0}' not found locally")]
+    ImageNotFound(String),
+
     /// Volume operation failed
     #[error("Volume operation failed: {0}")]
     Volume(String),
@@ -47,6 +55,13 @@ pub enum DockerError {
     Timeout,
 }
 
+impl DockerError {
+    /// Whether the daemon itself could not be reached, rather than a request failing
+    pub fn is_daemon_unreachable(&self) -> bool {
+        matches!(self, DockerError::NotRunning | DockerError::SocketNotFound)
+    }
+}
+
 impl From<bollard::errors::Error> for DockerError {
     fn from(err: bollard::errors::Error) -> Self {
         let msg = err.to_string();
@@ -83,5 +98,23 @@ mod tests {
 
         let err = DockerError::Build("layer failed".to_string());
         assert!(err.to_string().contains("layer failed"));
+
+        let err = DockerError::ImageNotFound("example/image:latest".to_string());
+        assert!(err.to_string().contains("example/image:latest"));
+    }
+
+    #[test]
+    fn daemon_unreachable_classification() {
+        let refused = bollard::errors::Error::IOError {
+            err: std::io::Error::new(std::io::ErrorKind::ConnectionRefused, "connection refused"),
+        };
+        assert!(DockerError::from(refused).is_daemon_unreachable());
+        assert!(DockerError::SocketNotFound.is_daemon_unreachable());
+
+        let server = bollard::errors::Error::DockerResponseServerError {
+            status_code: 500,
+            message: "internal error".to_string(),
+        };
+        assert!(!DockerError::from(server).is_daemon_unreachable());
     }
 }
@@ -2,11 +2,73 @@
 //!
 //! Provides health checking functionality by querying OpenCode's /global/health endpoint.
 
+use bollard::service::HealthConfig;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 use thiserror::Error;
 
 use super::DockerClient;
+use crate::config::Config;
+
+/// Grace period after container start before failed probes count against retries
+///
+/// Systemd mode can take a while to bring opencode up, so this is generous.
+const HEALTH_CHECK_START_PERIOD_SECS: u64 = 60;
+
+/// Docker healthcheck settings for the opencode container
+///
+/// Built from the `health_check_*` config fields and converted into a bollard
+/// [`HealthConfig`] when the container is created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HealthCheckSettings {
+    /// Seconds between probes
+    pub interval_secs: u32,
+    /// Seconds before a probe is considered failed
+    pub timeout_secs: u32,
+    /// Consecutive failures before the container is marked unhealthy
+    pub retries: u32,
+}
+
+impl HealthCheckSettings {
+    /// Build settings from config, or None if the health check is disabled
+    pub fn from_config(config: &Config) -> Option<Self> {
+        if !config.health_check_enabled {
+            return None;
+        }
+
+        Some(Self {
+            interval_secs: config.health_check_interval,
+            timeout_secs: config.health_check_timeout,
+            retries: config.health_check_retries,
+        })
+    }
+
+    /// Convert into the bollard healthcheck definition
+    ///
+    /// The probe hits the opencode health endpoint on the internal port,
+    /// falling back to wget for images without curl.
+    pub fn to_health_config(&self) -> HealthConfig {
+        let url = format!(
+            "http://127.0.0.1:{}/global/health",
+            super::OPENCODE_WEB_PORT
+        );
+        let probe = format!("curl -fsS -o /dev/null {url} || wget -q -O /dev/null {url} || exit 1");
+
+        HealthConfig {
+            test: Some(vec!["CMD-SHELL".to_string(), probe]),
+            interval: Some(secs_to_nanos(u64::from(self.interval_secs))),
+            timeout: Some(secs_to_nanos(u64::from(self.timeout_secs))),
+            retries: Some(i64::from(self.retries)),
+            start_period: Some(secs_to_nanos(HEALTH_CHECK_START_PERIOD_SECS)),
+            start_interval: None,
+        }
+    }
+}
+
+/// Docker expresses healthcheck durations in nanoseconds
+fn secs_to_nanos(secs: u64) -> i64 {
+    i64::try_from(Duration::from_secs(secs).as_nanos()).unwrap_or(i64::MAX)
+}
 
 /// Response from OpenCode's /global/health endpoint
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -89,6 +151,21 @@ pub async fn check_health(port: u16) -> Result<HealthResponse, HealthError> {
     }
 }
 
+/// Fetch the HTTP status code of a URL (http or https)
+///
+/// Used by custom readiness probes. Redirects are followed; connection
+/// failures and timeouts map to the same errors as [`check_health`].
+pub async fn probe_http_status(url: &str, timeout: Duration) -> Result<u16, HealthError> {
+    let client = reqwest::Client::builder().timeout(timeout).build()?;
+
+    match client.get(url).send().await {
+        Ok(resp) => Ok(resp.status().as_u16()),
+        Err(e) if e.is_connect() => Err(HealthError::ConnectionRefused),
+        Err(e) if e.is_timeout() => Err(HealthError::Timeout),
+        Err(e) => Err(HealthError::RequestError(e)),
+    }
+}
+
 /// Check health with extended information including container stats
 ///
 /// Combines basic health check with container statistics from Docker.
@@ -152,6 +229,59 @@ pub async fn check_health_extended(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_health_check_settings_from_default_config() {
+        let settings = HealthCheckSettings::from_config(&Config::default()).unwrap();
+        assert_eq!(
+            settings,
+            HealthCheckSettings {
+                interval_secs: 30,
+                timeout_secs: 5,
+                retries: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn test_health_check_settings_disabled() {
+        let config = Config {
+            health_check_enabled: false,
+            ..Config::default()
+        };
+        assert!(HealthCheckSettings::from_config(&config).is_none());
+    }
+
+    #[test]
+    fn test_health_config_uses_config_values() {
+        let config = Config {
+            health_check_interval: 15,
+            health_check_timeout: 2,
+            health_check_retries: 7,
+            ..Config::default()
+        };
+        let health = HealthCheckSettings::from_config(&config)
+            .unwrap()
+            .to_health_config();
+
+        assert_eq!(health.interval, Some(15_000_000_000));
+        assert_eq!(health.timeout, Some(2_000_000_000));
+        assert_eq!(health.retries, Some(7));
+        assert_eq!(health.start_period, Some(60_000_000_000));
+    }
+
+    #[test]
+    fn test_health_config_probes_internal_port() {
+        let health = HealthCheckSettings::from_config(&Config::default())
+            .unwrap()
+            .to_health_config();
+        let test = health.test.unwrap();
+
+        assert_eq!(test[0], "CMD-SHELL");
+        assert!(test[1].contains("http://127.0.0.1:3000/global/health"));
+        assert!(test[1].contains("curl"));
+        assert!(test[1].contains("wget"));
+    }
+
     #[tokio::test]
     async fn test_health_check_connection_refused() {
         // Port 1 should always refuse connection
@@ -4,19 +4,21 @@
 //! Dockerfile and pull images from registries with progress feedback.
 
 use super::progress::ProgressReporter;
+use super::registry_auth::{RegistryAuth, registry_host};
 use super::{
     DOCKERFILE, DockerClient, DockerError, IMAGE_NAME_DOCKERHUB, IMAGE_NAME_GHCR, IMAGE_TAG_DEFAULT,
 };
+use bollard::auth::DockerCredentials;
 use bollard::image::{BuildImageOptions, BuilderVersion, CreateImageOptions};
 use bollard::moby::buildkit::v1::StatusResponse as BuildkitStatusResponse;
-use bollard::models::BuildInfoAux;
+use bollard::models::{BuildInfoAux, HistoryResponseItem};
 use bytes::Bytes;
 use flate2::Compression;
 use flate2::write::GzEncoder;
 use futures_util::StreamExt;
 use std::collections::{HashMap, VecDeque};
 use std::env;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tar::Builder as TarBuilder;
 use tracing::{debug, warn};
 
@@ -66,6 +68,24 @@ pub async fn image_exists(
     }
 }
 
+/// Layer history of a local image, newest layer first
+///
+/// `image` is a full reference such as `ghcr.io/owner/name:latest`.
+pub async fn image_history(
+    client: &DockerClient,
+    image: &str,
+) -> Result<Vec<HistoryResponseItem>, DockerError> {
+    debug!("Reading history of image: {}", image);
+
+    match client.inner().image_history(image).await {
+        Ok(history) => Ok(history),
+        Err(bollard::errors::Error::DockerResponseServerError {
+            status_code: 404, ..
+        }) => Err(DockerError::ImageNotFound(image.to_string())),
+        Err(e) => Err(DockerError::from(e)),
+    }
+}
+
 /// Build the opencode image from embedded Dockerfile
 ///
 /// Shows real-time build progress with streaming output.
@@ -121,6 +141,7 @@ pub async fn build_image(
 
     // Add main build spinner (context prefix like "Building image" is set by caller)
     progress.add_spinner("build", "Initializing...");
+    let started = Instant::now();
 
     let mut maybe_image_id = None;
     let mut log_state = BuildLogState::new();
@@ -163,7 +184,12 @@ pub async fn build_image(
     }
 
     let image_id = maybe_image_id.unwrap_or_else(|| "unknown".to_string());
-    let finish_msg = format!("Build complete: {image_id}");
+    let cache_stats = log_state.cache.stats();
+    debug!("Build cache: {:?}", cache_stats);
+    let finish_msg = format!(
+        "Build complete: {image_id} ({})",
+        cache_stats.summary(started.elapsed())
+    );
     progress.finish("build", &finish_msg);
 
     Ok(full_name)
@@ -179,6 +205,135 @@ struct BuildLogState {
     last_buildkit_vertex_id: Option<String>,
     buildkit_logs_by_vertex_id: HashMap<String, String>,
     vertex_name_by_vertex_id: HashMap<String, String>,
+    cache: BuildCacheTracker,
+}
+
+/// How many build steps were served from cache vs executed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BuildCacheStats {
+    /// Steps reused from the build cache
+    pub cached: u32,
+    /// Steps that actually ran
+    pub executed: u32,
+}
+
+impl BuildCacheStats {
+    /// e.g. "12 cached, 3 executed, 1m 05s"
+    pub fn summary(&self, elapsed: Duration) -> String {
+        format!(
+            "{} cached, {} executed, {}",
+            self.cached,
+            self.executed,
+            format_build_duration(elapsed)
+        )
+    }
+}
+
+/// Counts cache hits from classic builder lines and BuildKit vertices
+///
+/// The classic builder prints "Step N/M" followed by " ---> Using cache" for
+/// reused steps. BuildKit reports a `cached` flag on each vertex (rendered
+/// as "#N CACHED" in plain progress output); vertices are keyed by digest so
+/// repeated status updates are counted once, and only Dockerfile steps
+/// ("[stage N/M] ...") are counted.
+#[derive(Debug, Default)]
+struct BuildCacheTracker {
+    classic_steps: u32,
+    classic_cached: u32,
+    vertices: HashMap<String, bool>,
+}
+
+impl BuildCacheTracker {
+    fn record_stream_line(&mut self, msg: &str) {
+        if parse_classic_step(msg).is_some() {
+            // FROM only resolves the base image and never reports a cache hit
+            let is_from = msg
+                .split_once(" : ")
+                .is_some_and(|(_, instruction)| instruction.starts_with("FROM "));
+            if !is_from {
+                self.classic_steps += 1;
+            }
+        } else if msg.contains("---> Using cache") {
+            self.classic_cached += 1;
+        } else if let Some(id) = parse_plain_cached_vertex(msg) {
+            self.vertices.insert(id.to_string(), true);
+        }
+    }
+
+    fn record_vertex(&mut self, digest: &str, name: &str, cached: bool, completed: bool) {
+        if parse_buildkit_step(name).is_none() || !(cached || completed) {
+            return;
+        }
+        // A vertex can be reported before and after it is resolved from cache
+        let entry = self.vertices.entry(digest.to_string()).or_insert(cached);
+        *entry |= cached;
+    }
+
+    fn stats(&self) -> BuildCacheStats {
+        let buildkit_cached = self.vertices.values().filter(|cached| **cached).count() as u32;
+        let buildkit_executed = self.vertices.len() as u32 - buildkit_cached;
+        BuildCacheStats {
+            cached: self.classic_cached + buildkit_cached,
+            executed: self.classic_steps.saturating_sub(self.classic_cached) + buildkit_executed,
+        }
+    }
+}
+
+/// Extract the vertex number from a plain BuildKit line ("#7 CACHED")
+fn parse_plain_cached_vertex(msg: &str) -> Option<&str> {
+    let (id, rest) = msg.split_once(' ')?;
+    let number = id.strip_prefix('#')?;
+    (!number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()) && rest.trim() == "CACHED")
+        .then_some(id)
+}
+
+/// Format a build duration as "45s" or "2m 05s"
+fn format_build_duration(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    if secs < 60 {
+        format!("{secs}s")
+    } else {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    }
+}
+
+/// Overall build position parsed from builder output ("Step 12/34")
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct BuildStep {
+    current: u32,
+    total: u32,
+}
+
+impl BuildStep {
+    /// Completion percentage, clamped to 0..=100
+    fn percent(&self) -> u32 {
+        if self.total == 0 {
+            return 0;
+        }
+        self.current.min(self.total) * 100 / self.total
+    }
+}
+
+/// Parse "N/M" into a build step, rejecting zero or out-of-range values
+fn parse_step_fraction(fraction: &str) -> Option<BuildStep> {
+    let (current, total) = fraction.split_once('/')?;
+    let current = current.trim().parse::<u32>().ok()?;
+    let total = total.trim().parse::<u32>().ok()?;
+    (current > 0 && current <= total).then_some(BuildStep { current, total })
+}
+
+/// Extract the step from a classic builder line ("Step 12/34 : RUN ...")
+fn parse_classic_step(msg: &str) -> Option<BuildStep> {
+    let rest = msg.strip_prefix("Step ")?;
+    let fraction = rest.split_whitespace().next()?;
+    parse_step_fraction(fraction)
+}
+
+/// Extract the step from a BuildKit vertex name ("[runtime 5/20] RUN ..." or "[2/4] COPY ...")
+fn parse_buildkit_step(name: &str) -> Option<BuildStep> {
+    let inner = name.strip_prefix('[')?.split(']').next()?;
+    let fraction = inner.rsplit(' ').next()?;
+    parse_step_fraction(fraction)
 }
 
 impl BuildLogState {
@@ -201,6 +356,7 @@ impl BuildLogState {
             last_buildkit_vertex_id: None,
             buildkit_logs_by_vertex_id: HashMap::new(),
             vertex_name_by_vertex_id: HashMap::new(),
+            cache: BuildCacheTracker::default(),
         }
     }
 }
@@ -220,6 +376,14 @@ fn handle_stream_message(
 
     if progress.is_plain_output() {
         eprint!("{stream_msg}");
+    } else if let Some(step) = parse_classic_step(msg) {
+        let description = msg.split_once(" : ").map_or(msg, |(_, rest)| rest);
+        progress.update_step(
+            "build",
+            u64::from(step.current),
+            u64::from(step.total),
+            description,
+        );
     } else {
         let has_runtime_vertex = state
             .last_buildkit_vertex
@@ -231,6 +395,8 @@ fn handle_stream_message(
         }
     }
 
+    state.cache.record_stream_line(msg);
+
     if state.recent_logs.len() >= state.build_log_buffer_size {
         state.recent_logs.pop_front();
     }
@@ -243,8 +409,8 @@ fn handle_stream_message(
         state.error_logs.push_back(msg.to_string());
     }
 
-    if msg.starts_with("Step ") {
-        debug!("Build step: {}", msg);
+    if let Some(step) = parse_classic_step(msg) {
+        debug!("Build step {}% ({})", step.percent(), msg);
     }
 }
 
@@ -255,6 +421,7 @@ fn handle_buildkit_status(
 ) {
     let latest_logs = append_buildkit_logs(&mut state.buildkit_logs_by_vertex_id, status);
     update_buildkit_vertex_names(&mut state.vertex_name_by_vertex_id, status);
+    record_buildkit_cache(&mut state.cache, &state.vertex_name_by_vertex_id, status);
     let (vertex_id, vertex_name) =
         match select_latest_buildkit_vertex(status, &state.vertex_name_by_vertex_id) {
             Some((vertex_id, vertex_name)) => (vertex_id, vertex_name),
@@ -277,6 +444,7 @@ fn handle_buildkit_status(
         state.last_buildkit_vertex = Some(vertex_name.clone());
     }
 
+    let step = parse_buildkit_step(&vertex_name);
     let message = if progress.is_plain_output() {
         vertex_name
     } else if let Some(log_entry) = latest_logs.last() {
@@ -284,7 +452,15 @@ fn handle_buildkit_status(
     } else {
         vertex_name
     };
-    progress.update_spinner("build", &message);
+    match step {
+        Some(step) => progress.update_step(
+            "build",
+            u64::from(step.current),
+            u64::from(step.total),
+            &message,
+        ),
+        None => progress.update_spinner("build", &message),
+    }
 
     if progress.is_plain_output() {
         for log_entry in latest_logs {
@@ -357,6 +533,24 @@ fn update_buildkit_vertex_names(
     }
 }
 
+fn record_buildkit_cache(
+    cache: &mut BuildCacheTracker,
+    vertex_name_by_vertex_id: &HashMap<String, String>,
+    status: &BuildkitStatusResponse,
+) {
+    for vertex in &status.vertexes {
+        let Some(name) = vertex_name_by_vertex_id.get(&vertex.digest) else {
+            continue;
+        };
+        cache.record_vertex(
+            &vertex.digest,
+            name,
+            vertex.cached,
+            vertex.completed.is_some(),
+        );
+    }
+}
+
 fn select_latest_buildkit_vertex(
     status: &BuildkitStatusResponse,
     vertex_name_by_vertex_id: &HashMap<String, String>,
@@ -482,18 +676,20 @@ fn append_buildkit_logs(
 
 /// Pull the opencode image from registry with automatic fallback
 ///
-/// Tries GHCR first, falls back to Docker Hub on failure.
+/// Tries GHCR first, falls back to Docker Hub on failure. Each registry gets
+/// only its own credentials from `auth` (anonymous when there are none).
 /// Returns the full image:tag string on success.
 pub async fn pull_image(
     client: &DockerClient,
     tag: Option<&str>,
+    auth: &RegistryAuth,
     progress: &mut ProgressReporter,
 ) -> Result<String, DockerError> {
     let tag = tag.unwrap_or(IMAGE_TAG_DEFAULT);
 
     // Try GHCR first
     debug!("Attempting to pull from GHCR: {}:{}", IMAGE_NAME_GHCR, tag);
-    let ghcr_err = match pull_from_registry(client, IMAGE_NAME_GHCR, tag, progress).await {
+    let ghcr_err = match pull_from_registry(client, IMAGE_NAME_GHCR, tag, auth, progress).await {
         Ok(()) => {
             let full_name = format!("{IMAGE_NAME_GHCR}:{tag}");
             return Ok(full_name);
@@ -511,7 +707,7 @@ pub async fn pull_image(
         "Attempting to pull from Docker Hub: {}:{}",
         IMAGE_NAME_DOCKERHUB, tag
     );
-    match pull_from_registry(client, IMAGE_NAME_DOCKERHUB, tag, progress).await {
+    match pull_from_registry(client, IMAGE_NAME_DOCKERHUB, tag, auth, progress).await {
         Ok(()) => {
             let full_name = format!("{IMAGE_NAME_DOCKERHUB}:{tag}");
             Ok(full_name)
@@ -530,9 +726,14 @@ async fn pull_from_registry(
     client: &DockerClient,
     image: &str,
     tag: &str,
+    auth: &RegistryAuth,
     progress: &mut ProgressReporter,
 ) -> Result<(), DockerError> {
     let full_name = format!("{image}:{tag}");
+    let credentials = auth.credentials_for(image);
+    if credentials.is_some() {
+        debug!("Using registry credentials for {}", registry_host(image));
+    }
 
     // Manual retry loop since async closures can't capture mutable references
     let mut last_error = None;
@@ -542,7 +743,7 @@ async fn pull_from_registry(
             attempt, MAX_PULL_RETRIES, full_name
         );
 
-        match do_pull(client, image, tag, progress).await {
+        match do_pull(client, image, tag, credentials.clone(), progress).await {
             Ok(()) => return Ok(()),
             Err(e) => {
                 warn!("Pull attempt {} failed: {}", attempt, e);
@@ -569,6 +770,7 @@ async fn do_pull(
     client: &DockerClient,
     image: &str,
     tag: &str,
+    credentials: Option<DockerCredentials>,
     progress: &mut ProgressReporter,
 ) -> Result<(), DockerError> {
     let full_name = format!("{image}:{tag}");
@@ -579,7 +781,9 @@ async fn do_pull(
         ..Default::default()
     };
 
-    let mut stream = client.inner().create_image(Some(options), None, None);
+    let mut stream = client
+        .inner()
+        .create_image(Some(options), None, credentials);
 
     // Add main spinner for overall progress
     progress.add_spinner("pull", &format!("Pulling {full_name}..."));
@@ -747,6 +951,86 @@ mod tests {
         assert_eq!(context[1], 0x8b, "should be gzip compressed");
     }
 
+    #[test]
+    fn parse_classic_step_extracts_fraction() {
+        assert_eq!(
+            parse_classic_step("Step 12/34 : RUN apt-get update"),
+            Some(BuildStep {
+                current: 12,
+                total: 34
+            })
+        );
+        assert_eq!(
+            parse_classic_step("Step 3/3"),
+            Some(BuildStep {
+                current: 3,
+                total: 3
+            })
+        );
+        assert_eq!(parse_classic_step("Step x/34 : RUN true"), None);
+        assert_eq!(parse_classic_step("Step 5/4 : RUN true"), None);
+        assert_eq!(parse_classic_step(" ---> Running in abc"), None);
+    }
+
+    #[test]
+    fn parse_buildkit_step_extracts_fraction() {
+        assert_eq!(
+            parse_buildkit_step("[runtime 5/20] RUN apt-get install -y curl"),
+            Some(BuildStep {
+                current: 5,
+                total: 20
+            })
+        );
+        assert_eq!(
+            parse_buildkit_step("[2/4] COPY . ."),
+            Some(BuildStep {
+                current: 2,
+                total: 4
+            })
+        );
+        assert_eq!(
+            parse_buildkit_step("[internal] load build definition"),
+            None
+        );
+        assert_eq!(parse_buildkit_step("exporting to image"), None);
+    }
+
+    #[test]
+    fn build_step_percent() {
+        assert_eq!(
+            BuildStep {
+                current: 12,
+                total: 34
+            }
+            .percent(),
+            35
+        );
+        assert_eq!(
+            BuildStep {
+                current: 34,
+                total: 34
+            }
+            .percent(),
+            100
+        );
+        assert_eq!(
+            BuildStep {
+                current: 1,
+                total: 3
+            }
+            .percent(),
+            33
+        );
+        assert_eq!(
+            BuildStep {
+                current: 0,
+                total: 0
+            }
+            .percent(),
+            0
+        );
+    }
+
     #[test]
     fn default_tag_is_latest() {
         assert_eq!(IMAGE_TAG_DEFAULT, "latest");
@@ -849,4 +1133,80 @@ mod tests {
         assert!(!is_error_line("Compiling foo v1.0"));
         assert!(!is_error_line("Successfully installed"));
     }
+
+    #[test]
+    fn cache_tracker_counts_classic_markers() {
+        let mut tracker = BuildCacheTracker::default();
+        for line in [
+            "Step 1/4 : FROM ubuntu:24.04",
+            " ---> 1234abcd",
+            "Step 2/4 : RUN apt-get update",
+            " ---> Using cache",
+            " ---> 5678efgh",
+            "Step 3/4 : COPY . /app",
+            " ---> Using cache",
+            "Step 4/4 : RUN make",
+            " ---> Running in 9abc",
+        ] {
+            tracker.record_stream_line(line.trim());
+        }
+        assert_eq!(
+            tracker.stats(),
+            BuildCacheStats {
+                cached: 2,
+                executed: 1
+            }
+        );
+    }
+
+    #[test]
+    fn cache_tracker_counts_buildkit_vertices_once() {
+        let mut tracker = BuildCacheTracker::default();
+        // Repeated updates for the same vertex are counted once
+        tracker.record_vertex("sha256:a", "[runtime 1/3] FROM ubuntu", false, false);
+        tracker.record_vertex("sha256:a", "[runtime 1/3] FROM ubuntu", true, true);
+        tracker.record_vertex("sha256:b", "[runtime 2/3] RUN apt-get", false, true);
+        tracker.record_vertex("sha256:b", "[runtime 2/3] RUN apt-get", false, true);
+        tracker.record_vertex("sha256:c", "[runtime 3/3] RUN make", false, false);
+        // Internal vertices are not Dockerfile steps
+        tracker.record_vertex("sha256:d", "[internal] load build context", true, true);
+
+        assert_eq!(
+            tracker.stats(),
+            BuildCacheStats {
+                cached: 1,
+                executed: 1
+            }
+        );
+    }
+
+    #[test]
+    fn cache_tracker_counts_plain_buildkit_cached_lines() {
+        let mut tracker = BuildCacheTracker::default();
+        for line in [
+            "#5 [runtime 2/9] RUN apt-get update",
+            "#5 CACHED",
+            "#6 CACHED",
+            "#7 DONE 0.1s",
+        ] {
+            tracker.record_stream_line(line);
+        }
+        assert_eq!(tracker.stats().cached, 2);
+        assert_eq!(parse_plain_cached_vertex("#12 CACHED"), Some("#12"));
+        assert_eq!(parse_plain_cached_vertex("# CACHED"), None);
+        assert_eq!(parse_plain_cached_vertex("#12 DONE"), None);
+    }
+
+    #[test]
+    fn cache_stats_summary() {
+        let stats = BuildCacheStats {
+            cached: 12,
+            executed: 3,
+        };
+        assert_eq!(
+            stats.summary(Duration::from_secs(65)),
+            "12 cached, 3 executed, 1m 05s"
+        );
+        assert_eq!(format_build_duration(Duration::from_millis(45_900)), "45s");
+    }
 }
@@ -0,0 +1,142 @@
+//! Container timezone and locale
+//!
+//! Builds the `TZ`, `LANG` and `LC_ALL` environment variables injected into
+//! the container from the `timezone` and `locale` config fields, so
+//! timestamps and locale-sensitive tools in the sandbox match the host.
+
+use std::path::Path;
+
+use crate::config::Config;
+
+/// Config value that asks for the host timezone to be detected
+pub const TIMEZONE_AUTO: &str = "auto";
+
+/// Environment variables for the configured timezone and locale
+///
+/// Empty when neither is configured, or when `timezone = "auto"` and the
+/// host timezone cannot be detected.
+pub fn locale_env(config: &Config) -> Vec<String> {
+    locale_env_with(
+        config.timezone.as_deref(),
+        config.locale.as_deref(),
+        detect_host_timezone,
+    )
+}
+
+fn locale_env_with(
+    timezone: Option<&str>,
+    locale: Option<&str>,
+    detect: impl FnOnce() -> Option<String>,
+) -> Vec<String> {
+    let mut env = Vec::new();
+
+    let timezone = match timezone.map(str::trim) {
+        Some(TIMEZONE_AUTO) => detect(),
+        Some(tz) if !tz.is_empty() => Some(tz.to_string()),
+        _ => None,
+    };
+    if let Some(tz) = timezone {
+        env.push(format!("TZ={tz}"));
+    }
+
+    if let Some(locale) = locale.map(str::trim).filter(|l| !l.is_empty()) {
+        env.push(format!("LANG={locale}"));
+        env.push(format!("LC_ALL={locale}"));
+    }
+
+    env
+}
+
+/// Detect the host's IANA timezone name (e.g. "America/New_York")
+///
+/// Checks `TZ`, then the `/etc/localtime` symlink (Linux and macOS), then
+/// `/etc/timezone` (Debian-style).
+pub fn detect_host_timezone() -> Option<String> {
+    if let Ok(tz) = std::env::var("TZ") {
+        let tz = tz.trim_start_matches(':').trim();
+        if !tz.is_empty() {
+            return Some(tz.to_string());
+        }
+    }
+
+    if let Some(tz) = std::fs::read_link("/etc/localtime")
+        .ok()
+        .and_then(|target| timezone_from_localtime_target(&target))
+    {
+        return Some(tz);
+    }
+
+    std::fs::read_to_string("/etc/timezone")
+        .ok()
+        .map(|contents| contents.trim().to_string())
+        .filter(|tz| !tz.is_empty())
+}
+
+/// Extract the zone name from a `/etc/localtime` symlink target
+///
+/// Handles `/usr/share/zoneinfo/Europe/Berlin` (Linux) and
+/// `/var/db/timezone/zoneinfo/Europe/Berlin` (macOS).
+fn timezone_from_localtime_target(target: &Path) -> Option<String> {
+    let target = target.to_string_lossy();
+    let (_, zone) = target.split_once("zoneinfo/")?;
+    let zone = zone.trim_start_matches("posix/");
+    (!zone.is_empty()).then(|| zone.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn no_detect() -> Option<String> {
+        panic!("detection should not run")
+    }
+
+    #[test]
+    fn unset_injects_nothing() {
+        assert!(locale_env_with(None, None, no_detect).is_empty());
+    }
+
+    #[test]
+    fn explicit_timezone_and_locale() {
+        assert_eq!(
+            locale_env_with(Some("Europe/Berlin"), Some("de_DE.UTF-8"), no_detect),
+            vec!["TZ=Europe/Berlin", "LANG=de_DE.UTF-8", "LC_ALL=de_DE.UTF-8"]
+        );
+    }
+
+    #[test]
+    fn auto_timezone_uses_detection() {
+        let env = locale_env_with(Some("auto"), None, || Some("Asia/Tokyo".to_string()));
+        assert_eq!(env, vec!["TZ=Asia/Tokyo"]);
+    }
+
+    #[test]
+    fn auto_timezone_skipped_when_undetectable() {
+        assert!(locale_env_with(Some("auto"), None, || None).is_empty());
+    }
+
+    #[test]
+    fn blank_values_are_ignored() {
+        assert!(locale_env_with(Some(" "), Some(""), no_detect).is_empty());
+    }
+
+    #[test]
+    fn localtime_targets() {
+        assert_eq!(
+            timezone_from_localtime_target(Path::new("/usr/share/zoneinfo/Europe/Berlin")),
+            Some("Europe/Berlin".to_string())
+        );
+        assert_eq!(
+            timezone_from_localtime_target(Path::new("/var/db/timezone/zoneinfo/America/New_York")),
+            Some("America/New_York".to_string())
+        );
+        assert_eq!(
+            timezone_from_localtime_target(Path::new("../usr/share/zoneinfo/posix/UTC")),
+            Some("UTC".to_string())
+        );
+        assert_eq!(
+            timezone_from_localtime_target(Path::new("/etc/localtime.bak")),
+            None
+        );
+    }
+}
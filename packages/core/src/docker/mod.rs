@@ -11,41 +11,65 @@
 //! - Container exec for running commands inside containers
 //! - User management operations (create, delete, lock/unlock users)
 //! - Image update and rollback operations
-
+//! - Aggregate cleanup of stale containers, images and volumes
+//! - Container resource usage sampling
+//! - Container timezone and locale environment
+//! - Container message of the day
+//! - Reverse-proxy base path
+//! - Container memory and CPU limits
+
+pub mod base_path;
 mod client;
 pub mod container;
 mod dockerfile;
+mod endpoint;
 mod error;
 pub mod exec;
 mod health;
 pub mod image;
+pub mod locale;
+pub mod motd;
 pub mod mount;
 pub mod progress;
+pub mod prune;
+mod registry_auth;
+mod resources;
 pub mod state;
+pub mod stats;
 pub mod update;
 pub mod users;
 mod version;
 pub mod volume;
 
 // Core types
+pub use base_path::{BASE_PATH_ENV_VAR, base_path_env};
 pub use client::DockerClient;
+pub use endpoint::{DockerEndpoint, docker_endpoint, set_docker_endpoint};
 pub use error::DockerError;
-pub use progress::ProgressReporter;
+pub use locale::{detect_host_timezone, locale_env};
+pub use motd::{MAX_MOTD_LEN, MOTD_ENV_VAR, motd_env};
+pub use progress::{ProgressReporter, plain_output_enabled, set_plain_output};
+pub use registry_auth::{RegistryAuth, RegistryLogin, registry_host};
+pub use resources::ResourceLimits;
 
 // Health check operations
 pub use health::{
-    ExtendedHealthResponse, HealthError, HealthResponse, check_health, check_health_extended,
+    ExtendedHealthResponse, HealthCheckSettings, HealthError, HealthResponse, check_health,
+    check_health_extended, probe_http_status,
 };
 
 // Dockerfile constants
 pub use dockerfile::{DOCKERFILE, IMAGE_NAME_DOCKERHUB, IMAGE_NAME_GHCR, IMAGE_TAG_DEFAULT};
 
 // Image operations
-pub use image::{build_image, image_exists, pull_image};
+pub use image::{build_image, image_exists, image_history, pull_image};
 
 // Update operations
 pub use update::{UpdateResult, has_previous_image, rollback_image, update_image};
 
+// Aggregate cleanup
+pub use prune::{PruneCandidate, PruneCategory, PrunePlan, execute_prune, plan_prune};
+
 // Version detection
 pub use version::{VERSION_LABEL, get_cli_version, get_image_version, versions_compatible};
 
@@ -54,26 +78,38 @@ pub use exec::{exec_command, exec_command_exit_code, exec_command_with_stdin};
 
 // User management operations
 pub use users::{
-    UserInfo, create_user, delete_user, list_users, lock_user, set_user_password, unlock_user,
-    user_exists,
+    UserInfo, create_user, delete_user, grant_admin, list_live_usernames, list_users, lock_user,
+    set_user_password, unlock_user, user_exists,
 };
 
 // Volume management
 pub use volume::{
-    MOUNT_CONFIG, MOUNT_PROJECTS, MOUNT_SESSION, VOLUME_CONFIG, VOLUME_NAMES, VOLUME_PROJECTS,
-    VOLUME_SESSION, ensure_volumes_exist, remove_all_volumes, remove_volume, volume_exists,
+    MOUNT_CONFIG, MOUNT_PROJECTS, MOUNT_SESSION, VOLUME_CONFIG, VOLUME_MANAGED_LABEL, VOLUME_NAMES,
+    VOLUME_PROJECTS, VOLUME_SESSION, ensure_volumes_exist, is_managed_volume, remove_all_volumes,
+    remove_volume, volume_exists,
 };
 
-// Bind mount parsing and validation
-pub use mount::{MountError, ParsedMount, check_container_path_warning, validate_mount_path};
+// Bind mount and named-volume mount parsing and validation
+pub use mount::{
+    MountError, MountSource, MountSpecError, ParsedMount, VOLUME_MOUNT_PREFIX,
+    check_container_path_conflicts, check_container_path_warning, check_host_path_warning,
+    parse_mount_specs, validate_mount_path,
+};
 
 // Container lifecycle
 pub use container::{
-    CONTAINER_NAME, ContainerBindMount, ContainerPorts, OPENCODE_WEB_PORT, container_exists,
-    container_is_running, container_state, create_container, get_container_bind_mounts,
-    get_container_ports, remove_container, start_container, stop_container,
+    CONTAINER_MANAGED_LABEL, CONTAINER_NAME, ContainerBindMount, ContainerPorts, ContainerState,
+    ManagedContainer, OPENCODE_WEB_PORT, container_exists, container_is_running, container_state,
+    container_top, create_container, get_container_bind_mounts, get_container_labels,
+    get_container_ports, get_container_resource_limits, list_managed_containers,
+    merge_container_labels, parse_container_label, parse_stop_signal, remove_container,
+    restart_policy, restart_policy_update, signal_container, start_container, stop_container,
+    update_resource_limits, update_restart_policy,
 };
 
+// Container resource usage
+pub use stats::{ResourceUsage, get_container_stats};
+
 // Image state tracking
 pub use state::{ImageState, clear_state, get_state_path, load_state, save_state};
 
@@ -90,6 +126,12 @@ pub use state::{ImageState, clear_state, get_state_path, load_state, save_state}
 /// * `cockpit_port` - Port to bind on host for Cockpit (defaults to 9090)
 /// * `cockpit_enabled` - Whether to enable Cockpit port mapping (defaults to true)
 /// * `bind_mounts` - User-defined bind mounts from config and CLI flags (optional)
+/// * `labels` - Extra labels to set when creating the container (optional)
+/// * `health_check` - Docker healthcheck to inject when creating the container (optional)
+/// * `auto_restart` - Docker restart policy to set when creating the container (optional)
+/// * `resource_limits` - Memory and CPU limits to set when creating the container
+/// * `force_recreate` - Remove and recreate an existing container instead of reusing it
+#[allow(clippy::too_many_arguments)]
 pub async fn setup_and_start(
     client: &DockerClient,
     opencode_web_port: Option<u16>,
@@ -98,12 +140,19 @@ pub async fn setup_and_start(
     cockpit_port: Option<u16>,
     cockpit_enabled: Option<bool>,
     bind_mounts: Option<Vec<mount::ParsedMount>>,
+    labels: Option<Vec<(String, String)>>,
+    health_check: Option<HealthCheckSettings>,
+    auto_restart: Option<bool>,
+    resource_limits: ResourceLimits,
+    force_recreate: bool,
 ) -> Result<String, DockerError> {
     // Ensure volumes exist first
     volume::ensure_volumes_exist(client).await?;
 
-    // Check if container already exists
-    let container_id = if container::container_exists(client, container::CONTAINER_NAME).await? {
+    // Reuse an existing container unless a recreate was requested
+    let reuse_existing =
+        !force_recreate && container::container_exists(client, container::CONTAINER_NAME).await?;
+    let container_id = if reuse_existing {
         // Get existing container ID
         let info = client
             .inner()
@@ -126,6 +175,11 @@ pub async fn setup_and_start(
             cockpit_port,
             cockpit_enabled,
             bind_mounts,
+            labels,
+            health_check,
+            auto_restart,
+            resource_limits,
+            force_recreate,
         )
         .await?
     };
@@ -138,6 +192,19 @@ pub async fn setup_and_start(
     Ok(container_id)
 }
 
+/// Environment variables injected into the container from config
+///
+/// Combines the timezone/locale variables with the message of the day, the
+/// reverse-proxy base path and the user's `container_env` entries. The
+/// `container_env` entries come last so Docker lets them win over derived values.
+pub fn container_env(config: &crate::config::Config) -> Vec<String> {
+    let mut env = locale::locale_env(config);
+    env.extend(motd::motd_env(config));
+    env.extend(base_path::base_path_env(config));
+    env.extend(config.container_env.iter().cloned());
+    env
+}
+
 /// Default graceful shutdown timeout in seconds
 pub const DEFAULT_STOP_TIMEOUT_SECS: i64 = 30;
 
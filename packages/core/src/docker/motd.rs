@@ -0,0 +1,53 @@
+//! Container message of the day
+//!
+//! Builds the `OPENCODE_MOTD` environment variable from the `motd` config
+//! field, so the web UI and terminal inside the container can show a notice
+//! to everyone sharing the sandbox.
+
+use crate::config::Config;
+
+/// Environment variable carrying the message of the day into the container
+pub const MOTD_ENV_VAR: &str = "OPENCODE_MOTD";
+
+/// Longest accepted message, in characters
+pub const MAX_MOTD_LEN: usize = 500;
+
+/// Environment variable for the configured message of the day
+///
+/// Empty when no message is set or it is blank.
+pub fn motd_env(config: &Config) -> Vec<String> {
+    config
+        .motd
+        .as_deref()
+        .map(str::trim)
+        .filter(|motd| !motd.is_empty())
+        .map(|motd| format!("{MOTD_ENV_VAR}={motd}"))
+        .into_iter()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_with_motd(motd: Option<&str>) -> Config {
+        Config {
+            motd: motd.map(str::to_string),
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn set_motd_is_injected() {
+        assert_eq!(
+            motd_env(&config_with_motd(Some("Maintenance at 18:00 UTC"))),
+            vec!["OPENCODE_MOTD=Maintenance at 18:00 UTC"]
+        );
+    }
+
+    #[test]
+    fn absent_motd_injects_nothing() {
+        assert!(motd_env(&config_with_motd(None)).is_empty());
+        assert!(motd_env(&config_with_motd(Some("  "))).is_empty());
+    }
+}
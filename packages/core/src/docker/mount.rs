@@ -2,28 +2,48 @@
 //!
 //! This module provides functionality to:
 //! - Parse mount strings in Docker format (`/host:/container[:ro|rw]`)
+//! - Parse named-volume mounts (`volume:<name>:/container[:ro|rw]`)
+//! - Resolve host paths (`~`, `.`, `..`, and symlinks) to their canonical form
 //! - Validate mount paths (existence, type, permissions)
 //! - Convert parsed mounts to Bollard's Mount type for Docker API
 //! - Warn about potentially dangerous container mount points
 
 use bollard::service::{Mount, MountTypeEnum};
-use std::path::PathBuf;
+use std::path::{Component, Path, PathBuf};
 use thiserror::Error;
 
 /// Errors that can occur during mount parsing and validation.
 #[derive(Debug, Error)]
 pub enum MountError {
     /// Mount path is relative, but must be absolute.
-    #[error("Mount paths must be absolute. Use: /full/path/to/dir (got: {0})")]
-    RelativePath(String),
+    #[error(
+        "Mount paths must be absolute (got: {0}). Use a full path like /home/you/project or ~/project"
+    )]
+    NotAbsolute(String),
 
     /// Mount string format is invalid.
-    #[error("Invalid mount format. Expected: /host/path:/container/path[:ro] (got: {0})")]
+    #[error(
+        "Invalid mount format (got: {0}). Expected: /host/path:/container/path[:ro] or volume:<name>:/container/path[:ro]"
+    )]
     InvalidFormat(String),
 
-    /// Path does not exist or cannot be accessed.
-    #[error("Path not found: {0} ({1})")]
-    PathNotFound(String, String),
+    /// Host path does not exist.
+    #[error(
+        "Host path not found: {path}. Create it, or if the directory moved run: occ mount remove {path}",
+        path = .0.display()
+    )]
+    HostPathMissing(PathBuf),
+
+    /// Two mounts target the same container path.
+    #[error(
+        "Container path {container_path} is already used by mount '{existing}'. Run: occ mount remove {container_path}"
+    )]
+    ContainerPathConflict {
+        /// The contested path inside the container.
+        container_path: String,
+        /// The mount spec that already uses it.
+        existing: String,
+    },
 
     /// Path exists but is not a directory.
     #[error("Path is not a directory: {0}")]
@@ -32,13 +52,52 @@ pub enum MountError {
     /// Permission denied accessing path.
     #[error("Cannot access path (permission denied): {0}")]
     PermissionDenied(String),
+
+    /// Named volume has a name Docker would reject.
+    #[error(
+        "Invalid volume name '{0}'. Use letters, digits, '_', '.' or '-', starting with a letter or digit"
+    )]
+    InvalidVolumeName(String),
+}
+
+/// Prefix marking a named-volume mount spec (`volume:<name>:/container`)
+pub const VOLUME_MOUNT_PREFIX: &str = "volume:";
+
+/// A mount string that failed to parse or validate.
+#[derive(Debug, Error)]
+#[error("Invalid mount '{spec}': {source}")]
+pub struct MountSpecError {
+    /// The offending mount string as given.
+    pub spec: String,
+    /// Why it was rejected.
+    #[source]
+    pub source: MountError,
+}
+
+/// What a mount makes available in the container.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MountSource {
+    /// Absolute host path, bind-mounted.
+    Bind(PathBuf),
+    /// Named Docker volume.
+    Volume(String),
+}
+
+impl std::fmt::Display for MountSource {
+    /// The volume name or the host path.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bind(path) => write!(f, "{}", path.display()),
+            Self::Volume(name) => f.write_str(name),
+        }
+    }
 }
 
-/// A parsed bind mount specification.
+/// A parsed mount specification (bind mount or named volume).
 #[derive(Debug, Clone, PartialEq)]
 pub struct ParsedMount {
-    /// Host path to mount (absolute).
-    pub host_path: PathBuf,
+    /// Host path or named volume to mount.
+    pub source: MountSource,
 
     /// Container path where the host path is mounted.
     pub container_path: String,
@@ -52,6 +111,10 @@ impl ParsedMount {
     ///
     /// Format: `/host/path:/container/path[:ro|rw]`
     ///
+    /// The host path is resolved to its canonical form: a leading `~` is
+    /// expanded, `.`/`..` are normalized, and symlinks are followed when the
+    /// path exists. Use [`ParsedMount::parse_with_options`] to keep symlinks.
+    ///
     /// # Arguments
     /// * `mount_str` - The mount specification string.
     ///
@@ -65,65 +128,158 @@ impl ParsedMount {
     ///
     /// // Read-write mount (default)
     /// let mount = ParsedMount::parse("/home/user/data:/workspace/data").unwrap();
-    /// assert_eq!(mount.host_path.to_str().unwrap(), "/home/user/data");
+    /// assert_eq!(mount.host_path().unwrap().to_str().unwrap(), "/home/user/data");
     /// assert_eq!(mount.container_path, "/workspace/data");
     /// assert!(!mount.read_only);
     ///
     /// // Read-only mount
     /// let mount = ParsedMount::parse("/home/user/config:/etc/app:ro").unwrap();
     /// assert!(mount.read_only);
+    ///
+    /// // Named volume
+    /// let mount = ParsedMount::parse("volume:cache:/workspace/.cache").unwrap();
+    /// assert_eq!(mount.volume_name(), Some("cache"));
     /// ```
     pub fn parse(mount_str: &str) -> Result<Self, MountError> {
+        Self::parse_with_options(mount_str, true)
+    }
+
+    /// Parse a mount string, optionally keeping the literal host path.
+    ///
+    /// With `resolve_symlinks` false only `~` is expanded; `.`, `..`, and
+    /// symlinks are left as written.
+    pub fn parse_with_options(mount_str: &str, resolve_symlinks: bool) -> Result<Self, MountError> {
+        if let Some(rest) = mount_str.strip_prefix(VOLUME_MOUNT_PREFIX) {
+            return Self::parse_volume(mount_str, rest);
+        }
+
         let parts: Vec<&str> = mount_str.split(':').collect();
+        let read_only = parse_mode(mount_str, &parts)?;
 
-        match parts.len() {
-            2 => {
-                // /host:/container (default rw)
-                let host_path = PathBuf::from(parts[0]);
-                if !host_path.is_absolute() {
-                    return Err(MountError::RelativePath(parts[0].to_string()));
-                }
-                Ok(Self {
-                    host_path,
-                    container_path: parts[1].to_string(),
-                    read_only: false,
-                })
-            }
-            3 => {
-                // /host:/container:ro or /host:/container:rw
-                let host_path = PathBuf::from(parts[0]);
-                if !host_path.is_absolute() {
-                    return Err(MountError::RelativePath(parts[0].to_string()));
-                }
-                let read_only = match parts[2].to_lowercase().as_str() {
-                    "ro" => true,
-                    "rw" => false,
-                    _ => return Err(MountError::InvalidFormat(mount_str.to_string())),
-                };
-                Ok(Self {
-                    host_path,
-                    container_path: parts[1].to_string(),
-                    read_only,
-                })
+        let host_path = expand_home(parts[0]);
+        if !host_path.is_absolute() {
+            return Err(MountError::NotAbsolute(parts[0].to_string()));
+        }
+
+        let host_path = if resolve_symlinks {
+            resolve_host_path(&host_path)
+        } else {
+            host_path
+        };
+
+        Ok(Self {
+            source: MountSource::Bind(host_path),
+            container_path: parts[1].to_string(),
+            read_only,
+        })
+    }
+
+    /// Parse the part of a `volume:<name>:/container[:ro|rw]` spec after the prefix.
+    fn parse_volume(mount_str: &str, rest: &str) -> Result<Self, MountError> {
+        let parts: Vec<&str> = rest.split(':').collect();
+        let read_only = parse_mode(mount_str, &parts)?;
+
+        let name = parts[0];
+        if !is_valid_volume_name(name) {
+            return Err(MountError::InvalidVolumeName(name.to_string()));
+        }
+        let container_path = parts[1];
+        if !container_path.starts_with('/') {
+            return Err(MountError::NotAbsolute(container_path.to_string()));
+        }
+
+        Ok(Self {
+            source: MountSource::Volume(name.to_string()),
+            container_path: container_path.to_string(),
+            read_only,
+        })
+    }
+
+    /// Whether this mounts a named volume rather than a host path.
+    pub fn is_volume(&self) -> bool {
+        matches!(self.source, MountSource::Volume(_))
+    }
+
+    /// The host path of a bind mount; `None` for named volumes.
+    pub fn host_path(&self) -> Option<&Path> {
+        match &self.source {
+            MountSource::Bind(path) => Some(path),
+            MountSource::Volume(_) => None,
+        }
+    }
+
+    /// The volume name of a named-volume mount; `None` for bind mounts.
+    pub fn volume_name(&self) -> Option<&str> {
+        match &self.source {
+            MountSource::Bind(_) => None,
+            MountSource::Volume(name) => Some(name),
+        }
+    }
+
+    /// Check that the mount source is usable.
+    ///
+    /// Bind mounts run [`validate_mount_path`]; named volumes were validated
+    /// when parsed and are created by Docker on first use.
+    pub fn validate_source(&self) -> Result<(), MountError> {
+        match &self.source {
+            MountSource::Bind(path) => validate_mount_path(path).map(|_| ()),
+            MountSource::Volume(_) => Ok(()),
+        }
+    }
+
+    /// Format back into a mount string (`/host:/container[:ro]` or
+    /// `volume:<name>:/container[:ro]`).
+    pub fn to_spec(&self) -> String {
+        let ro = if self.read_only { ":ro" } else { "" };
+        match &self.source {
+            MountSource::Volume(name) => {
+                format!("{VOLUME_MOUNT_PREFIX}{name}:{}{ro}", self.container_path)
             }
-            _ => Err(MountError::InvalidFormat(mount_str.to_string())),
+            MountSource::Bind(path) => format!("{}:{}{ro}", path.display(), self.container_path),
         }
     }
 
     /// Convert to a Bollard Mount for the Docker API.
     ///
-    /// Returns a bind mount with the parsed host and container paths.
+    /// Returns a volume mount for named volumes and a bind mount otherwise.
     pub fn to_bollard_mount(&self) -> Mount {
+        let (source, typ) = match &self.source {
+            MountSource::Volume(name) => (name.clone(), MountTypeEnum::VOLUME),
+            MountSource::Bind(path) => (path.to_string_lossy().to_string(), MountTypeEnum::BIND),
+        };
         Mount {
             target: Some(self.container_path.clone()),
-            source: Some(self.host_path.to_string_lossy().to_string()),
-            typ: Some(MountTypeEnum::BIND),
+            source: Some(source),
+            typ: Some(typ),
             read_only: Some(self.read_only),
             ..Default::default()
         }
     }
 }
 
+/// Read the optional `ro`/`rw` suffix of a split `source:target[:mode]` spec.
+fn parse_mode(mount_str: &str, parts: &[&str]) -> Result<bool, MountError> {
+    match parts.len() {
+        // source:/container (default rw)
+        2 => Ok(false),
+        // source:/container:ro or source:/container:rw
+        3 => match parts[2].to_lowercase().as_str() {
+            "ro" => Ok(true),
+            "rw" => Ok(false),
+            _ => Err(MountError::InvalidFormat(mount_str.to_string())),
+        },
+        _ => Err(MountError::InvalidFormat(mount_str.to_string())),
+    }
+}
+
+/// Whether `name` is a valid Docker volume name (`[a-zA-Z0-9][a-zA-Z0-9_.-]+`).
+fn is_valid_volume_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphanumeric())
+        && name.len() >= 2
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+}
+
 /// Validate that a mount host path exists and is accessible.
 ///
 /// Checks:
@@ -140,7 +296,7 @@ impl ParsedMount {
 pub fn validate_mount_path(path: &std::path::Path) -> Result<PathBuf, MountError> {
     // Check absolute
     if !path.is_absolute() {
-        return Err(MountError::RelativePath(path.display().to_string()));
+        return Err(MountError::NotAbsolute(path.display().to_string()));
     }
 
     // Canonicalize (resolves symlinks, checks existence)
@@ -148,7 +304,7 @@ pub fn validate_mount_path(path: &std::path::Path) -> Result<PathBuf, MountError
         if e.kind() == std::io::ErrorKind::PermissionDenied {
             MountError::PermissionDenied(path.display().to_string())
         } else {
-            MountError::PathNotFound(path.display().to_string(), e.to_string())
+            MountError::HostPathMissing(path.to_path_buf())
         }
     })?;
 
@@ -157,7 +313,7 @@ pub fn validate_mount_path(path: &std::path::Path) -> Result<PathBuf, MountError
         if e.kind() == std::io::ErrorKind::PermissionDenied {
             MountError::PermissionDenied(path.display().to_string())
         } else {
-            MountError::PathNotFound(path.display().to_string(), e.to_string())
+            MountError::HostPathMissing(path.to_path_buf())
         }
     })?;
 
@@ -168,6 +324,106 @@ pub fn validate_mount_path(path: &std::path::Path) -> Result<PathBuf, MountError
     Ok(canonical)
 }
 
+/// Parse and validate a list of mount strings.
+///
+/// Each entry is parsed with [`ParsedMount::parse`] and its source checked
+/// with [`ParsedMount::validate_source`]. Stops at the first invalid entry.
+pub fn parse_mount_specs(specs: &[String]) -> Result<Vec<ParsedMount>, MountSpecError> {
+    specs
+        .iter()
+        .map(|spec| {
+            ParsedMount::parse(spec)
+                .and_then(|parsed| parsed.validate_source().map(|()| parsed))
+                .map_err(|source| MountSpecError {
+                    spec: spec.clone(),
+                    source,
+                })
+        })
+        .collect()
+}
+
+/// Check that no two mounts target the same container path.
+///
+/// Docker refuses duplicate mount points, so this reports the conflict
+/// up front with the spec that claimed the path first.
+pub fn check_container_path_conflicts(mounts: &[ParsedMount]) -> Result<(), MountError> {
+    for (i, mount) in mounts.iter().enumerate() {
+        if let Some(existing) = mounts[..i]
+            .iter()
+            .find(|m| m.container_path == mount.container_path)
+        {
+            return Err(MountError::ContainerPathConflict {
+                container_path: mount.container_path.clone(),
+                existing: existing.to_spec(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Expand a leading `~` to the user's home directory.
+fn expand_home(path: &str) -> PathBuf {
+    let home = || dirs::home_dir().unwrap_or_else(|| PathBuf::from("~"));
+    if path == "~" {
+        return home();
+    }
+    match path.strip_prefix("~/") {
+        Some(rest) => home().join(rest),
+        None => PathBuf::from(path),
+    }
+}
+
+/// Normalize `.` and `..` components without touching the filesystem.
+fn normalize_lexically(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+/// Resolve a host path to its canonical form.
+///
+/// Follows symlinks when the path exists; otherwise falls back to lexical
+/// normalization so that validation can report the missing path later.
+fn resolve_host_path(path: &Path) -> PathBuf {
+    std::fs::canonicalize(path).unwrap_or_else(|_| normalize_lexically(path))
+}
+
+/// Check whether a mount's host path resolved somewhere unexpected.
+///
+/// `~` expansion and `.`/`..` cleanup are expected; a difference beyond that
+/// means a symlink was followed, which is worth pointing out because the
+/// mount may expose a directory outside the one the user typed.
+///
+/// # Arguments
+/// * `mount_str` - The original mount specification string.
+/// * `parsed` - The mount as returned by [`ParsedMount::parse`].
+///
+/// # Returns
+/// * `Some(String)` - Warning describing where the path resolved to.
+/// * `None` - The path resolved as written.
+pub fn check_host_path_warning(mount_str: &str, parsed: &ParsedMount) -> Option<String> {
+    let host_path = parsed.host_path()?;
+    let literal = mount_str.split(':').next()?;
+    let expected = normalize_lexically(&expand_home(literal));
+
+    if expected == host_path {
+        return None;
+    }
+
+    Some(format!(
+        "Note: mount source '{literal}' resolves to '{}' (symlink followed)",
+        host_path.display()
+    ))
+}
+
 /// System paths that should typically not be mounted over.
 const SYSTEM_PATHS: &[&str] = &["/etc", "/usr", "/bin", "/sbin", "/lib", "/var"];
 
@@ -201,7 +457,7 @@ mod tests {
     #[test]
     fn parse_valid_mount_rw() {
         let mount = ParsedMount::parse("/a:/b").unwrap();
-        assert_eq!(mount.host_path, PathBuf::from("/a"));
+        assert_eq!(mount.host_path(), Some(Path::new("/a")));
         assert_eq!(mount.container_path, "/b");
         assert!(!mount.read_only);
     }
@@ -209,7 +465,7 @@ mod tests {
     #[test]
     fn parse_valid_mount_ro() {
         let mount = ParsedMount::parse("/a:/b:ro").unwrap();
-        assert_eq!(mount.host_path, PathBuf::from("/a"));
+        assert_eq!(mount.host_path(), Some(Path::new("/a")));
         assert_eq!(mount.container_path, "/b");
         assert!(mount.read_only);
     }
@@ -217,7 +473,7 @@ mod tests {
     #[test]
     fn parse_valid_mount_explicit_rw() {
         let mount = ParsedMount::parse("/a:/b:rw").unwrap();
-        assert_eq!(mount.host_path, PathBuf::from("/a"));
+        assert_eq!(mount.host_path(), Some(Path::new("/a")));
         assert_eq!(mount.container_path, "/b");
         assert!(!mount.read_only);
     }
@@ -249,13 +505,84 @@ mod tests {
     #[test]
     fn parse_relative_path_rejected() {
         let result = ParsedMount::parse("./rel:/b");
-        assert!(matches!(result, Err(MountError::RelativePath(_))));
+        assert!(matches!(result, Err(MountError::NotAbsolute(_))));
     }
 
     #[test]
     fn parse_relative_path_no_dot_rejected() {
         let result = ParsedMount::parse("relative/path:/b");
-        assert!(matches!(result, Err(MountError::RelativePath(_))));
+        assert!(matches!(result, Err(MountError::NotAbsolute(_))));
+    }
+
+    #[test]
+    fn parse_expands_home_directory() {
+        let home = dirs::home_dir().unwrap();
+        let mount = ParsedMount::parse_with_options("~/projects:/workspace/p", false).unwrap();
+        assert_eq!(mount.host_path(), Some(home.join("projects").as_path()));
+    }
+
+    #[test]
+    fn parse_tilde_not_treated_as_relative() {
+        assert!(ParsedMount::parse("~/does-not-exist-xyz123:/b").is_ok());
+    }
+
+    #[test]
+    fn parse_normalizes_parent_components() {
+        let mount = ParsedMount::parse("/nonexistent-xyz123/a/../b/./c:/data").unwrap();
+        assert_eq!(
+            mount.host_path(),
+            Some(Path::new("/nonexistent-xyz123/b/c"))
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn parse_resolves_symlinks() {
+        let temp = tempfile::tempdir().unwrap();
+        let target = temp.path().join("real");
+        let link = temp.path().join("link");
+        std::fs::create_dir(&target).unwrap();
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+
+        let spec = format!("{}:/data", link.display());
+        let mount = ParsedMount::parse(&spec).unwrap();
+        assert_eq!(
+            mount.host_path(),
+            Some(std::fs::canonicalize(&target).unwrap().as_path())
+        );
+
+        let warning = check_host_path_warning(&spec, &mount);
+        assert!(warning.unwrap().contains("symlink"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn parse_without_resolution_keeps_symlink() {
+        let temp = tempfile::tempdir().unwrap();
+        let target = temp.path().join("real");
+        let link = temp.path().join("link");
+        std::fs::create_dir(&target).unwrap();
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+
+        let spec = format!("{}:/data", link.display());
+        let mount = ParsedMount::parse_with_options(&spec, false).unwrap();
+        assert_eq!(mount.host_path(), Some(link.as_path()));
+        assert!(check_host_path_warning(&spec, &mount).is_none());
+    }
+
+    #[test]
+    fn host_path_warning_ignores_plain_normalization() {
+        let spec = "/nonexistent-xyz123/a/../b:/data";
+        let mount = ParsedMount::parse(spec).unwrap();
+        assert!(check_host_path_warning(spec, &mount).is_none());
+    }
+
+    #[test]
+    fn to_spec_roundtrip() {
+        let mount = ParsedMount::parse("/a:/b:ro").unwrap();
+        assert_eq!(mount.to_spec(), "/a:/b:ro");
+        let mount = ParsedMount::parse("/a:/b").unwrap();
+        assert_eq!(mount.to_spec(), "/a:/b");
     }
 
     #[test]
@@ -298,7 +625,7 @@ mod tests {
     #[test]
     fn to_bollard_mount_structure() {
         let mount = ParsedMount {
-            host_path: PathBuf::from("/host/path"),
+            source: MountSource::Bind(PathBuf::from("/host/path")),
             container_path: "/container/path".to_string(),
             read_only: true,
         };
@@ -312,13 +639,13 @@ mod tests {
     #[test]
     fn validate_mount_path_relative_rejected() {
         let result = validate_mount_path(std::path::Path::new("./relative"));
-        assert!(matches!(result, Err(MountError::RelativePath(_))));
+        assert!(matches!(result, Err(MountError::NotAbsolute(_))));
     }
 
     #[test]
     fn validate_mount_path_nonexistent() {
         let result = validate_mount_path(std::path::Path::new("/nonexistent/path/xyz123"));
-        assert!(matches!(result, Err(MountError::PathNotFound(_, _))));
+        assert!(matches!(result, Err(MountError::HostPathMissing(_))));
     }
 
     #[test]
@@ -327,4 +654,138 @@ mod tests {
         let result = validate_mount_path(std::path::Path::new("/tmp"));
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn parse_mount_specs_valid() {
+        let specs = vec!["/tmp:/workspace/tmp:ro".to_string()];
+        let mounts = parse_mount_specs(&specs).unwrap();
+        assert_eq!(mounts.len(), 1);
+        assert_eq!(mounts[0].container_path, "/workspace/tmp");
+        assert!(mounts[0].read_only);
+    }
+
+    #[test]
+    fn parse_mount_specs_empty() {
+        assert!(parse_mount_specs(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn parse_mount_specs_reports_bad_format() {
+        let specs = vec!["/tmp:/a".to_string(), "/tmp".to_string()];
+        let err = parse_mount_specs(&specs).unwrap_err();
+        assert_eq!(err.spec, "/tmp");
+        assert!(matches!(err.source, MountError::InvalidFormat(_)));
+        assert!(err.to_string().contains("'/tmp'"));
+    }
+
+    #[test]
+    fn parse_mount_specs_reports_missing_host_path() {
+        let specs = vec!["/nonexistent/path/xyz123:/workspace".to_string()];
+        let err = parse_mount_specs(&specs).unwrap_err();
+        assert_eq!(err.spec, "/nonexistent/path/xyz123:/workspace");
+        assert!(matches!(err.source, MountError::HostPathMissing(_)));
+    }
+
+    #[test]
+    fn mount_error_messages_include_path_and_hint() {
+        let msg = MountError::NotAbsolute("./src".to_string()).to_string();
+        assert!(msg.contains("./src"));
+        assert!(msg.contains("~/project"));
+
+        let msg = MountError::InvalidFormat("/tmp".to_string()).to_string();
+        assert!(msg.contains("/tmp"));
+        assert!(msg.contains("/host/path:/container/path[:ro]"));
+
+        let msg = MountError::HostPathMissing(PathBuf::from("/gone/dir")).to_string();
+        assert!(msg.contains("Host path not found: /gone/dir"));
+        assert!(msg.contains("occ mount remove /gone/dir"));
+
+        let msg = MountError::ContainerPathConflict {
+            container_path: "/workspace/app".to_string(),
+            existing: "/srv/app:/workspace/app".to_string(),
+        }
+        .to_string();
+        assert!(msg.contains("'/srv/app:/workspace/app'"));
+        assert!(msg.contains("occ mount remove /workspace/app"));
+    }
+
+    #[test]
+    fn container_path_conflicts_detected() {
+        let a = ParsedMount::parse_with_options("/a:/workspace/app", false).unwrap();
+        let b = ParsedMount::parse_with_options("/b:/workspace/other", false).unwrap();
+        let c = ParsedMount::parse_with_options("/c:/workspace/app:ro", false).unwrap();
+        assert!(check_container_path_conflicts(&[a.clone(), b.clone()]).is_ok());
+
+        let err = check_container_path_conflicts(&[a, b, c]).unwrap_err();
+        match err {
+            MountError::ContainerPathConflict {
+                container_path,
+                existing,
+            } => {
+                assert_eq!(container_path, "/workspace/app");
+                assert_eq!(existing, "/a:/workspace/app");
+            }
+            other => panic!("unexpected error: {other}"),
+        }
+    }
+
+    #[test]
+    fn parse_volume_mount() {
+        let mount = ParsedMount::parse("volume:build-cache:/workspace/.cache:ro").unwrap();
+        assert_eq!(mount.volume_name(), Some("build-cache"));
+        assert_eq!(mount.host_path(), None);
+        assert_eq!(mount.container_path, "/workspace/.cache");
+        assert!(mount.read_only);
+        assert!(mount.is_volume());
+        assert_eq!(mount.source.to_string(), "build-cache");
+        assert_eq!(mount.to_spec(), "volume:build-cache:/workspace/.cache:ro");
+        assert!(mount.validate_source().is_ok());
+        assert!(
+            check_host_path_warning("volume:build-cache:/workspace/.cache:ro", &mount).is_none()
+        );
+    }
+
+    #[test]
+    fn volume_mount_yields_volume_type() {
+        let mount = ParsedMount::parse("volume:data_1:/data").unwrap();
+        let bollard_mount = mount.to_bollard_mount();
+        assert_eq!(bollard_mount.typ, Some(MountTypeEnum::VOLUME));
+        assert_eq!(bollard_mount.source, Some("data_1".to_string()));
+        assert_eq!(bollard_mount.target, Some("/data".to_string()));
+        assert_eq!(bollard_mount.read_only, Some(false));
+    }
+
+    #[test]
+    fn parse_volume_rejects_invalid_names() {
+        for spec in [
+            "volume::/data",
+            "volume:a:/data",
+            "volume:-x:/data",
+            "volume:my/vol:/data",
+        ] {
+            assert!(
+                matches!(
+                    ParsedMount::parse(spec),
+                    Err(MountError::InvalidVolumeName(_))
+                ),
+                "{spec}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_volume_requires_absolute_container_path() {
+        assert!(matches!(
+            ParsedMount::parse("volume:cache:relative"),
+            Err(MountError::NotAbsolute(_))
+        ));
+        assert!(matches!(
+            ParsedMount::parse("volume:cache"),
+            Err(MountError::InvalidFormat(_))
+        ));
+        assert!(matches!(
+            ParsedMount::parse("volume:cache:/data:bad"),
+            Err(MountError::InvalidFormat(_))
+        ));
+    }
 }
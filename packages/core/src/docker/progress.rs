@@ -2,14 +2,39 @@
 //!
 //! This module provides progress bars and spinners for Docker image
 //! builds and pulls, using indicatif for terminal output.
+//!
+//! In plain mode (see [`set_plain_output`]) no spinners are drawn; each
+//! progress change is written as a single newline-terminated line instead,
+//! which keeps CI logs free of carriage-return redraws.
 
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use std::collections::HashMap;
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
 
 /// Minimum time between spinner message updates to prevent flickering
 const SPINNER_UPDATE_THROTTLE: Duration = Duration::from_millis(150);
 
+/// Layer progress is reported in plain mode only when crossing this percentage step
+const PLAIN_LAYER_PERCENT_STEP: u64 = 10;
+
+/// Process-wide default for plain output (set from the `--plain` flag)
+static PLAIN_OUTPUT: AtomicBool = AtomicBool::new(false);
+
+/// Enable or disable plain output for reporters created afterwards
+///
+/// Mirrors `console::set_colors_enabled`: the CLI calls this once at startup
+/// so every command picks up the setting without extra plumbing.
+pub fn set_plain_output(enabled: bool) {
+    PLAIN_OUTPUT.store(enabled, Ordering::Relaxed);
+}
+
+/// Check whether plain output is enabled process-wide
+pub fn plain_output_enabled() -> bool {
+    PLAIN_OUTPUT.load(Ordering::Relaxed)
+}
+
 /// Strip ANSI escape codes from a string
 ///
 /// Docker build output often contains ANSI color codes that can interfere
@@ -49,10 +74,14 @@ pub struct ProgressReporter {
     bars: HashMap<String, ProgressBar>,
     last_update_by_id: HashMap<String, Instant>,
     last_message_by_id: HashMap<String, String>,
+    /// Last (current, total) build step shown per id; presence means the bar style is active
+    step_by_id: HashMap<String, (u64, u64)>,
     /// Optional context prefix shown before step messages (e.g., "Building Docker image")
     context: Option<String>,
     /// When true, print build output lines directly instead of spinners
     plain_output: bool,
+    /// Destination for plain-mode lines (stderr outside of tests)
+    plain_sink: Box<dyn Write + Send>,
 }
 
 impl Default for ProgressReporter {
@@ -64,42 +93,46 @@ impl Default for ProgressReporter {
 impl ProgressReporter {
     /// Create a new progress reporter
     pub fn new() -> Self {
-        Self {
-            multi: MultiProgress::new(),
-            bars: HashMap::new(),
-            last_update_by_id: HashMap::new(),
-            last_message_by_id: HashMap::new(),
-            context: None,
-            plain_output: false,
-        }
+        Self::build(None, plain_output_enabled())
     }
 
     /// Create a new progress reporter with a context prefix
     ///
     /// The context is shown before step messages, e.g., "Building Docker image · Step 1/10"
     pub fn with_context(context: &str) -> Self {
-        Self {
-            multi: MultiProgress::new(),
-            bars: HashMap::new(),
-            last_update_by_id: HashMap::new(),
-            last_message_by_id: HashMap::new(),
-            context: Some(context.to_string()),
-            plain_output: false,
-        }
+        Self::build(Some(context.to_string()), plain_output_enabled())
     }
 
     /// Create a progress reporter that prints build output directly
     pub fn with_context_plain(context: &str) -> Self {
+        Self::build(Some(context.to_string()), true)
+    }
+
+    fn build(context: Option<String>, plain_output: bool) -> Self {
         Self {
             multi: MultiProgress::new(),
             bars: HashMap::new(),
             last_update_by_id: HashMap::new(),
             last_message_by_id: HashMap::new(),
-            context: Some(context.to_string()),
-            plain_output: true,
+            step_by_id: HashMap::new(),
+            context,
+            plain_output,
+            plain_sink: Box::new(std::io::stderr()),
         }
     }
 
+    /// Write a plain-mode line unless it repeats the last line for this id
+    fn emit_plain(&mut self, id: &str, message: &str) {
+        if self.last_message_by_id.get(id).map(String::as_str) == Some(message) {
+            return;
+        }
+
+        let formatted = self.format_message(message);
+        let _ = writeln!(self.plain_sink, "{formatted}");
+        self.last_message_by_id
+            .insert(id.to_string(), message.to_string());
+    }
+
     /// Check if plain output mode is enabled
     pub fn is_plain_output(&self) -> bool {
         self.plain_output
@@ -172,6 +205,8 @@ impl ProgressReporter {
     /// `current` and `total` are in bytes, `status` is the Docker status message
     pub fn update_layer(&mut self, layer_id: &str, current: u64, total: u64, status: &str) {
         if self.plain_output {
+            let message = plain_layer_message(layer_id, current, total, status);
+            self.emit_plain(layer_id, &message);
             return;
         }
 
@@ -196,12 +231,7 @@ impl ProgressReporter {
     /// "Step X/Y" messages always update immediately as they indicate significant progress.
     pub fn update_spinner(&mut self, id: &str, message: &str) {
         if self.plain_output {
-            let clean = strip_ansi_codes(message);
-            let formatted = match &self.context {
-                Some(ctx) => format!("{ctx} · {clean}"),
-                None => clean,
-            };
-            eprintln!("{formatted}");
+            self.emit_plain(id, message);
             return;
         }
 
@@ -240,8 +270,63 @@ impl ProgressReporter {
             .insert(id.to_string(), message.to_string());
     }
 
+    /// Show determinate build progress ("step N/M") for a spinner
+    ///
+    /// The spinner switches to a progress bar the first time step info arrives;
+    /// builds that never report steps keep the indeterminate spinner. Updates
+    /// within the same step are throttled like [`Self::update_spinner`].
+    pub fn update_step(&mut self, id: &str, current: u64, total: u64, message: &str) {
+        let step_message = format!("step {current}/{total} · {message}");
+        let step_changed = self.step_by_id.get(id) != Some(&(current, total));
+
+        if self.plain_output {
+            if step_changed {
+                self.step_by_id.insert(id.to_string(), (current, total));
+                self.emit_plain(id, &step_message);
+            }
+            return;
+        }
+
+        let now = Instant::now();
+        if !step_changed {
+            if let Some(last) = self.last_update_by_id.get(id) {
+                if now.duration_since(*last) < SPINNER_UPDATE_THROTTLE {
+                    return;
+                }
+            }
+        }
+
+        if !self.bars.contains_key(id) {
+            self.add_spinner(id, &step_message);
+        }
+        let formatted = self.format_message(&step_message);
+        let bar = self.bars.get(id).expect("spinner exists");
+        if !self.step_by_id.contains_key(id) {
+            bar.set_style(
+                ProgressStyle::default_bar()
+                    .template(
+                        "{spinner:.green} [{elapsed}] [{bar:30.cyan/blue}] {percent:>3}% {msg}",
+                    )
+                    .expect("valid template")
+                    .progress_chars("=>-"),
+            );
+        }
+        bar.set_length(total);
+        bar.set_position(current.min(total));
+        bar.set_message(formatted);
+
+        self.step_by_id.insert(id.to_string(), (current, total));
+        self.last_update_by_id.insert(id.to_string(), now);
+        self.last_message_by_id.insert(id.to_string(), step_message);
+    }
+
     /// Mark a layer/step as complete
     pub fn finish(&mut self, id: &str, message: &str) {
+        if self.plain_output {
+            self.emit_plain(id, message);
+            return;
+        }
+
         if let Some(bar) = self.bars.get(id) {
             bar.finish_with_message(message.to_string());
         }
@@ -262,9 +347,127 @@ impl ProgressReporter {
     }
 }
 
+/// Describe layer progress for plain mode, bucketed so each step is logged once
+///
+/// e.g. "Pulling layer abc123def456: 40%"
+fn plain_layer_message(layer_id: &str, current: u64, total: u64, status: &str) -> String {
+    let short_id = &layer_id[..12.min(layer_id.len())];
+    if total == 0 {
+        return format!("{status} layer {short_id}");
+    }
+
+    let percent =
+        (current.min(total) * 100 / total) / PLAIN_LAYER_PERCENT_STEP * PLAIN_LAYER_PERCENT_STEP;
+    format!("{status} layer {short_id}: {percent}%")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::{Arc, Mutex};
+
+    /// Shared buffer standing in for stderr in plain-mode tests
+    #[derive(Clone, Default)]
+    struct CaptureSink(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for CaptureSink {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl CaptureSink {
+        fn contents(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    fn plain_reporter(context: &str) -> (ProgressReporter, CaptureSink) {
+        let sink = CaptureSink::default();
+        let mut reporter = ProgressReporter::with_context_plain(context);
+        reporter.plain_sink = Box::new(sink.clone());
+        (reporter, sink)
+    }
+
+    #[test]
+    fn plain_mode_emits_no_carriage_returns() {
+        let (mut reporter, sink) = plain_reporter("Pulling image");
+        reporter.update_layer("abc123def456789", 450, 1000, "Downloading");
+        reporter.update_spinner("build", "\x1b[32mStep 1/3 : FROM ubuntu\x1b[0m");
+        reporter.finish("build", "Done");
+
+        let output = sink.contents();
+        assert!(!output.contains('\r'));
+        assert!(!output.contains('\x1b'));
+        assert!(output.lines().all(|line| !line.is_empty()));
+        assert!(output.ends_with('\n'));
+        assert!(reporter.bars.is_empty());
+    }
+
+    #[test]
+    fn plain_mode_logs_each_step_once() {
+        let (mut reporter, sink) = plain_reporter("Building");
+        reporter.update_spinner("build", "Step 1/2 : FROM ubuntu");
+        reporter.update_spinner("build", "Step 1/2 : FROM ubuntu");
+        reporter.update_spinner("build", "Step 2/2 : RUN true");
+
+        assert_eq!(
+            sink.contents(),
+            "Building · Step 1/2 : FROM ubuntu\nBuilding · Step 2/2 : RUN true\n"
+        );
+    }
+
+    #[test]
+    fn plain_mode_buckets_layer_progress() {
+        let (mut reporter, sink) = plain_reporter("Pulling image");
+        for current in [0, 10, 20, 450, 460, 1000] {
+            reporter.update_layer("abc123", current, 1000, "Downloading");
+        }
+
+        assert_eq!(
+            sink.contents(),
+            "Pulling image · Downloading layer abc123: 0%\n\
+             Pulling image · Downloading layer abc123: 40%\n\
+             Pulling image · Downloading layer abc123: 100%\n"
+        );
+    }
+
+    #[test]
+    fn plain_mode_logs_each_build_step_once() {
+        let (mut reporter, sink) = plain_reporter("Building");
+        reporter.update_step("build", 1, 3, "FROM ubuntu");
+        reporter.update_step("build", 1, 3, "FROM ubuntu · log line");
+        reporter.update_step("build", 2, 3, "RUN true");
+
+        assert_eq!(
+            sink.contents(),
+            "Building · step 1/3 · FROM ubuntu\nBuilding · step 2/3 · RUN true\n"
+        );
+    }
+
+    #[test]
+    fn update_step_switches_spinner_to_bar() {
+        let mut reporter = ProgressReporter::new();
+        reporter.add_spinner("build", "Initializing...");
+        reporter.update_step("build", 12, 34, "RUN apt-get update");
+
+        let bar = reporter.bars.get("build").unwrap();
+        assert_eq!(bar.length(), Some(34));
+        assert_eq!(bar.position(), 12);
+    }
+
+    #[test]
+    fn plain_layer_message_without_total() {
+        assert_eq!(
+            plain_layer_message("abc123def456789", 0, 0, "Pull complete"),
+            "Pull complete layer abc123def456"
+        );
+    }
 
     #[test]
     fn progress_reporter_creation() {
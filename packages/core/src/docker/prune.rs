@@ -0,0 +1,363 @@
+//! Aggregate cleanup of opencode-cloud Docker resources
+//!
+//! Collects everything opencode-cloud left behind that is safe to remove:
+//! the stopped service container, opencode images other than the current and
+//! rollback tags, and opencode-managed volumes that are no longer in use
+//! (identified by the ownership label set in [`super::volume`]).
+//! Planning never deletes anything; [`execute_prune`] applies a plan.
+
+use super::update::PREVIOUS_TAG;
+use super::volume::{VOLUME_NAMES, is_managed_volume};
+use super::{
+    CONTAINER_NAME, DockerClient, DockerError, IMAGE_NAME_DOCKERHUB, IMAGE_NAME_GHCR,
+    IMAGE_TAG_DEFAULT, VERSION_LABEL,
+};
+use bollard::image::RemoveImageOptions;
+use bollard::models::{ContainerSummary, ImageSummary, SystemDataUsageResponse, Volume};
+use tracing::debug;
+
+/// Kind of resource a prune candidate belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PruneCategory {
+    /// The stopped opencode container
+    Container,
+    /// Old or dangling opencode images
+    Image,
+    /// Orphaned opencode volumes
+    Volume,
+}
+
+impl PruneCategory {
+    /// All categories in display order
+    pub const ALL: [PruneCategory; 3] = [
+        PruneCategory::Container,
+        PruneCategory::Image,
+        PruneCategory::Volume,
+    ];
+
+    /// Human-readable label for reports
+    pub fn label(&self) -> &'static str {
+        match self {
+            PruneCategory::Container => "Containers",
+            PruneCategory::Image => "Images",
+            PruneCategory::Volume => "Volumes",
+        }
+    }
+}
+
+/// A single resource selected for removal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PruneCandidate {
+    pub category: PruneCategory,
+    /// ID or name used to remove the resource
+    pub id: String,
+    /// Display name (tag, container name, or volume name)
+    pub name: String,
+    /// Reclaimable size in bytes (0 if Docker did not report it)
+    pub size_bytes: u64,
+}
+
+/// Resources that `occ prune` would remove
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrunePlan {
+    pub candidates: Vec<PruneCandidate>,
+}
+
+impl PrunePlan {
+    /// Build a plan from a `docker system df` response
+    pub fn from_usage(usage: &SystemDataUsageResponse) -> Self {
+        let mut candidates = Vec::new();
+        if let Some(containers) = &usage.containers {
+            candidates.extend(select_containers(containers));
+        }
+        if let Some(images) = &usage.images {
+            candidates.extend(select_images(images));
+        }
+        if let Some(volumes) = &usage.volumes {
+            candidates.extend(select_volumes(volumes));
+        }
+        Self { candidates }
+    }
+
+    /// Candidates in the given category
+    pub fn in_category(&self, category: PruneCategory) -> impl Iterator<Item = &PruneCandidate> {
+        self.candidates
+            .iter()
+            .filter(move |c| c.category == category)
+    }
+
+    /// Total reclaimable bytes for a category
+    pub fn reclaimable_bytes(&self, category: PruneCategory) -> u64 {
+        self.in_category(category).map(|c| c.size_bytes).sum()
+    }
+
+    /// Whether there is nothing to remove
+    pub fn is_empty(&self) -> bool {
+        self.candidates.is_empty()
+    }
+}
+
+/// Select the opencode container if it exists and is not running
+fn select_containers(containers: &[ContainerSummary]) -> Vec<PruneCandidate> {
+    let target = format!("/{CONTAINER_NAME}");
+    containers
+        .iter()
+        .filter(|c| {
+            c.names
+                .as_ref()
+                .is_some_and(|names| names.iter().any(|n| n == &target))
+        })
+        .filter(|c| c.state.as_deref() != Some("running"))
+        .map(|c| PruneCandidate {
+            category: PruneCategory::Container,
+            id: c.id.clone().unwrap_or_else(|| CONTAINER_NAME.to_string()),
+            name: CONTAINER_NAME.to_string(),
+            size_bytes: non_negative(c.size_rw.unwrap_or(0)),
+        })
+        .collect()
+}
+
+/// Select opencode images that are neither the current nor the rollback image
+///
+/// Tagged images count as opencode images by repository name; dangling
+/// images are recognized by the version label set at build time. Images
+/// still used by a container are always kept.
+fn select_images(images: &[ImageSummary]) -> Vec<PruneCandidate> {
+    images
+        .iter()
+        .filter(|image| image.containers <= 0)
+        .filter(|image| is_opencode_image(image) && !is_kept_image(image))
+        .map(|image| PruneCandidate {
+            category: PruneCategory::Image,
+            id: image.id.clone(),
+            name: tagged_names(image)
+                .next()
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("<none> ({})", short_id(&image.id))),
+            size_bytes: non_negative(image.size),
+        })
+        .collect()
+}
+
+/// Select opencode-managed volumes that are not one of the active volumes
+fn select_volumes(volumes: &[Volume]) -> Vec<PruneCandidate> {
+    volumes
+        .iter()
+        .filter(|v| is_managed_volume(&v.labels))
+        .filter(|v| !VOLUME_NAMES.contains(&v.name.as_str()))
+        .filter(|v| v.usage_data.as_ref().is_none_or(|u| u.ref_count <= 0))
+        .map(|v| PruneCandidate {
+            category: PruneCategory::Volume,
+            id: v.name.clone(),
+            name: v.name.clone(),
+            size_bytes: non_negative(v.usage_data.as_ref().map(|u| u.size).unwrap_or(0)),
+        })
+        .collect()
+}
+
+/// Repo tags, excluding Docker's `<none>:<none>` placeholder
+fn tagged_names(image: &ImageSummary) -> impl Iterator<Item = &str> {
+    image
+        .repo_tags
+        .iter()
+        .map(String::as_str)
+        .filter(|tag| *tag != "<none>:<none>")
+}
+
+fn is_opencode_image(image: &ImageSummary) -> bool {
+    let mut tags = tagged_names(image).peekable();
+    if tags.peek().is_none() {
+        return image.labels.contains_key(VERSION_LABEL);
+    }
+    tags.any(|tag| {
+        tag.starts_with(&format!("{IMAGE_NAME_GHCR}:"))
+            || tag.starts_with(&format!("{IMAGE_NAME_DOCKERHUB}:"))
+    })
+}
+
+fn is_kept_image(image: &ImageSummary) -> bool {
+    tagged_names(image).any(|tag| {
+        [IMAGE_TAG_DEFAULT, PREVIOUS_TAG]
+            .iter()
+            .any(|kept| tag.ends_with(&format!(":{kept}")))
+    })
+}
+
+fn short_id(id: &str) -> &str {
+    let id = id.strip_prefix("sha256:").unwrap_or(id);
+    &id[..id.len().min(12)]
+}
+
+fn non_negative(size: i64) -> u64 {
+    u64::try_from(size).unwrap_or(0)
+}
+
+/// Collect the prune plan without removing anything
+pub async fn plan_prune(client: &DockerClient) -> Result<PrunePlan, DockerError> {
+    debug!("Collecting disk usage for prune");
+    let usage = client
+        .inner()
+        .df()
+        .await
+        .map_err(|e| DockerError::Connection(format!("Failed to query disk usage: {e}")))?;
+    Ok(PrunePlan::from_usage(&usage))
+}
+
+/// Remove every resource in the plan
+///
+/// Containers are removed first so that images and volumes they reference
+/// are free to delete.
+pub async fn execute_prune(client: &DockerClient, plan: &PrunePlan) -> Result<(), DockerError> {
+    for candidate in plan.in_category(PruneCategory::Container) {
+        super::container::remove_container(client, &candidate.id, false).await?;
+    }
+
+    for candidate in plan.in_category(PruneCategory::Image) {
+        debug!("Removing image {}", candidate.name);
+        let options = RemoveImageOptions {
+            // Needed for images carrying several stale tags; in-use images were never selected
+            force: true,
+            noprune: false,
+        };
+        client
+            .inner()
+            .remove_image(&candidate.id, Some(options), None)
+            .await
+            .map_err(|e| {
+                DockerError::Container(format!("Failed to remove image {}: {e}", candidate.name))
+            })?;
+    }
+
+    for candidate in plan.in_category(PruneCategory::Volume) {
+        super::volume::remove_volume(client, &candidate.id).await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::docker::volume::VOLUME_MANAGED_LABEL;
+    use bollard::models::VolumeUsageData;
+    use std::collections::HashMap;
+
+    fn container(name: &str, state: &str) -> ContainerSummary {
+        ContainerSummary {
+            id: Some(format!("{name}-id")),
+            names: Some(vec![format!("/{name}")]),
+            state: Some(state.to_string()),
+            size_rw: Some(1024),
+            ..Default::default()
+        }
+    }
+
+    fn image(id: &str, tags: &[&str], labels: &[(&str, &str)]) -> ImageSummary {
+        ImageSummary {
+            id: id.to_string(),
+            repo_tags: tags.iter().map(|t| t.to_string()).collect(),
+            labels: labels
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            size: 100,
+            ..Default::default()
+        }
+    }
+
+    fn volume(name: &str, managed: bool, ref_count: i64) -> Volume {
+        let mut labels = HashMap::new();
+        if managed {
+            labels.insert(VOLUME_MANAGED_LABEL.to_string(), "true".to_string());
+        }
+        Volume {
+            name: name.to_string(),
+            labels,
+            usage_data: Some(VolumeUsageData {
+                size: 2048,
+                ref_count,
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn stopped_container_is_selected() {
+        let selected = select_containers(&[container(CONTAINER_NAME, "exited")]);
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].size_bytes, 1024);
+    }
+
+    #[test]
+    fn running_or_foreign_containers_are_kept() {
+        assert!(select_containers(&[container(CONTAINER_NAME, "running")]).is_empty());
+        assert!(select_containers(&[container("other", "exited")]).is_empty());
+    }
+
+    #[test]
+    fn current_and_rollback_images_are_kept() {
+        let images = [
+            image("a", &[&format!("{IMAGE_NAME_GHCR}:latest")], &[]),
+            image("b", &[&format!("{IMAGE_NAME_GHCR}:previous")], &[]),
+            image("c", &[&format!("{IMAGE_NAME_DOCKERHUB}:1.0.0")], &[]),
+        ];
+        let selected = select_images(&images);
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].id, "c");
+    }
+
+    #[test]
+    fn dangling_images_need_version_label() {
+        let images = [
+            image(
+                "sha256:0123456789abcdef",
+                &["<none>:<none>"],
+                &[(VERSION_LABEL, "1.0.0")],
+            ),
+            image("sha256:fedcba9876543210", &[], &[]),
+            image("d", &["ubuntu:24.04"], &[]),
+        ];
+        let selected = select_images(&images);
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].name, "<none> (0123456789ab)");
+    }
+
+    #[test]
+    fn images_in_use_are_kept() {
+        let mut in_use = image("e", &[&format!("{IMAGE_NAME_GHCR}:0.9.0")], &[]);
+        in_use.containers = 1;
+        assert!(select_images(&[in_use]).is_empty());
+    }
+
+    #[test]
+    fn only_orphaned_managed_volumes_are_selected() {
+        let volumes = [
+            volume(VOLUME_NAMES[0], true, 0),
+            volume("opencode-cloud-old", true, 0),
+            volume("opencode-cloud-busy", true, 1),
+            volume("someone-elses", false, 0),
+        ];
+        let selected = select_volumes(&volumes);
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].name, "opencode-cloud-old");
+    }
+
+    #[test]
+    fn plan_totals_per_category() {
+        let usage = SystemDataUsageResponse {
+            containers: Some(vec![container(CONTAINER_NAME, "exited")]),
+            images: Some(vec![
+                image("x", &[&format!("{IMAGE_NAME_GHCR}:0.1.0")], &[]),
+                image("y", &[&format!("{IMAGE_NAME_GHCR}:0.2.0")], &[]),
+            ]),
+            volumes: Some(vec![volume("opencode-cloud-old", true, 0)]),
+            ..Default::default()
+        };
+        let plan = PrunePlan::from_usage(&usage);
+        assert_eq!(plan.reclaimable_bytes(PruneCategory::Container), 1024);
+        assert_eq!(plan.reclaimable_bytes(PruneCategory::Image), 200);
+        assert_eq!(plan.reclaimable_bytes(PruneCategory::Volume), 2048);
+        assert!(!plan.is_empty());
+        assert!(PrunePlan::default().is_empty());
+    }
+}
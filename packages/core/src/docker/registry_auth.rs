@@ -0,0 +1,328 @@
+//! Registry credentials for image pulls
+//!
+//! Credentials come from the `image_registry_auth` config field
+//! (`registry=username:password`) or from the `auths` section of the Docker
+//! CLI config (`$DOCKER_CONFIG/config.json`, default `~/.docker/config.json`).
+//! They are only sent to the registry they belong to, so the public images
+//! keep pulling anonymously. Credential helpers (`credsStore`,
+//! `credHelpers`) are not consulted.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use bollard::auth::DockerCredentials;
+use serde::Deserialize;
+use tracing::debug;
+
+use crate::config::Config;
+
+/// Canonical host for Docker Hub
+const DOCKER_HUB_REGISTRY: &str = "docker.io";
+
+/// Placeholder for passwords in `Debug` output
+const REDACTED: &str = "<redacted>";
+
+/// Explicit credentials for one registry, from `image_registry_auth`
+#[derive(Clone, PartialEq, Eq)]
+pub struct RegistryLogin {
+    /// Registry host, e.g. "ghcr.io"
+    pub registry: String,
+    pub username: String,
+    pub password: String,
+}
+
+impl RegistryLogin {
+    /// Parse `registry=username:password`
+    ///
+    /// Errors never include the value, since it may hold the password.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let invalid = || "Invalid registry auth. Expected registry=username:password".to_string();
+        let (registry, credentials) = value.trim().split_once('=').ok_or_else(invalid)?;
+        let (username, password) = credentials.split_once(':').ok_or_else(invalid)?;
+        let registry = normalize_registry(registry);
+        if registry.is_empty() || username.is_empty() || password.is_empty() {
+            return Err(invalid());
+        }
+        Ok(Self {
+            registry,
+            username: username.to_string(),
+            password: password.to_string(),
+        })
+    }
+}
+
+impl std::fmt::Debug for RegistryLogin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RegistryLogin")
+            .field("registry", &self.registry)
+            .field("username", &self.username)
+            .field("password", &REDACTED)
+            .finish()
+    }
+}
+
+/// One entry of the Docker CLI config's `auths` map
+#[derive(Clone, Default, Deserialize)]
+struct DockerConfigAuth {
+    /// base64 of "username:password"
+    auth: Option<String>,
+    username: Option<String>,
+    password: Option<String>,
+    identitytoken: Option<String>,
+}
+
+impl std::fmt::Debug for DockerConfigAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let redact = |secret: &Option<String>| secret.as_ref().map(|_| REDACTED);
+        f.debug_struct("DockerConfigAuth")
+            .field("auth", &redact(&self.auth))
+            .field("username", &self.username)
+            .field("password", &redact(&self.password))
+            .field("identitytoken", &redact(&self.identitytoken))
+            .finish()
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct DockerConfigFile {
+    #[serde(default)]
+    auths: HashMap<String, DockerConfigAuth>,
+}
+
+/// Credentials available for image pulls, looked up per registry
+#[derive(Debug, Clone, Default)]
+pub struct RegistryAuth {
+    login: Option<RegistryLogin>,
+    auths: HashMap<String, DockerConfigAuth>,
+}
+
+impl RegistryAuth {
+    /// Credentials from the config and the Docker CLI config file
+    ///
+    /// An unreadable or invalid Docker config is ignored (pulls go out
+    /// anonymously); `image_registry_auth` is validated on save.
+    pub fn from_config(config: &Config) -> Self {
+        let login = config
+            .image_registry_auth
+            .as_deref()
+            .and_then(|value| RegistryLogin::parse(value).ok());
+        let docker_config = docker_config_path().and_then(|path| {
+            std::fs::read_to_string(&path)
+                .inspect_err(|e| debug!("Not reading {}: {}", path.display(), e))
+                .ok()
+        });
+        Self::new(login, docker_config.as_deref())
+    }
+
+    /// Build from an explicit login and Docker config file contents
+    pub fn new(login: Option<RegistryLogin>, docker_config: Option<&str>) -> Self {
+        let auths = docker_config
+            .and_then(|contents| {
+                serde_json::from_str::<DockerConfigFile>(contents)
+                    .inspect_err(|e| debug!("Ignoring invalid Docker config: {}", e))
+                    .ok()
+            })
+            .map(|file| {
+                file.auths
+                    .into_iter()
+                    .map(|(key, auth)| (normalize_registry(&key), auth))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self { login, auths }
+    }
+
+    /// Credentials to send when pulling `image`, if any apply to its registry
+    ///
+    /// The configured login wins over the Docker config for the same registry.
+    pub fn credentials_for(&self, image: &str) -> Option<DockerCredentials> {
+        let registry = registry_host(image);
+        if let Some(login) = self.login.as_ref().filter(|l| l.registry == registry) {
+            return Some(DockerCredentials {
+                username: Some(login.username.clone()),
+                password: Some(login.password.clone()),
+                serveraddress: Some(registry),
+                ..Default::default()
+            });
+        }
+
+        let entry = self.auths.get(&registry)?;
+        let (username, password) = match (&entry.username, &entry.password, &entry.auth) {
+            (Some(user), Some(pass), _) => (Some(user.clone()), Some(pass.clone())),
+            (_, _, Some(auth)) => {
+                let (user, pass) = decode_auth(auth)?;
+                (Some(user), Some(pass))
+            }
+            _ => (None, None),
+        };
+        if username.is_none() && entry.identitytoken.is_none() {
+            return None;
+        }
+        Some(DockerCredentials {
+            username,
+            password,
+            identitytoken: entry.identitytoken.clone(),
+            serveraddress: Some(registry),
+            ..Default::default()
+        })
+    }
+}
+
+/// Path of the Docker CLI config file
+fn docker_config_path() -> Option<PathBuf> {
+    if let Some(dir) = std::env::var_os("DOCKER_CONFIG") {
+        return Some(PathBuf::from(dir).join("config.json"));
+    }
+    directories::BaseDirs::new().map(|dirs| dirs.home_dir().join(".docker").join("config.json"))
+}
+
+/// Decode a base64 "username:password" auth value
+fn decode_auth(auth: &str) -> Option<(String, String)> {
+    let decoded = String::from_utf8(STANDARD.decode(auth.trim()).ok()?).ok()?;
+    let (user, pass) = decoded.split_once(':')?;
+    Some((user.to_string(), pass.to_string()))
+}
+
+/// Registry host an image reference is pulled from
+///
+/// The first path component is a registry when it looks like a host
+/// (contains '.' or ':', or is "localhost"); otherwise it is Docker Hub.
+pub fn registry_host(image: &str) -> String {
+    match image.split_once('/') {
+        Some((first, _)) if first.contains(['.', ':']) || first == "localhost" => {
+            normalize_registry(first)
+        }
+        _ => DOCKER_HUB_REGISTRY.to_string(),
+    }
+}
+
+/// Canonical registry host for a Docker config key or user input
+///
+/// Strips the scheme and path, and maps Docker Hub's aliases
+/// (`https://index.docker.io/v1/`) to "docker.io".
+fn normalize_registry(value: &str) -> String {
+    let value = value.trim();
+    let value = value
+        .strip_prefix("https://")
+        .or_else(|| value.strip_prefix("http://"))
+        .unwrap_or(value);
+    let host = value.split('/').next().unwrap_or_default().to_lowercase();
+    match host.as_str() {
+        "index.docker.io" | "registry-1.docker.io" | "registry.hub.docker.com" => {
+            DOCKER_HUB_REGISTRY.to_string()
+        }
+        _ => host,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // "octocat:ghp_token" and "hubuser:hubpass"
+    const SAMPLE_DOCKER_CONFIG: &str = r#"{
+        "auths": {
+            "ghcr.io": { "auth": "b2N0b2NhdDpnaHBfdG9rZW4=" },
+            "https://index.docker.io/v1/": { "auth": "aHVidXNlcjpodWJwYXNz" },
+            "registry.example.com": { "identitytoken": "id-token" },
+            "broken.example.com": { "auth": "not base64!" }
+        },
+        "credsStore": "desktop"
+    }"#;
+
+    fn sample() -> RegistryAuth {
+        RegistryAuth::new(None, Some(SAMPLE_DOCKER_CONFIG))
+    }
+
+    #[test]
+    fn registry_hosts() {
+        assert_eq!(
+            registry_host("ghcr.io/prizz/opencode-cloud-sandbox"),
+            "ghcr.io"
+        );
+        assert_eq!(registry_host("prizz/opencode-cloud-sandbox"), "docker.io");
+        assert_eq!(registry_host("ubuntu"), "docker.io");
+        assert_eq!(registry_host("localhost:5000/app"), "localhost:5000");
+    }
+
+    #[test]
+    fn docker_config_auth_is_decoded() {
+        let creds = sample()
+            .credentials_for("ghcr.io/prizz/opencode-cloud-sandbox")
+            .unwrap();
+        assert_eq!(creds.username.as_deref(), Some("octocat"));
+        assert_eq!(creds.password.as_deref(), Some("ghp_token"));
+        assert_eq!(creds.serveraddress.as_deref(), Some("ghcr.io"));
+    }
+
+    #[test]
+    fn docker_hub_key_is_normalized() {
+        let creds = sample()
+            .credentials_for("prizz/opencode-cloud-sandbox")
+            .unwrap();
+        assert_eq!(creds.username.as_deref(), Some("hubuser"));
+        assert_eq!(creds.password.as_deref(), Some("hubpass"));
+    }
+
+    #[test]
+    fn identity_token_and_invalid_entries() {
+        let auth = sample();
+        let creds = auth.credentials_for("registry.example.com/app").unwrap();
+        assert_eq!(creds.identitytoken.as_deref(), Some("id-token"));
+        assert!(auth.credentials_for("broken.example.com/app").is_none());
+        assert!(auth.credentials_for("quay.io/app").is_none());
+    }
+
+    #[test]
+    fn no_config_means_anonymous() {
+        assert!(
+            RegistryAuth::default()
+                .credentials_for("ghcr.io/prizz/opencode-cloud-sandbox")
+                .is_none()
+        );
+        assert!(
+            RegistryAuth::new(None, Some("not json"))
+                .credentials_for("ghcr.io/x/y")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn configured_login_wins_for_its_registry_only() {
+        let login = RegistryLogin::parse("ghcr.io=bot:secret").unwrap();
+        let auth = RegistryAuth::new(Some(login), Some(SAMPLE_DOCKER_CONFIG));
+        let creds = auth.credentials_for("ghcr.io/prizz/x").unwrap();
+        assert_eq!(creds.username.as_deref(), Some("bot"));
+        assert_eq!(creds.password.as_deref(), Some("secret"));
+        // Docker Hub still uses the Docker config entry
+        let hub = auth.credentials_for("prizz/x").unwrap();
+        assert_eq!(hub.username.as_deref(), Some("hubuser"));
+    }
+
+    #[test]
+    fn registry_login_parsing() {
+        let login = RegistryLogin::parse("https://GHCR.io/=user:pa:ss").unwrap();
+        assert_eq!(login.registry, "ghcr.io");
+        assert_eq!(login.username, "user");
+        assert_eq!(login.password, "pa:ss");
+        assert!(RegistryLogin::parse("ghcr.io").is_err());
+        assert!(RegistryLogin::parse("ghcr.io=user").is_err());
+        assert!(RegistryLogin::parse("=user:pass").is_err());
+        assert!(RegistryLogin::parse("ghcr.io=:pass").is_err());
+    }
+
+    #[test]
+    fn registry_login_never_reveals_password() {
+        let err = RegistryLogin::parse("ghcr.io=:hunter2").unwrap_err();
+        assert!(!err.contains("hunter2"));
+        let err = RegistryLogin::parse("ghcr.io:user:hunter2").unwrap_err();
+        assert!(!err.contains("hunter2"));
+
+        let login = RegistryLogin::parse("ghcr.io=user:hunter2").unwrap();
+        let debug = format!("{login:?}");
+        assert!(debug.contains("user"));
+        assert!(!debug.contains("hunter2"));
+    }
+}
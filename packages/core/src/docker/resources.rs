@@ -0,0 +1,163 @@
+//! Container resource limits
+//!
+//! Converts the `memory_limit_mb` and `cpu_limit` config fields (or their
+//! `occ start --memory`/`--cpus` overrides) into Docker HostConfig fields,
+//! and reads them back from an existing container to detect drift.
+
+use bollard::container::UpdateContainerOptions;
+use bollard::service::HostConfig;
+
+use crate::config::Config;
+
+const BYTES_PER_MB: i64 = 1024 * 1024;
+const NANO_CPUS_PER_CPU: f64 = 1_000_000_000.0;
+
+/// Memory and CPU limits applied when the container is created
+///
+/// `None` means no limit, matching Docker's default.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ResourceLimits {
+    /// Memory limit in megabytes
+    pub memory_mb: Option<u64>,
+    /// CPU limit in cores (e.g. 1.5)
+    pub cpus: Option<f64>,
+}
+
+impl ResourceLimits {
+    /// Limits from the (effective) config
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            memory_mb: config.memory_limit_mb,
+            cpus: config.cpu_limit,
+        }
+    }
+
+    /// Limits currently set on a container's HostConfig
+    ///
+    /// Docker reports "no limit" as 0, which maps back to `None`.
+    pub fn from_host_config(host_config: &HostConfig) -> Self {
+        Self {
+            memory_mb: host_config
+                .memory
+                .filter(|bytes| *bytes > 0)
+                .map(|bytes| (bytes / BYTES_PER_MB) as u64),
+            cpus: host_config
+                .nano_cpus
+                .filter(|nanos| *nanos > 0)
+                .map(|nanos| nanos as f64 / NANO_CPUS_PER_CPU),
+        }
+    }
+
+    /// Set the Docker memory and NanoCpus fields on `host_config`
+    pub fn apply(&self, host_config: &mut HostConfig) {
+        host_config.memory = self.memory_bytes();
+        host_config.nano_cpus = self.nano_cpus();
+    }
+
+    /// Whether these limits match `other` as Docker stores them
+    ///
+    /// CPUs are compared at NanoCpus precision so 1.5 and 1.50 are equal.
+    pub fn matches(&self, other: &Self) -> bool {
+        self.memory_bytes() == other.memory_bytes() && self.nano_cpus() == other.nano_cpus()
+    }
+
+    /// Update options that set only these limits on a running container
+    ///
+    /// Swap is set to twice the memory limit, matching Docker's default
+    /// when a container is created with only a memory limit. Unset limits
+    /// are left unchanged; Docker cannot remove a limit without recreating.
+    pub fn update_options(&self) -> UpdateContainerOptions<String> {
+        UpdateContainerOptions {
+            memory: self.memory_bytes(),
+            memory_swap: self.memory_bytes().map(|bytes| bytes.saturating_mul(2)),
+            nano_cpus: self.nano_cpus(),
+            ..Default::default()
+        }
+    }
+
+    fn memory_bytes(&self) -> Option<i64> {
+        self.memory_mb
+            .map(|mb| i64::try_from(mb).unwrap_or(i64::MAX / BYTES_PER_MB) * BYTES_PER_MB)
+    }
+
+    fn nano_cpus(&self) -> Option<i64> {
+        self.cpus
+            .map(|cpus| (cpus * NANO_CPUS_PER_CPU).round() as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unset_limits_leave_host_config_unlimited() {
+        let mut host_config = HostConfig::default();
+        ResourceLimits::default().apply(&mut host_config);
+        assert_eq!(host_config.memory, None);
+        assert_eq!(host_config.nano_cpus, None);
+    }
+
+    #[test]
+    fn limits_are_converted_to_docker_units() {
+        let mut host_config = HostConfig::default();
+        ResourceLimits {
+            memory_mb: Some(512),
+            cpus: Some(1.5),
+        }
+        .apply(&mut host_config);
+        assert_eq!(host_config.memory, Some(512 * 1024 * 1024));
+        assert_eq!(host_config.nano_cpus, Some(1_500_000_000));
+    }
+
+    #[test]
+    fn host_config_roundtrip() {
+        let limits = ResourceLimits {
+            memory_mb: Some(2048),
+            cpus: Some(0.25),
+        };
+        let mut host_config = HostConfig::default();
+        limits.apply(&mut host_config);
+        assert!(ResourceLimits::from_host_config(&host_config).matches(&limits));
+    }
+
+    #[test]
+    fn zero_means_unlimited() {
+        let host_config = HostConfig {
+            memory: Some(0),
+            nano_cpus: Some(0),
+            ..Default::default()
+        };
+        assert_eq!(
+            ResourceLimits::from_host_config(&host_config),
+            ResourceLimits::default()
+        );
+    }
+
+    #[test]
+    fn update_options_set_only_limits() {
+        let options = ResourceLimits {
+            memory_mb: Some(512),
+            cpus: None,
+        }
+        .update_options();
+        assert_eq!(options.memory, Some(512 * 1024 * 1024));
+        assert_eq!(options.memory_swap, Some(1024 * 1024 * 1024));
+        assert_eq!(options.nano_cpus, None);
+        assert_eq!(options.restart_policy, None);
+    }
+
+    #[test]
+    fn changed_limits_do_not_match() {
+        let current = ResourceLimits {
+            memory_mb: Some(1024),
+            cpus: None,
+        };
+        let requested = ResourceLimits {
+            memory_mb: Some(1024),
+            cpus: Some(2.0),
+        };
+        assert!(!current.matches(&requested));
+        assert!(current.matches(&current));
+    }
+}
@@ -0,0 +1,185 @@
+//! Container resource usage
+//!
+//! Takes a single stats sample from Docker and derives CPU and memory usage
+//! the same way `docker stats` does.
+
+use bollard::container::{CPUStats, MemoryStats, MemoryStatsStats, StatsOptions};
+use futures_util::StreamExt;
+
+use super::{DockerClient, DockerError};
+
+/// Point-in-time resource usage of a container
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResourceUsage {
+    /// CPU usage as a percentage of one CPU (can exceed 100 on multi-core hosts)
+    pub cpu_percent: f64,
+    /// Memory in use, excluding reclaimable page cache
+    pub memory_used_bytes: u64,
+    /// Memory limit (host memory when no limit is set)
+    pub memory_limit_bytes: u64,
+}
+
+impl ResourceUsage {
+    /// Memory usage as a percentage of the limit
+    pub fn memory_percent(&self) -> f64 {
+        if self.memory_limit_bytes == 0 {
+            return 0.0;
+        }
+        self.memory_used_bytes as f64 / self.memory_limit_bytes as f64 * 100.0
+    }
+}
+
+/// Take one stats sample for a running container
+///
+/// Docker fills in the previous CPU sample for non-streaming requests, so
+/// this blocks for roughly one second.
+pub async fn get_container_stats(
+    client: &DockerClient,
+    name: &str,
+) -> Result<ResourceUsage, DockerError> {
+    let options = StatsOptions {
+        stream: false,
+        one_shot: false,
+    };
+    let mut stream = client.inner().stats(name, Some(options));
+
+    let stats = stream
+        .next()
+        .await
+        .ok_or_else(|| DockerError::Container(format!("No stats returned for {name}")))?
+        .map_err(|e| DockerError::Container(format!("Failed to get stats for {name}: {e}")))?;
+
+    Ok(ResourceUsage {
+        cpu_percent: cpu_percent(&stats.cpu_stats, &stats.precpu_stats),
+        memory_used_bytes: memory_used_bytes(&stats.memory_stats),
+        memory_limit_bytes: stats.memory_stats.limit.unwrap_or(0),
+    })
+}
+
+/// CPU percentage between two samples
+///
+/// `(container usage delta / system usage delta) * online CPUs * 100`.
+/// Returns 0 when either delta is not positive (e.g. the first sample).
+pub fn cpu_percent(cpu: &CPUStats, precpu: &CPUStats) -> f64 {
+    let cpu_delta = cpu
+        .cpu_usage
+        .total_usage
+        .saturating_sub(precpu.cpu_usage.total_usage);
+    let system_delta = cpu
+        .system_cpu_usage
+        .unwrap_or(0)
+        .saturating_sub(precpu.system_cpu_usage.unwrap_or(0));
+
+    if cpu_delta == 0 || system_delta == 0 {
+        return 0.0;
+    }
+
+    let online_cpus = cpu
+        .online_cpus
+        .filter(|n| *n > 0)
+        .or_else(|| cpu.cpu_usage.percpu_usage.as_ref().map(|v| v.len() as u64))
+        .filter(|n| *n > 0)
+        .unwrap_or(1);
+
+    cpu_delta as f64 / system_delta as f64 * online_cpus as f64 * 100.0
+}
+
+/// Memory in use, excluding inactive page cache (matches `docker stats`)
+pub fn memory_used_bytes(memory: &MemoryStats) -> u64 {
+    let usage = memory.usage.unwrap_or(0);
+    let cache = match &memory.stats {
+        Some(MemoryStatsStats::V1(v1)) => v1.total_inactive_file,
+        Some(MemoryStatsStats::V2(v2)) => v2.inactive_file,
+        None => 0,
+    };
+    usage.saturating_sub(cache)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bollard::container::{CPUUsage, ThrottlingData};
+
+    fn cpu_sample(total_usage: u64, system: u64, online_cpus: Option<u64>) -> CPUStats {
+        CPUStats {
+            cpu_usage: CPUUsage {
+                percpu_usage: None,
+                usage_in_usermode: 0,
+                total_usage,
+                usage_in_kernelmode: 0,
+            },
+            system_cpu_usage: Some(system),
+            online_cpus,
+            throttling_data: ThrottlingData {
+                periods: 0,
+                throttled_periods: 0,
+                throttled_time: 0,
+            },
+        }
+    }
+
+    fn memory_sample(usage: u64, limit: u64) -> MemoryStats {
+        MemoryStats {
+            stats: None,
+            max_usage: None,
+            usage: Some(usage),
+            failcnt: None,
+            limit: Some(limit),
+            commit: None,
+            commit_peak: None,
+            commitbytes: None,
+            commitpeakbytes: None,
+            privateworkingset: None,
+        }
+    }
+
+    #[test]
+    fn cpu_percent_uses_deltas_and_cpu_count() {
+        let precpu = cpu_sample(1_000, 10_000, Some(4));
+        let cpu = cpu_sample(1_500, 12_000, Some(4));
+        // 500 / 2000 * 4 * 100
+        assert!((cpu_percent(&cpu, &precpu) - 100.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn cpu_percent_single_cpu() {
+        let precpu = cpu_sample(0, 0, Some(1));
+        let cpu = cpu_sample(250, 1_000, Some(1));
+        assert!((cpu_percent(&cpu, &precpu) - 25.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn cpu_percent_falls_back_to_percpu_count() {
+        let precpu = cpu_sample(0, 0, None);
+        let mut cpu = cpu_sample(100, 1_000, None);
+        cpu.cpu_usage.percpu_usage = Some(vec![50, 50]);
+        assert!((cpu_percent(&cpu, &precpu) - 20.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn cpu_percent_zero_without_previous_sample() {
+        let sample = cpu_sample(1_000, 10_000, Some(2));
+        assert_eq!(cpu_percent(&sample, &sample), 0.0);
+    }
+
+    #[test]
+    fn memory_percent_from_usage_and_limit() {
+        let memory = memory_sample(256 * 1024 * 1024, 1024 * 1024 * 1024);
+        let usage = ResourceUsage {
+            cpu_percent: 0.0,
+            memory_used_bytes: memory_used_bytes(&memory),
+            memory_limit_bytes: memory.limit.unwrap(),
+        };
+        assert!((usage.memory_percent() - 25.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn memory_percent_zero_limit() {
+        let usage = ResourceUsage {
+            cpu_percent: 0.0,
+            memory_used_bytes: 100,
+            memory_limit_bytes: 0,
+        };
+        assert_eq!(usage.memory_percent(), 0.0);
+    }
+}
@@ -5,6 +5,7 @@
 
 use super::image::{image_exists, pull_image};
 use super::progress::ProgressReporter;
+use super::registry_auth::RegistryAuth;
 use super::{DockerClient, DockerError, IMAGE_NAME_GHCR, IMAGE_TAG_DEFAULT};
 use bollard::image::TagImageOptions;
 use tracing::debug;
@@ -81,9 +82,11 @@ pub async fn has_previous_image(client: &DockerClient) -> Result<bool, DockerErr
 ///
 /// # Arguments
 /// * `client` - Docker client
+/// * `auth` - Registry credentials for the pull
 /// * `progress` - Progress reporter for user feedback
 pub async fn update_image(
     client: &DockerClient,
+    auth: &RegistryAuth,
     progress: &mut ProgressReporter,
 ) -> Result<UpdateResult, DockerError> {
     // Step 1: Tag current image as previous for rollback
@@ -93,7 +96,7 @@ pub async fn update_image(
 
     // Step 2: Pull latest image
     progress.add_spinner("pull", "Pulling latest image");
-    pull_image(client, Some(IMAGE_TAG_DEFAULT), progress).await?;
+    pull_image(client, Some(IMAGE_TAG_DEFAULT), auth, progress).await?;
     progress.finish("pull", "Latest image pulled");
 
     Ok(UpdateResult::Success)
@@ -9,6 +9,7 @@
 
 use super::exec::{exec_command, exec_command_exit_code, exec_command_with_stdin};
 use super::{DockerClient, DockerError};
+use crate::host::{DistroFamily, parse_os_release};
 
 /// Information about a container user
 #[derive(Debug, Clone, PartialEq)]
@@ -96,6 +97,61 @@ pub async fn set_user_password(
     Ok(())
 }
 
+/// Grant a user admin (sudo) rights in the container
+///
+/// Reads the container's `/etc/os-release` to pick the admin group
+/// (`sudo` on Debian images, `wheel` elsewhere) and adds the user to it.
+/// Returns the name of the group the user was added to.
+///
+/// # Arguments
+/// * `client` - Docker client
+/// * `container` - Container name or ID
+/// * `username` - Existing user to promote
+pub async fn grant_admin(
+    client: &DockerClient,
+    container: &str,
+    username: &str,
+) -> Result<String, DockerError> {
+    let os_release = exec_command(client, container, vec!["cat", "/etc/os-release"]).await?;
+    let distro = parse_os_release(&os_release).map_err(|_| {
+        DockerError::Container("Could not detect the container's Linux distribution".to_string())
+    })?;
+
+    let Some((group, cmd)) = admin_group_command(&distro.family, username) else {
+        return Err(DockerError::Container(format!(
+            "Don't know which admin group to use for {}",
+            distro.family
+        )));
+    };
+
+    let cmd_refs: Vec<&str> = cmd.iter().map(String::as_str).collect();
+    let exit_code = exec_command_exit_code(client, container, cmd_refs).await?;
+
+    if exit_code != 0 {
+        return Err(DockerError::Container(format!(
+            "Failed to add '{username}' to group '{group}': exit code {exit_code}"
+        )));
+    }
+
+    Ok(group.to_string())
+}
+
+/// Select the admin group and the command that adds a user to it
+///
+/// Alpine (busybox) lacks `usermod`, so it uses `addgroup <user> <group>`.
+fn admin_group_command(
+    family: &DistroFamily,
+    username: &str,
+) -> Option<(&'static str, Vec<String>)> {
+    let group = family.admin_group()?;
+    let cmd = match family {
+        DistroFamily::Alpine => vec!["addgroup", username, group],
+        _ => vec!["usermod", "-aG", group, username],
+    };
+
+    Some((group, cmd.into_iter().map(String::from).collect()))
+}
+
 /// Check if a user exists in the container
 ///
 /// # Arguments
@@ -234,6 +290,38 @@ pub async fn list_users(
     Ok(users)
 }
 
+/// First UID handed out to regular (non-system) accounts
+const MIN_REGULAR_UID: u32 = 1000;
+
+/// UID of the `nobody` account, which sits above the regular range
+const NOBODY_UID: u32 = 65534;
+
+/// List the usernames of regular accounts in the container
+///
+/// Reads the live `/etc/passwd` via `getent passwd` and keeps accounts with
+/// UID >= 1000, so it also sees users created outside `occ user add`.
+///
+/// # Arguments
+/// * `client` - Docker client
+/// * `container` - Container name or ID
+pub async fn list_live_usernames(
+    client: &DockerClient,
+    container: &str,
+) -> Result<Vec<String>, DockerError> {
+    let output = exec_command(client, container, vec!["getent", "passwd"]).await?;
+    Ok(parse_regular_usernames(&output))
+}
+
+/// Extract regular account usernames from `getent passwd` output
+fn parse_regular_usernames(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter_map(parse_passwd_line)
+        .filter(|user| user.uid >= MIN_REGULAR_UID && user.uid != NOBODY_UID)
+        .map(|user| user.username)
+        .collect()
+}
+
 /// Check if a user account is locked
 ///
 /// Uses `passwd -S` to get account status.
@@ -314,6 +402,52 @@ mod tests {
         assert!(parse_passwd_line("user:x:not_a_number:1000::/home/user:/bin/bash").is_none());
     }
 
+    #[test]
+    fn test_admin_group_command_debian_uses_sudo() {
+        let (group, cmd) = admin_group_command(&DistroFamily::Debian, "admin").unwrap();
+        assert_eq!(group, "sudo");
+        assert_eq!(cmd, vec!["usermod", "-aG", "sudo", "admin"]);
+    }
+
+    #[test]
+    fn test_admin_group_command_redhat_uses_wheel() {
+        let (group, cmd) = admin_group_command(&DistroFamily::RedHat, "admin").unwrap();
+        assert_eq!(group, "wheel");
+        assert_eq!(cmd, vec!["usermod", "-aG", "wheel", "admin"]);
+    }
+
+    #[test]
+    fn test_admin_group_command_alpine_uses_addgroup() {
+        let (group, cmd) = admin_group_command(&DistroFamily::Alpine, "admin").unwrap();
+        assert_eq!(group, "wheel");
+        assert_eq!(cmd, vec!["addgroup", "admin", "wheel"]);
+    }
+
+    #[test]
+    fn test_admin_group_command_unknown_distro() {
+        let family = DistroFamily::Unknown("plan9".to_string());
+        assert!(admin_group_command(&family, "admin").is_none());
+    }
+
+    #[test]
+    fn test_admin_group_from_container_os_release() {
+        let os_release = "ID=ubuntu\nID_LIKE=debian\nPRETTY_NAME=\"Ubuntu 24.04 LTS\"\n";
+        let distro = parse_os_release(os_release).unwrap();
+        let (group, _) = admin_group_command(&distro.family, "admin").unwrap();
+        assert_eq!(group, "sudo");
+    }
+
+    #[test]
+    fn test_parse_regular_usernames_skips_system_accounts() {
+        let output = "root:x:0:0:root:/root:/bin/bash\n\
+                      daemon:x:1:1:daemon:/usr/sbin:/usr/sbin/nologin\n\
+                      opencode:x:1000:1000::/home/opencode:/bin/bash\n\
+                      alice:x:1001:1001::/srv/alice:/bin/bash\n\
+                      nobody:x:65534:65534:nobody:/nonexistent:/usr/sbin/nologin\n\
+                      garbage line\n";
+        assert_eq!(parse_regular_usernames(output), vec!["opencode", "alice"]);
+    }
+
     #[test]
     fn test_user_info_struct() {
         let info = UserInfo {
@@ -6,7 +6,7 @@
 use super::{DockerClient, DockerError};
 use bollard::volume::CreateVolumeOptions;
 use std::collections::HashMap;
-use tracing::debug;
+use tracing::{debug, warn};
 
 /// Volume name for opencode session history
 pub const VOLUME_SESSION: &str = "opencode-cloud-session";
@@ -20,6 +20,12 @@ pub const VOLUME_CONFIG: &str = "opencode-cloud-config";
 /// All volume names as array for iteration
 pub const VOLUME_NAMES: [&str; 3] = [VOLUME_SESSION, VOLUME_PROJECTS, VOLUME_CONFIG];
 
+/// Label marking a volume as owned by opencode-cloud
+pub const VOLUME_MANAGED_LABEL: &str = "com.opencode-cloud.managed";
+
+/// Legacy ownership label set by older releases (still written for compatibility)
+pub const VOLUME_LEGACY_LABEL: (&str, &str) = ("managed-by", "opencode-cloud");
+
 /// Mount point for session history inside container
 pub const MOUNT_SESSION: &str = "/home/opencode/.opencode";
 
@@ -32,7 +38,9 @@ pub const MOUNT_CONFIG: &str = "/home/opencode/.config";
 /// Ensure all required volumes exist
 ///
 /// Creates volumes if they don't exist. This operation is idempotent -
-/// calling it multiple times has no additional effect.
+/// calling it multiple times has no additional effect. Existing volumes
+/// without the ownership label are left untouched (labels can't be added
+/// after creation) and a warning is logged.
 pub async fn ensure_volumes_exist(client: &DockerClient) -> Result<(), DockerError> {
     debug!("Ensuring all required volumes exist");
 
@@ -48,18 +56,31 @@ pub async fn ensure_volumes_exist(client: &DockerClient) -> Result<(), DockerErr
 async fn ensure_volume_exists(client: &DockerClient, name: &str) -> Result<(), DockerError> {
     debug!("Checking volume: {}", name);
 
-    // Create volume options with default local driver
-    let options = CreateVolumeOptions {
-        name,
-        driver: "local",
-        driver_opts: HashMap::new(),
-        labels: HashMap::from([("managed-by", "opencode-cloud")]),
-    };
+    match client.inner().inspect_volume(name).await {
+        Ok(volume) => {
+            if !is_managed_volume(&volume.labels) {
+                warn!(
+                    "Volume {} exists without the {}=true label; it predates labeling and \
+                     will not be recognized by cleanup. Data is unaffected.",
+                    name, VOLUME_MANAGED_LABEL
+                );
+            }
+            debug!("Volume {} already exists", name);
+            return Ok(());
+        }
+        Err(bollard::errors::Error::DockerResponseServerError {
+            status_code: 404, ..
+        }) => {}
+        Err(e) => {
+            return Err(DockerError::Volume(format!(
+                "Failed to inspect volume {name}: {e}"
+            )));
+        }
+    }
 
-    // create_volume is idempotent - returns existing volume if it exists
     client
         .inner()
-        .create_volume(options)
+        .create_volume(volume_create_options(name))
         .await
         .map_err(|e| DockerError::Volume(format!("Failed to create volume {name}: {e}")))?;
 
@@ -67,6 +88,27 @@ async fn ensure_volume_exists(client: &DockerClient, name: &str) -> Result<(), D
     Ok(())
 }
 
+/// Create options for a managed volume: local driver plus ownership labels
+fn volume_create_options(name: &str) -> CreateVolumeOptions<&str> {
+    CreateVolumeOptions {
+        name,
+        driver: "local",
+        driver_opts: HashMap::new(),
+        labels: HashMap::from([
+            (VOLUME_MANAGED_LABEL, "true"),
+            (VOLUME_LEGACY_LABEL.0, VOLUME_LEGACY_LABEL.1),
+        ]),
+    }
+}
+
+/// Whether volume labels mark it as owned by opencode-cloud
+///
+/// Accepts both the current label and the legacy `managed-by` label.
+pub fn is_managed_volume(labels: &HashMap<String, String>) -> bool {
+    labels.get(VOLUME_MANAGED_LABEL).map(String::as_str) == Some("true")
+        || labels.get(VOLUME_LEGACY_LABEL.0).map(String::as_str) == Some(VOLUME_LEGACY_LABEL.1)
+}
+
 /// Check if a specific volume exists
 pub async fn volume_exists(client: &DockerClient, name: &str) -> Result<bool, DockerError> {
     debug!("Checking if volume exists: {}", name);
@@ -135,6 +177,30 @@ mod tests {
         assert!(VOLUME_NAMES.contains(&VOLUME_CONFIG));
     }
 
+    #[test]
+    fn create_options_include_managed_label() {
+        let options = volume_create_options(VOLUME_SESSION);
+        assert_eq!(options.name, VOLUME_SESSION);
+        assert_eq!(options.driver, "local");
+        assert_eq!(options.labels.get(VOLUME_MANAGED_LABEL), Some(&"true"));
+        assert_eq!(
+            options.labels.get(VOLUME_LEGACY_LABEL.0),
+            Some(&VOLUME_LEGACY_LABEL.1)
+        );
+    }
+
+    #[test]
+    fn managed_volume_detection() {
+        let current = HashMap::from([(VOLUME_MANAGED_LABEL.to_string(), "true".to_string())]);
+        let legacy = HashMap::from([("managed-by".to_string(), "opencode-cloud".to_string())]);
+        let foreign = HashMap::from([(VOLUME_MANAGED_LABEL.to_string(), "false".to_string())]);
+
+        assert!(is_managed_volume(&current));
+        assert!(is_managed_volume(&legacy));
+        assert!(!is_managed_volume(&foreign));
+        assert!(!is_managed_volume(&HashMap::new()));
+    }
+
     #[test]
     fn mount_points_are_correct() {
         assert_eq!(MOUNT_SESSION, "/home/opencode/.opencode");
@@ -0,0 +1,69 @@
+//! Human-friendly duration parsing
+//!
+//! Shared by every flag that accepts a duration so they all understand the
+//! same forms: plain seconds (`30`) or a number with an `s`, `m`, `h` or `d`
+//! suffix (`30s`, `5m`, `2h`, `1d`).
+
+use std::time::Duration;
+
+/// Parse a duration such as `30`, `30s`, `5m`, `2h` or `1d`
+///
+/// Returns a message suitable for CLI errors on invalid input. The
+/// signature matches clap's `value_parser` so it can be used directly.
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let trimmed = s.trim();
+    if trimmed.is_empty() {
+        return Err("Duration cannot be empty. Examples: 30, 30s, 5m, 2h, 1d".to_string());
+    }
+
+    let (number, multiplier) = match trimmed.char_indices().last() {
+        Some((i, 's')) => (&trimmed[..i], 1),
+        Some((i, 'm')) => (&trimmed[..i], 60),
+        Some((i, 'h')) => (&trimmed[..i], 60 * 60),
+        Some((i, 'd')) => (&trimmed[..i], 24 * 60 * 60),
+        _ => (trimmed, 1),
+    };
+
+    number
+        .parse::<u64>()
+        .ok()
+        .and_then(|n| n.checked_mul(multiplier))
+        .map(Duration::from_secs)
+        .ok_or_else(|| format!("Invalid duration: '{s}'. Examples: 30, 30s, 5m, 2h, 1d"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_seconds() {
+        assert_eq!(parse_duration("30"), Ok(Duration::from_secs(30)));
+        assert_eq!(parse_duration("0"), Ok(Duration::ZERO));
+    }
+
+    #[test]
+    fn suffixes() {
+        assert_eq!(parse_duration("30s"), Ok(Duration::from_secs(30)));
+        assert_eq!(parse_duration("5m"), Ok(Duration::from_secs(300)));
+        assert_eq!(parse_duration("2h"), Ok(Duration::from_secs(7200)));
+        assert_eq!(parse_duration("1d"), Ok(Duration::from_secs(86400)));
+    }
+
+    #[test]
+    fn surrounding_whitespace_is_ignored() {
+        assert_eq!(parse_duration(" 5m "), Ok(Duration::from_secs(300)));
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        for input in ["", "  ", "5x", "s", "m5", "-5s", "1.5h", "5 m"] {
+            assert!(parse_duration(input).is_err(), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn overflow_is_rejected() {
+        assert!(parse_duration(&format!("{}d", u64::MAX)).is_err());
+    }
+}
@@ -0,0 +1,199 @@
+//! Service lifecycle event history
+//!
+//! Starts, stops, detected crashes and updates are appended to
+//! `events.jsonl` in the data directory, one JSON object per line:
+//!
+//! ```text
+//! {"timestamp":"2026-01-01T12:00:00+00:00","event":"started","detail":"port 3000"}
+//! ```
+//!
+//! The file keeps only the most recent [`MAX_EVENTS`] entries. Recording is
+//! best-effort: failures are logged and never fail the command.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::paths::get_events_path;
+
+/// Maximum number of events kept in the history file
+pub const MAX_EVENTS: usize = 200;
+
+/// Kind of lifecycle event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LifecycleEventKind {
+    /// The service was started
+    Started,
+    /// The service was stopped
+    Stopped,
+    /// The container was found exited after it was last started
+    CrashDetected,
+    /// The service was updated or rolled back
+    Updated,
+}
+
+impl LifecycleEventKind {
+    /// Short label used when rendering history
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Started => "started",
+            Self::Stopped => "stopped",
+            Self::CrashDetected => "crash detected",
+            Self::Updated => "updated",
+        }
+    }
+}
+
+/// A single entry in the lifecycle history
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LifecycleEvent {
+    /// RFC 3339 timestamp of the event
+    pub timestamp: String,
+    /// What happened
+    pub event: LifecycleEventKind,
+    /// Remote host name, if the event happened on a remote host
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    /// Extra context (e.g. port, exit code, version)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+impl LifecycleEvent {
+    /// Create an event stamped with the current time
+    pub fn now(event: LifecycleEventKind, host: Option<&str>, detail: Option<String>) -> Self {
+        Self {
+            timestamp: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
+            event,
+            host: host.map(str::to_string),
+            detail,
+        }
+    }
+}
+
+/// Append an event to the history file, dropping the oldest entries past the cap
+pub fn record_event(event: LifecycleEventKind, host: Option<&str>, detail: Option<String>) {
+    let Some(path) = get_events_path() else {
+        tracing::warn!("Could not determine events path; lifecycle event not recorded");
+        return;
+    };
+
+    let event = LifecycleEvent::now(event, host, detail);
+    if let Err(e) = append_event(&path, &event, MAX_EVENTS) {
+        tracing::warn!("Failed to write lifecycle event {}: {e}", path.display());
+    }
+}
+
+/// Read the most recent `limit` events, oldest first
+pub fn recent_events(limit: usize) -> Vec<LifecycleEvent> {
+    let events = get_events_path()
+        .map(|path| read_events(&path))
+        .unwrap_or_default();
+    let skip = events.len().saturating_sub(limit);
+    events.into_iter().skip(skip).collect()
+}
+
+/// Read all events from `path`, skipping malformed lines
+fn read_events(path: &Path) -> Vec<LifecycleEvent> {
+    let Ok(contents) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    contents
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+/// Append `event` to `path`, keeping at most `max` entries
+fn append_event(path: &Path, event: &LifecycleEvent, max: usize) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut events = read_events(path);
+    events.push(event.clone());
+    let skip = events.len().saturating_sub(max);
+
+    let mut contents = String::new();
+    for event in &events[skip..] {
+        contents.push_str(&serde_json::to_string(event)?);
+        contents.push('\n');
+    }
+    std::fs::write(path, contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(kind: LifecycleEventKind, detail: &str) -> LifecycleEvent {
+        LifecycleEvent {
+            timestamp: "2026-01-01T12:00:00+00:00".to_string(),
+            event: kind,
+            host: None,
+            detail: Some(detail.to_string()),
+        }
+    }
+
+    #[test]
+    fn append_and_read_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.jsonl");
+        let started = event(LifecycleEventKind::Started, "port 3000");
+        let stopped = event(LifecycleEventKind::Stopped, "");
+
+        append_event(&path, &started, 10).unwrap();
+        append_event(&path, &stopped, 10).unwrap();
+
+        assert_eq!(read_events(&path), vec![started, stopped]);
+    }
+
+    #[test]
+    fn append_drops_oldest_past_cap() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.jsonl");
+        for i in 0..5 {
+            append_event(
+                &path,
+                &event(LifecycleEventKind::Started, &i.to_string()),
+                3,
+            )
+            .unwrap();
+        }
+
+        let details: Vec<_> = read_events(&path)
+            .into_iter()
+            .filter_map(|e| e.detail)
+            .collect();
+        assert_eq!(details, vec!["2", "3", "4"]);
+    }
+
+    #[test]
+    fn malformed_lines_are_skipped() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.jsonl");
+        std::fs::write(
+            &path,
+            "not json\n{\"timestamp\":\"t\",\"event\":\"crash_detected\"}\n",
+        )
+        .unwrap();
+
+        let events = read_events(&path);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event, LifecycleEventKind::CrashDetected);
+        assert_eq!(events[0].detail, None);
+    }
+
+    #[test]
+    fn optional_fields_are_omitted() {
+        let json = serde_json::to_string(&LifecycleEvent {
+            timestamp: "t".to_string(),
+            event: LifecycleEventKind::Updated,
+            host: None,
+            detail: None,
+        })
+        .unwrap();
+        assert_eq!(json, r#"{"timestamp":"t","event":"updated"}"#);
+    }
+}
@@ -47,6 +47,10 @@ pub enum HostError {
     #[error("SSH tunnel connection timed out after {0} attempts")]
     TunnelTimeout(u32),
 
+    /// Docker is not installed on the remote host
+    #[error("Docker is not installed on remote host {0}")]
+    DockerNotFound(String),
+
     /// Remote Docker not available
     #[error("Docker not available on remote host: {0}")]
     RemoteDockerUnavailable(String),
@@ -18,11 +18,12 @@ mod tunnel;
 pub use error::HostError;
 pub use provision::{
     DistroFamily, DistroInfo, detect_distro, get_docker_install_commands, install_docker,
-    verify_docker_installed,
+    parse_os_release, verify_docker_installed,
 };
-pub use schema::{HostConfig, HostsFile};
+pub use schema::{HostConfig, HostsFile, StrictHostKeyChecking};
 pub use ssh_config::{
-    SshConfigMatch, get_ssh_config_path, host_exists_in_ssh_config, query_ssh_config,
+    SshConfigHost, SshConfigMatch, get_ssh_config_path, host_exists_in_ssh_config,
+    is_ssh_host_pattern, list_ssh_config_hosts, query_ssh_config, ssh_glob_matches,
     write_ssh_config_entry,
 };
 pub use storage::{load_hosts, save_hosts};
@@ -37,6 +37,23 @@ impl std::fmt::Display for DistroFamily {
     }
 }
 
+impl DistroFamily {
+    /// Group that grants sudo rights on this distribution family
+    ///
+    /// Debian derivatives use `sudo`; the others conventionally use `wheel`.
+    /// Returns None for unknown distributions.
+    pub fn admin_group(&self) -> Option<&'static str> {
+        match self {
+            DistroFamily::Debian => Some("sudo"),
+            DistroFamily::RedHat
+            | DistroFamily::Alpine
+            | DistroFamily::Arch
+            | DistroFamily::Suse => Some("wheel"),
+            DistroFamily::Unknown(_) => None,
+        }
+    }
+}
+
 /// Detected distribution information
 #[derive(Debug, Clone)]
 pub struct DistroInfo {
@@ -60,7 +77,9 @@ pub fn detect_distro(host: &HostConfig) -> Result<DistroInfo, HostError> {
 }
 
 /// Parse /etc/os-release content into DistroInfo
-fn parse_os_release(content: &str) -> Result<DistroInfo, HostError> {
+///
+/// Pure parsing with no I/O, so it works for remote hosts and containers alike.
+pub fn parse_os_release(content: &str) -> Result<DistroInfo, HostError> {
     let mut id = String::new();
     let mut id_like = String::new();
     let mut pretty_name = String::new();
@@ -293,7 +312,7 @@ fn build_ssh_command(host: &HostConfig) -> Command {
         .arg("-o")
         .arg("ConnectTimeout=30")
         .arg("-o")
-        .arg("StrictHostKeyChecking=accept-new");
+        .arg(host.strict_host_key_checking.ssh_option());
 
     // Host-specific options (port, identity, jump, user@host)
     cmd.args(host.ssh_args());
@@ -303,9 +322,20 @@ fn build_ssh_command(host: &HostConfig) -> Command {
 
 /// Verify Docker is working after installation
 ///
+/// Returns `HostError::DockerNotFound` when the `docker` binary is missing;
+/// SSH failures are returned unchanged.
+///
 /// Note: Due to group membership changes, this may fail until the user
 /// reconnects. We run with sudo as a fallback.
 pub fn verify_docker_installed(host: &HostConfig) -> Result<String, HostError> {
+    let found = run_ssh_command(
+        host,
+        "if command -v docker >/dev/null 2>&1; then echo yes; else echo no; fi",
+    )?;
+    if found.trim() != "yes" {
+        return Err(HostError::DockerNotFound(host.hostname.clone()));
+    }
+
     // Try without sudo first (if group membership is active)
     let output = run_ssh_command(
         host,
@@ -370,6 +400,19 @@ ID=debian
         assert_eq!(info.id, "debian");
     }
 
+    #[test]
+    fn test_admin_group_by_family() {
+        assert_eq!(DistroFamily::Debian.admin_group(), Some("sudo"));
+        assert_eq!(DistroFamily::RedHat.admin_group(), Some("wheel"));
+        assert_eq!(DistroFamily::Alpine.admin_group(), Some("wheel"));
+        assert_eq!(DistroFamily::Arch.admin_group(), Some("wheel"));
+        assert_eq!(DistroFamily::Suse.admin_group(), Some("wheel"));
+        assert_eq!(
+            DistroFamily::Unknown("gentoo".to_string()).admin_group(),
+            None
+        );
+    }
+
     #[test]
     fn test_get_docker_install_commands() {
         let debian_info = DistroInfo {
@@ -391,4 +434,28 @@ ID=debian
         let commands = get_docker_install_commands(&redhat_info).unwrap();
         assert!(!commands.is_empty());
     }
+
+    fn args_of(cmd: &std::process::Command) -> Vec<String> {
+        cmd.get_args()
+            .map(|a| a.to_string_lossy().into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_build_ssh_command_host_key_mode() {
+        use crate::host::StrictHostKeyChecking;
+
+        let default = args_of(&build_ssh_command(&HostConfig::new("h")));
+        assert!(default.contains(&"StrictHostKeyChecking=accept-new".to_string()));
+
+        for (mode, expected) in [
+            (StrictHostKeyChecking::Yes, "StrictHostKeyChecking=yes"),
+            (StrictHostKeyChecking::No, "StrictHostKeyChecking=no"),
+        ] {
+            let host = HostConfig::new("h").with_strict_host_key_checking(mode);
+            let args = args_of(&build_ssh_command(&host));
+            assert!(args.contains(&expected.to_string()), "{args:?}");
+            assert!(!args.contains(&"StrictHostKeyChecking=accept-new".to_string()));
+        }
+    }
 }
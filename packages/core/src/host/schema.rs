@@ -2,8 +2,42 @@
 //!
 //! Data structures for storing remote host configurations.
 
+use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+
+/// SSH `StrictHostKeyChecking` mode used when connecting to a host
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum StrictHostKeyChecking {
+    /// Only connect to hosts already in known_hosts
+    Yes,
+    /// Trust and record unknown host keys, reject changed ones
+    #[default]
+    AcceptNew,
+    /// Never verify host keys (insecure)
+    No,
+}
+
+impl StrictHostKeyChecking {
+    /// Value as passed to ssh
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Yes => "yes",
+            Self::AcceptNew => "accept-new",
+            Self::No => "no",
+        }
+    }
+
+    /// `-o` option value, e.g. `StrictHostKeyChecking=accept-new`
+    pub fn ssh_option(self) -> String {
+        format!("StrictHostKeyChecking={}", self.as_str())
+    }
+
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
 
 /// Configuration for a remote host
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -35,6 +69,10 @@ pub struct HostConfig {
     /// Optional description
     #[serde(default)]
     pub description: Option<String>,
+
+    /// Host key checking mode for provisioning and tunneling (default: accept-new)
+    #[serde(default, skip_serializing_if = "StrictHostKeyChecking::is_default")]
+    pub strict_host_key_checking: StrictHostKeyChecking,
 }
 
 fn default_user() -> String {
@@ -51,6 +89,7 @@ impl Default for HostConfig {
             jump_host: None,
             groups: Vec::new(),
             description: None,
+            strict_host_key_checking: StrictHostKeyChecking::default(),
         }
     }
 }
@@ -100,6 +139,12 @@ impl HostConfig {
         self
     }
 
+    /// Builder pattern: set host key checking mode
+    pub fn with_strict_host_key_checking(mut self, mode: StrictHostKeyChecking) -> Self {
+        self.strict_host_key_checking = mode;
+        self
+    }
+
     /// Get SSH command arguments for this host
     ///
     /// Returns arguments for port, identity file, jump host, and target (user@hostname).
@@ -227,6 +272,29 @@ impl HostsFile {
     pub fn host_names(&self) -> Vec<&str> {
         self.hosts.keys().map(|s| s.as_str()).collect()
     }
+
+    /// Hosts in a group (all hosts when `group` is None), sorted by name
+    pub fn hosts_in_group(&self, group: Option<&str>) -> Vec<(&str, &HostConfig)> {
+        let mut hosts: Vec<_> = self
+            .hosts
+            .iter()
+            .filter(|(_, config)| group.is_none_or(|g| config.groups.iter().any(|h| h == g)))
+            .map(|(name, config)| (name.as_str(), config))
+            .collect();
+        hosts.sort_by_key(|(name, _)| *name);
+        hosts
+    }
+
+    /// All groups with the number of hosts in each, sorted by group name
+    pub fn group_counts(&self) -> BTreeMap<&str, usize> {
+        let mut counts = BTreeMap::new();
+        for config in self.hosts.values() {
+            for group in &config.groups {
+                *counts.entry(group.as_str()).or_insert(0) += 1;
+            }
+        }
+        counts
+    }
 }
 
 #[cfg(test)]
@@ -243,6 +311,10 @@ mod tests {
         assert!(config.jump_host.is_none());
         assert!(config.groups.is_empty());
         assert!(config.description.is_none());
+        assert_eq!(
+            config.strict_host_key_checking,
+            StrictHostKeyChecking::AcceptNew
+        );
     }
 
     #[test]
@@ -280,6 +352,48 @@ mod tests {
         assert!(hosts.default_host.is_none());
     }
 
+    fn sample_hosts() -> HostsFile {
+        serde_json::from_str(
+            r#"{
+                "version": 1,
+                "hosts": {
+                    "web-1": {"hostname": "10.0.0.1", "groups": ["web", "prod"]},
+                    "web-2": {"hostname": "10.0.0.2", "groups": ["web"]},
+                    "db-1": {"hostname": "10.0.0.3", "groups": ["prod"]},
+                    "scratch": {"hostname": "10.0.0.4"}
+                }
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_hosts_in_group() {
+        let hosts = sample_hosts();
+        let names = |group| -> Vec<&str> {
+            hosts
+                .hosts_in_group(group)
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect()
+        };
+        assert_eq!(names(Some("web")), vec!["web-1", "web-2"]);
+        assert_eq!(names(Some("prod")), vec!["db-1", "web-1"]);
+        assert!(names(Some("missing")).is_empty());
+        assert_eq!(names(None), vec!["db-1", "scratch", "web-1", "web-2"]);
+    }
+
+    #[test]
+    fn test_group_counts() {
+        let hosts = sample_hosts();
+        let counts = hosts.group_counts();
+        assert_eq!(
+            counts.into_iter().collect::<Vec<_>>(),
+            vec![("prod", 2), ("web", 2)]
+        );
+        assert!(HostsFile::new().group_counts().is_empty());
+    }
+
     #[test]
     fn test_serialize_deserialize() {
         let mut hosts = HostsFile::new();
@@ -305,4 +419,23 @@ mod tests {
         assert!(hosts.hosts.is_empty());
         assert!(hosts.default_host.is_none());
     }
+
+    #[test]
+    fn test_strict_host_key_checking_serde() {
+        let default_json = serde_json::to_string(&HostConfig::new("h")).unwrap();
+        assert!(!default_json.contains("strict_host_key_checking"));
+
+        let strict = HostConfig::new("h").with_strict_host_key_checking(StrictHostKeyChecking::Yes);
+        let json = serde_json::to_string(&strict).unwrap();
+        assert!(json.contains(r#""strict_host_key_checking":"yes""#));
+        assert_eq!(serde_json::from_str::<HostConfig>(&json).unwrap(), strict);
+
+        let parsed: HostConfig =
+            serde_json::from_str(r#"{"hostname":"h","strict_host_key_checking":"accept-new"}"#)
+                .unwrap();
+        assert_eq!(
+            parsed.strict_host_key_checking,
+            StrictHostKeyChecking::AcceptNew
+        );
+    }
 }
@@ -1,12 +1,13 @@
 //! SSH config file parsing and writing
 //!
-//! Parses ~/.ssh/config to auto-fill host settings and can write new entries.
+//! Parses ~/.ssh/config to auto-fill host settings, enumerate its hosts and
+//! write new entries.
 
 use std::fs::{self, File, OpenOptions};
-use std::io::{BufReader, Write};
+use std::io::{BufRead, BufReader, Write};
 use std::path::PathBuf;
 
-use ssh2_config_rs::{ParseRule, SshConfig};
+use ssh2_config_rs::{HostClause, HostParams, ParseRule, SshConfig};
 
 use super::error::HostError;
 
@@ -55,6 +56,27 @@ impl SshConfigMatch {
     }
 }
 
+/// A concrete `Host` alias declared in the SSH config
+#[derive(Debug, Clone)]
+pub struct SshConfigHost {
+    /// The alias from the `Host` line
+    pub alias: String,
+    /// `HostName` for the alias, or the alias itself when unset
+    pub hostname: String,
+    /// Effective settings for the alias (including matching wildcard blocks)
+    pub settings: SshConfigMatch,
+}
+
+/// Whether an SSH `Host` pattern is a wildcard or negation rather than an alias
+pub fn is_ssh_host_pattern(pattern: &str) -> bool {
+    pattern.contains(['*', '?', '!'])
+}
+
+/// Whether `name` matches a glob (`*` and `?`), using SSH's own pattern rules
+pub fn ssh_glob_matches(glob: &str, name: &str) -> bool {
+    HostClause::new(glob.to_string(), false).intersects(name)
+}
+
 /// Get the path to the user's SSH config file
 pub fn get_ssh_config_path() -> Option<PathBuf> {
     dirs::home_dir().map(|home| home.join(".ssh").join("config"))
@@ -73,20 +95,64 @@ pub fn query_ssh_config(hostname: &str) -> Result<SshConfigMatch, HostError> {
         }
     };
 
-    let file = File::open(&config_path).map_err(|e| {
+    let config = read_ssh_config(&config_path)?;
+
+    // Query for the hostname
+    Ok(match_from_params(config.query(hostname)))
+}
+
+/// List the concrete host aliases in the user's SSH config, in file order
+///
+/// Wildcard and negated patterns (`Host *`, `Host !bastion`) are skipped;
+/// their settings still apply to the aliases they match. Returns an empty
+/// list when there is no SSH config.
+pub fn list_ssh_config_hosts() -> Result<Vec<SshConfigHost>, HostError> {
+    let config_path = match get_ssh_config_path() {
+        Some(path) if path.exists() => path,
+        _ => return Ok(Vec::new()),
+    };
+    Ok(enumerate_ssh_config_hosts(&read_ssh_config(&config_path)?))
+}
+
+fn read_ssh_config(config_path: &std::path::Path) -> Result<SshConfig, HostError> {
+    let file = File::open(config_path).map_err(|e| {
         HostError::SshConfigRead(format!("Failed to open {}: {}", config_path.display(), e))
     })?;
+    parse_ssh_config(&mut BufReader::new(file))
+}
 
-    let mut reader = BufReader::new(file);
-
+fn parse_ssh_config(reader: &mut impl BufRead) -> Result<SshConfig, HostError> {
     // Use ALLOW_UNKNOWN_FIELDS to be lenient with SSH config options we don't support
-    let config = SshConfig::default()
-        .parse(&mut reader, ParseRule::ALLOW_UNKNOWN_FIELDS)
-        .map_err(|e| HostError::SshConfigRead(format!("Failed to parse SSH config: {e}")))?;
+    SshConfig::default()
+        .parse(reader, ParseRule::ALLOW_UNKNOWN_FIELDS)
+        .map_err(|e| HostError::SshConfigRead(format!("Failed to parse SSH config: {e}")))
+}
 
-    // Query for the hostname
-    let params = config.query(hostname);
+fn enumerate_ssh_config_hosts(config: &SshConfig) -> Vec<SshConfigHost> {
+    let mut hosts: Vec<SshConfigHost> = Vec::new();
+    for clause in config.get_hosts().iter().flat_map(|host| &host.pattern) {
+        let alias = clause.pattern.as_str();
+        if clause.negated || is_ssh_host_pattern(alias) {
+            continue;
+        }
+        if hosts.iter().any(|h| h.alias == alias) {
+            continue;
+        }
+        let params = config.query(alias);
+        hosts.push(SshConfigHost {
+            alias: alias.to_string(),
+            hostname: params
+                .host_name
+                .clone()
+                .unwrap_or_else(|| alias.to_string()),
+            settings: match_from_params(params),
+        });
+    }
+    hosts
+}
 
+/// Extract the settings we use from merged SSH config params
+fn match_from_params(params: HostParams) -> SshConfigMatch {
     let mut result = SshConfigMatch {
         matched: true,
         ..Default::default()
@@ -117,7 +183,7 @@ pub fn query_ssh_config(hostname: &str) -> Result<SshConfigMatch, HostError> {
         result.matched = false;
     }
 
-    Ok(result)
+    result
 }
 
 /// Write a new host entry to the user's SSH config file
@@ -272,6 +338,58 @@ mod tests {
         assert!(with_user.has_settings());
     }
 
+    fn parse(contents: &str) -> SshConfig {
+        parse_ssh_config(&mut contents.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn test_enumerate_hosts_skips_wildcards() {
+        let config = parse(
+            "Host prod-1 prod-2\n    HostName 10.0.0.1\n\n\
+             Host staging\n    User deploy\n    Port 2222\n\n\
+             Host *.internal !bastion\n    User ops\n\n\
+             Host *\n    IdentityFile ~/.ssh/id_ed25519\n",
+        );
+
+        let hosts = enumerate_ssh_config_hosts(&config);
+        let aliases: Vec<_> = hosts.iter().map(|h| h.alias.as_str()).collect();
+        assert_eq!(aliases, vec!["prod-1", "prod-2", "staging"]);
+
+        assert_eq!(hosts[0].hostname, "10.0.0.1");
+        assert_eq!(hosts[2].hostname, "staging");
+        assert_eq!(hosts[2].settings.user.as_deref(), Some("deploy"));
+        assert_eq!(hosts[2].settings.port, Some(2222));
+        // Settings from `Host *` apply to every alias
+        assert!(
+            hosts[0]
+                .settings
+                .identity_file
+                .as_deref()
+                .is_some_and(|f| f.ends_with("id_ed25519"))
+        );
+    }
+
+    #[test]
+    fn test_enumerate_hosts_dedupes_aliases() {
+        let config = parse("Host web\n    User a\n\nHost web\n    Port 2200\n");
+        let hosts = enumerate_ssh_config_hosts(&config);
+        assert_eq!(hosts.len(), 1);
+        assert_eq!(hosts[0].settings.user.as_deref(), Some("a"));
+        assert_eq!(hosts[0].settings.port, Some(2200));
+    }
+
+    #[test]
+    fn test_ssh_host_patterns_and_globs() {
+        assert!(is_ssh_host_pattern("*"));
+        assert!(is_ssh_host_pattern("web-?"));
+        assert!(is_ssh_host_pattern("!bastion"));
+        assert!(!is_ssh_host_pattern("prod-1"));
+
+        assert!(ssh_glob_matches("prod-*", "prod-1"));
+        assert!(ssh_glob_matches("web-?", "web-a"));
+        assert!(!ssh_glob_matches("prod-*", "staging"));
+    }
+
     #[test]
     fn test_get_ssh_config_path() {
         let path = get_ssh_config_path();
@@ -30,50 +30,7 @@ impl SshTunnel {
         // Find available local port
         let local_port = find_available_port()?;
 
-        // Build SSH command
-        let mut cmd = Command::new("ssh");
-
-        // Local port forward: local_port -> remote docker.sock
-        cmd.arg("-L")
-            .arg(format!("{local_port}:/var/run/docker.sock"));
-
-        // No command, just forward
-        cmd.arg("-N");
-
-        // Suppress prompts, fail fast on auth issues
-        cmd.arg("-o").arg("BatchMode=yes");
-
-        // Accept new host keys automatically (first connection)
-        cmd.arg("-o").arg("StrictHostKeyChecking=accept-new");
-
-        // Connection timeout
-        cmd.arg("-o").arg("ConnectTimeout=10");
-
-        // Prevent SSH from reading stdin (fixes issues with background operation)
-        cmd.arg("-o").arg("RequestTTY=no");
-
-        // Jump host support
-        if let Some(jump) = &host.jump_host {
-            cmd.arg("-J").arg(jump);
-        }
-
-        // Identity file
-        if let Some(key) = &host.identity_file {
-            cmd.arg("-i").arg(key);
-        }
-
-        // Custom port
-        if let Some(port) = host.port {
-            cmd.arg("-p").arg(port.to_string());
-        }
-
-        // Target: user@hostname
-        cmd.arg(format!("{}@{}", host.user, host.hostname));
-
-        // Configure stdio
-        cmd.stdin(Stdio::null())
-            .stdout(Stdio::null())
-            .stderr(Stdio::piped());
+        let mut cmd = build_tunnel_command(host, local_port);
 
         tracing::debug!(
             "Spawning SSH tunnel: ssh -L {}:/var/run/docker.sock {}@{}",
@@ -166,6 +123,56 @@ impl Drop for SshTunnel {
     }
 }
 
+/// Build the `ssh -L ... -N` command for a tunnel to `host`
+fn build_tunnel_command(host: &HostConfig, local_port: u16) -> Command {
+    let mut cmd = Command::new("ssh");
+
+    // Local port forward: local_port -> remote docker.sock
+    cmd.arg("-L")
+        .arg(format!("{local_port}:/var/run/docker.sock"));
+
+    // No command, just forward
+    cmd.arg("-N");
+
+    // Suppress prompts, fail fast on auth issues
+    cmd.arg("-o").arg("BatchMode=yes");
+
+    // Host key checking (accept-new unless the host config says otherwise)
+    cmd.arg("-o")
+        .arg(host.strict_host_key_checking.ssh_option());
+
+    // Connection timeout
+    cmd.arg("-o").arg("ConnectTimeout=10");
+
+    // Prevent SSH from reading stdin (fixes issues with background operation)
+    cmd.arg("-o").arg("RequestTTY=no");
+
+    // Jump host support
+    if let Some(jump) = &host.jump_host {
+        cmd.arg("-J").arg(jump);
+    }
+
+    // Identity file
+    if let Some(key) = &host.identity_file {
+        cmd.arg("-i").arg(key);
+    }
+
+    // Custom port
+    if let Some(port) = host.port {
+        cmd.arg("-p").arg(port.to_string());
+    }
+
+    // Target: user@hostname
+    cmd.arg(format!("{}@{}", host.user, host.hostname));
+
+    // Configure stdio
+    cmd.stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped());
+
+    cmd
+}
+
 /// Find an available local port for the tunnel
 fn find_available_port() -> Result<u16, HostError> {
     // Bind to port 0 to get OS-assigned port
@@ -197,7 +204,7 @@ pub async fn test_connection(host: &HostConfig) -> Result<String, HostError> {
         .arg("-o")
         .arg("ConnectTimeout=10")
         .arg("-o")
-        .arg("StrictHostKeyChecking=accept-new");
+        .arg(host.strict_host_key_checking.ssh_option());
 
     // Host-specific options (port, identity, jump, user@host)
     cmd.args(host.ssh_args());
@@ -265,4 +272,21 @@ mod tests {
         let url = format!("tcp://127.0.0.1:{}", 12345);
         assert_eq!(url, "tcp://127.0.0.1:12345");
     }
+
+    #[test]
+    fn test_tunnel_command_host_key_mode() {
+        use crate::host::StrictHostKeyChecking;
+
+        let host = HostConfig::new("h")
+            .with_user("deploy")
+            .with_strict_host_key_checking(StrictHostKeyChecking::Yes);
+        let args: Vec<String> = build_tunnel_command(&host, 40000)
+            .get_args()
+            .map(|a| a.to_string_lossy().into_owned())
+            .collect();
+
+        assert_eq!(&args[..2], ["-L", "40000:/var/run/docker.sock"]);
+        assert!(args.contains(&"StrictHostKeyChecking=yes".to_string()));
+        assert_eq!(args.last().unwrap(), "deploy@h");
+    }
 }
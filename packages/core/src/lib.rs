@@ -5,34 +5,44 @@
 
 pub mod config;
 pub mod docker;
+pub mod duration;
+pub mod events;
 pub mod host;
 pub mod platform;
 pub mod singleton;
+pub mod update_check;
 pub mod version;
 
 // Re-export version functions for Rust consumers
 pub use version::{get_version, get_version_long};
 
+// Re-export duration parsing
+pub use duration::parse_duration;
+
+// Re-export update check types
+pub use update_check::{UPDATE_CHECK_VALUES, UpdateCheckPolicy, UpdateInfo, check_cli_update};
+
 // Re-export config types and functions
 pub use config::{Config, get_hosts_path, load_config, save_config};
 
 // Re-export singleton types
-pub use singleton::{InstanceLock, SingletonError};
+pub use singleton::{InstanceLock, SingletonError, pid_file_is_stale};
 
 // Re-export docker types
 pub use docker::{CONTAINER_NAME, DockerClient, DockerError, OPENCODE_WEB_PORT};
 
 // Re-export platform types
 pub use platform::{
-    InstallResult, ServiceConfig, ServiceManager, get_service_manager,
-    is_service_registration_supported,
+    InstallResult, ServiceConfig, ServiceManager, ServiceStatus, get_service_manager,
+    get_service_manager_for_mode, is_service_registration_supported,
 };
 
 // Re-export host types
 pub use host::{
-    DistroFamily, DistroInfo, HostConfig, HostError, HostsFile, SshConfigMatch, SshTunnel,
-    detect_distro, get_docker_install_commands, get_ssh_config_path, host_exists_in_ssh_config,
-    install_docker, load_hosts, query_ssh_config, save_hosts, test_connection,
+    DistroFamily, DistroInfo, HostConfig, HostError, HostsFile, SshConfigHost, SshConfigMatch,
+    SshTunnel, StrictHostKeyChecking, detect_distro, get_docker_install_commands,
+    get_ssh_config_path, host_exists_in_ssh_config, install_docker, list_ssh_config_hosts,
+    load_hosts, query_ssh_config, save_hosts, ssh_glob_matches, test_connection,
     verify_docker_installed, write_ssh_config_entry,
 };
 
@@ -56,3 +66,87 @@ pub fn get_version_js() -> String {
 pub fn get_version_long_js() -> String {
     get_version_long()
 }
+
+/// A config lint finding for Node.js consumers
+#[cfg(feature = "napi")]
+#[napi(object)]
+pub struct LintFindingJs {
+    /// "low", "medium" or "high"
+    pub severity: String,
+    /// Stable rule identifier, e.g. "no-users"
+    pub code: String,
+    /// What is wrong
+    pub message: String,
+    /// Command or setting that addresses it
+    pub fix_hint: String,
+}
+
+/// Lint the saved config for security best practices
+#[cfg(feature = "napi")]
+#[napi]
+pub fn lint_config_js() -> napi::Result<Vec<LintFindingJs>> {
+    let config = load_config().map_err(|e| napi::Error::from_reason(e.to_string()))?;
+    Ok(config::lint_config(&config)
+        .into_iter()
+        .map(|finding| LintFindingJs {
+            severity: finding.severity.label().to_string(),
+            code: finding.code.to_string(),
+            message: finding.message,
+            fix_hint: finding.fix_hint,
+        })
+        .collect())
+}
+
+/// Options for starting the service from Node.js
+#[cfg(feature = "napi")]
+#[napi(object)]
+#[derive(Default)]
+pub struct StartServiceOptions {
+    /// Host port for the web UI (defaults to the container default)
+    pub port: Option<u32>,
+    /// Bind mounts, each `/host/path:/container/path[:ro]`
+    pub mounts: Option<Vec<String>>,
+}
+
+/// Start the opencode service for Node.js consumers
+///
+/// Resolves to the container ID. Invalid mounts reject with an error that
+/// names the offending mount string.
+#[cfg(feature = "napi")]
+#[napi]
+pub async fn start_service_js(options: Option<StartServiceOptions>) -> napi::Result<String> {
+    let options = options.unwrap_or_default();
+
+    let port = options
+        .port
+        .map(|port| {
+            u16::try_from(port)
+                .ok()
+                .filter(|port| *port != 0)
+                .ok_or_else(|| napi::Error::from_reason(format!("Invalid port: {port}")))
+        })
+        .transpose()?;
+    let mounts = options
+        .mounts
+        .map(|specs| docker::parse_mount_specs(&specs))
+        .transpose()
+        .map_err(|e| napi::Error::from_reason(e.to_string()))?;
+
+    let client = DockerClient::new().map_err(|e| napi::Error::from_reason(e.to_string()))?;
+    docker::setup_and_start(
+        &client,
+        port,
+        None,
+        None,
+        None,
+        None,
+        mounts,
+        None, // labels
+        None,
+        None,
+        docker::ResourceLimits::default(),
+        false,
+    )
+    .await
+    .map_err(|e| napi::Error::from_reason(e.to_string()))
+}
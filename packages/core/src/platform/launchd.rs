@@ -11,7 +11,7 @@ use std::process::Command;
 use anyhow::{Result, anyhow};
 use serde::Serialize;
 
-use super::{InstallResult, ServiceConfig, ServiceManager};
+use super::{InstallResult, ServiceConfig, ServiceManager, ServiceStatus, parse_launchctl_print};
 
 /// Service label used for launchd registration
 const SERVICE_LABEL: &str = "com.opencode-cloud.service";
@@ -183,6 +183,36 @@ impl ServiceManager for LaunchdManager {
         Ok(self.service_file_path().exists())
     }
 
+    fn status(&self) -> Result<ServiceStatus> {
+        let target = if self.user_mode {
+            format!("gui/{}/{}", get_user_id()?, self.label())
+        } else {
+            format!("system/{}", self.label())
+        };
+        let output = Command::new("launchctl")
+            .args(["print", &target])
+            .output()?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            // Not loaded into launchd at all
+            if stderr.contains("Could not find") || stderr.contains("not find") {
+                return Ok(ServiceStatus::Inactive);
+            }
+            return Ok(ServiceStatus::Unknown);
+        }
+
+        Ok(parse_launchctl_print(&String::from_utf8_lossy(
+            &output.stdout,
+        )))
+    }
+
+    fn render_service_file(&self, config: &ServiceConfig) -> Result<String> {
+        let mut buffer = Vec::new();
+        plist::to_writer_xml(&mut buffer, &self.generate_plist(config))?;
+        Ok(String::from_utf8(buffer)?)
+    }
+
     fn service_file_path(&self) -> PathBuf {
         self.service_dir().join(format!("{}.plist", self.label()))
     }
@@ -360,4 +390,22 @@ mod tests {
         assert!(xml.contains("<key>KeepAlive</key>"));
         assert!(xml.contains("<key>ThrottleInterval</key>"));
     }
+
+    #[test]
+    fn test_render_service_file() {
+        let manager = LaunchdManager::new("user");
+        let config = ServiceConfig {
+            executable_path: PathBuf::from("/opt/occ/bin/occ"),
+            restart_retries: 3,
+            restart_delay: 9,
+            boot_mode: "user".to_string(),
+        };
+
+        let xml = manager.render_service_file(&config).unwrap();
+
+        assert!(xml.contains("<string>/opt/occ/bin/occ</string>"));
+        assert!(xml.contains("<key>KeepAlive</key>"));
+        assert!(xml.contains("<key>SuccessfulExit</key>"));
+        assert!(xml.contains("<integer>9</integer>"));
+    }
 }
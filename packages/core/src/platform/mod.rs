@@ -57,6 +57,69 @@ pub struct InstallResult {
     pub requires_root: bool,
 }
 
+/// Whether the init system considers the service running
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceStatus {
+    /// Running (or starting/reloading)
+    Active,
+    /// Loaded but not running, or not loaded at all
+    Inactive,
+    /// Stopped after a failure (crash, bad exit code, restart limit hit)
+    Failed,
+    /// The init system gave an answer we don't recognize
+    Unknown,
+}
+
+impl ServiceStatus {
+    /// Short label for display
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Inactive => "inactive",
+            Self::Failed => "failed",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+/// Map `systemctl is-active` output to a [`ServiceStatus`]
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_systemctl_is_active(stdout: &str) -> ServiceStatus {
+    match stdout.trim() {
+        "active" | "activating" | "reloading" | "refreshing" => ServiceStatus::Active,
+        "inactive" | "deactivating" => ServiceStatus::Inactive,
+        "failed" => ServiceStatus::Failed,
+        _ => ServiceStatus::Unknown,
+    }
+}
+
+/// Map `launchctl print <service-target>` output to a [`ServiceStatus`]
+///
+/// A service that is not running counts as failed when its last exit code
+/// is non-zero (launchd prints `(never exited)` before the first exit).
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_launchctl_print(stdout: &str) -> ServiceStatus {
+    let field = |name: &str| {
+        stdout.lines().find_map(|line| {
+            let (key, value) = line.trim().split_once(" = ")?;
+            (key == name).then(|| value.trim())
+        })
+    };
+
+    match field("state") {
+        Some("running") => ServiceStatus::Active,
+        Some(_) => {
+            let last_exit = field("last exit code").unwrap_or("(never exited)");
+            let code = last_exit.split(':').next().unwrap_or_default().trim();
+            match code.parse::<i64>() {
+                Ok(0) | Err(_) => ServiceStatus::Inactive,
+                Ok(_) => ServiceStatus::Failed,
+            }
+        }
+        None => ServiceStatus::Unknown,
+    }
+}
+
 /// Trait for platform-specific service managers
 ///
 /// Implementations handle the details of registering services with
@@ -76,6 +139,12 @@ pub trait ServiceManager: Send + Sync {
     /// Check if the service is currently installed
     fn is_installed(&self) -> Result<bool>;
 
+    /// Ask the init system whether the service is active or has failed
+    fn status(&self) -> Result<ServiceStatus>;
+
+    /// Render the service file `install` would write, without installing it
+    fn render_service_file(&self, config: &ServiceConfig) -> Result<String>;
+
     /// Get the path to the service file
     fn service_file_path(&self) -> PathBuf;
 
@@ -108,6 +177,26 @@ pub fn get_service_manager() -> Result<Box<dyn ServiceManager>> {
     }
 }
 
+/// Get the service manager for `boot_mode` without probing the init system
+///
+/// Used to render service files (e.g. `occ service export`) on machines
+/// where the init system is not running, such as containers or CI.
+pub fn get_service_manager_for_mode(boot_mode: &str) -> Result<Box<dyn ServiceManager>> {
+    #[cfg(target_os = "linux")]
+    {
+        Ok(Box::new(systemd::SystemdManager::new(boot_mode)))
+    }
+    #[cfg(target_os = "macos")]
+    {
+        Ok(Box::new(launchd::LaunchdManager::new(boot_mode)))
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+    {
+        let _ = boot_mode;
+        Err(anyhow!("Unsupported platform for service registration"))
+    }
+}
+
 /// Check if service registration is supported on the current platform
 ///
 /// Returns true for Linux (systemd) and macOS (launchd).
@@ -188,4 +277,55 @@ mod tests {
             assert!(result.is_err());
         }
     }
+
+    #[test]
+    fn test_parse_systemctl_is_active() {
+        assert_eq!(parse_systemctl_is_active("active\n"), ServiceStatus::Active);
+        assert_eq!(
+            parse_systemctl_is_active("activating\n"),
+            ServiceStatus::Active
+        );
+        assert_eq!(
+            parse_systemctl_is_active("inactive\n"),
+            ServiceStatus::Inactive
+        );
+        assert_eq!(parse_systemctl_is_active("failed\n"), ServiceStatus::Failed);
+        assert_eq!(parse_systemctl_is_active(""), ServiceStatus::Unknown);
+        assert_eq!(
+            parse_systemctl_is_active("maintenance"),
+            ServiceStatus::Unknown
+        );
+    }
+
+    const LAUNCHCTL_RUNNING: &str = "gui/501/com.opencode-cloud.service = {
+\tactive count = 1
+\tpath = /Users/me/Library/LaunchAgents/com.opencode-cloud.service.plist
+\tstate = running
+
+\tprogram = /usr/local/bin/occ
+\tpid = 4242
+\tlast exit code = (never exited)
+}
+";
+
+    #[test]
+    fn test_parse_launchctl_print() {
+        assert_eq!(
+            parse_launchctl_print(LAUNCHCTL_RUNNING),
+            ServiceStatus::Active
+        );
+
+        let stopped = LAUNCHCTL_RUNNING
+            .replace("state = running", "state = not running")
+            .replace("\tpid = 4242\n", "");
+        assert_eq!(parse_launchctl_print(&stopped), ServiceStatus::Inactive);
+
+        let failed = stopped.replace("(never exited)", "78: EX_CONFIG");
+        assert_eq!(parse_launchctl_print(&failed), ServiceStatus::Failed);
+
+        let clean_exit = stopped.replace("(never exited)", "0");
+        assert_eq!(parse_launchctl_print(&clean_exit), ServiceStatus::Inactive);
+
+        assert_eq!(parse_launchctl_print("garbage"), ServiceStatus::Unknown);
+    }
 }
@@ -9,7 +9,9 @@ use std::process::{Command, Output};
 
 use anyhow::{Result, anyhow};
 
-use super::{InstallResult, ServiceConfig, ServiceManager};
+use super::{
+    InstallResult, ServiceConfig, ServiceManager, ServiceStatus, parse_systemctl_is_active,
+};
 
 /// Service name used for systemd unit
 const SERVICE_NAME: &str = "opencode-cloud";
@@ -64,9 +66,8 @@ impl SystemdManager {
             format!("{executable_path} stop")
         };
 
-        // Calculate StartLimitIntervalSec: restart_delay * restart_retries * 2
-        // This gives enough window for the allowed burst of restarts
-        let start_limit_interval = config.restart_delay * config.restart_retries * 2;
+        let start_limit_interval =
+            start_limit_interval_secs(config.restart_delay, config.restart_retries);
 
         format!(
             r#"[Unit]
@@ -79,6 +80,7 @@ Requires=docker.service
 Type=simple
 ExecStart={exec_start}
 ExecStop={exec_stop}
+ExecReload=/bin/kill -HUP $MAINPID
 Restart=on-failure
 RestartSec={restart_delay}s
 StartLimitBurst={restart_retries}
@@ -213,6 +215,18 @@ impl ServiceManager for SystemdManager {
         Ok(self.service_file_path().exists())
     }
 
+    fn status(&self) -> Result<ServiceStatus> {
+        // is-active exits non-zero for anything but "active"; the state is on stdout
+        let output = self.systemctl(&["is-active", SERVICE_NAME])?;
+        Ok(parse_systemctl_is_active(&String::from_utf8_lossy(
+            &output.stdout,
+        )))
+    }
+
+    fn render_service_file(&self, config: &ServiceConfig) -> Result<String> {
+        Ok(self.generate_unit_file(config))
+    }
+
     fn service_file_path(&self) -> PathBuf {
         self.service_dir().join(format!("{SERVICE_NAME}.service"))
     }
@@ -222,6 +236,16 @@ impl ServiceManager for SystemdManager {
     }
 }
 
+/// StartLimitIntervalSec for the unit: restart_delay * restart_retries * 2
+///
+/// This gives enough window for the allowed burst of restarts. Computed in
+/// u64 with saturation so hand-edited configs cannot overflow it.
+fn start_limit_interval_secs(restart_delay: u32, restart_retries: u32) -> u64 {
+    u64::from(restart_delay)
+        .saturating_mul(u64::from(restart_retries))
+        .saturating_mul(2)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -294,6 +318,7 @@ mod tests {
         assert!(unit.contains("Description=opencode-cloud container service"));
         assert!(unit.contains("ExecStart=/usr/local/bin/occ start --no-daemon"));
         assert!(unit.contains("ExecStop=/usr/local/bin/occ stop"));
+        assert!(unit.contains("ExecReload=/bin/kill -HUP $MAINPID"));
         assert!(unit.contains("Restart=on-failure"));
         assert!(unit.contains("RestartSec=5s"));
         assert!(unit.contains("StartLimitBurst=3"));
@@ -344,4 +369,32 @@ mod tests {
         assert!(result.is_ok());
         // Can't assert false because the service might actually be installed on some systems
     }
+
+    #[test]
+    fn test_render_service_file_matches_unit() {
+        let manager = SystemdManager::new("system");
+        let config = ServiceConfig {
+            executable_path: PathBuf::from("/usr/bin/occ"),
+            restart_retries: 2,
+            restart_delay: 7,
+            boot_mode: "system".to_string(),
+        };
+
+        let rendered = manager.render_service_file(&config).unwrap();
+
+        assert_eq!(rendered, manager.generate_unit_file(&config));
+        assert!(rendered.contains("ExecStart=/usr/bin/occ start --no-daemon"));
+        assert!(rendered.contains("RestartSec=7s"));
+    }
+
+    #[test]
+    fn test_start_limit_interval_does_not_overflow() {
+        assert_eq!(start_limit_interval_secs(5, 3), 30);
+        assert_eq!(
+            start_limit_interval_secs(300, u32::MAX),
+            600 * u64::from(u32::MAX)
+        );
+        assert_eq!(start_limit_interval_secs(u32::MAX, u32::MAX), u64::MAX);
+        assert_eq!(start_limit_interval_secs(0, 10), 0);
+    }
 }
@@ -6,7 +6,7 @@
 
 use std::fs::{self, File};
 use std::io::{Read, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use thiserror::Error;
 
@@ -112,6 +112,20 @@ impl Drop for InstanceLock {
     }
 }
 
+/// Whether `pid_path` is a PID file left behind by a process that is gone
+///
+/// A file that cannot be parsed as a PID also counts as stale; a missing
+/// file does not.
+pub fn pid_file_is_stale(pid_path: &Path) -> bool {
+    match fs::read_to_string(pid_path) {
+        Ok(contents) => match contents.trim().parse::<u32>() {
+            Ok(pid) => !is_process_running(pid),
+            Err(_) => true,
+        },
+        Err(_) => false,
+    }
+}
+
 /// Check if a process with the given PID is currently running
 ///
 /// Uses platform-specific methods to check process existence:
@@ -219,6 +233,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_pid_file_is_stale() {
+        let temp_dir = TempDir::new().unwrap();
+        let pid_path = temp_dir.path().join("test.pid");
+        assert!(!pid_file_is_stale(&pid_path));
+
+        std::fs::write(&pid_path, std::process::id().to_string()).unwrap();
+        assert!(!pid_file_is_stale(&pid_path));
+
+        std::fs::write(&pid_path, "4000000000").unwrap();
+        assert!(pid_file_is_stale(&pid_path));
+
+        std::fs::write(&pid_path, "garbage").unwrap();
+        assert!(pid_file_is_stale(&pid_path));
+    }
+
     #[test]
     fn test_is_process_running_with_current_process() {
         let current_pid = std::process::id();
@@ -0,0 +1,375 @@
+//! CLI update checks
+//!
+//! Queries the GitHub releases API for the latest opencode-cloud release and
+//! reports when it is newer than the running CLI. Nothing is downloaded; the
+//! caller decides how to notify the user.
+//!
+//! Results are cached in `update-check.json` in the data directory so the
+//! API is queried at most once per interval allowed by `update_check`.
+
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::config::paths::get_update_check_cache_path;
+use crate::version::get_version;
+
+/// GitHub API endpoint for the latest release
+const LATEST_RELEASE_URL: &str =
+    "https://api.github.com/repos/pRizz/opencode-cloud/releases/latest";
+
+/// Releases page shown to the user when an update is available
+const RELEASES_PAGE_URL: &str = "https://github.com/pRizz/opencode-cloud/releases";
+
+/// Minimum interval between API queries with `update_check = always`
+const ALWAYS_MIN_INTERVAL_SECS: i64 = 60 * 60;
+
+/// Interval between API queries with `update_check = daily`
+const DAILY_INTERVAL_SECS: i64 = 24 * 60 * 60;
+
+/// Interval between API queries with `update_check = weekly`
+const WEEKLY_INTERVAL_SECS: i64 = 7 * DAILY_INTERVAL_SECS;
+
+/// Accepted `update_check` values
+pub const UPDATE_CHECK_VALUES: &[&str] = &["always", "daily", "weekly", "once", "never"];
+
+/// Timeout for the releases API request
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// How often the CLI may query for updates (from config `update_check`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateCheckPolicy {
+    /// Query at most once per hour
+    Always,
+    /// Query at most once per day
+    Daily,
+    /// Query at most once per week
+    Weekly,
+    /// Query once per installed CLI version
+    Once,
+    /// Never query
+    Never,
+}
+
+impl UpdateCheckPolicy {
+    /// Parse one of [`UPDATE_CHECK_VALUES`]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "always" => Some(Self::Always),
+            "daily" => Some(Self::Daily),
+            "weekly" => Some(Self::Weekly),
+            "once" => Some(Self::Once),
+            "never" => Some(Self::Never),
+            _ => None,
+        }
+    }
+
+    /// Parse the config value, treating unknown values as `always`
+    pub fn from_config(value: &str) -> Self {
+        Self::parse(value).unwrap_or(Self::Always)
+    }
+
+    /// Minimum time between queries, or `None` when the policy is not time-based
+    pub fn interval(self) -> Option<Duration> {
+        let secs = match self {
+            Self::Always => ALWAYS_MIN_INTERVAL_SECS,
+            Self::Daily => DAILY_INTERVAL_SECS,
+            Self::Weekly => WEEKLY_INTERVAL_SECS,
+            Self::Once | Self::Never => return None,
+        };
+        Some(Duration::from_secs(secs as u64))
+    }
+}
+
+/// A newer CLI release than the one running
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpdateInfo {
+    /// Version of the running CLI
+    pub current_version: String,
+    /// Latest released version
+    pub latest_version: String,
+    /// Where to download the release
+    pub release_url: String,
+}
+
+/// Cached result of the last releases query
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+struct UpdateCache {
+    /// Unix timestamp of the last successful query
+    checked_at: i64,
+    /// CLI version that performed the query
+    cli_version: String,
+    /// Latest released version at that time
+    latest_version: String,
+}
+
+#[derive(Deserialize)]
+struct LatestRelease {
+    tag_name: String,
+    html_url: Option<String>,
+}
+
+/// Check whether a newer CLI release is available
+///
+/// Respects the `update_check` policy and the on-disk cache. Returns
+/// `Ok(None)` when up to date, when checks are disabled, or when the cached
+/// result shows no update.
+pub async fn check_cli_update(update_check: &str) -> Result<Option<UpdateInfo>> {
+    let policy = UpdateCheckPolicy::from_config(update_check);
+    if policy == UpdateCheckPolicy::Never {
+        return Ok(None);
+    }
+
+    let current = get_version();
+    let cache_path = get_update_check_cache_path();
+    let cache = cache_path.as_deref().and_then(read_cache);
+    let now = chrono::Utc::now().timestamp();
+
+    let (latest, release_url) = if should_query(policy, cache.as_ref(), now, &current) {
+        let release = fetch_latest_release().await?;
+        let latest = release.tag_name.trim_start_matches('v').to_string();
+        if let Some(path) = cache_path.as_deref() {
+            let cache = UpdateCache {
+                checked_at: now,
+                cli_version: current.clone(),
+                latest_version: latest.clone(),
+            };
+            if let Err(e) = write_cache(path, &cache) {
+                tracing::debug!("Failed to write update check cache: {e}");
+            }
+        }
+        let url = release
+            .html_url
+            .unwrap_or_else(|| RELEASES_PAGE_URL.to_string());
+        (latest, url)
+    } else {
+        match cache {
+            Some(cache) => (cache.latest_version, RELEASES_PAGE_URL.to_string()),
+            None => return Ok(None),
+        }
+    };
+
+    Ok(is_newer_version(&current, &latest).then_some(UpdateInfo {
+        current_version: current,
+        latest_version: latest,
+        release_url,
+    }))
+}
+
+/// Decide whether the cached result is stale for the given policy
+fn should_query(
+    policy: UpdateCheckPolicy,
+    cache: Option<&UpdateCache>,
+    now: i64,
+    current_version: &str,
+) -> bool {
+    let Some(cache) = cache else {
+        return policy != UpdateCheckPolicy::Never;
+    };
+    let age = now.saturating_sub(cache.checked_at);
+
+    match (policy, policy.interval()) {
+        (UpdateCheckPolicy::Never, _) => false,
+        (UpdateCheckPolicy::Once, _) => cache.cli_version != current_version,
+        (_, Some(interval)) => !(0..interval.as_secs() as i64).contains(&age),
+        (_, None) => true,
+    }
+}
+
+/// Compare dotted numeric versions, ignoring a leading `v` and pre-release suffixes
+fn is_newer_version(current: &str, latest: &str) -> bool {
+    fn parts(version: &str) -> Vec<u64> {
+        version
+            .trim_start_matches('v')
+            .split(['-', '+'])
+            .next()
+            .unwrap_or("")
+            .split('.')
+            .map(|part| part.parse().unwrap_or(0))
+            .collect()
+    }
+
+    let (mut current, mut latest) = (parts(current), parts(latest));
+    let len = current.len().max(latest.len());
+    current.resize(len, 0);
+    latest.resize(len, 0);
+    latest > current
+}
+
+async fn fetch_latest_release() -> Result<LatestRelease> {
+    let client = reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .user_agent(format!("opencode-cloud/{}", get_version()))
+        .build()?;
+
+    let release = client
+        .get(LATEST_RELEASE_URL)
+        .header("Accept", "application/vnd.github+json")
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<LatestRelease>()
+        .await?;
+    Ok(release)
+}
+
+fn read_cache(path: &Path) -> Option<UpdateCache> {
+    let contents = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+fn write_cache(path: &Path, cache: &UpdateCache) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, serde_json::to_string(cache)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cache(checked_at: i64, cli_version: &str) -> UpdateCache {
+        UpdateCache {
+            checked_at,
+            cli_version: cli_version.to_string(),
+            latest_version: "9.9.9".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn never_short_circuits() {
+        assert_eq!(check_cli_update("never").await.unwrap(), None);
+    }
+
+    #[test]
+    fn never_policy_skips_query_without_cache() {
+        assert!(!should_query(UpdateCheckPolicy::Never, None, 0, "1.0.0"));
+    }
+
+    #[test]
+    fn missing_cache_is_stale() {
+        assert!(should_query(UpdateCheckPolicy::Daily, None, 0, "1.0.0"));
+        assert!(should_query(UpdateCheckPolicy::Once, None, 0, "1.0.0"));
+    }
+
+    #[test]
+    fn daily_cache_staleness() {
+        let now = 1_000_000;
+        let fresh = cache(now - DAILY_INTERVAL_SECS + 1, "1.0.0");
+        let stale = cache(now - DAILY_INTERVAL_SECS, "1.0.0");
+        assert!(!should_query(
+            UpdateCheckPolicy::Daily,
+            Some(&fresh),
+            now,
+            "1.0.0"
+        ));
+        assert!(should_query(
+            UpdateCheckPolicy::Daily,
+            Some(&stale),
+            now,
+            "1.0.0"
+        ));
+    }
+
+    #[test]
+    fn weekly_cache_staleness() {
+        let now = 10_000_000;
+        let fresh = cache(now - 6 * DAILY_INTERVAL_SECS, "1.0.0");
+        let stale = cache(now - WEEKLY_INTERVAL_SECS, "1.0.0");
+        let weekly = UpdateCheckPolicy::Weekly;
+        assert!(!should_query(weekly, Some(&fresh), now, "1.0.0"));
+        assert!(should_query(weekly, Some(&stale), now, "1.0.0"));
+    }
+
+    #[test]
+    fn cadence_intervals() {
+        let hours = |h: u64| Some(Duration::from_secs(h * 60 * 60));
+        assert_eq!(UpdateCheckPolicy::Always.interval(), hours(1));
+        assert_eq!(UpdateCheckPolicy::Daily.interval(), hours(24));
+        assert_eq!(UpdateCheckPolicy::Weekly.interval(), hours(24 * 7));
+        assert_eq!(UpdateCheckPolicy::Once.interval(), None);
+        assert_eq!(UpdateCheckPolicy::Never.interval(), None);
+    }
+
+    #[test]
+    fn parse_accepts_only_known_values() {
+        for value in UPDATE_CHECK_VALUES {
+            assert!(UpdateCheckPolicy::parse(value).is_some(), "{value}");
+        }
+        assert_eq!(UpdateCheckPolicy::parse("monthly"), None);
+        assert_eq!(UpdateCheckPolicy::parse("Daily"), None);
+        assert_eq!(
+            UpdateCheckPolicy::from_config("monthly"),
+            UpdateCheckPolicy::Always
+        );
+    }
+
+    #[test]
+    fn always_is_rate_limited() {
+        let now = 1_000_000;
+        let recent = cache(now - 60, "1.0.0");
+        let old = cache(now - ALWAYS_MIN_INTERVAL_SECS, "1.0.0");
+        assert!(!should_query(
+            UpdateCheckPolicy::Always,
+            Some(&recent),
+            now,
+            "1.0.0"
+        ));
+        assert!(should_query(
+            UpdateCheckPolicy::Always,
+            Some(&old),
+            now,
+            "1.0.0"
+        ));
+    }
+
+    #[test]
+    fn once_requeries_after_cli_upgrade() {
+        let entry = cache(0, "1.0.0");
+        assert!(!should_query(
+            UpdateCheckPolicy::Once,
+            Some(&entry),
+            i64::MAX,
+            "1.0.0"
+        ));
+        assert!(should_query(
+            UpdateCheckPolicy::Once,
+            Some(&entry),
+            0,
+            "1.1.0"
+        ));
+    }
+
+    #[test]
+    fn future_timestamp_is_stale() {
+        let entry = cache(2_000, "1.0.0");
+        assert!(should_query(
+            UpdateCheckPolicy::Daily,
+            Some(&entry),
+            1_000,
+            "1.0.0"
+        ));
+    }
+
+    #[test]
+    fn version_comparison() {
+        assert!(is_newer_version("1.0.8", "1.0.9"));
+        assert!(is_newer_version("1.0.8", "v1.1"));
+        assert!(!is_newer_version("1.0.8", "1.0.8"));
+        assert!(!is_newer_version("2.0.0", "1.9.9"));
+        assert!(!is_newer_version("1.0.8", "1.0.8-rc.1"));
+    }
+
+    #[test]
+    fn cache_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("update-check.json");
+        let entry = cache(42, "1.0.0");
+        write_cache(&path, &entry).unwrap();
+        assert_eq!(read_cache(&path), Some(entry));
+    }
+}